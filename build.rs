@@ -0,0 +1,51 @@
+//! Embeds the build information served at `/version` (`server::health`).
+//!
+//! `CAMARA_GIT_COMMIT` and `SOURCE_DATE_EPOCH`, when set, replace what this
+//! script finds out, for builds outside a git checkout and reproducible builds.
+
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=CAMARA_GIT_COMMIT");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    // A new commit or checkout changes one of these; watching a file that does
+    // not exist would rerun the script on every build.
+    for path in git_files() {
+        if Path::new(&path).exists() {
+            println!("cargo:rerun-if-changed={path}");
+        }
+    }
+
+    let commit = std::env::var("CAMARA_GIT_COMMIT")
+        .ok()
+        .filter(|commit| !commit.is_empty())
+        .or_else(|| git(&["rev-parse", "--short=12", "HEAD"]))
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let built_at = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|seconds| seconds.parse::<u64>().ok())
+        .unwrap_or_else(|| SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |now| now.as_secs()));
+
+    println!("cargo:rustc-env=CAMARA_GIT_COMMIT={commit}");
+    println!("cargo:rustc-env=CAMARA_BUILD_TIMESTAMP={built_at}");
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    let out = String::from_utf8(output.stdout).ok()?;
+    (output.status.success() && !out.trim().is_empty()).then(|| out.trim().to_string())
+}
+
+/// `.git/HEAD`, the branch it points to and the packed refs.
+fn git_files() -> Vec<String> {
+    let mut files = vec![".git/HEAD".to_string(), ".git/packed-refs".to_string()];
+    if let Ok(head) = std::fs::read_to_string(".git/HEAD") {
+        if let Some(branch) = head.trim().strip_prefix("ref: ") {
+            files.push(format!(".git/{branch}"));
+        }
+    }
+    files
+}
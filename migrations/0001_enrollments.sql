@@ -0,0 +1,26 @@
+-- Matrículas enviadas pelo formulário de /matricula.
+-- `IF NOT EXISTS` adopts databases created before migrations were versioned.
+CREATE TABLE IF NOT EXISTS enrollments (
+    id                      INTEGER PRIMARY KEY,
+    protocol                TEXT NOT NULL UNIQUE,
+    status                  TEXT NOT NULL DEFAULT 'pending',
+    student_name            TEXT NOT NULL,
+    birth_date              TEXT NOT NULL,
+    phone                   TEXT NOT NULL,
+    email                   TEXT NOT NULL DEFAULT '',
+    address                 TEXT NOT NULL DEFAULT '',
+    school                  TEXT NOT NULL DEFAULT '',
+    guardian_name           TEXT NOT NULL DEFAULT '',
+    guardian_relationship   TEXT NOT NULL DEFAULT '',
+    guardian_phone          TEXT NOT NULL DEFAULT '',
+    guardian_email          TEXT NOT NULL DEFAULT '',
+    session_id              TEXT NOT NULL,
+    health_notes            TEXT NOT NULL DEFAULT '',
+    consent_data            INTEGER NOT NULL,
+    consent_image           INTEGER NOT NULL,
+    consent_health          INTEGER NOT NULL,
+    consent_guardian        INTEGER NOT NULL,
+    created_at              TEXT NOT NULL,
+    updated_at              TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS enrollments_status ON enrollments (status);
@@ -0,0 +1,12 @@
+-- Chamada das oficinas: uma linha por aluno(a), turma e data.
+CREATE TABLE attendance (
+    id              INTEGER PRIMARY KEY,
+    session_id      TEXT NOT NULL,
+    date            TEXT NOT NULL,
+    enrollment_id   INTEGER NOT NULL REFERENCES enrollments (id) ON DELETE CASCADE,
+    present         INTEGER NOT NULL,
+    recorded_at     TEXT NOT NULL,
+    UNIQUE (session_id, date, enrollment_id)
+);
+CREATE INDEX attendance_date ON attendance (date);
+CREATE INDEX attendance_enrollment ON attendance (enrollment_id);
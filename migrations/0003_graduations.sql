@@ -0,0 +1,13 @@
+-- Histórico de graduações (troca de corda), registrado nos batizados.
+CREATE TABLE graduations (
+    id              INTEGER PRIMARY KEY,
+    enrollment_id   INTEGER NOT NULL REFERENCES enrollments (id) ON DELETE CASCADE,
+    corda           TEXT NOT NULL,
+    date            TEXT NOT NULL,
+    event           TEXT NOT NULL,
+    event_slug      TEXT,
+    awarded_by      TEXT NOT NULL,
+    recorded_at     TEXT NOT NULL,
+    UNIQUE (enrollment_id, corda)
+);
+CREATE INDEX graduations_enrollment ON graduations (enrollment_id, date);
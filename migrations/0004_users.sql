@@ -0,0 +1,11 @@
+-- Contas de acesso ao painel. As senhas são guardadas apenas como hash argon2.
+CREATE TABLE users (
+    id              INTEGER PRIMARY KEY,
+    email           TEXT NOT NULL UNIQUE COLLATE NOCASE,
+    name            TEXT NOT NULL,
+    password_hash   TEXT NOT NULL,
+    role            TEXT NOT NULL,
+    active          INTEGER NOT NULL DEFAULT 1,
+    created_at      TEXT NOT NULL,
+    last_login_at   TEXT
+);
@@ -0,0 +1,17 @@
+-- Rascunhos do painel de conteúdo. O conteúdo publicado continua nos
+-- arquivos TOML de content/; aqui ficam só as edições ainda não publicadas.
+CREATE TABLE content_drafts (
+    id              INTEGER PRIMARY KEY,
+    collection      TEXT NOT NULL,
+    -- Posição da entrada editada no arquivo; NULL para uma entrada nova.
+    entry_index     INTEGER,
+    title           TEXT NOT NULL,
+    -- Campos do formulário, serializados em TOML.
+    fields          TEXT NOT NULL,
+    -- Campos da entrada publicada quando o rascunho foi criado, para detectar edições concorrentes.
+    base            TEXT,
+    author_id       INTEGER NOT NULL REFERENCES users(id),
+    updated_at      TEXT NOT NULL
+);
+
+CREATE INDEX content_drafts_collection ON content_drafts (collection, entry_index);
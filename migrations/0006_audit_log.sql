@@ -0,0 +1,31 @@
+-- Registro de auditoria: quem fez o quê, quando e de onde. Só recebe
+-- inserções; os gatilhos abaixo recusam qualquer alteração ou exclusão.
+CREATE TABLE audit_log (
+    id              INTEGER PRIMARY KEY,
+    -- Identificador da requisição HTTP (UUID), o mesmo do cabeçalho X-Request-Id.
+    request_id      TEXT NOT NULL,
+    occurred_at     TEXT NOT NULL,
+    -- NULL para ações públicas, como uma matrícula enviada pelo site.
+    user_id         INTEGER REFERENCES users(id),
+    -- Nome da conta no momento da ação.
+    actor           TEXT,
+    ip              TEXT,
+    action          TEXT NOT NULL,
+    target          TEXT NOT NULL,
+    -- Só os campos alterados, serializados em TOML.
+    before          TEXT,
+    after           TEXT
+);
+
+CREATE INDEX audit_log_occurred_at ON audit_log (occurred_at);
+CREATE INDEX audit_log_user ON audit_log (user_id);
+
+CREATE TRIGGER audit_log_no_update BEFORE UPDATE ON audit_log
+BEGIN
+    SELECT RAISE(ABORT, 'audit_log is append-only');
+END;
+
+CREATE TRIGGER audit_log_no_delete BEFORE DELETE ON audit_log
+BEGIN
+    SELECT RAISE(ABORT, 'audit_log is append-only');
+END;
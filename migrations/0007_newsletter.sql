@@ -0,0 +1,44 @@
+-- Inscrições no boletim, com confirmação por e-mail (double opt-in).
+CREATE TABLE newsletter_subscribers (
+    id                  INTEGER PRIMARY KEY,
+    email               TEXT NOT NULL UNIQUE COLLATE NOCASE,
+    -- pending, active ou unsubscribed.
+    status              TEXT NOT NULL,
+    -- Enviado no e-mail de confirmação; NULL depois de usado.
+    confirm_token       TEXT UNIQUE,
+    -- Vai em todo boletim, no link e no cabeçalho List-Unsubscribe.
+    unsubscribe_token   TEXT NOT NULL UNIQUE,
+    created_at          TEXT NOT NULL,
+    confirm_sent_at     TEXT NOT NULL,
+    confirmed_at        TEXT,
+    unsubscribed_at     TEXT
+);
+
+-- Boletins enviados pelo painel.
+CREATE TABLE newsletter_digests (
+    id              INTEGER PRIMARY KEY,
+    subject         TEXT NOT NULL,
+    sent_by         INTEGER NOT NULL REFERENCES users(id),
+    sent_at         TEXT NOT NULL,
+    recipients      INTEGER NOT NULL
+);
+
+-- Fila de e-mails. Um processo em segundo plano envia as mensagens pendentes
+-- e tenta de novo, com intervalos crescentes, as que falharam.
+CREATE TABLE mail_outbox (
+    id                  INTEGER PRIMARY KEY,
+    recipient           TEXT NOT NULL,
+    subject             TEXT NOT NULL,
+    text_body           TEXT NOT NULL,
+    html_body           TEXT,
+    unsubscribe_url     TEXT,
+    created_at          TEXT NOT NULL,
+    attempts            INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at     TEXT NOT NULL,
+    last_error          TEXT,
+    sent_at             TEXT,
+    -- Preenchido quando as tentativas se esgotam.
+    failed_at           TEXT
+);
+
+CREATE INDEX mail_outbox_due ON mail_outbox (next_attempt_at) WHERE sent_at IS NULL AND failed_at IS NULL;
@@ -0,0 +1,3 @@
+-- Tentativas de acesso recusadas recentemente, por e-mail e por IP, contadas
+-- a cada entrada no painel para bloquear quem tenta adivinhar senhas.
+CREATE INDEX audit_log_action ON audit_log (action, occurred_at);
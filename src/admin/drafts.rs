@@ -0,0 +1,142 @@
+//! Unpublished edits, kept in the database until someone publishes or discards them.
+
+use chrono::NaiveDateTime;
+use rusqlite::{params, Connection, OptionalExtension, Row};
+
+use super::{Collection, Values};
+use crate::db::DbError;
+
+pub struct Draft {
+    pub id: i64,
+    pub collection: Collection,
+    pub entry_index: Option<usize>,
+    pub values: Values,
+    pub base: Option<Values>,
+    pub author: String,
+    pub updated_at: NaiveDateTime,
+}
+
+fn decode(column: usize, raw: &str) -> rusqlite::Result<Values> {
+    toml::from_str(raw).map_err(|e| rusqlite::Error::FromSqlConversionFailure(column, rusqlite::types::Type::Text, Box::new(e)))
+}
+
+fn encode(values: &Values) -> String {
+    // A map of strings always serializes.
+    toml::to_string(values).unwrap_or_default()
+}
+
+const SELECT: &str = "SELECT d.id, d.collection, d.entry_index, d.fields, d.base, u.name AS author, d.updated_at
+    FROM content_drafts d JOIN users u ON u.id = d.author_id";
+
+fn draft_from_row(row: &Row) -> rusqlite::Result<Draft> {
+    let collection: String = row.get("collection")?;
+    let base: Option<String> = row.get("base")?;
+    Ok(Draft {
+        id: row.get("id")?,
+        collection: Collection::parse(&collection).ok_or_else(|| {
+            rusqlite::Error::FromSqlConversionFailure(1, rusqlite::types::Type::Text, format!("unknown collection {collection:?}").into())
+        })?,
+        entry_index: row.get::<_, Option<i64>>("entry_index")?.map(|i| i as usize),
+        values: decode(3, &row.get::<_, String>("fields")?)?,
+        base: base.map(|raw| decode(4, &raw)).transpose()?,
+        author: row.get("author")?,
+        updated_at: row.get("updated_at")?,
+    })
+}
+
+pub fn find(conn: &Connection, id: i64) -> Result<Option<Draft>, DbError> {
+    Ok(conn.query_row(&format!("{SELECT} WHERE d.id = ?1"), params![id], draft_from_row).optional()?)
+}
+
+/// The draft editing the published entry at `index`, if any.
+pub fn for_entry(conn: &Connection, collection: Collection, index: usize) -> Result<Option<Draft>, DbError> {
+    Ok(conn
+        .query_row(
+            &format!("{SELECT} WHERE d.collection = ?1 AND d.entry_index = ?2 ORDER BY d.id LIMIT 1"),
+            params![collection.slug(), index as i64],
+            draft_from_row,
+        )
+        .optional()?)
+}
+
+/// Every draft of `collection`, most recently edited first.
+pub fn list(conn: &Connection, collection: Collection) -> Result<Vec<Draft>, DbError> {
+    let mut stmt = conn.prepare(&format!("{SELECT} WHERE d.collection = ?1 ORDER BY d.updated_at DESC"))?;
+    let drafts = stmt.query_map(params![collection.slug()], draft_from_row)?.collect::<Result<_, _>>()?;
+    Ok(drafts)
+}
+
+pub fn count(conn: &Connection, collection: Collection) -> Result<usize, DbError> {
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM content_drafts WHERE collection = ?1",
+        params![collection.slug()],
+        |row| row.get(0),
+    )?;
+    Ok(count as usize)
+}
+
+/// Stores `values` as draft `id`, or as a new draft (reusing the one already
+/// open for the same entry, so an entry never has two). Returns the draft id.
+#[allow(clippy::too_many_arguments)]
+pub fn save(
+    conn: &Connection,
+    id: Option<i64>,
+    collection: Collection,
+    entry_index: Option<usize>,
+    base: Option<&Values>,
+    values: &Values,
+    author_id: i64,
+    now: NaiveDateTime,
+) -> Result<i64, DbError> {
+    let existing = match (id, entry_index) {
+        (Some(id), _) => Some(id),
+        (None, Some(index)) => for_entry(conn, collection, index)?.map(|d| d.id),
+        (None, None) => None,
+    };
+    let title = collection.summarize(values).0;
+
+    match existing {
+        Some(id) => {
+            conn.execute(
+                "UPDATE content_drafts SET title = ?2, fields = ?3, author_id = ?4, updated_at = ?5 WHERE id = ?1",
+                params![id, title, encode(values), author_id, now],
+            )?;
+            Ok(id)
+        }
+        None => {
+            conn.execute(
+                "INSERT INTO content_drafts (collection, entry_index, title, fields, base, author_id, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    collection.slug(),
+                    entry_index.map(|i| i as i64),
+                    title,
+                    encode(values),
+                    base.map(encode),
+                    author_id,
+                    now
+                ],
+            )?;
+            Ok(conn.last_insert_rowid())
+        }
+    }
+}
+
+pub fn delete(conn: &Connection, id: i64) -> Result<(), DbError> {
+    conn.execute("DELETE FROM content_drafts WHERE id = ?1", params![id])?;
+    Ok(())
+}
+
+/// After the entry at `index` was removed from its file: drops its draft and
+/// moves the drafts of the entries after it one position up.
+pub fn entry_deleted(conn: &Connection, collection: Collection, index: usize) -> Result<(), DbError> {
+    conn.execute(
+        "DELETE FROM content_drafts WHERE collection = ?1 AND entry_index = ?2",
+        params![collection.slug(), index as i64],
+    )?;
+    conn.execute(
+        "UPDATE content_drafts SET entry_index = entry_index - 1 WHERE collection = ?1 AND entry_index > ?2",
+        params![collection.slug(), index as i64],
+    )?;
+    Ok(())
+}
@@ -0,0 +1,327 @@
+//! Reading and rewriting the content files on behalf of the panel.
+//!
+//! Files are rewritten whole: the leading comment block (the editing notes
+//! at the top of each file) is kept, the rest is serialized again from the
+//! parsed TOML, so comments between entries do not survive an edit.
+
+use std::sync::Mutex;
+
+use serde::de::DeserializeOwned;
+use toml::{Table, Value};
+
+use super::{Collection, FieldKind, FieldSpec, Values};
+use crate::content;
+
+/// Serializes writes from concurrent requests; each edit reads, changes and rewrites a whole file.
+static WRITE_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug)]
+pub enum EditError {
+    Read(String, std::io::Error),
+    Parse(String, toml::de::Error),
+    Write(String, std::io::Error),
+    Serialize(toml::ser::Error),
+    /// A field failed validation; the message is shown to the editor as is.
+    Invalid(String),
+    /// The entry changed on disk since the editor loaded it.
+    Conflict,
+    NotFound,
+}
+
+impl std::fmt::Display for EditError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EditError::Read(path, e) => write!(f, "could not read {path}: {e}"),
+            EditError::Parse(path, e) => write!(f, "invalid content in {path}: {e}"),
+            EditError::Write(path, e) => write!(f, "could not write {path}: {e}"),
+            EditError::Serialize(e) => write!(f, "could not serialize content: {e}"),
+            EditError::Invalid(message) => write!(f, "{message}"),
+            EditError::Conflict => write!(f, "Esta entrada foi alterada por outra pessoa. Recarregue a página e refaça a edição."),
+            EditError::NotFound => write!(f, "Entrada não encontrada."),
+        }
+    }
+}
+
+impl std::error::Error for EditError {}
+
+impl EditError {
+    /// Whether the message is meant for the editor rather than the server log.
+    pub fn is_user_facing(&self) -> bool {
+        matches!(self, EditError::Invalid(_) | EditError::Conflict | EditError::NotFound)
+    }
+}
+
+/// A content file split into its leading comment block and its parsed tables.
+struct ContentFile {
+    header: String,
+    table: Table,
+}
+
+fn path_of(collection: Collection) -> std::path::PathBuf {
+    content::dir().join(collection.file_name())
+}
+
+fn read_file(collection: Collection) -> Result<ContentFile, EditError> {
+    let path = path_of(collection);
+    let display = path.display().to_string();
+    let raw = match std::fs::read_to_string(&path) {
+        Ok(raw) => raw,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(EditError::Read(display, e)),
+    };
+
+    let header: String = raw
+        .lines()
+        .take_while(|line| line.trim().is_empty() || line.trim_start().starts_with('#'))
+        .map(|line| format!("{line}\n"))
+        .collect();
+    let table = raw.parse::<Table>().map_err(|e| EditError::Parse(display, e))?;
+    Ok(ContentFile { header: header.trim_end().to_string(), table })
+}
+
+fn write_file(collection: Collection, file: &ContentFile) -> Result<(), EditError> {
+    let path = path_of(collection);
+    let display = path.display().to_string();
+    let body = toml::to_string(&file.table).map_err(EditError::Serialize)?;
+    let contents = match file.header.as_str() {
+        "" => body,
+        header => format!("{header}\n\n{body}"),
+    };
+
+    // Write next to the target and rename, so readers never see a half-written file.
+    let write = || -> std::io::Result<()> {
+        use std::io::Write;
+        let mut temp = tempfile::NamedTempFile::new_in(content::dir())?;
+        temp.write_all(contents.as_bytes())?;
+        temp.persist(&path).map_err(|e| e.error)?;
+        Ok(())
+    };
+    write().map_err(|e| EditError::Write(display, e))
+}
+
+fn tables(file: &ContentFile, collection: Collection) -> Vec<Table> {
+    match file.table.get(collection.table()) {
+        Some(Value::Array(items)) => items.iter().filter_map(|v| v.as_table().cloned()).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn set_tables(file: &mut ContentFile, collection: Collection, entries: Vec<Table>) {
+    file.table.insert(collection.table().to_string(), Value::Array(entries.into_iter().map(Value::Table).collect()));
+}
+
+/// Form values of one stored entry; fields outside the collection's specs are left out.
+fn to_values(collection: Collection, entry: &Table) -> Values {
+    collection
+        .fields()
+        .iter()
+        .filter_map(|spec| {
+            let value = match entry.get(spec.name)? {
+                Value::String(s) => s.clone(),
+                Value::Array(items) => items
+                    .iter()
+                    .map(|item| item.as_str().map(str::to_string).unwrap_or_else(|| item.to_string()))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                other => other.to_string(),
+            };
+            let value = match spec.kind {
+                FieldKind::Choice(_) => value.to_lowercase(),
+                _ => value,
+            };
+            Some((spec.name.to_string(), value))
+        })
+        .collect()
+}
+
+/// Completes `HH:MM` (what `<input type="time">` sends) to the `HH:MM:SS` chrono expects.
+fn with_seconds(value: &str, short_len: usize) -> String {
+    if value.len() == short_len {
+        format!("{value}:00")
+    } else {
+        value.to_string()
+    }
+}
+
+fn field_value(collection: Collection, spec: &FieldSpec, raw: &str) -> Result<Value, EditError> {
+    let invalid = |what: &str| EditError::Invalid(format!("Campo “{}”: {}.", spec.label, what));
+    let value = match spec.kind {
+        FieldKind::Lines => {
+            return Ok(Value::Array(
+                raw.lines().map(str::trim).filter(|l| !l.is_empty()).map(|l| Value::String(l.to_string())).collect(),
+            ))
+        }
+        FieldKind::LongText => raw.replace("\r\n", "\n"),
+        FieldKind::DateTime => with_seconds(raw, "AAAA-MM-DDTHH:MM".len()),
+        FieldKind::Time => with_seconds(raw, "HH:MM".len()),
+        FieldKind::Url if !(raw.starts_with("https://") || raw.starts_with("http://")) => {
+            return Err(invalid("informe um endereço começando com https://"))
+        }
+        FieldKind::Choice(options) if !options.iter().any(|(value, _)| *value == raw) => {
+            return Err(invalid("escolha uma das opções"))
+        }
+        FieldKind::Text if collection.key_field() == Some(spec.name) => {
+            if !raw.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-') {
+                return Err(invalid("use só letras minúsculas sem acento, números e hífens"));
+            }
+            raw.to_string()
+        }
+        _ => raw.to_string(),
+    };
+    Ok(Value::String(value))
+}
+
+/// Applies the form `values` over `entry`, keeping any field the form does not know about.
+fn apply(collection: Collection, mut entry: Table, values: &Values) -> Result<Table, EditError> {
+    for spec in collection.fields() {
+        let raw = values.get(spec.name).map(|v| v.trim()).unwrap_or_default();
+        if raw.is_empty() {
+            if spec.required {
+                return Err(EditError::Invalid(format!("Preencha o campo “{}”.", spec.label)));
+            }
+            entry.remove(spec.name);
+            continue;
+        }
+        entry.insert(spec.name.to_string(), field_value(collection, spec, raw)?);
+    }
+    check_type(collection, &entry)?;
+    Ok(entry)
+}
+
+fn check_as<T: DeserializeOwned>(entry: &Table) -> Result<(), EditError> {
+    Value::Table(entry.clone())
+        .try_into::<T>()
+        .map(|_| ())
+        .map_err(|e| EditError::Invalid(format!("Algum campo tem um valor inválido: {}", e.message())))
+}
+
+/// Makes sure the public pages will still load the entry.
+fn check_type(collection: Collection, entry: &Table) -> Result<(), EditError> {
+    match collection {
+        Collection::News => check_as::<content::news::NewsArticle>(entry),
+        Collection::Events => check_as::<content::events::Event>(entry),
+        Collection::Sessions => check_as::<content::schedule::ClassSession>(entry),
+        Collection::Exceptions => check_as::<content::schedule::ScheduleException>(entry),
+        Collection::Press => check_as::<content::press::PressClipping>(entry),
+        Collection::Partners => check_as::<content::partners::Partner>(entry),
+        Collection::Albums => check_as::<content::albums::Album>(entry),
+        Collection::Songs => check_as::<content::cantigas::Song>(entry),
+    }
+}
+
+fn check_base(collection: Collection, entries: &[Table], index: usize, base: Option<&Values>) -> Result<(), EditError> {
+    let current = entries.get(index).ok_or(EditError::NotFound)?;
+    match base {
+        Some(base) if *base == to_values(collection, current) => Ok(()),
+        _ => Err(EditError::Conflict),
+    }
+}
+
+/// Every entry of `collection`, in file order.
+pub fn entries(collection: Collection) -> Result<Vec<Values>, EditError> {
+    let file = read_file(collection)?;
+    Ok(tables(&file, collection).iter().map(|entry| to_values(collection, entry)).collect())
+}
+
+/// Validates `values` and writes them over the entry at `index` (or appends a
+/// new one), returning the entry's position.
+///
+/// `base` must be the values the edit started from; if the stored entry no
+/// longer matches, nothing is written and [`EditError::Conflict`] is returned.
+pub fn publish(collection: Collection, index: Option<usize>, base: Option<&Values>, values: &Values) -> Result<usize, EditError> {
+    let _guard = WRITE_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut file = read_file(collection)?;
+    let mut entries = tables(&file, collection);
+
+    let original = match index {
+        Some(index) => {
+            check_base(collection, &entries, index, base)?;
+            entries[index].clone()
+        }
+        None => Table::new(),
+    };
+    let entry = apply(collection, original, values)?;
+
+    if let Some(key) = collection.key_field() {
+        let duplicate = entries
+            .iter()
+            .enumerate()
+            .any(|(i, other)| Some(i) != index && other.get(key) == entry.get(key));
+        if duplicate {
+            return Err(EditError::Invalid(format!("Já existe uma entrada com este identificador ({}).", key)));
+        }
+    }
+
+    let position = match index {
+        Some(index) => {
+            entries[index] = entry;
+            index
+        }
+        None => {
+            entries.push(entry);
+            entries.len() - 1
+        }
+    };
+    set_tables(&mut file, collection, entries);
+    write_file(collection, &file)?;
+    Ok(position)
+}
+
+/// Removes the entry at `index`, under the same concurrency check as [`publish`].
+pub fn delete(collection: Collection, index: usize, base: &Values) -> Result<Values, EditError> {
+    let _guard = WRITE_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut file = read_file(collection)?;
+    let mut entries = tables(&file, collection);
+    check_base(collection, &entries, index, Some(base))?;
+    let removed = entries.remove(index);
+    set_tables(&mut file, collection, entries);
+    write_file(collection, &file)?;
+    Ok(to_values(collection, &removed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(pairs: &[(&str, &str)]) -> Values {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn applies_form_values_as_content_entry() {
+        let form = values(&[
+            ("id", "capoeira-infantil-seg"),
+            ("weekday", "mon"),
+            ("start", "17:30"),
+            ("end", "18:30"),
+            ("modality", "capoeira"),
+            ("location", "Sede"),
+            ("age_group", "Infantil"),
+            ("instructor", "  Professor  "),
+        ]);
+        let entry = apply(Collection::Sessions, Table::new(), &form).unwrap();
+        assert_eq!(entry["start"].as_str(), Some("17:30:00"));
+        assert_eq!(entry["instructor"].as_str(), Some("Professor"));
+
+        let back = to_values(Collection::Sessions, &entry);
+        assert_eq!(back["start"], "17:30:00");
+        assert_eq!(back["weekday"], "mon");
+    }
+
+    #[test]
+    fn rejects_missing_and_malformed_fields() {
+        let mut form = values(&[("date", "2026-02-16"), ("reason", "")]);
+        assert!(matches!(apply(Collection::Exceptions, Table::new(), &form), Err(EditError::Invalid(_))));
+
+        form.insert("reason".into(), "Carnaval".into());
+        form.insert("sessions".into(), "capoeira-infantil-seg\n\n percussao-qua \n".into());
+        let entry = apply(Collection::Exceptions, Table::new(), &form).unwrap();
+        assert_eq!(entry["sessions"].as_array().map(Vec::len), Some(2));
+
+        form.insert("date".into(), "2026-02-30".into());
+        assert!(matches!(apply(Collection::Exceptions, Table::new(), &form), Err(EditError::Invalid(_))));
+
+        let news = values(&[("slug", "Roda Aberta"), ("title", "t"), ("date", "2026-01-01"), ("summary", "s")]);
+        assert!(matches!(apply(Collection::News, Table::new(), &news), Err(EditError::Invalid(_))));
+    }
+}
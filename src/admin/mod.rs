@@ -0,0 +1,373 @@
+//! Painel de conteúdo: edição, pelo navegador, dos arquivos TOML de `content/`.
+//!
+//! Each [`Collection`] is one array of tables in one content file. The editor
+//! works on flat string [`Values`] keyed by field name, described by
+//! [`FieldSpec`]s shared by the client and the server; the server turns them
+//! back into TOML and checks them against the real content type before
+//! writing. Unpublished edits are kept as drafts in the database.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::auth::Role;
+
+#[cfg(feature = "ssr")]
+pub mod drafts;
+#[cfg(feature = "ssr")]
+pub mod files;
+
+/// Reads the collections and keeps drafts: everyone who enters the panel.
+pub const EDIT_ROLE: Role = Role::Voluntario;
+/// Publishes drafts and deletes entries, i.e. changes the public site.
+pub const PUBLISH_ROLE: Role = Role::Admin;
+
+/// Form values of one entry, by field name. Lists are one item per line.
+pub type Values = BTreeMap<String, String>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Collection {
+    News,
+    Events,
+    Sessions,
+    Exceptions,
+    Press,
+    Partners,
+    Albums,
+    Songs,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    Text,
+    LongText,
+    Date,
+    DateTime,
+    Time,
+    Url,
+    /// One of a fixed set of `(value, label)` pairs.
+    Choice(&'static [(&'static str, &'static str)]),
+    /// A list of strings, edited one per line.
+    Lines,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldSpec {
+    pub name: &'static str,
+    pub label: &'static str,
+    pub kind: FieldKind,
+    pub required: bool,
+    pub hint: Option<&'static str>,
+}
+
+const fn field(name: &'static str, label: &'static str, kind: FieldKind, required: bool) -> FieldSpec {
+    FieldSpec { name, label, kind, required, hint: None }
+}
+
+const fn hinted(name: &'static str, label: &'static str, kind: FieldKind, required: bool, hint: &'static str) -> FieldSpec {
+    FieldSpec { name, label, kind, required, hint: Some(hint) }
+}
+
+const EVENT_KINDS: &[(&str, &str)] = &[
+    ("batizado", "Batizado"),
+    ("roda_aberta", "Roda Aberta"),
+    ("oficina", "Oficina"),
+    ("apresentacao", "Apresentação"),
+];
+
+const WEEKDAYS: &[(&str, &str)] = &[
+    ("mon", "Segunda-feira"),
+    ("tue", "Terça-feira"),
+    ("wed", "Quarta-feira"),
+    ("thu", "Quinta-feira"),
+    ("fri", "Sexta-feira"),
+    ("sat", "Sábado"),
+    ("sun", "Domingo"),
+];
+
+const MODALITIES: &[(&str, &str)] = &[
+    ("capoeira", "Capoeira"),
+    ("percussao", "Percussão"),
+    ("maculele", "Maculelê"),
+];
+
+const PARTNER_CATEGORIES: &[(&str, &str)] = &[
+    ("governo", "Realização e Fomento"),
+    ("apoio", "Apoio"),
+    ("parceiro", "Parceiro"),
+];
+
+const NEWS_FIELDS: &[FieldSpec] = &[
+    hinted("slug", "Identificador", FieldKind::Text, true, "Endereço da notícia: /noticias/identificador. Só letras minúsculas, números e hífens."),
+    field("title", "Título", FieldKind::Text, true),
+    field("date", "Data", FieldKind::Date, true),
+    hinted("summary", "Resumo", FieldKind::LongText, true, "Uma ou duas frases, mostradas na lista de notícias."),
+    hinted("body", "Texto", FieldKind::LongText, false, "Separe os parágrafos com uma linha em branco."),
+    hinted("cover", "Imagem de capa", FieldKind::Text, false, "Caminho em /assets/, por exemplo /assets/acervo/inicio/foto.jpg."),
+];
+
+const EVENT_FIELDS: &[FieldSpec] = &[
+    hinted("slug", "Identificador", FieldKind::Text, true, "Endereço do evento: /agenda/identificador. Não altere depois de divulgado."),
+    field("title", "Título", FieldKind::Text, true),
+    field("kind", "Tipo", FieldKind::Choice(EVENT_KINDS), true),
+    hinted("start", "Início", FieldKind::DateTime, true, "Horário de Mato Grosso do Sul."),
+    field("end", "Fim", FieldKind::DateTime, false),
+    field("location", "Local", FieldKind::Text, true),
+    field("address", "Endereço", FieldKind::Text, false),
+    hinted("description", "Descrição", FieldKind::LongText, false, "Separe os parágrafos com uma linha em branco."),
+];
+
+const SESSION_FIELDS: &[FieldSpec] = &[
+    hinted("id", "Identificador", FieldKind::Text, true, "Usado nas matrículas e listas de presença: não altere depois de publicado."),
+    field("weekday", "Dia da semana", FieldKind::Choice(WEEKDAYS), true),
+    field("start", "Início", FieldKind::Time, true),
+    field("end", "Fim", FieldKind::Time, true),
+    field("modality", "Modalidade", FieldKind::Choice(MODALITIES), true),
+    field("location", "Local", FieldKind::Text, true),
+    field("age_group", "Faixa etária", FieldKind::Text, true),
+    field("instructor", "Professor(a)", FieldKind::Text, true),
+];
+
+const EXCEPTION_FIELDS: &[FieldSpec] = &[
+    field("date", "Data", FieldKind::Date, true),
+    hinted("sessions", "Turmas afetadas", FieldKind::Lines, false, "Identificadores das turmas, um por linha. Em branco: todas as turmas do dia."),
+    field("reason", "Motivo", FieldKind::Text, true),
+];
+
+const PRESS_FIELDS: &[FieldSpec] = &[
+    field("outlet", "Veículo", FieldKind::Text, true),
+    field("title", "Título da matéria", FieldKind::Text, true),
+    hinted("date", "Data", FieldKind::Date, false, "Em branco se a data da matéria não puder ser conferida."),
+    field("snippet", "Trecho", FieldKind::LongText, true),
+    field("url", "Link", FieldKind::Url, true),
+    hinted("archive", "Cópia arquivada", FieldKind::Text, false, "PDF ou imagem em /assets/imprensa/."),
+    hinted("outlet_logo", "Logotipo do veículo", FieldKind::Text, false, "Imagem em /assets/imprensa/."),
+];
+
+const PARTNER_FIELDS: &[FieldSpec] = &[
+    field("name", "Nome", FieldKind::Text, true),
+    field("category", "Categoria", FieldKind::Choice(PARTNER_CATEGORIES), true),
+    hinted("logo", "Logotipo", FieldKind::Text, false, "Imagem em /assets/parceiros/."),
+    field("url", "Site", FieldKind::Url, false),
+    hinted("since", "Início da parceria", FieldKind::Date, false, "Só se a data constar no termo ou convênio."),
+    hinted("until", "Fim da parceria", FieldKind::Date, false, "Em branco enquanto a parceria estiver vigente."),
+];
+
+const SONG_KINDS: &[(&str, &str)] = &[
+    ("ladainha", "Ladainha"),
+    ("quadra", "Quadra"),
+    ("chula", "Chula"),
+    ("corrido", "Corrido"),
+];
+
+const ALBUM_FIELDS: &[FieldSpec] = &[
+    hinted("slug", "Identificador", FieldKind::Text, true, "Endereço do álbum: /galeria/identificador."),
+    field("title", "Título", FieldKind::Text, true),
+    field("date", "Data", FieldKind::Date, true),
+    field("description", "Descrição", FieldKind::LongText, false),
+    hinted("photos", "Fotos", FieldKind::Lines, false, "Caminhos das imagens em /assets/acervo/, um por linha. A primeira é a capa."),
+];
+
+const SONG_FIELDS: &[FieldSpec] = &[
+    hinted("slug", "Identificador", FieldKind::Text, true, "Endereço da cantiga: /cantigas/identificador."),
+    field("title", "Título", FieldKind::Text, true),
+    field("kind", "Tipo", FieldKind::Choice(SONG_KINDS), true),
+    hinted("lyrics", "Letra", FieldKind::LongText, true, "Estrofes separadas por uma linha em branco. Comece com \">\" as linhas do coro."),
+    field("author", "Autor", FieldKind::Text, false),
+    hinted("tradition", "Tradição", FieldKind::Text, false, "Origem, quando não há autor conhecido. Por exemplo: Domínio público."),
+    hinted("audio", "Gravação", FieldKind::Text, false, "Arquivo MP3 ou OGG em /assets/cantigas/."),
+    field("notes", "Observações", FieldKind::LongText, false),
+];
+
+impl Collection {
+    pub const ALL: [Collection; 8] = [
+        Collection::News,
+        Collection::Events,
+        Collection::Sessions,
+        Collection::Exceptions,
+        Collection::Press,
+        Collection::Partners,
+        Collection::Albums,
+        Collection::Songs,
+    ];
+
+    /// Path segment under `/admin/conteudo/`.
+    pub fn slug(self) -> &'static str {
+        match self {
+            Collection::News => "noticias",
+            Collection::Events => "eventos",
+            Collection::Sessions => "turmas",
+            Collection::Exceptions => "excecoes",
+            Collection::Press => "imprensa",
+            Collection::Partners => "parceiros",
+            Collection::Albums => "albuns",
+            Collection::Songs => "cantigas",
+        }
+    }
+
+    pub fn parse(slug: &str) -> Option<Self> {
+        Collection::ALL.into_iter().find(|c| c.slug() == slug)
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Collection::News => "Notícias",
+            Collection::Events => "Agenda",
+            Collection::Sessions => "Horários de treino",
+            Collection::Exceptions => "Feriados e cancelamentos",
+            Collection::Press => "Na Mídia",
+            Collection::Partners => "Parceiros",
+            Collection::Albums => "Álbuns de fotos",
+            Collection::Songs => "Cantigas",
+        }
+    }
+
+    /// Public page showing the collection.
+    pub fn public_href(self) -> &'static str {
+        match self {
+            Collection::News => "/noticias",
+            Collection::Events => "/agenda",
+            Collection::Sessions | Collection::Exceptions => "/horarios",
+            Collection::Press => "/na-midia",
+            Collection::Partners => "/",
+            Collection::Albums => "/galeria",
+            Collection::Songs => "/cantigas",
+        }
+    }
+
+    pub fn href(self) -> String {
+        format!("/admin/conteudo/{}", self.slug())
+    }
+
+    /// File under `content/` holding the collection.
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Collection::News => "news.toml",
+            Collection::Events => "events.toml",
+            Collection::Sessions | Collection::Exceptions => "schedule.toml",
+            Collection::Press => "press.toml",
+            Collection::Partners => "partners.toml",
+            Collection::Albums => "albums.toml",
+            Collection::Songs => "cantigas.toml",
+        }
+    }
+
+    /// Name of the array of tables inside [`Self::file_name`].
+    pub fn table(self) -> &'static str {
+        match self {
+            Collection::News => "articles",
+            Collection::Events => "events",
+            Collection::Sessions => "sessions",
+            Collection::Exceptions => "exceptions",
+            Collection::Press => "clippings",
+            Collection::Partners => "partners",
+            Collection::Albums => "albums",
+            Collection::Songs => "songs",
+        }
+    }
+
+    pub fn fields(self) -> &'static [FieldSpec] {
+        match self {
+            Collection::News => NEWS_FIELDS,
+            Collection::Events => EVENT_FIELDS,
+            Collection::Sessions => SESSION_FIELDS,
+            Collection::Exceptions => EXCEPTION_FIELDS,
+            Collection::Press => PRESS_FIELDS,
+            Collection::Partners => PARTNER_FIELDS,
+            Collection::Albums => ALBUM_FIELDS,
+            Collection::Songs => SONG_FIELDS,
+        }
+    }
+
+    /// Field that must be unique across the collection, when there is one.
+    pub fn key_field(self) -> Option<&'static str> {
+        match self {
+            Collection::News | Collection::Events | Collection::Albums | Collection::Songs => Some("slug"),
+            Collection::Sessions => Some("id"),
+            Collection::Exceptions | Collection::Press | Collection::Partners => None,
+        }
+    }
+
+    /// Field used as the entry's title in listings, and the fields shown under it.
+    fn summary_fields(self) -> (&'static str, &'static [&'static str]) {
+        match self {
+            Collection::News | Collection::Albums => ("title", &["date"]),
+            Collection::Events => ("title", &["start", "location"]),
+            Collection::Sessions => ("id", &["weekday", "start", "location"]),
+            Collection::Exceptions => ("reason", &["date"]),
+            Collection::Press => ("title", &["outlet", "date"]),
+            Collection::Partners => ("name", &["category", "since"]),
+            Collection::Songs => ("title", &["kind"]),
+        }
+    }
+
+    /// Title and one-line detail of an entry, for listings.
+    pub fn summarize(self, values: &Values) -> (String, String) {
+        let (title, detail) = self.summary_fields();
+        let get = |name: &str| values.get(name).map(|v| v.trim()).unwrap_or_default();
+        let title = match get(title) {
+            "" => "(sem título)".to_string(),
+            title => title.to_string(),
+        };
+        let detail = detail.iter().map(|name| get(name)).filter(|v| !v.is_empty()).collect::<Vec<_>>().join(" · ");
+        (title, detail)
+    }
+}
+
+/// Number of published entries and drafts of a collection, for the dashboard.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CollectionOverview {
+    pub collection: Collection,
+    pub entries: usize,
+    pub drafts: usize,
+}
+
+/// A published entry in the collection listing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EntryRow {
+    pub index: usize,
+    pub title: String,
+    pub detail: String,
+    /// Pending draft for this entry, if someone started editing it.
+    pub draft_id: Option<i64>,
+}
+
+/// A draft of an entry not yet published.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DraftRow {
+    pub id: i64,
+    pub title: String,
+    pub author: String,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CollectionListing {
+    pub collection: Collection,
+    pub entries: Vec<EntryRow>,
+    /// Drafts of new entries.
+    pub drafts: Vec<DraftRow>,
+}
+
+/// What the editor opens: a published entry, a draft, or a blank form.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EntryDocument {
+    pub collection: Collection,
+    /// Position in the content file; `None` for an entry not yet published.
+    pub index: Option<usize>,
+    pub draft_id: Option<i64>,
+    pub values: Values,
+    /// Published values the edit started from, sent back to detect concurrent changes.
+    pub base: Option<Values>,
+    /// The published entry changed after the draft was saved.
+    pub stale: bool,
+}
+
+/// Where an entry ended up after saving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SavedEntry {
+    Draft(i64),
+    Published(usize),
+}
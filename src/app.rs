@@ -1,16 +1,62 @@
 use leptos::prelude::*;
-use leptos_meta::{provide_meta_context, Stylesheet, Title};
+use leptos_meta::{provide_meta_context, MetaTags, Stylesheet, Title};
 use leptos_router::{
-    components::{Route, Router, Routes},
-    StaticSegment, WildcardSegment,
+    components::{ParentRoute, Route, Router, Routes},
+    ParamSegment, SsrMode, StaticSegment, WildcardSegment,
 };
+use crate::i18n::{self, LanguageAlternates};
+use crate::routes::admin::AdminDashboard;
+use crate::routes::admin::accounts::AccountsAdminPage;
+use crate::routes::admin::audit::AuditLogPage;
+use crate::routes::admin::content::{CollectionPage, EntryEditorPage};
+use crate::routes::admin::enrollments::EnrollmentsAdminPage;
+use crate::routes::admin::newsletter::NewsletterAdminPage;
+use crate::routes::agenda::{AgendaPage, EventPage};
+use crate::routes::albums::{AlbumPage, AlbumsPage};
+use crate::routes::attendance::AttendancePage;
+use crate::routes::attendance::history::{ClassAttendancePage, StudentAttendancePage};
+use crate::routes::auth::{provide_auth, AdminArea, LoginPage};
+use crate::routes::berimbau::BerimbauPage;
+use crate::routes::cantigas::{CantigaPage, CantigasPage, SongbookPage};
+use crate::routes::donate::DonatePage;
+use crate::routes::enrollment::{EnrollmentPage, EnrollmentStatusPage};
+use crate::routes::graduation::BatizadoPage;
+use crate::routes::graduation::timeline::GraduationTimelinePage;
 use crate::routes::home::HomePage;
+use crate::routes::news::{NewsArticlePage, NewsPage};
+use crate::routes::newsletter::{NewsletterConfirmPage, NewsletterUnsubscribePage};
+use crate::routes::press::PressArchivePage;
+use crate::routes::schedule::SchedulePage;
+use crate::routes::transparency::TransparencyPage;
+
+/// The whole HTML document around [`App`], for server-side rendering.
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    let locale = i18n::provide_locale();
+    view! {
+        <!DOCTYPE html>
+        <html lang=locale.tag()>
+            <head>
+                <meta charset="utf-8"/>
+                <meta name="viewport" content="width=device-width, initial-scale=1"/>
+                <AutoReload options=options.clone() />
+                <HydrationScripts options/>
+                <MetaTags/>
+            </head>
+            <body>
+                <App/>
+            </body>
+        </html>
+    }
+}
 
 #[component]
 pub fn App() -> impl IntoView {
     // Provides context that manages stylesheets, titles, meta tags, etc.
     provide_meta_context();
-    
+    // Signed-in user, available to every page during SSR and after hydration.
+    provide_auth();
+    // Idioma da página, tirado do prefixo do caminho (`/es/…`, `/en/…`).
+    let locale = i18n::provide_locale();
 
     view! {
         // injects a stylesheet into the document <head>
@@ -20,13 +66,47 @@ pub fn App() -> impl IntoView {
         <Stylesheet id="leptos" href="https://fonts.googleapis.com/css2?family=Zalando+Sans+Expanded:ital,wght@0,200..900;1,200..900&display=swap"/>
         // sets the document title
         
-        <Title text="Associação Camará Capoeira"/>
+        <Title text=locale.catalog().site_name/>
 
         // content for this welcome page
-        <Router>
+        <Router base=locale.base()>
+            <LanguageAlternates/>
             <main>
                 <Routes fallback=move || "Not found.">
                     <Route path=StaticSegment("") view=HomePage/>
+                    <Route path=StaticSegment("agenda") view=AgendaPage/>
+                    <Route path=(StaticSegment("agenda"), ParamSegment("slug")) view=EventPage/>
+                    <Route path=StaticSegment("noticias") view=NewsPage/>
+                    <Route path=(StaticSegment("noticias"), ParamSegment("slug")) view=NewsArticlePage/>
+                    <Route path=(StaticSegment("newsletter"), StaticSegment("confirmar"), ParamSegment("token")) view=NewsletterConfirmPage/>
+                    <Route path=(StaticSegment("newsletter"), StaticSegment("sair"), ParamSegment("token")) view=NewsletterUnsubscribePage/>
+                    <Route path=StaticSegment("galeria") view=AlbumsPage/>
+                    <Route path=(StaticSegment("galeria"), ParamSegment("slug")) view=AlbumPage/>
+                    <Route path=StaticSegment("cantigas") view=CantigasPage/>
+                    <Route path=(StaticSegment("cantigas"), StaticSegment("caderno")) view=SongbookPage/>
+                    <Route path=(StaticSegment("cantigas"), ParamSegment("slug")) view=CantigaPage/>
+                    <Route path=StaticSegment("berimbau") view=BerimbauPage/>
+                    <Route path=StaticSegment("horarios") view=SchedulePage/>
+                    <Route path=StaticSegment("na-midia") view=PressArchivePage/>
+                    <Route path=StaticSegment("transparencia") view=TransparencyPage/>
+                    <Route path=StaticSegment("apoie") view=DonatePage/>
+                    <Route path=StaticSegment("matricula") view=EnrollmentPage/>
+                    <Route path=(StaticSegment("matricula"), ParamSegment("protocolo")) view=EnrollmentStatusPage/>
+                    <Route path=StaticSegment("entrar") view=LoginPage/>
+                    <ParentRoute path=StaticSegment("admin") view=AdminArea ssr=SsrMode::Async>
+                        <Route path=StaticSegment("") view=AdminDashboard/>
+                        <Route path=(StaticSegment("conteudo"), ParamSegment("colecao")) view=CollectionPage/>
+                        <Route path=(StaticSegment("conteudo"), ParamSegment("colecao"), StaticSegment("editar")) view=EntryEditorPage/>
+                        <Route path=StaticSegment("auditoria") view=AuditLogPage/>
+                        <Route path=StaticSegment("contas") view=AccountsAdminPage/>
+                        <Route path=StaticSegment("newsletter") view=NewsletterAdminPage/>
+                        <Route path=StaticSegment("frequencia") view=AttendancePage/>
+                        <Route path=(StaticSegment("frequencia"), StaticSegment("aluno"), ParamSegment("id")) view=StudentAttendancePage/>
+                        <Route path=(StaticSegment("frequencia"), StaticSegment("turma"), ParamSegment("id")) view=ClassAttendancePage/>
+                        <Route path=StaticSegment("graduacoes") view=BatizadoPage/>
+                        <Route path=StaticSegment("matriculas") view=EnrollmentsAdminPage/>
+                        <Route path=(StaticSegment("graduacoes"), StaticSegment("aluno"), ParamSegment("id")) view=GraduationTimelinePage/>
+                    </ParentRoute>
                     <Route path=WildcardSegment("any") view=NotFound/>
                 </Routes>
             </main>
@@ -0,0 +1,329 @@
+//! Chamada das oficinas: presença por turma e data, histórico e relatórios
+//! mensais para a prestação de contas do Cultura Viva.
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::content::dates;
+use crate::content::schedule::{Modality, Schedule};
+use crate::i18n::Locale;
+
+#[cfg(feature = "ssr")]
+pub mod store;
+
+/// One enrolled student on the roll of a session, with the mark already saved (if any).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RollEntry {
+    pub enrollment_id: i64,
+    pub student_name: String,
+    pub present: Option<bool>,
+}
+
+/// The roll call of one turma on one date.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Roll {
+    pub session_id: String,
+    pub session_title: String,
+    pub date: NaiveDate,
+    pub entries: Vec<RollEntry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Mark {
+    pub enrollment_id: i64,
+    pub present: bool,
+}
+
+/// A saved mark, joined with the student's name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttendanceRecord {
+    pub session_id: String,
+    pub date: NaiveDate,
+    pub enrollment_id: i64,
+    pub student_name: String,
+    pub present: bool,
+}
+
+/// "85%", or "–" when nothing was recorded.
+pub fn rate_label(present: u32, total: u32) -> String {
+    match (present * 100 + total / 2).checked_div(total) {
+        Some(percent) => format!("{percent}%"),
+        None => "–".to_string(),
+    }
+}
+
+/// One line of a student's history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StudentMark {
+    pub date: NaiveDate,
+    pub session_title: String,
+    pub present: bool,
+}
+
+/// Every mark of one student, newest first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StudentAttendance {
+    pub student_name: String,
+    pub marks: Vec<StudentMark>,
+}
+
+impl StudentAttendance {
+    pub fn presences(&self) -> u32 {
+        self.marks.iter().filter(|m| m.present).count() as u32
+    }
+
+    pub fn rate_label(&self) -> String {
+        rate_label(self.presences(), self.marks.len() as u32)
+    }
+}
+
+/// Headcount of one meeting of a turma.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassDay {
+    pub date: NaiveDate,
+    pub present: u32,
+    pub absent: u32,
+}
+
+impl ClassDay {
+    pub fn rate_label(&self) -> String {
+        rate_label(self.present, self.present + self.absent)
+    }
+}
+
+/// Groups records per date, newest first.
+pub fn class_days(records: &[AttendanceRecord]) -> Vec<ClassDay> {
+    let mut days: BTreeMap<NaiveDate, (u32, u32)> = BTreeMap::new();
+    for record in records {
+        let day = days.entry(record.date).or_default();
+        if record.present {
+            day.0 += 1;
+        } else {
+            day.1 += 1;
+        }
+    }
+    days.into_iter()
+        .rev()
+        .map(|(date, (present, absent))| ClassDay { date, present, absent })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassAttendance {
+    pub session_title: String,
+    pub days: Vec<ClassDay>,
+}
+
+/// A calendar month, written `2026-03` in URLs and file names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Month {
+    pub year: i32,
+    pub month: u32,
+}
+
+impl Month {
+    pub fn of(date: NaiveDate) -> Self {
+        Month { year: date.year(), month: date.month() }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        let (year, month) = value.split_once('-')?;
+        let month = Month { year: year.parse().ok()?, month: month.parse().ok()? };
+        month.first_day().map(|_| month)
+    }
+
+    pub fn first_day(self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.year, self.month, 1)
+    }
+
+    /// First day of the following month (exclusive end of this one).
+    pub fn end(self) -> Option<NaiveDate> {
+        if self.month == 12 {
+            NaiveDate::from_ymd_opt(self.year + 1, 1, 1)
+        } else {
+            NaiveDate::from_ymd_opt(self.year, self.month + 1, 1)
+        }
+    }
+
+    pub fn previous(self) -> Self {
+        if self.month == 1 {
+            Month { year: self.year - 1, month: 12 }
+        } else {
+            Month { year: self.year, month: self.month - 1 }
+        }
+    }
+
+    /// "março de 2026"
+    pub fn label(self) -> String {
+        format!("{} de {}", dates::month_name(self.month, Locale::PtBr), self.year)
+    }
+}
+
+impl std::fmt::Display for Month {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{:02}", self.year, self.month)
+    }
+}
+
+/// Totals of one modality and age group over a period, as the grant reports ask for.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportRow {
+    pub modality: Modality,
+    pub age_group: String,
+    /// Distinct (turma, date) pairs with a roll call.
+    pub meetings: u32,
+    /// Distinct students with at least one presence.
+    pub students: u32,
+    pub presences: u32,
+    pub absences: u32,
+}
+
+impl ReportRow {
+    /// Share of presences among all marks, if any were recorded.
+    pub fn rate(&self) -> Option<f64> {
+        let total = self.presences + self.absences;
+        (total > 0).then(|| f64::from(self.presences) / f64::from(total))
+    }
+
+    pub fn rate_label(&self) -> String {
+        rate_label(self.presences, self.presences + self.absences)
+    }
+}
+
+/// Sums `records` per modality and age group of their turma.
+///
+/// Records of turmas no longer on the schedule are left out, since they
+/// cannot be classified.
+pub fn summarize(records: &[AttendanceRecord], schedule: &Schedule) -> Vec<ReportRow> {
+    #[derive(Default)]
+    struct Totals<'a> {
+        meetings: BTreeSet<(&'a str, NaiveDate)>,
+        students: BTreeSet<i64>,
+        presences: u32,
+        absences: u32,
+    }
+
+    let mut groups: BTreeMap<(u8, &str), (Modality, Totals)> = BTreeMap::new();
+    for record in records {
+        let Some(session) = schedule.session(&record.session_id) else {
+            continue;
+        };
+        let (_, totals) = groups
+            .entry((session.modality as u8, session.age_group.as_str()))
+            .or_insert_with(|| (session.modality, Totals::default()));
+
+        totals.meetings.insert((record.session_id.as_str(), record.date));
+        if record.present {
+            totals.students.insert(record.enrollment_id);
+            totals.presences += 1;
+        } else {
+            totals.absences += 1;
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|((_, age_group), (modality, totals))| ReportRow {
+            modality,
+            age_group: age_group.to_string(),
+            meetings: totals.meetings.len() as u32,
+            students: totals.students.len() as u32,
+            presences: totals.presences,
+            absences: totals.absences,
+        })
+        .collect()
+}
+
+/// The month's summary plus a link target for each export format.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonthlyReport {
+    pub month: Month,
+    pub rows: Vec<ReportRow>,
+}
+
+impl MonthlyReport {
+    pub fn summary_csv_href(&self) -> String {
+        format!("/admin/frequencia/{}/resumo.csv", self.month)
+    }
+
+    pub fn detail_csv_href(&self) -> String {
+        format!("/admin/frequencia/{}/chamadas.csv", self.month)
+    }
+
+    pub fn ods_href(&self) -> String {
+        format!("/admin/frequencia/{}.ods", self.month)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::schedule::ClassSession;
+    use chrono::{NaiveTime, Weekday};
+
+    fn session(id: &str, modality: Modality, age_group: &str) -> ClassSession {
+        ClassSession {
+            id: id.to_string(),
+            weekday: Weekday::Mon,
+            start: NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(19, 0, 0).unwrap(),
+            modality,
+            location: "Sede".to_string(),
+            age_group: age_group.to_string(),
+            instructor: "Mestre".to_string(),
+        }
+    }
+
+    fn record(session_id: &str, day: u32, enrollment_id: i64, present: bool) -> AttendanceRecord {
+        AttendanceRecord {
+            session_id: session_id.to_string(),
+            date: NaiveDate::from_ymd_opt(2026, 3, day).unwrap(),
+            enrollment_id,
+            student_name: String::new(),
+            present,
+        }
+    }
+
+    #[test]
+    fn summarizes_by_modality_and_age_group() {
+        let schedule = Schedule {
+            sessions: vec![
+                session("cap-inf-seg", Modality::Capoeira, "Infantil"),
+                session("cap-inf-qua", Modality::Capoeira, "Infantil"),
+                session("perc", Modality::Percussao, "Adultos"),
+            ],
+            exceptions: vec![],
+            extras: vec![],
+        };
+        let records = vec![
+            record("cap-inf-seg", 2, 1, true),
+            record("cap-inf-seg", 2, 2, false),
+            record("cap-inf-qua", 4, 1, true),
+            record("perc", 2, 3, false),
+            record("removed", 2, 4, true),
+        ];
+
+        let rows = summarize(&records, &schedule);
+        assert_eq!(rows.len(), 2);
+
+        let capoeira = &rows[0];
+        assert_eq!((capoeira.modality, capoeira.age_group.as_str()), (Modality::Capoeira, "Infantil"));
+        assert_eq!((capoeira.meetings, capoeira.students, capoeira.presences, capoeira.absences), (2, 1, 2, 1));
+        assert_eq!(capoeira.rate_label(), "67%");
+
+        let percussao = &rows[1];
+        assert_eq!((percussao.meetings, percussao.students, percussao.presences, percussao.absences), (1, 0, 0, 1));
+    }
+
+    #[test]
+    fn parses_months() {
+        let march = Month::parse("2026-03").unwrap();
+        assert_eq!(march.to_string(), "2026-03");
+        assert_eq!(march.end(), NaiveDate::from_ymd_opt(2026, 4, 1));
+        assert_eq!(Month::parse("2026-12").unwrap().end(), NaiveDate::from_ymd_opt(2027, 1, 1));
+        assert_eq!(Month::parse("2026-01").unwrap().previous(), Month { year: 2025, month: 12 });
+        assert_eq!(Month::parse("2026-13"), None);
+        assert_eq!(Month::parse("março"), None);
+    }
+}
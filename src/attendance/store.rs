@@ -0,0 +1,119 @@
+//! Reading and writing roll calls in the local database.
+
+use chrono::{NaiveDate, NaiveDateTime};
+use rusqlite::{params, Connection, OptionalExtension, Row, Transaction};
+
+use super::{AttendanceRecord, Mark, RollEntry};
+use crate::audit::Fields;
+use crate::db::DbError;
+
+const RECORD_QUERY: &str = "SELECT a.session_id, a.date, a.enrollment_id, e.student_name, a.present \
+    FROM attendance a JOIN enrollments e ON e.id = a.enrollment_id";
+
+/// Students of `session_id` whose enrollment was accepted, with their mark on `date`.
+///
+/// Anyone already marked on that date stays on the roll, even if they have
+/// since changed turma.
+pub fn roster(conn: &Connection, session_id: &str, date: NaiveDate) -> Result<Vec<RollEntry>, DbError> {
+    let mut stmt = conn.prepare(
+        "SELECT e.id, e.student_name, a.present
+         FROM enrollments e
+         LEFT JOIN attendance a ON a.enrollment_id = e.id AND a.session_id = ?1 AND a.date = ?2
+         WHERE (e.session_id = ?1 AND e.status IN ('accepted', 'active')) OR a.id IS NOT NULL
+         ORDER BY e.student_name COLLATE NOCASE",
+    )?;
+    let rows = stmt.query_map(params![session_id, date], |row| {
+        Ok(RollEntry {
+            enrollment_id: row.get(0)?,
+            student_name: row.get(1)?,
+            present: row.get(2)?,
+        })
+    })?;
+    Ok(rows.collect::<Result<_, _>>()?)
+}
+
+/// Saves (or corrects) the marks of one roll call and returns how many were stored.
+///
+/// Marks for students who are not on the roster are ignored. A student marked
+/// present for the first time moves from accepted to active.
+pub fn save_roll(
+    tx: &Transaction,
+    session_id: &str,
+    date: NaiveDate,
+    marks: &[Mark],
+    now: NaiveDateTime,
+) -> Result<usize, DbError> {
+    let allowed: Vec<i64> = roster(tx, session_id, date)?.into_iter().map(|e| e.enrollment_id).collect();
+    let mut saved = 0;
+
+    for mark in marks.iter().filter(|m| allowed.contains(&m.enrollment_id)) {
+        saved += tx.execute(
+            "INSERT INTO attendance (session_id, date, enrollment_id, present, recorded_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT (session_id, date, enrollment_id)
+             DO UPDATE SET present = excluded.present, recorded_at = excluded.recorded_at",
+            params![session_id, date, mark.enrollment_id, mark.present, now],
+        )?;
+        if mark.present {
+            tx.execute(
+                "UPDATE enrollments SET status = 'active', updated_at = ?2 WHERE id = ?1 AND status = 'accepted'",
+                params![mark.enrollment_id, now],
+            )?;
+        }
+    }
+
+    Ok(saved)
+}
+
+/// The marks of one roll call as audit fields: "Name (#id)" → presente/ausente.
+pub fn roll_snapshot(conn: &Connection, session_id: &str, date: NaiveDate) -> Result<Fields, DbError> {
+    Ok(roster(conn, session_id, date)?
+        .into_iter()
+        .filter_map(|e| {
+            let mark = if e.present? { "presente" } else { "ausente" };
+            Some((format!("{} (#{})", e.student_name, e.enrollment_id), mark.to_string()))
+        })
+        .collect())
+}
+
+/// Name of the student and every mark they received, newest first.
+pub fn student_records(
+    conn: &Connection,
+    enrollment_id: i64,
+) -> Result<Option<(String, Vec<AttendanceRecord>)>, DbError> {
+    let Some(name) = conn
+        .query_row("SELECT student_name FROM enrollments WHERE id = ?1", params![enrollment_id], |row| row.get(0))
+        .optional()?
+    else {
+        return Ok(None);
+    };
+
+    let mut stmt = conn.prepare(&format!("{RECORD_QUERY} WHERE a.enrollment_id = ?1 ORDER BY a.date DESC, a.session_id"))?;
+    let records = stmt.query_map(params![enrollment_id], record_from_row)?.collect::<Result<_, _>>()?;
+    Ok(Some((name, records)))
+}
+
+pub fn class_records(conn: &Connection, session_id: &str) -> Result<Vec<AttendanceRecord>, DbError> {
+    let mut stmt = conn.prepare(&format!("{RECORD_QUERY} WHERE a.session_id = ?1 ORDER BY a.date, e.student_name"))?;
+    let rows = stmt.query_map(params![session_id], record_from_row)?;
+    Ok(rows.collect::<Result<_, _>>()?)
+}
+
+/// Marks with `from <= date < until`, in date order.
+pub fn records_between(conn: &Connection, from: NaiveDate, until: NaiveDate) -> Result<Vec<AttendanceRecord>, DbError> {
+    let mut stmt = conn.prepare(&format!(
+        "{RECORD_QUERY} WHERE a.date >= ?1 AND a.date < ?2 ORDER BY a.date, a.session_id, e.student_name"
+    ))?;
+    let rows = stmt.query_map(params![from, until], record_from_row)?;
+    Ok(rows.collect::<Result<_, _>>()?)
+}
+
+fn record_from_row(row: &Row) -> rusqlite::Result<AttendanceRecord> {
+    Ok(AttendanceRecord {
+        session_id: row.get(0)?,
+        date: row.get(1)?,
+        enrollment_id: row.get(2)?,
+        student_name: row.get(3)?,
+        present: row.get(4)?,
+    })
+}
@@ -0,0 +1,210 @@
+//! Registro de auditoria: quem alterou o quê, quando e de onde.
+//!
+//! Every mutating server function records an [`AuditEntry`] through a
+//! [`Recorder`], in the same transaction as the change itself when the change
+//! is in the database. Only the fields that changed are kept, as flat string
+//! maps like the content editor's. The table is append-only (see migration 6).
+
+use std::collections::BTreeMap;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+pub mod store;
+#[cfg(feature = "ssr")]
+mod recorder;
+
+#[cfg(feature = "ssr")]
+pub use recorder::Recorder;
+
+/// A record (or the part of it an action touched), by field name.
+pub type Fields = BTreeMap<String, String>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    SignedIn,
+    SignInFailed,
+    SignedOut,
+    UserCreated,
+    UserRoleChanged,
+    UserDeactivated,
+    UserReactivated,
+    RollSaved,
+    BatizadoRecorded,
+    EnrollmentSubmitted,
+    EnrollmentAccepted,
+    EnrollmentActivated,
+    ContentPublished,
+    ContentDeleted,
+    DraftSaved,
+    DraftDiscarded,
+    NewsletterSubscribed,
+    NewsletterConfirmed,
+    NewsletterUnsubscribed,
+    DigestSent,
+}
+
+impl Action {
+    pub const ALL: [Action; 20] = [
+        Action::SignedIn,
+        Action::SignInFailed,
+        Action::SignedOut,
+        Action::UserCreated,
+        Action::UserRoleChanged,
+        Action::UserDeactivated,
+        Action::UserReactivated,
+        Action::RollSaved,
+        Action::BatizadoRecorded,
+        Action::EnrollmentSubmitted,
+        Action::EnrollmentAccepted,
+        Action::EnrollmentActivated,
+        Action::ContentPublished,
+        Action::ContentDeleted,
+        Action::DraftSaved,
+        Action::DraftDiscarded,
+        Action::NewsletterSubscribed,
+        Action::NewsletterConfirmed,
+        Action::NewsletterUnsubscribed,
+        Action::DigestSent,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Action::SignedIn => "Entrada no painel",
+            Action::SignInFailed => "Tentativa de entrada recusada",
+            Action::SignedOut => "Saída do painel",
+            Action::UserCreated => "Conta criada",
+            Action::UserRoleChanged => "Papel alterado",
+            Action::UserDeactivated => "Conta desativada",
+            Action::UserReactivated => "Conta reativada",
+            Action::RollSaved => "Chamada salva",
+            Action::BatizadoRecorded => "Batizado registrado",
+            Action::EnrollmentSubmitted => "Matrícula enviada",
+            Action::EnrollmentAccepted => "Matrícula aceita",
+            Action::EnrollmentActivated => "Matrícula ativada",
+            Action::ContentPublished => "Conteúdo publicado",
+            Action::ContentDeleted => "Conteúdo excluído",
+            Action::DraftSaved => "Rascunho salvo",
+            Action::DraftDiscarded => "Rascunho descartado",
+            Action::NewsletterSubscribed => "Inscrição no boletim",
+            Action::NewsletterConfirmed => "Inscrição no boletim confirmada",
+            Action::NewsletterUnsubscribed => "Inscrição no boletim cancelada",
+            Action::DigestSent => "Boletim enviado",
+        }
+    }
+
+    /// Value stored in the database.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Action::SignedIn => "signed_in",
+            Action::SignInFailed => "sign_in_failed",
+            Action::SignedOut => "signed_out",
+            Action::UserCreated => "user_created",
+            Action::UserRoleChanged => "user_role_changed",
+            Action::UserDeactivated => "user_deactivated",
+            Action::UserReactivated => "user_reactivated",
+            Action::RollSaved => "roll_saved",
+            Action::BatizadoRecorded => "batizado_recorded",
+            Action::EnrollmentSubmitted => "enrollment_submitted",
+            Action::EnrollmentAccepted => "enrollment_accepted",
+            Action::EnrollmentActivated => "enrollment_activated",
+            Action::ContentPublished => "content_published",
+            Action::ContentDeleted => "content_deleted",
+            Action::DraftSaved => "draft_saved",
+            Action::DraftDiscarded => "draft_discarded",
+            Action::NewsletterSubscribed => "newsletter_subscribed",
+            Action::NewsletterConfirmed => "newsletter_confirmed",
+            Action::NewsletterUnsubscribed => "newsletter_unsubscribed",
+            Action::DigestSent => "digest_sent",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Action::ALL.into_iter().find(|a| a.as_str() == value)
+    }
+}
+
+/// One field of a record before and after an action; `None` when it did not exist.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// The fields that differ between `before` and `after`, in field order.
+pub fn changes(before: &Fields, after: &Fields) -> Vec<FieldChange> {
+    let mut fields: Vec<&String> = before.keys().chain(after.keys()).collect();
+    fields.sort();
+    fields.dedup();
+
+    fields
+        .into_iter()
+        .filter(|field| before.get(*field) != after.get(*field))
+        .map(|field| FieldChange {
+            field: field.clone(),
+            before: before.get(field).cloned(),
+            after: after.get(field).cloned(),
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub id: i64,
+    pub request_id: String,
+    pub occurred_at: NaiveDateTime,
+    /// Name of the account at the time; `None` for anonymous actions.
+    pub actor: Option<String>,
+    pub ip: Option<String>,
+    pub action: Action,
+    pub target: String,
+    pub changes: Vec<FieldChange>,
+}
+
+/// Filters of the `/admin/auditoria` viewer. Empty fields match everything.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AuditFilter {
+    pub user_id: Option<i64>,
+    pub action: Option<Action>,
+    pub from: Option<NaiveDate>,
+    pub until: Option<NaiveDate>,
+    /// Part of the target, the IP or the request ID.
+    pub search: Option<String>,
+}
+
+/// One page of the log, newest first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditPage {
+    pub entries: Vec<AuditEntry>,
+    /// Accounts that appear in the log, for the filter.
+    pub actors: Vec<(i64, String)>,
+    pub has_more: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(pairs: &[(&str, &str)]) -> Fields {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn changes_keep_only_differing_fields() {
+        let before = fields(&[("title", "Roda"), ("date", "2026-11-01"), ("cover", "a.jpg")]);
+        let after = fields(&[("title", "Roda"), ("date", "2026-11-08"), ("summary", "Na praça")]);
+
+        assert_eq!(
+            changes(&before, &after),
+            vec![
+                FieldChange { field: "cover".into(), before: Some("a.jpg".into()), after: None },
+                FieldChange { field: "date".into(), before: Some("2026-11-01".into()), after: Some("2026-11-08".into()) },
+                FieldChange { field: "summary".into(), before: None, after: Some("Na praça".into()) },
+            ]
+        );
+        assert!(changes(&after, &after).is_empty());
+    }
+}
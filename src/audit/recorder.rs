@@ -0,0 +1,116 @@
+use std::net::IpAddr;
+
+use actix_web::web;
+use chrono::NaiveDateTime;
+use rusqlite::Connection;
+
+use super::store::{self, NewEntry};
+use super::{Action, Fields};
+use crate::auth::CurrentUser;
+use crate::db::DbError;
+use crate::server::request_id::RequestId;
+use crate::settings::Settings;
+
+/// Who is acting, from where and in which request; built once per server
+/// function call and moved into its database closure.
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    request_id: String,
+    user_id: Option<i64>,
+    actor: Option<String>,
+    ip: Option<String>,
+    now: NaiveDateTime,
+}
+
+impl Recorder {
+    /// Captures the current request. `user` is `None` for public actions.
+    pub async fn new(user: Option<&CurrentUser>) -> Recorder {
+        let request: Option<actix_web::HttpRequest> = leptos_actix::extract().await.ok();
+        Recorder::for_request(request.as_ref(), user)
+    }
+
+    /// Like [`Recorder::new`], for plain actix handlers.
+    ///
+    /// The IP is the peer address, or the client reported in `X-Forwarded-For`
+    /// when the peer is one of the `trusted_proxies` of the settings.
+    pub fn for_request(request: Option<&actix_web::HttpRequest>, user: Option<&CurrentUser>) -> Recorder {
+        let request_id = request.and_then(RequestId::of).map(|id| id.0).unwrap_or_else(uuid::Uuid::new_v4);
+        let ip = request.and_then(|r| {
+            let trusted = r.app_data::<web::Data<Settings>>().map(|s| s.security.trusted_proxies.as_slice()).unwrap_or_default();
+            let forwarded_for = r.headers().get("x-forwarded-for").and_then(|value| value.to_str().ok());
+            client_ip(r.peer_addr().map(|addr| addr.ip()), forwarded_for, trusted).map(|ip| ip.to_string())
+        });
+
+        Recorder {
+            request_id: request_id.to_string(),
+            user_id: user.map(|u| u.id),
+            actor: user.map(|u| u.name.clone()),
+            ip,
+            now: crate::content::events::now_local(),
+        }
+    }
+
+    /// Client address of the request, if known.
+    pub fn ip(&self) -> Option<&str> {
+        self.ip.as_deref()
+    }
+
+    /// Appends an entry; `before` and `after` are the record around the change
+    /// (empty when it did not exist before, or no longer exists after).
+    pub fn record(&self, conn: &Connection, action: Action, target: &str, before: &Fields, after: &Fields) -> Result<(), DbError> {
+        store::record(
+            conn,
+            &NewEntry {
+                request_id: &self.request_id,
+                occurred_at: self.now,
+                user_id: self.user_id,
+                actor: self.actor.as_deref(),
+                ip: self.ip.as_deref(),
+                action,
+                target,
+                before,
+                after,
+            },
+        )
+    }
+
+    /// Records an action without a database change of its own (sign-in, content files).
+    pub async fn record_now(self, action: Action, target: String, before: Fields, after: Fields) -> Result<(), DbError> {
+        crate::db::with_conn(move |conn| self.record(conn, action, &target, &before, &after)).await
+    }
+}
+
+/// Walks `X-Forwarded-For` back from the peer while the hop it came from is a
+/// trusted proxy: the first address not among them is the client. Entries
+/// further left were written by the client itself and are never believed.
+fn client_ip(peer: Option<IpAddr>, forwarded_for: Option<&str>, trusted: &[IpAddr]) -> Option<IpAddr> {
+    let mut client = peer?;
+    for hop in forwarded_for.unwrap_or_default().rsplit(',') {
+        if !trusted.contains(&client) {
+            break;
+        }
+        match hop.trim().parse() {
+            Ok(ip) => client = ip,
+            Err(_) => break,
+        }
+    }
+    Some(client)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forwarded_for_counts_only_behind_a_trusted_proxy() {
+        let proxy = IpAddr::from([127, 0, 0, 1]);
+        let visitor = IpAddr::from([200, 1, 2, 3]);
+        let forged = Some("10.9.9.9, 200.1.2.3");
+
+        assert_eq!(client_ip(Some(visitor), Some("10.9.9.9"), &[proxy]), Some(visitor));
+        assert_eq!(client_ip(Some(proxy), forged, &[proxy]), Some(visitor));
+        assert_eq!(client_ip(Some(proxy), forged, &[]), Some(proxy));
+        assert_eq!(client_ip(Some(proxy), Some("lixo"), &[proxy]), Some(proxy));
+        assert_eq!(client_ip(None, forged, &[proxy]), None);
+    }
+}
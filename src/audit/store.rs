@@ -0,0 +1,198 @@
+//! Writing and querying the audit log.
+
+use chrono::{Days, NaiveDateTime};
+use rusqlite::{params_from_iter, types::Value, Connection, Row};
+
+use super::{changes, Action, AuditEntry, AuditFilter, Fields};
+use crate::db::DbError;
+
+/// An action about to be recorded; see [`super::Recorder`].
+pub struct NewEntry<'a> {
+    pub request_id: &'a str,
+    pub occurred_at: NaiveDateTime,
+    pub user_id: Option<i64>,
+    pub actor: Option<&'a str>,
+    pub ip: Option<&'a str>,
+    pub action: Action,
+    pub target: &'a str,
+    pub before: &'a Fields,
+    pub after: &'a Fields,
+}
+
+fn encode(fields: &Fields) -> Option<String> {
+    // A map of strings always serializes.
+    (!fields.is_empty()).then(|| toml::to_string(fields).unwrap_or_default())
+}
+
+fn decode(column: &str, row: &Row) -> rusqlite::Result<Fields> {
+    match row.get::<_, Option<String>>(column)? {
+        Some(raw) => toml::from_str(&raw).map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(0, rusqlite::types::Type::Text, Box::new(e))
+        }),
+        None => Ok(Fields::new()),
+    }
+}
+
+/// Appends `entry`, keeping only the fields that changed.
+pub fn record(conn: &Connection, entry: &NewEntry) -> Result<(), DbError> {
+    let changed = changes(entry.before, entry.after);
+    let before: Fields = changed.iter().filter_map(|c| Some((c.field.clone(), c.before.clone()?))).collect();
+    let after: Fields = changed.iter().filter_map(|c| Some((c.field.clone(), c.after.clone()?))).collect();
+
+    conn.execute(
+        "INSERT INTO audit_log (request_id, occurred_at, user_id, actor, ip, action, target, before, after)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        rusqlite::params![
+            entry.request_id,
+            entry.occurred_at,
+            entry.user_id,
+            entry.actor,
+            entry.ip,
+            entry.action.as_str(),
+            entry.target,
+            encode(&before),
+            encode(&after),
+        ],
+    )?;
+    Ok(())
+}
+
+fn entry_from_row(row: &Row) -> rusqlite::Result<AuditEntry> {
+    let action: String = row.get("action")?;
+    Ok(AuditEntry {
+        id: row.get("id")?,
+        request_id: row.get("request_id")?,
+        occurred_at: row.get("occurred_at")?,
+        actor: row.get("actor")?,
+        ip: row.get("ip")?,
+        action: Action::parse(&action).ok_or_else(|| {
+            rusqlite::Error::FromSqlConversionFailure(0, rusqlite::types::Type::Text, format!("unknown action {action:?}").into())
+        })?,
+        target: row.get("target")?,
+        changes: changes(&decode("before", row)?, &decode("after", row)?),
+    })
+}
+
+/// Entries matching `filter`, newest first, skipping `offset` and returning at
+/// most `limit`. The flag tells whether there are more after them.
+pub fn list(conn: &Connection, filter: &AuditFilter, limit: usize, offset: usize) -> Result<(Vec<AuditEntry>, bool), DbError> {
+    let mut conditions = Vec::new();
+    let mut values: Vec<Value> = Vec::new();
+
+    if let Some(user_id) = filter.user_id {
+        values.push(user_id.into());
+        conditions.push(format!("user_id = ?{}", values.len()));
+    }
+    if let Some(action) = filter.action {
+        values.push(action.as_str().to_string().into());
+        conditions.push(format!("action = ?{}", values.len()));
+    }
+    if let Some(from) = filter.from {
+        values.push(from.and_hms_opt(0, 0, 0).unwrap_or_default().to_string().into());
+        conditions.push(format!("occurred_at >= ?{}", values.len()));
+    }
+    if let Some(until) = filter.until.and_then(|d| d.checked_add_days(Days::new(1))) {
+        values.push(until.and_hms_opt(0, 0, 0).unwrap_or_default().to_string().into());
+        conditions.push(format!("occurred_at < ?{}", values.len()));
+    }
+    if let Some(search) = filter.search.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+        values.push(format!("%{search}%").into());
+        let n = values.len();
+        conditions.push(format!("(target LIKE ?{n} OR ip LIKE ?{n} OR request_id LIKE ?{n})"));
+    }
+
+    let filter_sql = match conditions.is_empty() {
+        true => String::new(),
+        false => format!("WHERE {}", conditions.join(" AND ")),
+    };
+    values.push((limit as i64 + 1).into());
+    values.push((offset as i64).into());
+    let sql = format!(
+        "SELECT id, request_id, occurred_at, actor, ip, action, target, before, after FROM audit_log {filter_sql}
+         ORDER BY id DESC LIMIT ?{} OFFSET ?{}",
+        values.len() - 1,
+        values.len()
+    );
+
+    let mut stmt = conn.prepare(&sql)?;
+    let mut entries: Vec<AuditEntry> = stmt.query_map(params_from_iter(values), entry_from_row)?.collect::<Result<_, _>>()?;
+    let has_more = entries.len() > limit;
+    entries.truncate(limit);
+    Ok((entries, has_more))
+}
+
+/// Failed sign-ins since `since`: those for `email` (from anywhere) and those
+/// from `ip` (for any e-mail).
+pub fn sign_in_failures(conn: &Connection, email: &str, ip: Option<&str>, since: NaiveDateTime) -> Result<(u32, u32), DbError> {
+    Ok(conn.query_row(
+        "SELECT COALESCE(SUM(target = ?2 COLLATE NOCASE), 0), COALESCE(SUM(ip = ?3), 0) FROM audit_log
+         WHERE action = ?1 AND occurred_at >= ?4",
+        rusqlite::params![Action::SignInFailed.as_str(), email.trim(), ip, since],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )?)
+}
+
+/// Accounts that have entries in the log, by name.
+pub fn actors(conn: &Connection) -> Result<Vec<(i64, String)>, DbError> {
+    let mut stmt = conn.prepare(
+        "SELECT u.id, u.name FROM users u WHERE EXISTS (SELECT 1 FROM audit_log a WHERE a.user_id = u.id)
+         ORDER BY u.name COLLATE NOCASE",
+    )?;
+    let actors = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?.collect::<Result<_, _>>()?;
+    Ok(actors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(pairs: &[(&str, &str)]) -> Fields {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn log_keeps_changes_and_refuses_edits() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        crate::db::migrations::migrate(&mut conn).unwrap();
+        let at = chrono::NaiveDate::from_ymd_opt(2026, 11, 3).unwrap().and_hms_opt(19, 30, 0).unwrap();
+
+        let before = fields(&[("title", "Roda"), ("date", "2026-11-01")]);
+        let after = fields(&[("title", "Roda"), ("date", "2026-11-08")]);
+        let entry = NewEntry {
+            request_id: "7f1c",
+            occurred_at: at,
+            user_id: None,
+            actor: None,
+            ip: Some("127.0.0.1"),
+            action: Action::ContentPublished,
+            target: "Notícias: Roda",
+            before: &before,
+            after: &after,
+        };
+        record(&conn, &entry).unwrap();
+        record(&conn, &NewEntry { action: Action::SignedOut, target: "saída", ..entry }).unwrap();
+
+        let filter = AuditFilter { action: Some(Action::ContentPublished), ..Default::default() };
+        let (entries, has_more) = list(&conn, &filter, 10, 0).unwrap();
+        assert!(!has_more);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].changes, changes(&before, &after));
+        assert_eq!(entries[0].changes.len(), 1);
+
+        let (entries, has_more) = list(&conn, &AuditFilter::default(), 1, 0).unwrap();
+        assert_eq!((entries[0].action, has_more), (Action::SignedOut, true));
+
+        let next_day = AuditFilter { from: at.date().succ_opt(), ..Default::default() };
+        assert!(list(&conn, &next_day, 10, 0).unwrap().0.is_empty());
+
+        let failed = NewEntry { action: Action::SignInFailed, target: "Aluna@example.com", ..entry };
+        record(&conn, &failed).unwrap();
+        record(&conn, &NewEntry { target: "outra@example.com", ..failed }).unwrap();
+        record(&conn, &NewEntry { ip: Some("10.0.0.9"), occurred_at: at - chrono::Duration::hours(1), ..failed }).unwrap();
+        assert_eq!(sign_in_failures(&conn, "aluna@example.com", Some("127.0.0.1"), at).unwrap(), (1, 2));
+        assert_eq!(sign_in_failures(&conn, "aluna@example.com", None, at - chrono::Duration::hours(2)).unwrap(), (2, 0));
+
+        assert!(conn.execute("UPDATE audit_log SET target = 'x'", []).is_err());
+        assert!(conn.execute("DELETE FROM audit_log", []).is_err());
+    }
+}
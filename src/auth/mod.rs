@@ -0,0 +1,182 @@
+//! Contas, sessões e papéis de acesso ao painel administrativo.
+//!
+//! Sessions live in an encrypted cookie (actix-session) that holds only the
+//! user id; the account is reloaded on every request, so deactivating a user
+//! or changing their role takes effect immediately.
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+pub mod password;
+#[cfg(feature = "ssr")]
+pub mod store;
+
+/// Access level of an account. Each role includes the permissions of the ones before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// Family member following their children's enrollment and attendance.
+    Responsavel,
+    /// Helps with roll calls and events.
+    Voluntario,
+    /// Mestre or professor: classes, graduations, students.
+    Mestre,
+    /// Everything, including accounts and site content.
+    Admin,
+}
+
+impl Role {
+    pub const ALL: [Role; 4] = [Role::Responsavel, Role::Voluntario, Role::Mestre, Role::Admin];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Role::Responsavel => "Responsável",
+            Role::Voluntario => "Voluntário(a)",
+            Role::Mestre => "Mestre / Professor(a)",
+            Role::Admin => "Administrador(a)",
+        }
+    }
+
+    /// Value stored in the database.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Role::Responsavel => "responsavel",
+            Role::Voluntario => "voluntario",
+            Role::Mestre => "mestre",
+            Role::Admin => "admin",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Role::ALL.into_iter().find(|r| r.as_str() == value)
+    }
+}
+
+/// The signed-in account, as seen by components and server functions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CurrentUser {
+    pub id: i64,
+    pub name: String,
+    pub email: String,
+    pub role: Role,
+}
+
+impl CurrentUser {
+    pub fn has_role(&self, role: Role) -> bool {
+        self.role >= role
+    }
+}
+
+/// An account as listed in `/admin/contas`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Account {
+    pub user: CurrentUser,
+    pub active: bool,
+    pub last_login_at: Option<NaiveDateTime>,
+}
+
+/// Failed sign-ins, counted over the last [`SIGN_IN_WINDOW_MINUTES`], after
+/// which an e-mail or an IP is refused until the window moves on.
+#[cfg(feature = "ssr")]
+pub const MAX_FAILURES_PER_EMAIL: u32 = 5;
+#[cfg(feature = "ssr")]
+pub const MAX_FAILURES_PER_IP: u32 = 20;
+#[cfg(feature = "ssr")]
+pub const SIGN_IN_WINDOW_MINUTES: i64 = 15;
+
+/// Whether a sign-in for `email` from `ip` must be refused without checking
+/// the password. Failures are the `SignInFailed` entries of the audit log.
+#[cfg(feature = "ssr")]
+pub fn sign_in_throttled(
+    conn: &rusqlite::Connection,
+    email: &str,
+    ip: Option<&str>,
+    now: NaiveDateTime,
+) -> Result<bool, crate::db::DbError> {
+    let since = now - chrono::Duration::minutes(SIGN_IN_WINDOW_MINUTES);
+    let (by_email, by_ip) = crate::audit::store::sign_in_failures(conn, email, ip, since)?;
+    Ok(by_email >= MAX_FAILURES_PER_EMAIL || by_ip >= MAX_FAILURES_PER_IP)
+}
+
+/// Session key holding the signed-in user's id.
+#[cfg(feature = "ssr")]
+pub const SESSION_USER_KEY: &str = "user_id";
+
+/// Loads the account stored in `session`, if it still exists and is active.
+#[cfg(feature = "ssr")]
+pub async fn user_from_session(
+    session: &actix_session::Session,
+    pool: &crate::db::Pool,
+) -> Result<Option<CurrentUser>, crate::db::DbError> {
+    let Ok(Some(id)) = session.get::<i64>(SESSION_USER_KEY) else {
+        return Ok(None);
+    };
+    crate::db::with_pool(pool.clone(), move |conn| store::find_active(conn, id)).await
+}
+
+/// The user signed in on the current request, from a server function or the SSR render.
+#[cfg(feature = "ssr")]
+pub async fn current_user() -> Result<Option<CurrentUser>, leptos::prelude::ServerFnError> {
+    use leptos::prelude::*;
+
+    let session: actix_session::Session = leptos_actix::extract().await?;
+    let pool = use_context::<crate::db::Pool>().ok_or_else(|| ServerFnError::new(crate::db::DbError::MissingPool))?;
+    user_from_session(&session, &pool).await.map_err(ServerFnError::new)
+}
+
+/// Guard for server functions: the current user if they hold at least `role`.
+///
+/// Fails with 401 when nobody is signed in and 403 when the role is too low.
+#[cfg(feature = "ssr")]
+pub async fn require_role(role: Role) -> Result<CurrentUser, leptos::prelude::ServerFnError> {
+    use actix_web::http::StatusCode;
+    use leptos::prelude::*;
+
+    let deny = |status: StatusCode, message: &str| {
+        if let Some(response) = use_context::<leptos_actix::ResponseOptions>() {
+            response.set_status(status);
+        }
+        ServerFnError::new(message)
+    };
+
+    match current_user().await? {
+        Some(user) if user.has_role(role) => Ok(user),
+        Some(_) => Err(deny(StatusCode::FORBIDDEN, "Você não tem permissão para esta ação.")),
+        None => Err(deny(StatusCode::UNAUTHORIZED, "Entre com sua conta para continuar.")),
+    }
+}
+
+/// Guard for plain actix handlers (report downloads and the like).
+#[cfg(feature = "ssr")]
+pub async fn require_role_for(
+    session: &actix_session::Session,
+    pool: &crate::db::Pool,
+    role: Role,
+) -> actix_web::Result<CurrentUser> {
+    match user_from_session(session, pool).await.map_err(actix_web::error::ErrorInternalServerError)? {
+        Some(user) if user.has_role(role) => Ok(user),
+        Some(_) => Err(actix_web::error::ErrorForbidden("forbidden")),
+        None => Err(actix_web::error::ErrorUnauthorized("unauthorized")),
+    }
+}
+
+/// Creates the first administrator from `CAMARA_ADMIN_EMAIL` / `CAMARA_ADMIN_PASSWORD`
+/// when the database has no accounts yet. Returns the e-mail of the account created.
+#[cfg(feature = "ssr")]
+pub fn bootstrap_admin(pool: &crate::db::Pool) -> Result<Option<String>, String> {
+    let (Ok(email), Ok(password)) = (std::env::var("CAMARA_ADMIN_EMAIL"), std::env::var("CAMARA_ADMIN_PASSWORD")) else {
+        return Ok(None);
+    };
+    let conn = pool.get().map_err(|e| e.to_string())?;
+    if store::count(&conn).map_err(|e| e.to_string())? > 0 {
+        return Ok(None);
+    }
+    if password.chars().count() < password::MIN_LENGTH {
+        return Err(format!("CAMARA_ADMIN_PASSWORD must have at least {} characters", password::MIN_LENGTH));
+    }
+    let hash = password::hash(&password).map_err(|e| e.to_string())?;
+    let now = crate::content::events::now_local();
+    store::create(&conn, &email, "Administração", &hash, Role::Admin, now).map_err(|e| e.to_string())?;
+    Ok(Some(email))
+}
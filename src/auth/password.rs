@@ -0,0 +1,42 @@
+//! Argon2id password hashing with the crate's default parameters.
+
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+
+/// Shorter passwords are refused when creating an account.
+pub const MIN_LENGTH: usize = 10;
+
+/// PHC string (`$argon2id$v=19$...`) with a fresh random salt.
+pub fn hash(password: &str) -> Result<String, argon2::password_hash::Error> {
+    let salt = SaltString::generate(&mut OsRng);
+    Ok(Argon2::default().hash_password(password.as_bytes(), &salt)?.to_string())
+}
+
+/// Whether `password` matches the stored `hash`; a malformed hash never matches.
+pub fn verify(password: &str, hash: &str) -> bool {
+    PasswordHash::new(hash)
+        .map(|parsed| Argon2::default().verify_password(password.as_bytes(), &parsed).is_ok())
+        .unwrap_or(false)
+}
+
+/// Spends about as long as [`verify`] so unknown e-mails are not told apart by timing.
+pub fn verify_dummy(password: &str) {
+    static DUMMY: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+    let dummy = DUMMY.get_or_init(|| hash("dummy password").unwrap_or_default());
+    let _ = verify(password, dummy);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verifies_only_the_original_password() {
+        let stored = hash("roda de sábado").unwrap();
+        assert!(stored.starts_with("$argon2id$"));
+        assert!(verify("roda de sábado", &stored));
+        assert!(!verify("roda de domingo", &stored));
+        assert!(!verify("roda de sábado", "not a hash"));
+    }
+}
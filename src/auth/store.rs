@@ -0,0 +1,151 @@
+//! Reading and writing accounts in the local database.
+
+use chrono::NaiveDateTime;
+use rusqlite::{params, Connection, OptionalExtension, Row};
+
+use super::{Account, CurrentUser, Role};
+use crate::db::DbError;
+
+/// An account together with its password hash, for signing in.
+pub struct Credentials {
+    pub user: CurrentUser,
+    pub password_hash: String,
+}
+
+fn user_from_row(row: &Row) -> rusqlite::Result<CurrentUser> {
+    let role: String = row.get("role")?;
+    Ok(CurrentUser {
+        id: row.get("id")?,
+        name: row.get("name")?,
+        email: row.get("email")?,
+        role: Role::parse(&role).ok_or_else(|| {
+            rusqlite::Error::FromSqlConversionFailure(4, rusqlite::types::Type::Text, format!("unknown role {role:?}").into())
+        })?,
+    })
+}
+
+pub fn find_active(conn: &Connection, id: i64) -> Result<Option<CurrentUser>, DbError> {
+    Ok(conn
+        .query_row(
+            "SELECT id, name, email, role FROM users WHERE id = ?1 AND active = 1",
+            params![id],
+            user_from_row,
+        )
+        .optional()?)
+}
+
+pub fn credentials(conn: &Connection, email: &str) -> Result<Option<Credentials>, DbError> {
+    Ok(conn
+        .query_row(
+            "SELECT id, name, email, role, password_hash FROM users WHERE email = ?1 AND active = 1",
+            params![email.trim()],
+            |row| Ok(Credentials { user: user_from_row(row)?, password_hash: row.get("password_hash")? }),
+        )
+        .optional()?)
+}
+
+pub fn create(
+    conn: &Connection,
+    email: &str,
+    name: &str,
+    password_hash: &str,
+    role: Role,
+    now: NaiveDateTime,
+) -> Result<i64, DbError> {
+    conn.execute(
+        "INSERT INTO users (email, name, password_hash, role, created_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![email.trim(), name.trim(), password_hash, role.as_str(), now],
+    )?;
+    Ok(conn.last_insert_rowid())
+}
+
+fn account_from_row(row: &Row) -> rusqlite::Result<Account> {
+    Ok(Account {
+        user: user_from_row(row)?,
+        active: row.get("active")?,
+        last_login_at: row.get("last_login_at")?,
+    })
+}
+
+/// Every account, active ones first, by name.
+pub fn list(conn: &Connection) -> Result<Vec<Account>, DbError> {
+    let mut stmt = conn.prepare(
+        "SELECT id, name, email, role, active, last_login_at FROM users ORDER BY active DESC, name COLLATE NOCASE",
+    )?;
+    let accounts = stmt.query_map([], account_from_row)?.collect::<Result<_, _>>()?;
+    Ok(accounts)
+}
+
+/// An account whether active or not.
+pub fn find(conn: &Connection, id: i64) -> Result<Option<Account>, DbError> {
+    Ok(conn
+        .query_row(
+            "SELECT id, name, email, role, active, last_login_at FROM users WHERE id = ?1",
+            params![id],
+            account_from_row,
+        )
+        .optional()?)
+}
+
+/// Whether some account, active or not, already uses `email`.
+pub fn email_taken(conn: &Connection, email: &str) -> Result<bool, DbError> {
+    Ok(conn.query_row("SELECT EXISTS (SELECT 1 FROM users WHERE email = ?1)", params![email.trim()], |row| row.get(0))?)
+}
+
+pub fn set_role(conn: &Connection, id: i64, role: Role) -> Result<(), DbError> {
+    conn.execute("UPDATE users SET role = ?2 WHERE id = ?1", params![id, role.as_str()])?;
+    Ok(())
+}
+
+/// Deactivated accounts cannot sign in, and their sessions stop working at the next request.
+pub fn set_active(conn: &Connection, id: i64, active: bool) -> Result<(), DbError> {
+    conn.execute("UPDATE users SET active = ?2 WHERE id = ?1", params![id, active])?;
+    Ok(())
+}
+
+pub fn count(conn: &Connection) -> Result<i64, DbError> {
+    Ok(conn.query_row("SELECT COUNT(*) FROM users", [], |row| row.get(0))?)
+}
+
+pub fn touch_login(conn: &Connection, id: i64, now: NaiveDateTime) -> Result<(), DbError> {
+    conn.execute("UPDATE users SET last_login_at = ?2 WHERE id = ?1", params![id, now])?;
+    Ok(())
+}
+
+/// The active account matching `email` and `password`, recording the sign-in.
+///
+/// Runs the argon2 check, so call it off the async executor (see [`crate::db::with_conn`]).
+pub fn authenticate(conn: &Connection, email: &str, password: &str, now: NaiveDateTime) -> Result<Option<CurrentUser>, DbError> {
+    let Some(found) = credentials(conn, email)? else {
+        super::password::verify_dummy(password);
+        return Ok(None);
+    };
+    if !super::password::verify(password, &found.password_hash) {
+        return Ok(None);
+    }
+    touch_login(conn, found.user.id, now)?;
+    Ok(Some(found.user))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deactivated_accounts_are_listed_but_cannot_sign_in() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        crate::db::migrations::migrate(&mut conn).unwrap();
+        let now = chrono::NaiveDate::from_ymd_opt(2026, 11, 3).unwrap().and_hms_opt(9, 0, 0).unwrap();
+
+        let id = create(&conn, "mestre@example.com", "Mestre", "hash", Role::Voluntario, now).unwrap();
+        assert!(email_taken(&conn, "MESTRE@example.com").unwrap());
+        set_role(&conn, id, Role::Mestre).unwrap();
+        set_active(&conn, id, false).unwrap();
+
+        assert!(find_active(&conn, id).unwrap().is_none());
+        assert!(credentials(&conn, "mestre@example.com").unwrap().is_none());
+        let account = find(&conn, id).unwrap().unwrap();
+        assert_eq!((account.user.role, account.active), (Role::Mestre, false));
+        assert_eq!(list(&conn).unwrap(), vec![account]);
+    }
+}
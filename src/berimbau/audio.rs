@@ -0,0 +1,168 @@
+//! Berimbau sounds synthesized with Web Audio, and the playback loop.
+//!
+//! No samples are downloaded: dom and dim are a filtered sawtooth a tone
+//! apart with a plucked envelope, the chiado a short burst of band-passed
+//! noise.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::time::Duration;
+
+use leptos::prelude::{set_interval_with_handle, IntervalHandle};
+use wasm_bindgen::JsValue;
+use web_sys::{AudioBuffer, AudioContext, AudioScheduledSourceNode, BiquadFilterType, OscillatorType};
+
+use super::{Hit, Sequencer, Stroke, Toque};
+
+/// Open string of a gunga, roughly.
+const DONG_HZ: f32 = 110.0;
+/// One whole tone above [`DONG_HZ`].
+const DING_HZ: f32 = 123.5;
+/// How often the loop wakes up to schedule more strokes.
+const TICK: Duration = Duration::from_millis(25);
+/// How far ahead of the audio clock strokes are scheduled, in seconds.
+const LOOKAHEAD: f64 = 0.12;
+
+#[derive(Clone)]
+pub struct Synth {
+    ctx: AudioContext,
+    noise: AudioBuffer,
+}
+
+impl Synth {
+    pub fn new() -> Result<Synth, JsValue> {
+        let ctx = AudioContext::new()?;
+        let length = (ctx.sample_rate() * 0.1) as u32;
+        let noise = ctx.create_buffer(1, length, ctx.sample_rate())?;
+        let samples: Vec<f32> = (0..length).map(|_| (js_sys::Math::random() * 2.0 - 1.0) as f32).collect();
+        noise.copy_to_channel(&samples, 0)?;
+        Ok(Synth { ctx, noise })
+    }
+
+    /// Seconds on the audio clock, the time base of [`Sequencer`].
+    pub fn now(&self) -> f64 {
+        self.ctx.current_time()
+    }
+
+    /// Browsers start audio suspended until a user gesture; call from one.
+    pub fn resume(&self) {
+        let _ = self.ctx.resume();
+    }
+
+    pub fn play(&self, stroke: Stroke, at: f64) -> Result<(), JsValue> {
+        match stroke {
+            Stroke::Dong => self.pluck(DONG_HZ, at, 0.7),
+            Stroke::Ding => self.pluck(DING_HZ, at, 0.45),
+            Stroke::Chiado => self.buzz(at),
+            Stroke::Rest => Ok(()),
+        }
+    }
+
+    fn pluck(&self, frequency: f32, at: f64, decay: f64) -> Result<(), JsValue> {
+        let osc = self.ctx.create_oscillator()?;
+        osc.set_type(OscillatorType::Sawtooth);
+        osc.frequency().set_value(frequency);
+
+        let filter = self.ctx.create_biquad_filter()?;
+        filter.set_type(BiquadFilterType::Lowpass);
+        filter.frequency().set_value(1400.0);
+        filter.q().set_value(6.0);
+
+        let gain = self.ctx.create_gain()?;
+        gain.gain().set_value_at_time(0.0, at)?;
+        gain.gain().linear_ramp_to_value_at_time(0.5, at + 0.004)?;
+        gain.gain().exponential_ramp_to_value_at_time(0.001, at + decay)?;
+
+        osc.connect_with_audio_node(&filter)?;
+        filter.connect_with_audio_node(&gain)?;
+        gain.connect_with_audio_node(&self.ctx.destination())?;
+        osc.start_with_when(at)?;
+        osc.stop_with_when(at + decay + 0.05)
+    }
+
+    fn buzz(&self, at: f64) -> Result<(), JsValue> {
+        let source = self.ctx.create_buffer_source()?;
+        source.set_buffer(Some(&self.noise));
+
+        let filter = self.ctx.create_biquad_filter()?;
+        filter.set_type(BiquadFilterType::Bandpass);
+        filter.frequency().set_value(3200.0);
+        filter.q().set_value(1.5);
+
+        let gain = self.ctx.create_gain()?;
+        gain.gain().set_value_at_time(0.35, at)?;
+        gain.gain().exponential_ramp_to_value_at_time(0.001, at + 0.07)?;
+
+        source.connect_with_audio_node(&filter)?;
+        filter.connect_with_audio_node(&gain)?;
+        gain.connect_with_audio_node(&self.ctx.destination())?;
+        // Through the base class: the buffer source's own `stop` binding is deprecated.
+        let source: &AudioScheduledSourceNode = &source;
+        source.start_with_when(at)?;
+        source.stop_with_when(at + 0.08)
+    }
+}
+
+struct Run {
+    sequencer: Rc<RefCell<Sequencer>>,
+    interval: IntervalHandle,
+}
+
+/// Plays one toque at a time, reporting the step being heard to `on_step`.
+#[derive(Default)]
+pub struct Player {
+    synth: Option<Synth>,
+    run: Option<Run>,
+}
+
+impl Player {
+    pub fn start(&mut self, toque: &'static Toque, bpm: f64, on_step: impl Fn(Option<usize>) + 'static) -> Result<(), JsValue> {
+        self.stop();
+        let synth = match &self.synth {
+            Some(synth) => synth.clone(),
+            None => self.synth.insert(Synth::new()?).clone(),
+        };
+        synth.resume();
+
+        let sequencer = Rc::new(RefCell::new(Sequencer::new(toque, bpm, synth.now() + LOOKAHEAD)));
+        let pending: RefCell<VecDeque<Hit>> = RefCell::default();
+        let interval = set_interval_with_handle(
+            {
+                let sequencer = sequencer.clone();
+                move || {
+                    let now = synth.now();
+                    let mut pending = pending.borrow_mut();
+                    for hit in sequencer.borrow_mut().hits_until(now + LOOKAHEAD) {
+                        let _ = synth.play(hit.stroke, hit.time);
+                        pending.push_back(hit);
+                    }
+                    let mut heard = None;
+                    while pending.front().is_some_and(|hit| hit.time <= now) {
+                        heard = pending.pop_front();
+                    }
+                    if let Some(hit) = heard {
+                        on_step(Some(hit.step));
+                    }
+                }
+            },
+            TICK,
+        )?;
+
+        self.run = Some(Run { sequencer, interval });
+        Ok(())
+    }
+
+    pub fn set_bpm(&mut self, bpm: f64) {
+        if let Some(run) = &self.run {
+            run.sequencer.borrow_mut().set_bpm(bpm);
+        }
+    }
+
+    /// Stops the loop; strokes already scheduled (a tenth of a second) still sound.
+    pub fn stop(&mut self) {
+        if let Some(run) = self.run.take() {
+            run.interval.clear();
+        }
+    }
+}
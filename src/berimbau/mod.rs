@@ -0,0 +1,219 @@
+//! Toques de berimbau para o treinador em `/berimbau`.
+//!
+//! A toque is a cycle of [`Step`]s on an eighth-note grid; the tempo is given
+//! in quarter-note beats per minute. [`Sequencer`] turns the cycle into
+//! timed [`Hit`]s ahead of the playback clock, the usual look-ahead scheme
+//! for Web Audio: the browser side ([`audio`]) only asks for "everything
+//! until t" and schedules it, so timing never depends on timers firing on
+//! time.
+
+#[cfg(feature = "hydrate")]
+pub mod audio;
+
+/// The three sounds of the berimbau (plus silence).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stroke {
+    /// Open string ("dom").
+    Dong,
+    /// String pressed by the dobrão, one tone higher ("dim").
+    Ding,
+    /// Dobrão resting on the string, a buzz ("tch").
+    Chiado,
+    Rest,
+}
+
+impl Stroke {
+    /// Syllable shown on the timeline, as it is sung in class.
+    pub fn syllable(self) -> &'static str {
+        match self {
+            Stroke::Dong => "dom",
+            Stroke::Ding => "dim",
+            Stroke::Chiado => "tch",
+            Stroke::Rest => "·",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub stroke: Stroke,
+    /// Length in eighth notes.
+    pub length: u8,
+}
+
+const fn s(stroke: Stroke, length: u8) -> Step {
+    Step { stroke, length }
+}
+
+// Short names, so the patterns below read like the notation used in class.
+use Stroke::{Chiado as T, Ding as I, Dong as O, Rest as R};
+
+/// A rhythm played on the berimbau, as taught in the group's classes.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Toque {
+    pub slug: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Slowest, suggested and fastest tempo, in beats per minute.
+    pub tempo: (u32, u32, u32),
+    pub steps: &'static [Step],
+}
+
+pub const TOQUES: &[Toque] = &[
+    Toque {
+        slug: "angola",
+        name: "Angola",
+        description: "Lento e cadenciado, para o jogo baixo e malicioso da capoeira angola.",
+        tempo: (50, 66, 90),
+        steps: &[s(T, 1), s(T, 1), s(O, 2), s(I, 2), s(R, 2)],
+    },
+    Toque {
+        slug: "sao-bento-pequeno",
+        name: "São Bento Pequeno",
+        description: "O inverso da Angola: o dim vem antes do dom. Jogo de dentro, ainda compassado.",
+        tempo: (60, 76, 100),
+        steps: &[s(T, 1), s(T, 1), s(I, 2), s(O, 2), s(R, 2)],
+    },
+    Toque {
+        slug: "sao-bento-grande",
+        name: "São Bento Grande",
+        description: "Rápido e corrido, pede um jogo em pé, com golpes altos e floreios.",
+        tempo: (80, 104, 140),
+        steps: &[s(T, 1), s(T, 1), s(I, 2), s(O, 2), s(I, 2)],
+    },
+    Toque {
+        slug: "benguela",
+        name: "Benguela",
+        description: "Jogo mais perto do chão, sem pressa, com troca de golpes amistosa.",
+        tempo: (56, 72, 96),
+        steps: &[s(T, 1), s(T, 1), s(O, 2), s(I, 1), s(I, 1), s(R, 2)],
+    },
+    Toque {
+        slug: "iuna",
+        name: "Iúna",
+        description: "Toque sem canto, reservado a jogos de formados e mestres. Duas frases que se respondem.",
+        tempo: (50, 64, 84),
+        steps: &[
+            s(T, 1), s(T, 1), s(I, 2), s(O, 2), s(R, 2),
+            s(T, 1), s(T, 1), s(I, 2), s(O, 1), s(I, 1), s(O, 2),
+        ],
+    },
+    Toque {
+        slug: "cavalaria",
+        name: "Cavalaria",
+        description: "Imita o galope dos cavalos da polícia montada: era o aviso de que ela vinha chegando.",
+        tempo: (90, 120, 150),
+        steps: &[s(T, 1), s(O, 1), s(I, 2), s(T, 1), s(O, 1), s(I, 2)],
+    },
+];
+
+impl Toque {
+    pub fn find(slug: &str) -> Option<&'static Toque> {
+        TOQUES.iter().find(|t| t.slug == slug)
+    }
+
+    /// Length of one cycle, in eighth notes.
+    pub fn cycle_length(&self) -> u32 {
+        self.steps.iter().map(|s| s.length as u32).sum()
+    }
+
+    /// `bpm` limited to the toque's tempo range.
+    pub fn clamp_bpm(&self, bpm: f64) -> f64 {
+        bpm.clamp(self.tempo.0 as f64, self.tempo.2 as f64)
+    }
+}
+
+/// Seconds per eighth note at `bpm` quarter notes per minute.
+fn unit_seconds(bpm: f64) -> f64 {
+    30.0 / bpm
+}
+
+/// A step of the cycle due at `time` (seconds on the playback clock).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hit {
+    /// Index in [`Toque::steps`].
+    pub step: usize,
+    pub stroke: Stroke,
+    pub time: f64,
+}
+
+/// Loops a toque from a start time, handing out hits as the clock advances.
+#[derive(Debug, Clone)]
+pub struct Sequencer {
+    toque: &'static Toque,
+    bpm: f64,
+    next_step: usize,
+    next_time: f64,
+}
+
+impl Sequencer {
+    pub fn new(toque: &'static Toque, bpm: f64, start: f64) -> Self {
+        Sequencer { toque, bpm: toque.clamp_bpm(bpm), next_step: 0, next_time: start }
+    }
+
+    pub fn toque(&self) -> &'static Toque {
+        self.toque
+    }
+
+    pub fn bpm(&self) -> f64 {
+        self.bpm
+    }
+
+    /// Changes the tempo from the next step on; hits already handed out keep their time.
+    pub fn set_bpm(&mut self, bpm: f64) {
+        self.bpm = self.toque.clamp_bpm(bpm);
+    }
+
+    /// Every hit starting before `until`, rests included, in order.
+    pub fn hits_until(&mut self, until: f64) -> Vec<Hit> {
+        let mut hits = Vec::new();
+        while self.next_time < until {
+            let step = self.toque.steps[self.next_step];
+            hits.push(Hit { step: self.next_step, stroke: step.stroke, time: self.next_time });
+            self.next_time += step.length as f64 * unit_seconds(self.bpm);
+            self.next_step = (self.next_step + 1) % self.toque.steps.len();
+        }
+        hits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toques_are_well_formed() {
+        for toque in TOQUES {
+            let (min, default, max) = toque.tempo;
+            assert!(min <= default && default <= max, "{}", toque.slug);
+            assert!(toque.steps.iter().all(|s| s.length > 0), "{}", toque.slug);
+            // Every toque fills whole 2/4 bars.
+            assert_eq!(toque.cycle_length() % 4, 0, "{}", toque.slug);
+            assert_eq!(Toque::find(toque.slug), Some(toque));
+        }
+    }
+
+    #[test]
+    fn sequences_loops_and_tempo_changes() {
+        let angola = Toque::find("angola").unwrap();
+        // 60 bpm: an eighth note lasts half a second, the cycle four seconds.
+        let mut sequencer = Sequencer::new(angola, 60.0, 10.0);
+        let hits = sequencer.hits_until(14.0);
+        let times: Vec<f64> = hits.iter().map(|h| h.time).collect();
+        assert_eq!(times, [10.0, 10.5, 11.0, 12.0, 13.0]);
+        assert_eq!(hits.iter().map(|h| h.stroke).collect::<Vec<_>>(), [T, T, O, I, R]);
+
+        // Nothing is handed out twice; the cycle starts over after the rest.
+        assert!(sequencer.hits_until(14.0).is_empty());
+        let next = sequencer.hits_until(14.2);
+        assert_eq!(next, [Hit { step: 0, stroke: T, time: 14.0 }]);
+
+        // A new tempo applies from the next step on, within the toque's range.
+        sequencer.set_bpm(120.0);
+        assert_eq!(sequencer.bpm(), 90.0, "limited to the toque's range");
+        sequencer.set_bpm(80.0);
+        let hits = sequencer.hits_until(15.0);
+        assert_eq!(hits[0].time, 14.5);
+        assert_eq!(hits[1].time, 14.5 + 30.0 / 80.0);
+    }
+}
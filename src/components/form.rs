@@ -0,0 +1,24 @@
+use leptos::prelude::*;
+
+/// Text inputs, selects and textareas across the site's forms.
+pub const INPUT_STYLE: &str = "width: 100%; box-sizing: border-box; padding: 0.7rem 1rem; border-radius: 8px; border: 1px solid #ccc; font-size: 1rem; font-family: inherit;";
+
+/// Label above its control, with an optional help line below.
+#[component]
+pub fn LabeledField(
+    children: Children,
+    #[prop(into)] label: String,
+    #[prop(default = None)] hint: Option<&'static str>,
+    #[prop(optional)] required: bool,
+) -> impl IntoView {
+    view! {
+        <label style="display: flex; flex-direction: column; gap: 0.4rem; font-weight: 600; color: #444;">
+            <span>
+                {label}
+                {required.then(|| view! { <span style="color: #b5431f;">" *"</span> })}
+            </span>
+            {children()}
+            {hint.map(|h| view! { <span style="font-weight: 400; font-size: 0.85rem; color: #777;">{h}</span> })}
+        </label>
+    }
+}
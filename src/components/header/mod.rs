@@ -2,11 +2,14 @@ use leptos::prelude::*;
 use leptos_router::components::A;
 use crate::components::stacks::hstack::{HStack, HStackItem, AlignItems as HAlign, JustifyContent as HJustify};
 use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::i18n::{use_locale, LanguageSwitcher};
 
 /// The main Header component for the application.
 #[component]
 pub fn Header() -> impl IntoView {
     let (is_open, set_is_open) = signal(false);
+    let locale = use_locale();
+    let t = locale.catalog();
 
     // Design Tokens
     let primary_bg = "#332175";
@@ -38,10 +41,10 @@ pub fn Header() -> impl IntoView {
             >
                 // --- LEFT: Logo ---
                 <HStackItem style="flex: 1">
-                    <A href="/" attr:style="display: flex; align-items: center; text-decoration: none;">
+                    <A href=locale.href("/") attr:style="display: flex; align-items: center; text-decoration: none;">
                         <img 
                             src="/assets/logo.svg" 
-                            alt="Logotipo da Associação Camará Capoeira" 
+                            alt=t.logo_alt
                             style="height: 36px; width: auto; object-fit: contain;"
                         />
                     </A>
@@ -62,7 +65,7 @@ pub fn Header() -> impl IntoView {
                         ", nav_text_color())
                     >
                         <A
-                            href="/projetos"
+                            href=locale.href("/projetos")
                             attr:style="
                                 text-decoration: none;
                                 font-weight: 700;
@@ -72,7 +75,7 @@ pub fn Header() -> impl IntoView {
                                 text-transform: uppercase;
                             "
                         >
-                            "PROJETOS"
+                            {t.nav.projects}
                         </A>
 
                         // Caret Button
@@ -91,7 +94,7 @@ pub fn Header() -> impl IntoView {
                                 align-items: center;
                                 color: inherit;
                             "
-                            aria-label="Toggle Dropdown"
+                            aria-label=t.nav.toggle_menu
                         >
                             <svg
                                 width="16"
@@ -113,9 +116,9 @@ pub fn Header() -> impl IntoView {
                     </div>
                 </HStackItem>
 
-                // --- RIGHT: Spacer (Balances the logo on the left to keep nav centered) ---
+                // --- RIGHT: Language switcher (same width as the logo side, keeping nav centered) ---
                 <HStackItem style="flex: 1">
-                    <div />
+                    <LanguageSwitcher />
                 </HStackItem>
             </HStack>
 
@@ -140,7 +143,7 @@ pub fn Header() -> impl IntoView {
                     spacing="1rem".to_string()
                 >
                     <A
-                        href="/projetos/ponto-de-cultura"
+                        href=locale.href("/projetos/ponto-de-cultura")
                         attr:style=format!("
                             display: block;
                             color: white;
@@ -148,12 +151,13 @@ pub fn Header() -> impl IntoView {
                             font-weight: 600;
                             font-size: 1.1rem;
                             transition: color 0.2s ease;
+                            text-transform: uppercase;
                         ")
                     >
                         // Inline style for hover effect simulation in pure styling
                         // Note: Real hover states usually require CSS classes or state tracking.
                         // Since this is inline, it stays white, but fits the palette.
-                        "PONTO DE CULTURA"
+                        {t.nav.ponto_de_cultura}
                     </A>
                 </VStack>
             </div>
@@ -1,4 +1,6 @@
 pub mod stacks;
 pub mod responsive_view;
 pub mod header;
-pub mod stacking_container;
\ No newline at end of file
+pub mod stacking_container;
+pub mod form;
+pub mod page_layout;pub mod seo;
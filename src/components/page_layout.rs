@@ -0,0 +1,43 @@
+use leptos::prelude::*;
+use crate::components::header::Header;
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::routes::home::footer_section::FooterSection;
+
+/// Shared frame for inner pages: sticky header, a title banner, the page body
+/// on a light background and the site footer.
+#[component]
+pub fn PageLayout(
+    children: Children,
+    #[prop(into)] title: String,
+    #[prop(optional, into)] subtitle: Option<String>,
+) -> impl IntoView {
+    let primary_bg = "#332175";
+    let accent_color = "#f2e300";
+    let gray_light = "#f4f4f9";
+
+    view! {
+        <div style="position: sticky; top: 0; z-index: 1000;">
+            <Header />
+        </div>
+
+        <section style=format!("background-color: {}; color: white; padding: 4rem 2rem 3rem;", primary_bg)>
+            <VStack spacing="1rem".to_string() align=VAlign::Center>
+                <h1 style="font-size: 2.6rem; font-weight: 800; margin: 0; font-family: 'Zalando Sans Expanded', sans-serif;">
+                    {title}
+                </h1>
+                <div style=format!("width: 80px; height: 6px; background-color: {};", accent_color)></div>
+                {subtitle.map(|s| view! {
+                    <p style="font-size: 1.1rem; max-width: 700px; line-height: 1.6; opacity: 0.9; margin: 0;">{s}</p>
+                })}
+            </VStack>
+        </section>
+
+        <div style=format!("background-color: {}; padding: 3rem 2rem 5rem; min-height: 50vh;", gray_light)>
+            <div style="max-width: 1100px; margin: 0 auto; text-align: left;">
+                {children()}
+            </div>
+        </div>
+
+        <FooterSection />
+    }
+}
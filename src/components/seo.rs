@@ -0,0 +1,247 @@
+//! Metadados para buscadores e pré-visualizações de links.
+//!
+//! [`Seo`] replaces a bare `<Title>` on public pages: besides the document
+//! title it sets the description, the canonical URL, the Open Graph and
+//! Twitter card tags read by WhatsApp, Facebook and friends, and a JSON-LD
+//! graph with the [`organization`] plus, on event and news pages, the
+//! [`event`] or [`news_article`] itself. Staff pages keep a plain `<Title>`.
+
+use leptos::prelude::*;
+use leptos_meta::{Link, Meta, Script, Title};
+use leptos_router::hooks::use_location;
+use serde_json::{json, Value};
+
+use crate::content::events::{Event, UTC_OFFSET};
+use crate::content::news::NewsArticle;
+use crate::i18n::{self, use_locale, Locale};
+
+/// Picture of a page that has none of its own.
+pub const DEFAULT_IMAGE: &str = "/assets/acervo/inicio/20251116_110228.jpg";
+
+pub const EMAIL: &str = "contato@camaracapoeira.org.br";
+
+/// Endereço da sede, mostrado no rodapé e no JSON-LD.
+#[derive(Debug, Clone, Copy)]
+pub struct Address {
+    pub street: &'static str,
+    pub neighbourhood: &'static str,
+    pub city: &'static str,
+    pub region: &'static str,
+    pub postal_code: &'static str,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+pub const ADDRESS: Address = Address {
+    street: "Rua São Judas Tadeu, nº 663",
+    neighbourhood: "Parque dos Ipês II",
+    city: "Ponta Porã",
+    region: "MS",
+    postal_code: "79900-000",
+    latitude: -22.507296,
+    longitude: -55.737256,
+};
+
+/// `og:type` of a page.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PageKind {
+    #[default]
+    Website,
+    Article,
+}
+
+impl PageKind {
+    fn og_type(self) -> &'static str {
+        match self {
+            PageKind::Website => "website",
+            PageKind::Article => "article",
+        }
+    }
+}
+
+/// Title, description, canonical link, link previews and JSON-LD of a page.
+///
+/// `title` is the page's own name; the site name is appended, and the home
+/// page leaves it out to get the site name alone. `image` may be site-relative;
+/// the canonical URL is the current path in the current locale.
+#[component]
+pub fn Seo(
+    #[prop(optional, into)] title: Option<String>,
+    #[prop(into)] description: String,
+    #[prop(optional_no_strip)] image: Option<String>,
+    #[prop(optional)] kind: PageKind,
+    /// The page's main item ([`event`], [`news_article`]), next to the organization.
+    #[prop(optional)]
+    json_ld: Option<Value>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let t = locale.catalog();
+    let origin = i18n::site_origin();
+    let canonical = format!("{origin}{}", use_location().pathname.get_untracked());
+    let image = absolute(&origin, image.as_deref().unwrap_or(DEFAULT_IMAGE));
+    let document_title = title.as_deref().map_or_else(|| t.site_name.to_string(), |title| t.title(title));
+    let card_title = title.unwrap_or_else(|| t.site_name.to_string());
+
+    let mut graph = vec![organization(&origin, locale)];
+    graph.extend(json_ld);
+    let json_ld = script_json(&json!({ "@context": "https://schema.org", "@graph": graph }));
+
+    view! {
+        <Title text=document_title/>
+        <Meta name="description" content=description.clone()/>
+        <Link rel="canonical" href=canonical.clone()/>
+
+        <Meta property="og:site_name" content=t.site_name/>
+        <Meta property="og:locale" content=og_locale(locale)/>
+        <Meta property="og:type" content=kind.og_type()/>
+        <Meta property="og:title" content=card_title.clone()/>
+        <Meta property="og:description" content=description.clone()/>
+        <Meta property="og:url" content=canonical/>
+        <Meta property="og:image" content=image.clone()/>
+
+        <Meta name="twitter:card" content="summary_large_image"/>
+        <Meta name="twitter:title" content=card_title/>
+        <Meta name="twitter:description" content=description/>
+        <Meta name="twitter:image" content=image/>
+
+        <Script type_="application/ld+json">{json_ld}</Script>
+    }
+}
+
+/// The association itself, referenced by `@id` from events and articles.
+pub fn organization(origin: &str, locale: Locale) -> Value {
+    json!({
+        "@type": "SportsOrganization",
+        "@id": format!("{origin}/#organizacao"),
+        "name": locale.catalog().site_name,
+        "description": locale.catalog().home.lead,
+        "url": format!("{origin}{}", locale.href("/")),
+        "logo": format!("{origin}/assets/logo.svg"),
+        "email": EMAIL,
+        "sport": "Capoeira",
+        "address": postal_address(),
+        "geo": {
+            "@type": "GeoCoordinates",
+            "latitude": ADDRESS.latitude,
+            "longitude": ADDRESS.longitude,
+        },
+    })
+}
+
+fn postal_address() -> Value {
+    json!({
+        "@type": "PostalAddress",
+        "streetAddress": format!("{}, {}", ADDRESS.street, ADDRESS.neighbourhood),
+        "addressLocality": ADDRESS.city,
+        "addressRegion": ADDRESS.region,
+        "postalCode": ADDRESS.postal_code,
+        "addressCountry": "BR",
+    })
+}
+
+/// schema.org `Event` for `/agenda/<slug>`.
+pub fn event(origin: &str, event: &Event) -> Value {
+    let datetime = |at: chrono::NaiveDateTime| format!("{}{UTC_OFFSET}", at.format("%Y-%m-%dT%H:%M:%S"));
+    let mut place = json!({ "@type": "Place", "name": event.location });
+    if let Some(address) = &event.address {
+        place["address"] = json!(address);
+    }
+
+    let mut value = json!({
+        "@type": "Event",
+        "name": event.title,
+        "startDate": datetime(event.start),
+        "eventStatus": "https://schema.org/EventScheduled",
+        "eventAttendanceMode": "https://schema.org/OfflineEventAttendanceMode",
+        "location": place,
+        "image": absolute(origin, &event.share_image()),
+        "organizer": { "@id": format!("{origin}/#organizacao") },
+        "url": format!("{origin}/agenda/{}", event.slug),
+    });
+    if let Some(end) = event.end {
+        value["endDate"] = json!(datetime(end));
+    }
+    if !event.description.is_empty() {
+        value["description"] = json!(event.description);
+    }
+    value
+}
+
+/// schema.org `NewsArticle` for `/noticias/<slug>`.
+pub fn news_article(origin: &str, article: &NewsArticle) -> Value {
+    let organization = json!({ "@id": format!("{origin}/#organizacao") });
+    json!({
+        "@type": "NewsArticle",
+        "headline": article.title,
+        "description": article.summary,
+        "datePublished": article.date.format("%Y-%m-%d").to_string(),
+        "image": absolute(origin, article.cover.as_deref().unwrap_or(DEFAULT_IMAGE)),
+        "author": organization,
+        "publisher": organization,
+        "mainEntityOfPage": format!("{origin}{}", article.href()),
+        "inLanguage": Locale::PtBr.tag(),
+    })
+}
+
+/// Open Graph wants `language_TERRITORY`.
+fn og_locale(locale: Locale) -> &'static str {
+    match locale {
+        Locale::PtBr => "pt_BR",
+        Locale::Es => "es_LA",
+        Locale::En => "en_US",
+    }
+}
+
+fn absolute(origin: &str, src: &str) -> String {
+    if src.starts_with('/') {
+        format!("{origin}{src}")
+    } else {
+        src.to_string()
+    }
+}
+
+/// JSON for an inline `<script>`, whose text is not escaped: a `</script>`
+/// inside some content must not end the tag.
+fn script_json(value: &Value) -> String {
+    value.to_string().replace("</", "<\\/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn event_dates_carry_the_local_offset() {
+        let start = NaiveDate::from_ymd_opt(2026, 11, 21).unwrap().and_hms_opt(19, 0, 0).unwrap();
+        let event = Event {
+            slug: "batizado-2026".into(),
+            title: "Batizado 2026".into(),
+            kind: crate::content::events::EventKind::Batizado,
+            start,
+            end: None,
+            location: "Ginásio".into(),
+            address: None,
+            description: String::new(),
+        };
+        let value = super::event("https://camara.org", &event);
+
+        assert_eq!(value["startDate"], "2026-11-21T19:00:00-04:00");
+        assert_eq!(value["url"], "https://camara.org/agenda/batizado-2026");
+        assert!(value.get("endDate").is_none());
+        assert!(value["location"].get("address").is_none());
+    }
+
+    #[test]
+    fn script_text_cannot_close_the_tag() {
+        let json = script_json(&json!({ "headline": "</script><script>alert(1)" }));
+        assert!(!json.contains("</"));
+        assert_eq!(serde_json::from_str::<Value>(&json).unwrap()["headline"], "</script><script>alert(1)");
+    }
+
+    #[test]
+    fn images_become_absolute() {
+        assert_eq!(absolute("https://camara.org", "/assets/a.jpg"), "https://camara.org/assets/a.jpg");
+        assert_eq!(absolute("https://camara.org", "https://cdn.org/a.jpg"), "https://cdn.org/a.jpg");
+    }
+}
@@ -0,0 +1,47 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::dates;
+use crate::i18n::Locale;
+
+/// A photo album of the group's archive (`/galeria`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Album {
+    pub slug: String,
+    pub title: String,
+    pub date: NaiveDate,
+    #[serde(default)]
+    pub description: String,
+    /// Image paths under `/assets/acervo/`, in display order. The first one is the cover.
+    #[serde(default)]
+    pub photos: Vec<String>,
+}
+
+impl Album {
+    pub fn href(&self) -> String {
+        format!("/galeria/{}", self.slug)
+    }
+
+    pub fn date_label(&self, locale: Locale) -> String {
+        dates::long_date(self.date, locale)
+    }
+
+    pub fn cover(&self) -> Option<&str> {
+        self.photos.first().map(String::as_str)
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Default, Deserialize)]
+struct AlbumsFile {
+    #[serde(default)]
+    albums: Vec<Album>,
+}
+
+/// Loads `content/albums.toml`, newest album first.
+#[cfg(feature = "ssr")]
+pub fn load_albums() -> Result<Vec<Album>, super::ContentError> {
+    let mut albums = super::load_toml::<AlbumsFile>("albums.toml")?.albums;
+    albums.sort_by_key(|a| std::cmp::Reverse(a.date));
+    Ok(albums)
+}
@@ -0,0 +1,221 @@
+use serde::{Deserialize, Serialize};
+
+/// The four kinds of song sung in the roda, in the order they come in a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SongKind {
+    Ladainha,
+    Quadra,
+    Chula,
+    Corrido,
+}
+
+impl SongKind {
+    pub const ALL: [SongKind; 4] = [SongKind::Ladainha, SongKind::Quadra, SongKind::Chula, SongKind::Corrido];
+
+    /// Value used in the content file and in `?tipo=`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SongKind::Ladainha => "ladainha",
+            SongKind::Quadra => "quadra",
+            SongKind::Chula => "chula",
+            SongKind::Corrido => "corrido",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        SongKind::ALL.into_iter().find(|k| k.as_str() == value)
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SongKind::Ladainha => "Ladainha",
+            SongKind::Quadra => "Quadra",
+            SongKind::Chula => "Chula",
+            SongKind::Corrido => "Corrido",
+        }
+    }
+
+    /// Plural, for section headings.
+    pub fn plural(self) -> &'static str {
+        match self {
+            SongKind::Ladainha => "Ladainhas",
+            SongKind::Quadra => "Quadras",
+            SongKind::Chula => "Chulas",
+            SongKind::Corrido => "Corridos",
+        }
+    }
+}
+
+/// A song of the library (`/cantigas`).
+///
+/// In `lyrics`, stanzas are separated by a blank line and a line starting
+/// with `>` is the coro's response; every other line is sung by the soloist.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Song {
+    pub slug: String,
+    pub title: String,
+    pub kind: SongKind,
+    pub lyrics: String,
+    /// Composer, when known ("Mestre Toni Vargas").
+    #[serde(default)]
+    pub author: Option<String>,
+    /// Where the song comes from when there is no known author ("Domínio público").
+    #[serde(default)]
+    pub tradition: Option<String>,
+    /// Recording under `/assets/cantigas/`.
+    #[serde(default)]
+    pub audio: Option<String>,
+    /// Meaning of words, context, when it is sung.
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+/// Who sings a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Voice {
+    Solo,
+    Coro,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LyricLine {
+    pub voice: Voice,
+    pub text: String,
+}
+
+impl Song {
+    pub fn href(&self) -> String {
+        format!("/cantigas/{}", self.slug)
+    }
+
+    /// "Mestre Toni Vargas", "Domínio público" or both.
+    pub fn credit(&self) -> Option<String> {
+        match (&self.author, &self.tradition) {
+            (Some(author), Some(tradition)) => Some(format!("{author} · {tradition}")),
+            (Some(credit), None) | (None, Some(credit)) => Some(credit.clone()),
+            (None, None) => None,
+        }
+    }
+
+    /// Lyrics split into stanzas of marked lines.
+    pub fn stanzas(&self) -> Vec<Vec<LyricLine>> {
+        self.lyrics
+            .split("\n\n")
+            .map(|stanza| {
+                stanza
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .map(|line| match line.strip_prefix('>') {
+                        Some(response) => LyricLine { voice: Voice::Coro, text: response.trim().to_string() },
+                        None => LyricLine { voice: Voice::Solo, text: line.to_string() },
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .filter(|stanza| !stanza.is_empty())
+            .collect()
+    }
+
+    /// First line of the lyrics, shown in listings.
+    pub fn first_line(&self) -> String {
+        self.stanzas().first().and_then(|s| s.first()).map(|l| l.text.clone()).unwrap_or_default()
+    }
+}
+
+/// Lowercases and strips accents, so "Iê, Camará" matches "ie camara".
+fn fold(text: &str) -> String {
+    text.chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ã' | 'ä' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            'ñ' => 'n',
+            c if c.is_alphanumeric() => c,
+            _ => ' ',
+        })
+        .collect()
+}
+
+/// Songs of `kind` (all kinds for `None`) matching every word of `query`,
+/// ignoring case, accents and punctuation.
+///
+/// Matches in the title come first; otherwise the content file order is kept.
+pub fn search(songs: Vec<Song>, kind: Option<SongKind>, query: &str) -> Vec<Song> {
+    let terms: Vec<String> = fold(query).split_whitespace().map(str::to_string).collect();
+    let mut found: Vec<(bool, Song)> = songs
+        .into_iter()
+        .filter(|song| kind.is_none_or(|k| song.kind == k))
+        .filter_map(|song| {
+            let title = fold(&song.title);
+            let text = [Some(&song.lyrics), song.author.as_ref(), song.tradition.as_ref(), song.notes.as_ref()]
+                .into_iter()
+                .flatten()
+                .map(|t| fold(t))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let matches = terms.iter().all(|t| title.contains(t.as_str()) || text.contains(t.as_str()));
+            matches.then(|| (terms.iter().any(|t| title.contains(t.as_str())), song))
+        })
+        .collect();
+    found.sort_by_key(|(in_title, _)| !in_title);
+    found.into_iter().map(|(_, song)| song).collect()
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Default, Deserialize)]
+struct CantigasFile {
+    #[serde(default)]
+    songs: Vec<Song>,
+}
+
+/// Loads `content/cantigas.toml`, in file order.
+#[cfg(feature = "ssr")]
+pub fn load_songs() -> Result<Vec<Song>, super::ContentError> {
+    Ok(super::load_toml::<CantigasFile>("cantigas.toml")?.songs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn song(slug: &str, title: &str, kind: SongKind, lyrics: &str) -> Song {
+        Song {
+            slug: slug.to_string(),
+            title: title.to_string(),
+            kind,
+            lyrics: lyrics.to_string(),
+            author: None,
+            tradition: Some("Domínio público".to_string()),
+            audio: None,
+            notes: None,
+        }
+    }
+
+    #[test]
+    fn marks_responses_and_searches_without_accents() {
+        let songs = vec![
+            song("paranaue", "Paranauê", SongKind::Corrido, "Paranauê, paranauê, paraná\n> Paranauê, paranauê, paraná\n\nVou dizer à minha mulher\n> Paraná\nViva meu mestre\n> Paraná"),
+            song("ie-viva-meu-deus", "Iê, viva meu Deus", SongKind::Chula, "Iê, viva meu Deus\n> Iê, viva meu Deus, camará"),
+        ];
+
+        let stanzas = songs[0].stanzas();
+        assert_eq!(stanzas.len(), 2);
+        assert_eq!(stanzas[0][1], LyricLine { voice: Voice::Coro, text: "Paranauê, paranauê, paraná".to_string() });
+        assert_eq!(stanzas[1][0].voice, Voice::Solo);
+        assert_eq!(songs[1].first_line(), "Iê, viva meu Deus");
+
+        let slugs = |found: Vec<Song>| found.into_iter().map(|s| s.slug).collect::<Vec<_>>();
+        assert_eq!(slugs(search(songs.clone(), None, "CAMARA")), ["ie-viva-meu-deus"]);
+        assert_eq!(slugs(search(songs.clone(), None, "mulher parana")), ["paranaue"]);
+        // Title matches first, then file order.
+        assert_eq!(slugs(search(songs.clone(), None, "viva")), ["ie-viva-meu-deus", "paranaue"]);
+        assert_eq!(slugs(search(songs.clone(), None, "publico")), ["paranaue", "ie-viva-meu-deus"]);
+        assert_eq!(slugs(search(songs.clone(), Some(SongKind::Chula), "")), ["ie-viva-meu-deus"]);
+        assert!(search(songs, Some(SongKind::Ladainha), "").is_empty());
+    }
+}
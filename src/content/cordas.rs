@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    Infantil,
+    Aluno,
+    Graduado,
+    Mestre,
+}
+
+impl Stage {
+    pub fn label(self) -> &'static str {
+        match self {
+            Stage::Infantil => "Infantil",
+            Stage::Aluno => "Aluno(a)",
+            Stage::Graduado => "Graduado(a)",
+            Stage::Mestre => "Mestre",
+        }
+    }
+}
+
+/// One level of the group's graduation system.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Corda {
+    /// Stable identifier stored in the graduation history.
+    pub slug: String,
+    pub name: String,
+    /// Hex colors, one per strand; a single color for a plain corda.
+    pub colors: Vec<String>,
+    pub stage: Stage,
+}
+
+impl Corda {
+    /// CSS background showing each color as an equal hard-edged band.
+    pub fn background(&self) -> String {
+        match self.colors.as_slice() {
+            [] => "#ccc".to_string(),
+            [color] => color.clone(),
+            colors => {
+                let step = 100.0 / colors.len() as f32;
+                let stops: Vec<String> = colors
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| format!("{c} {:.1}% {:.1}%", step * i as f32, step * (i + 1) as f32))
+                    .collect();
+                format!("linear-gradient(90deg, {})", stops.join(", "))
+            }
+        }
+    }
+}
+
+/// The cordas in ascending order, as listed in `content/cordas.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CordaSystem {
+    #[serde(default)]
+    pub cordas: Vec<Corda>,
+}
+
+impl CordaSystem {
+    pub fn get(&self, slug: &str) -> Option<&Corda> {
+        self.cordas.iter().find(|c| c.slug == slug)
+    }
+
+    /// Position in the system; higher is more advanced.
+    pub fn rank(&self, slug: &str) -> Option<usize> {
+        self.cordas.iter().position(|c| c.slug == slug)
+    }
+
+    /// The corda that follows `current`, or the first one for a student without a corda.
+    pub fn next_after(&self, current: Option<&str>) -> Option<&Corda> {
+        match current {
+            None => self.cordas.first(),
+            Some(slug) => self.rank(slug).and_then(|i| self.cordas.get(i + 1)),
+        }
+    }
+}
+
+/// Loads the graduation system from `content/cordas.toml`.
+#[cfg(feature = "ssr")]
+pub fn load_cordas() -> Result<CordaSystem, super::ContentError> {
+    super::load_toml("cordas.toml")
+}
@@ -0,0 +1,83 @@
+//! Datas e horários como aparecem nas páginas.
+//!
+//! Names come from the [`Catalog`](crate::i18n::Catalog) of the locale; the
+//! numeric forms (`15/11`, `15/11/2026`) read the same in every language the
+//! site has and take no locale.
+
+use chrono::{Datelike, NaiveDate, NaiveTime, Timelike, Weekday};
+
+use crate::i18n::Locale;
+
+/// Nome do dia da semana ("sábado", "Saturday").
+pub fn weekday_name(weekday: Weekday, locale: Locale) -> &'static str {
+    locale.catalog().dates.weekdays[weekday.num_days_from_monday() as usize]
+}
+
+/// Nome do mês, com `month` entre 1 e 12.
+pub fn month_name(month: u32, locale: Locale) -> &'static str {
+    locale.catalog().dates.months[month.clamp(1, 12) as usize - 1]
+}
+
+/// Abreviação de três letras do mês ("NOV"), usada nos selos de data.
+pub fn month_abbr(month: u32, locale: Locale) -> String {
+    month_name(month, locale).chars().take(3).collect::<String>().to_uppercase()
+}
+
+/// "sábado, 21 de novembro de 2026", "Saturday, 21 November 2026"
+pub fn long_date(date: NaiveDate, locale: Locale) -> String {
+    format!("{}, {}", weekday_name(date.weekday(), locale), medium_date(date, locale))
+}
+
+/// "19h" ou "19h30"; "19:00" in English.
+pub fn short_time(time: NaiveTime, locale: Locale) -> String {
+    match locale {
+        Locale::En => format!("{}:{:02}", time.hour(), time.minute()),
+        _ if time.minute() == 0 => format!("{}h", time.hour()),
+        _ => format!("{}h{:02}", time.hour(), time.minute()),
+    }
+}
+
+/// "15/11"
+pub fn day_month(date: NaiveDate) -> String {
+    format!("{:02}/{:02}", date.day(), date.month())
+}
+
+/// Nome curto do dia, usado nos cabeçalhos da grade ("Segunda", "Sábado").
+pub fn weekday_short(weekday: Weekday, locale: Locale) -> &'static str {
+    locale.catalog().dates.weekdays_short[weekday.num_days_from_monday() as usize]
+}
+
+/// "21 de novembro de 2026", "21 November 2026"
+pub fn medium_date(date: NaiveDate, locale: Locale) -> String {
+    let month = month_name(date.month(), locale);
+    match locale {
+        Locale::En => format!("{} {} {}", date.day(), month, date.year()),
+        Locale::PtBr | Locale::Es => format!("{} de {} de {}", date.day(), month, date.year()),
+    }
+}
+
+/// "15/11/2026"
+pub fn day_month_year(date: NaiveDate) -> String {
+    format!("{:02}/{:02}/{}", date.day(), date.month(), date.year())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_and_formats_follow_the_locale() {
+        let date = NaiveDate::from_ymd_opt(2026, 11, 21).unwrap();
+        assert_eq!(long_date(date, Locale::PtBr), "sábado, 21 de novembro de 2026");
+        assert_eq!(long_date(date, Locale::Es), "sábado, 21 de noviembre de 2026");
+        assert_eq!(long_date(date, Locale::En), "Saturday, 21 November 2026");
+        assert_eq!(month_abbr(3, Locale::PtBr), "MAR");
+        assert_eq!(month_abbr(1, Locale::Es), "ENE");
+        assert_eq!(weekday_short(Weekday::Mon, Locale::PtBr), "Segunda");
+
+        let time = NaiveTime::from_hms_opt(19, 30, 0).unwrap();
+        assert_eq!(short_time(time, Locale::PtBr), "19h30");
+        assert_eq!(short_time(NaiveTime::from_hms_opt(19, 0, 0).unwrap(), Locale::Es), "19h");
+        assert_eq!(short_time(time, Locale::En), "19:30");
+    }
+}
@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+
+use super::money::Money;
+
+/// What `/apoie` needs to render: suggested values and, when a PIX key is
+/// configured, the charge for the selected amount.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Donation {
+    pub suggested: Vec<Money>,
+    pub amount: Option<Money>,
+    /// `None` while `pix_key` is not set in `content/donations.toml`.
+    pub charge: Option<PixCharge>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PixCharge {
+    /// BR Code "copia e cola" string.
+    pub payload: String,
+    /// QR code for `payload`, as inline SVG markup.
+    pub qr_svg: String,
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Clone, Copy, Deserialize)]
+struct Reais(#[serde(deserialize_with = "Money::from_reais")] Money);
+
+/// Contents of `content/donations.toml`.
+#[cfg(feature = "ssr")]
+#[derive(Debug, Deserialize)]
+pub struct DonationSettings {
+    #[serde(default)]
+    pub pix_key: String,
+    #[serde(default = "default_merchant_name")]
+    pub merchant_name: String,
+    #[serde(default = "default_merchant_city")]
+    pub merchant_city: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub txid: Option<String>,
+    #[serde(default)]
+    suggested_amounts: Vec<Reais>,
+}
+
+#[cfg(feature = "ssr")]
+impl Default for DonationSettings {
+    fn default() -> Self {
+        DonationSettings {
+            pix_key: String::new(),
+            merchant_name: default_merchant_name(),
+            merchant_city: default_merchant_city(),
+            description: None,
+            txid: None,
+            suggested_amounts: Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl DonationSettings {
+    pub fn suggested_amounts(&self) -> Vec<Money> {
+        let values: Vec<Money> = self.suggested_amounts.iter().map(|r| r.0).collect();
+        crate::pix::suggested_amounts(&values)
+    }
+
+    pub fn br_code(&self, amount: Option<Money>) -> crate::pix::BrCode {
+        let mut code = crate::pix::BrCode::new(&self.pix_key, &self.merchant_name, &self.merchant_city);
+        if let Some(description) = &self.description {
+            code = code.description(description);
+        }
+        if let Some(txid) = &self.txid {
+            code = code.txid(txid);
+        }
+        if let Some(amount) = amount {
+            code = code.amount(amount);
+        }
+        code
+    }
+}
+
+#[cfg(feature = "ssr")]
+fn default_merchant_name() -> String {
+    "Associação Camará Capoeira".to_string()
+}
+
+#[cfg(feature = "ssr")]
+fn default_merchant_city() -> String {
+    "Ponta Porã".to_string()
+}
+
+#[cfg(feature = "ssr")]
+pub fn load_donation_settings() -> Result<DonationSettings, super::ContentError> {
+    super::load_toml("donations.toml")
+}
@@ -0,0 +1,131 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use super::dates;
+use crate::i18n::Locale;
+
+/// IANA time zone every event time in `content/events.toml` is written in.
+pub const TIMEZONE_NAME: &str = "America/Campo_Grande";
+
+#[cfg(feature = "ssr")]
+pub const TIMEZONE: chrono_tz::Tz = chrono_tz::America::Campo_Grande;
+
+/// UTC offset of [`TIMEZONE_NAME`], for ISO 8601 times outside the server
+/// (JSON-LD). Mato Grosso do Sul has had no daylight saving time since 2019.
+pub const UTC_OFFSET: &str = "-04:00";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKind {
+    Batizado,
+    RodaAberta,
+    Oficina,
+    Apresentacao,
+}
+
+impl EventKind {
+    pub fn label(self, locale: Locale) -> &'static str {
+        let labels = &locale.catalog().labels;
+        match self {
+            EventKind::Batizado => labels.batizado,
+            EventKind::RodaAberta => labels.roda_aberta,
+            EventKind::Oficina => labels.oficina,
+            EventKind::Apresentacao => labels.apresentacao,
+        }
+    }
+}
+
+/// An entry of the public calendar ("Agenda").
+///
+/// `start` and `end` are local times in [`TIMEZONE_NAME`]; conversion to UTC
+/// only happens when exporting to iCalendar.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Event {
+    pub slug: String,
+    pub title: String,
+    pub kind: EventKind,
+    pub start: NaiveDateTime,
+    #[serde(default)]
+    pub end: Option<NaiveDateTime>,
+    pub location: String,
+    #[serde(default)]
+    pub address: Option<String>,
+    #[serde(default)]
+    pub description: String,
+}
+
+impl Event {
+    /// An event is past once its end (or its start, if open-ended) is behind `now`.
+    pub fn is_past(&self, now: NaiveDateTime) -> bool {
+        self.end.unwrap_or(self.start) < now
+    }
+
+    /// Card shown when the event is shared (`server::share_image`).
+    pub fn share_image(&self) -> String {
+        format!("/og/agenda/{}.png", self.slug)
+    }
+
+    /// "sábado, 21 de novembro de 2026"
+    pub fn date_label(&self, locale: Locale) -> String {
+        dates::long_date(self.start.date(), locale)
+    }
+
+    /// "19h – 22h", or just "19h" when the event has no end time.
+    pub fn time_label(&self, locale: Locale) -> String {
+        match self.end {
+            Some(end) if end.date() == self.start.date() => format!(
+                "{} – {}",
+                dates::short_time(self.start.time(), locale),
+                dates::short_time(end.time(), locale)
+            ),
+            Some(end) => format!(
+                "{} – {}, {}",
+                dates::short_time(self.start.time(), locale),
+                dates::long_date(end.date(), locale),
+                dates::short_time(end.time(), locale)
+            ),
+            None => dates::short_time(self.start.time(), locale),
+        }
+    }
+}
+
+/// Events split around the current time, as shown on `/agenda`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Agenda {
+    /// Soonest first.
+    pub upcoming: Vec<Event>,
+    /// Most recent first.
+    pub past: Vec<Event>,
+}
+
+impl Agenda {
+    pub fn split(mut events: Vec<Event>, now: NaiveDateTime) -> Self {
+        events.sort_by_key(|e| e.start);
+        let (past, upcoming): (Vec<_>, Vec<_>) = events.into_iter().partition(|e| e.is_past(now));
+        Agenda {
+            upcoming,
+            past: past.into_iter().rev().collect(),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Default, Deserialize)]
+struct EventsFile {
+    #[serde(default)]
+    events: Vec<Event>,
+}
+
+/// Loads every event from `content/events.toml`, sorted by start time.
+#[cfg(feature = "ssr")]
+pub fn load_events() -> Result<Vec<Event>, super::ContentError> {
+    let mut events = super::load_toml::<EventsFile>("events.toml")?.events;
+    events.sort_by_key(|e| e.start);
+    Ok(events)
+}
+
+/// Current wall-clock time in Campo Grande.
+#[cfg(feature = "ssr")]
+pub fn now_local() -> NaiveDateTime {
+    chrono::Utc::now().with_timezone(&TIMEZONE).naive_local()
+}
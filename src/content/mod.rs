@@ -0,0 +1,82 @@
+pub mod albums;
+pub mod cantigas;
+pub mod cordas;
+pub mod dates;
+pub mod donations;
+pub mod events;
+pub mod money;
+pub mod news;
+pub mod partners;
+pub mod press;
+pub mod schedule;
+pub mod transparency;
+
+/// Default `content_dir` setting, relative to the working directory.
+#[cfg(feature = "ssr")]
+pub const DEFAULT_DIR: &str = "content";
+
+#[cfg(feature = "ssr")]
+static DIR: std::sync::OnceLock<std::path::PathBuf> = std::sync::OnceLock::new();
+
+/// Points the loaders at the `content_dir` setting. `main` calls it once,
+/// right after loading the settings; later calls are ignored.
+#[cfg(feature = "ssr")]
+pub fn set_dir(dir: std::path::PathBuf) {
+    let _ = DIR.set(dir);
+}
+
+/// Directory holding the editable content files: the `content_dir` setting,
+/// or [`DEFAULT_DIR`] where nothing set it (tests).
+#[cfg(feature = "ssr")]
+pub fn dir() -> &'static std::path::Path {
+    DIR.get().map(|dir| dir.as_path()).unwrap_or(std::path::Path::new(DEFAULT_DIR))
+}
+
+/// Errors raised while reading a content file from [`dir`].
+#[cfg(feature = "ssr")]
+#[derive(Debug)]
+pub enum ContentError {
+    Io(String, std::io::Error),
+    Parse(String, toml::de::Error),
+}
+
+#[cfg(feature = "ssr")]
+impl std::fmt::Display for ContentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContentError::Io(path, e) => write!(f, "could not read {path}: {e}"),
+            ContentError::Parse(path, e) => write!(f, "invalid content in {path}: {e}"),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl std::error::Error for ContentError {}
+
+/// Reads `<content_dir>/<file_name>` and deserializes it as TOML.
+///
+/// A missing file is treated as an empty collection (`T::default()`), so a
+/// fresh checkout renders empty pages instead of errors.
+#[cfg(feature = "ssr")]
+pub fn load_toml<T>(file_name: &str) -> Result<T, ContentError>
+where
+    T: serde::de::DeserializeOwned + Default,
+{
+    let path = dir().join(file_name);
+    let display = path.display().to_string();
+
+    let raw = match std::fs::read_to_string(&path) {
+        Ok(raw) => raw,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(T::default()),
+        Err(e) => return Err(ContentError::Io(display, e)),
+    };
+
+    toml::from_str(&raw).map_err(|e| ContentError::Parse(display, e))
+}
+
+/// Day `<content_dir>/<file_name>` was last changed, for `lastmod` in the sitemap.
+#[cfg(feature = "ssr")]
+pub fn modified(file_name: &str) -> Option<chrono::NaiveDate> {
+    let modified = std::fs::metadata(dir().join(file_name)).ok()?.modified().ok()?;
+    Some(chrono::DateTime::<chrono::Utc>::from(modified).with_timezone(&events::TIMEZONE).date_naive())
+}
@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+
+/// Amount in centavos.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Money(pub i64);
+
+impl Money {
+    /// "R$ 12.500,00"
+    pub fn to_brl(self) -> String {
+        format!("R$ {}", self.to_decimal(true))
+    }
+
+    /// "12500,00" (or "12.500,00" with `group`), as spreadsheets in pt-BR expect.
+    pub fn to_decimal(self, group: bool) -> String {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let abs = self.0.unsigned_abs();
+        let reais = (abs / 100).to_string();
+        let reais = if group {
+            let mut grouped = String::with_capacity(reais.len() + reais.len() / 3);
+            for (i, c) in reais.chars().enumerate() {
+                if i > 0 && (reais.len() - i).is_multiple_of(3) {
+                    grouped.push('.');
+                }
+                grouped.push(c);
+            }
+            grouped
+        } else {
+            reais
+        };
+        format!("{}{},{:02}", sign, reais, abs % 100)
+    }
+
+    /// Parses an amount typed by a person: "50", "50,5", "1.234,56" or "1234.56".
+    pub fn parse_reais(input: &str) -> Option<Money> {
+        let input = input.trim().trim_start_matches("R$").trim();
+        let (whole, fraction) = match (input.rfind(','), input.rfind('.')) {
+            // Vírgula decimal; pontos são separadores de milhar.
+            (Some(comma), _) => (input[..comma].replace('.', ""), &input[comma + 1..]),
+            // Ponto seguido de 1 ou 2 dígitos é decimal; com 3, é milhar.
+            (None, Some(dot)) if input.len() - dot - 1 <= 2 => (input[..dot].to_string(), &input[dot + 1..]),
+            (None, Some(_)) => (input.replace('.', ""), ""),
+            (None, None) => (input.to_string(), ""),
+        };
+
+        if whole.is_empty() || !whole.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        if fraction.len() > 2 || !fraction.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let reais: i64 = whole.parse().ok()?;
+        let centavos: i64 = format!("{:0<2}", fraction).parse().ok()?;
+        reais.checked_mul(100)?.checked_add(centavos).map(Money)
+    }
+
+    /// Reads an amount in reais from the content files: `12500`, `12500.5` or `"12500.50"`.
+    #[cfg(feature = "ssr")]
+    pub fn from_reais<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Money, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Int(i64),
+            Float(f64),
+            Str(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Int(reais) => reais
+                .checked_mul(100)
+                .map(Money)
+                .ok_or_else(|| serde::de::Error::custom(format!("amount too large: {reais}"))),
+            Raw::Float(reais) => Ok(Money((reais * 100.0).round() as i64)),
+            Raw::Str(s) => s
+                .trim()
+                .parse::<f64>()
+                .map(|reais| Money((reais * 100.0).round() as i64))
+                .map_err(|_| serde::de::Error::custom(format!("invalid amount: {s:?}"))),
+        }
+    }
+}
+
+impl std::ops::Add for Money {
+    type Output = Money;
+
+    fn add(self, rhs: Money) -> Money {
+        Money(self.0 + rhs.0)
+    }
+}
+
+impl std::iter::Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::default(), |a, b| a + b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typed_amounts_are_parsed_in_brazilian_format() {
+        assert_eq!(Money::parse_reais("50"), Some(Money(5_000)));
+        assert_eq!(Money::parse_reais("25,5"), Some(Money(2_550)));
+        assert_eq!(Money::parse_reais("R$ 1.234,56"), Some(Money(123_456)));
+        assert_eq!(Money::parse_reais("1234.56"), Some(Money(123_456)));
+        assert_eq!(Money::parse_reais("1.000"), Some(Money(100_000)));
+        assert_eq!(Money::parse_reais("abc"), None);
+        assert_eq!(Money::parse_reais("10,999"), None);
+        assert_eq!(Money::parse_reais(""), None);
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn content_amounts_are_read_in_reais() {
+        #[derive(Debug, Deserialize)]
+        struct Amount {
+            #[serde(deserialize_with = "Money::from_reais")]
+            value: Money,
+        }
+        let read = |raw: &str| toml::from_str::<Amount>(raw).map(|amount| amount.value);
+
+        assert_eq!(read("value = 12500").unwrap(), Money(1_250_000));
+        assert_eq!(read("value = 12500.5").unwrap(), Money(1_250_050));
+        assert_eq!(read(r#"value = "12500.50""#).unwrap(), Money(1_250_050));
+        let error = read(&format!("value = {}", i64::MAX / 10)).unwrap_err();
+        assert!(error.to_string().contains("amount too large"));
+    }
+}
@@ -0,0 +1,55 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::dates;
+use crate::i18n::Locale;
+
+/// A news post written by the group itself (`/noticias`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewsArticle {
+    pub slug: String,
+    pub title: String,
+    pub date: NaiveDate,
+    /// One or two sentences shown on the listing.
+    pub summary: String,
+    /// Paragraphs separated by blank lines.
+    #[serde(default)]
+    pub body: String,
+    /// Cover image under `/assets/`.
+    #[serde(default)]
+    pub cover: Option<String>,
+}
+
+impl NewsArticle {
+    pub fn href(&self) -> String {
+        format!("/noticias/{}", self.slug)
+    }
+
+    pub fn date_label(&self, locale: Locale) -> String {
+        dates::long_date(self.date, locale)
+    }
+
+    /// Card shown when the article is shared (`server::share_image`).
+    pub fn share_image(&self) -> String {
+        format!("/og/noticias/{}.png", self.slug)
+    }
+
+    pub fn paragraphs(&self) -> impl Iterator<Item = &str> {
+        self.body.split("\n\n").map(str::trim).filter(|p| !p.is_empty())
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Default, Deserialize)]
+struct NewsFile {
+    #[serde(default)]
+    articles: Vec<NewsArticle>,
+}
+
+/// Loads `content/news.toml`, newest article first.
+#[cfg(feature = "ssr")]
+pub fn load_news() -> Result<Vec<NewsArticle>, super::ContentError> {
+    let mut articles = super::load_toml::<NewsFile>("news.toml")?.articles;
+    articles.sort_by_key(|a| std::cmp::Reverse(a.date));
+    Ok(articles)
+}
@@ -0,0 +1,103 @@
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartnerCategory {
+    Governo,
+    Apoio,
+    Parceiro,
+}
+
+impl PartnerCategory {
+    pub const ALL: [PartnerCategory; 3] = [
+        PartnerCategory::Governo,
+        PartnerCategory::Apoio,
+        PartnerCategory::Parceiro,
+    ];
+
+    /// Heading used on the logo wall.
+    pub fn label(self) -> &'static str {
+        match self {
+            PartnerCategory::Governo => "Realização e Fomento",
+            PartnerCategory::Apoio => "Apoio",
+            PartnerCategory::Parceiro => "Parceiros",
+        }
+    }
+}
+
+/// A funder, supporter or partner institution.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Partner {
+    pub name: String,
+    pub category: PartnerCategory,
+    /// Path under `/assets/parceiros/`; the name is shown instead when missing.
+    #[serde(default)]
+    pub logo: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Start of the agreement, when known.
+    #[serde(default)]
+    pub since: Option<NaiveDate>,
+    /// End of the agreement; `None` while it is still running.
+    #[serde(default)]
+    pub until: Option<NaiveDate>,
+}
+
+impl Partner {
+    pub fn is_active(&self, today: NaiveDate) -> bool {
+        self.has_started(today) && self.until.is_none_or(|until| until >= today)
+    }
+
+    fn has_started(&self, today: NaiveDate) -> bool {
+        self.since.is_none_or(|since| since <= today)
+    }
+
+    /// "2022–2024", "desde 2023" while active, "até 2024" without a start;
+    /// `None` when neither date is known.
+    pub fn period_label(&self) -> Option<String> {
+        match (self.since, self.until) {
+            (Some(since), Some(until)) if until.year() == since.year() => Some(since.year().to_string()),
+            (Some(since), Some(until)) => Some(format!("{}–{}", since.year(), until.year())),
+            (Some(since), None) => Some(format!("desde {}", since.year())),
+            (None, Some(until)) => Some(format!("até {}", until.year())),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Partners split between the current logo wall and the list of past supporters.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartnerWall {
+    pub active: Vec<Partner>,
+    /// Most recently ended first.
+    pub past: Vec<Partner>,
+}
+
+impl PartnerWall {
+    pub fn split(partners: Vec<Partner>, today: NaiveDate) -> Self {
+        let (active, mut past): (Vec<_>, Vec<_>) = partners
+            .into_iter()
+            .filter(|p| p.has_started(today))
+            .partition(|p| p.is_active(today));
+        past.sort_by_key(|p| std::cmp::Reverse(p.until));
+        PartnerWall { active, past }
+    }
+
+    pub fn active_in(&self, category: PartnerCategory) -> Vec<Partner> {
+        self.active.iter().filter(|p| p.category == category).cloned().collect()
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Default, Deserialize)]
+struct PartnersFile {
+    #[serde(default)]
+    partners: Vec<Partner>,
+}
+
+/// Loads `content/partners.toml` in file order.
+#[cfg(feature = "ssr")]
+pub fn load_partners() -> Result<Vec<Partner>, super::ContentError> {
+    Ok(super::load_toml::<PartnersFile>("partners.toml")?.partners)
+}
@@ -0,0 +1,67 @@
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::dates;
+use crate::i18n::Locale;
+
+/// A news piece about the group published by an external outlet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PressClipping {
+    pub outlet: String,
+    pub title: String,
+    /// Publication date, when it could be confirmed. Undated clippings are
+    /// listed last and left out when the archive is filtered by year.
+    #[serde(default)]
+    pub date: Option<NaiveDate>,
+    pub snippet: String,
+    pub url: String,
+    /// Local copy (PDF or screenshot) under `/assets/imprensa/`, in case the original goes offline.
+    #[serde(default)]
+    pub archive: Option<String>,
+    #[serde(default)]
+    pub outlet_logo: Option<String>,
+}
+
+impl PressClipping {
+    pub fn year(&self) -> Option<i32> {
+        self.date.map(|date| date.year())
+    }
+
+    pub fn date_label(&self, locale: Locale) -> Option<String> {
+        self.date.map(|date| dates::medium_date(date, locale))
+    }
+
+    /// Link text for the archived copy, depending on its file type.
+    pub fn archive_label(&self) -> Option<&'static str> {
+        self.archive.as_ref().map(|path| {
+            if path.to_lowercase().ends_with(".pdf") {
+                "Cópia arquivada (PDF)"
+            } else {
+                "Cópia arquivada (imagem)"
+            }
+        })
+    }
+}
+
+/// Distinct publication years, newest first.
+pub fn years(clippings: &[PressClipping]) -> Vec<i32> {
+    let mut years: Vec<i32> = clippings.iter().filter_map(PressClipping::year).collect();
+    years.sort_unstable_by(|a, b| b.cmp(a));
+    years.dedup();
+    years
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Default, Deserialize)]
+struct PressFile {
+    #[serde(default)]
+    clippings: Vec<PressClipping>,
+}
+
+/// Loads `content/press.toml`, newest clipping first and undated ones last.
+#[cfg(feature = "ssr")]
+pub fn load_press() -> Result<Vec<PressClipping>, super::ContentError> {
+    let mut clippings = super::load_toml::<PressFile>("press.toml")?.clippings;
+    clippings.sort_by_key(|c| std::cmp::Reverse(c.date));
+    Ok(clippings)
+}
@@ -0,0 +1,330 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, Weekday};
+use serde::{Deserialize, Serialize};
+
+use super::dates;
+use crate::i18n::Locale;
+
+/// Days shown on the weekly grid, Monday first.
+pub const WEEK: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Modality {
+    Capoeira,
+    Percussao,
+    Maculele,
+}
+
+impl Modality {
+    pub fn label(self, locale: Locale) -> &'static str {
+        let labels = &locale.catalog().labels;
+        match self {
+            Modality::Capoeira => labels.capoeira,
+            Modality::Percussao => labels.percussao,
+            Modality::Maculele => labels.maculele,
+        }
+    }
+
+    /// Accent color used for the modality tag on schedule cards.
+    pub fn color(self) -> &'static str {
+        match self {
+            Modality::Capoeira => "#332175",
+            Modality::Percussao => "#b5431f",
+            Modality::Maculele => "#1f7a4d",
+        }
+    }
+}
+
+/// A weekly recurring class ("turma").
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassSession {
+    /// Stable identifier, referenced by exceptions, enrollments and attendance.
+    pub id: String,
+    pub weekday: Weekday,
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+    pub modality: Modality,
+    /// Núcleo where the class happens.
+    pub location: String,
+    pub age_group: String,
+    pub instructor: String,
+}
+
+impl ClassSession {
+    /// "18h – 19h30"
+    pub fn time_label(&self, locale: Locale) -> String {
+        format!("{} – {}", dates::short_time(self.start, locale), dates::short_time(self.end, locale))
+    }
+
+    /// "Capoeira · Infantil (7 a 12 anos)"
+    pub fn title(&self, locale: Locale) -> String {
+        format!("{} · {}", self.modality.label(locale), self.age_group)
+    }
+
+    /// "Capoeira · Adultos · segunda-feira 19h – 20h30 · Sede", for the turma pickers.
+    pub fn option_label(&self, locale: Locale) -> String {
+        format!(
+            "{} · {} {} · {}",
+            self.title(locale),
+            dates::weekday_name(self.weekday, locale),
+            self.time_label(locale),
+            self.location
+        )
+    }
+
+    /// First date on or after `from` that falls on this session's weekday.
+    pub fn next_date(&self, from: NaiveDate) -> NaiveDate {
+        let ahead = (7 + self.weekday.num_days_from_monday() - from.weekday().num_days_from_monday()) % 7;
+        from + Duration::days(ahead as i64)
+    }
+}
+
+/// A date on which some (or all) sessions do not happen: holidays, cancellations.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleException {
+    pub date: NaiveDate,
+    /// Ids of the affected sessions; empty means every session of that day.
+    #[serde(default)]
+    pub sessions: Vec<String>,
+    pub reason: String,
+}
+
+impl ScheduleException {
+    pub fn applies_to(&self, session: &ClassSession, date: NaiveDate) -> bool {
+        self.date == date
+            && session.weekday == date.weekday()
+            && (self.sessions.is_empty() || self.sessions.contains(&session.id))
+    }
+}
+
+/// A one-off class outside the weekly grid: a make-up class, an aulão.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExtraSession {
+    pub date: NaiveDate,
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+    pub modality: Modality,
+    pub location: String,
+    pub age_group: String,
+    pub instructor: String,
+    pub reason: String,
+}
+
+impl ExtraSession {
+    /// "9h – 11h"
+    pub fn time_label(&self, locale: Locale) -> String {
+        format!("{} – {}", dates::short_time(self.start, locale), dates::short_time(self.end, locale))
+    }
+
+    /// "Capoeira · Todas as idades"
+    pub fn title(&self, locale: Locale) -> String {
+        format!("{} · {}", self.modality.label(locale), self.age_group)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Schedule {
+    #[serde(default)]
+    pub sessions: Vec<ClassSession>,
+    #[serde(default)]
+    pub exceptions: Vec<ScheduleException>,
+    #[serde(default)]
+    pub extras: Vec<ExtraSession>,
+}
+
+impl Schedule {
+    /// Reason for which `session` does not happen on `date`, if any.
+    pub fn cancellation(&self, session: &ClassSession, date: NaiveDate) -> Option<&str> {
+        self.exceptions
+            .iter()
+            .find(|e| e.applies_to(session, date))
+            .map(|e| e.reason.as_str())
+    }
+
+    /// Sessions of one weekday, earliest first.
+    pub fn sessions_on(&self, weekday: Weekday) -> Vec<&ClassSession> {
+        let mut sessions: Vec<_> = self.sessions.iter().filter(|s| s.weekday == weekday).collect();
+        sessions.sort_by_key(|s| s.start);
+        sessions
+    }
+
+    pub fn session(&self, id: &str) -> Option<&ClassSession> {
+        self.sessions.iter().find(|s| s.id == id)
+    }
+
+    /// Resolves the schedule for the seven days starting at `today`, plus every
+    /// cancelled occurrence and extra class within `notice_days`.
+    pub fn week_view(&self, today: NaiveDate, notice_days: i64) -> WeekView {
+        let mut sessions: Vec<SessionView> = self
+            .sessions
+            .iter()
+            .map(|session| {
+                let next_date = session.next_date(today);
+                SessionView {
+                    cancelled: self.cancellation(session, next_date).map(str::to_string),
+                    session: session.clone(),
+                    next_date,
+                }
+            })
+            .collect();
+        sessions.sort_by_key(|s| (s.session.weekday.num_days_from_monday(), s.session.start));
+
+        let last_day = today + Duration::days(notice_days);
+        let mut cancellations: Vec<Cancellation> = self
+            .exceptions
+            .iter()
+            .filter(|e| e.date >= today && e.date <= last_day)
+            .flat_map(|e| {
+                self.sessions
+                    .iter()
+                    .filter(move |s| e.applies_to(s, e.date))
+                    .map(move |s| Cancellation { date: e.date, session: s.clone(), reason: e.reason.clone() })
+            })
+            .collect();
+        cancellations.sort_by_key(|c| (c.date, c.session.start));
+
+        let mut extras: Vec<ExtraSession> = self
+            .extras
+            .iter()
+            .filter(|extra| extra.date >= today && extra.date <= last_day)
+            .cloned()
+            .collect();
+        extras.sort_by_key(|extra| (extra.date, extra.start));
+
+        WeekView { sessions, cancellations, extras }
+    }
+}
+
+/// A session together with its next occurrence, as rendered on `/horarios`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionView {
+    pub session: ClassSession,
+    pub next_date: NaiveDate,
+    /// Reason the next occurrence will not happen.
+    pub cancelled: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cancellation {
+    pub date: NaiveDate,
+    pub session: ClassSession,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WeekView {
+    /// Monday first, then by start time.
+    pub sessions: Vec<SessionView>,
+    pub cancellations: Vec<Cancellation>,
+    /// One-off classes ahead, soonest first.
+    pub extras: Vec<ExtraSession>,
+}
+
+/// Loads the class schedule from `content/schedule.toml`.
+#[cfg(feature = "ssr")]
+pub fn load_schedule() -> Result<Schedule, super::ContentError> {
+    super::load_toml("schedule.toml")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        // March 2026: the 2nd is a Monday.
+        NaiveDate::from_ymd_opt(2026, 3, day).unwrap()
+    }
+
+    fn session(id: &str, weekday: Weekday, hour: u32) -> ClassSession {
+        ClassSession {
+            id: id.into(),
+            weekday,
+            start: NaiveTime::from_hms_opt(hour, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(hour + 1, 0, 0).unwrap(),
+            modality: Modality::Capoeira,
+            location: "Sede".into(),
+            age_group: "Adultos".into(),
+            instructor: "Instrutor".into(),
+        }
+    }
+
+    fn exception(day: u32, sessions: &[&str], reason: &str) -> ScheduleException {
+        ScheduleException { date: date(day), sessions: sessions.iter().map(|s| s.to_string()).collect(), reason: reason.into() }
+    }
+
+    #[test]
+    fn next_date_crosses_into_the_next_week() {
+        let monday = session("seg", Weekday::Mon, 19);
+        let friday = session("sex", Weekday::Fri, 19);
+
+        // From Saturday the 7th, Monday is the 9th: the following week.
+        assert_eq!(monday.next_date(date(7)), date(9));
+        // On the day itself, the session is today.
+        assert_eq!(monday.next_date(date(9)), date(9));
+        assert_eq!(friday.next_date(date(7)), date(13));
+        // Across the end of the month too.
+        assert_eq!(monday.next_date(date(29)), date(30));
+        assert_eq!(friday.next_date(date(28)), NaiveDate::from_ymd_opt(2026, 4, 3).unwrap());
+    }
+
+    #[test]
+    fn a_cancelled_date_drops_only_the_sessions_it_names() {
+        let schedule = Schedule {
+            sessions: vec![
+                session("seg-cedo", Weekday::Mon, 18),
+                session("seg-noite", Weekday::Mon, 19),
+                session("qua", Weekday::Wed, 19),
+            ],
+            exceptions: vec![exception(9, &["seg-noite"], "Batizado"), exception(11, &[], "Feriado")],
+            extras: Vec::new(),
+        };
+        let week = schedule.week_view(date(7), 45);
+
+        let cancelled = |id: &str| week.sessions.iter().find(|s| s.session.id == id).unwrap().cancelled.clone();
+        assert_eq!(cancelled("seg-noite").as_deref(), Some("Batizado"));
+        assert_eq!(cancelled("seg-cedo"), None);
+        // An exception without sessions takes the whole day.
+        assert_eq!(cancelled("qua").as_deref(), Some("Feriado"));
+        assert_eq!(week.cancellations.len(), 2);
+
+        // A date that is not the session's weekday changes nothing.
+        assert!(!exception(10, &[], "Terça").applies_to(&schedule.sessions[0], date(10)));
+        // The week after, the recurring session is back.
+        let later = schedule.week_view(date(14), 45);
+        assert!(later.sessions.iter().all(|s| s.cancelled.is_none()));
+    }
+
+    #[test]
+    fn extra_sessions_show_up_once() {
+        let extra = |day: u32, hour: u32| ExtraSession {
+            date: date(day),
+            start: NaiveTime::from_hms_opt(hour, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(hour + 2, 0, 0).unwrap(),
+            modality: Modality::Maculele,
+            location: "Praça".into(),
+            age_group: "Todas as idades".into(),
+            instructor: "Instrutor".into(),
+            reason: "Aulão".into(),
+        };
+        let schedule = Schedule {
+            sessions: vec![session("seg", Weekday::Mon, 19)],
+            exceptions: Vec::new(),
+            extras: vec![extra(21, 9), extra(5, 9), extra(14, 15), extra(14, 9)],
+        };
+
+        let week = schedule.week_view(date(7), 10);
+        let dates: Vec<_> = week.extras.iter().map(|e| (e.date, e.start.format("%H").to_string())).collect();
+        // The one on the 5th is past and the one on the 21st beyond the notice.
+        assert_eq!(dates, vec![(date(14), "09".to_string()), (date(14), "15".to_string())]);
+        // Extras never join the weekly grid.
+        assert_eq!(week.sessions.len(), 1);
+    }
+}
@@ -0,0 +1,247 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::money::Money;
+
+/// Default `documents_dir`, inside the content directory. Files outside the
+/// registry are never served.
+#[cfg(feature = "ssr")]
+pub const DOCUMENTS_SUBDIR: &str = "transparencia";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentCategory {
+    TermoFomento,
+    PlanoTrabalho,
+    Orcamento,
+    Relatorio,
+    PrestacaoContas,
+    Resultado,
+}
+
+impl DocumentCategory {
+    pub fn label(self) -> &'static str {
+        match self {
+            DocumentCategory::TermoFomento => "Termo de Fomento",
+            DocumentCategory::PlanoTrabalho => "Plano de Trabalho",
+            DocumentCategory::Orcamento => "Orçamento",
+            DocumentCategory::Relatorio => "Relatório",
+            DocumentCategory::PrestacaoContas => "Prestação de Contas",
+            DocumentCategory::Resultado => "Resultados",
+        }
+    }
+}
+
+/// A public document (PDF) registered in `content/transparency.toml`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransparencyDocument {
+    pub slug: String,
+    pub title: String,
+    pub project: String,
+    pub fiscal_year: i32,
+    pub category: DocumentCategory,
+    /// File name inside the `documents_dir` setting.
+    pub file: String,
+    #[serde(default)]
+    pub published: Option<NaiveDate>,
+}
+
+impl TransparencyDocument {
+    pub fn href(&self) -> String {
+        format!("/transparencia/documentos/{}", self.slug)
+    }
+}
+
+/// A transfer received for a project (parcela de convênio, doação, etc.).
+#[cfg(feature = "ssr")]
+#[derive(Debug, Clone, Deserialize)]
+pub struct FundReceived {
+    pub project: String,
+    pub fiscal_year: i32,
+    pub date: NaiveDate,
+    pub source: String,
+    #[serde(deserialize_with = "Money::from_reais")]
+    pub amount: Money,
+}
+
+/// Spending of a project under one budget line ("rubrica").
+#[cfg(feature = "ssr")]
+#[derive(Debug, Clone, Deserialize)]
+pub struct FundSpent {
+    pub project: String,
+    pub fiscal_year: i32,
+    pub category: String,
+    #[serde(deserialize_with = "Money::from_reais")]
+    pub amount: Money,
+}
+
+/// A value of a summary table; money is formatted differently for HTML and CSV.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Cell {
+    Text(String),
+    Money(Money),
+}
+
+impl Cell {
+    pub fn display(&self) -> String {
+        match self {
+            Cell::Text(text) => text.clone(),
+            Cell::Money(money) => money.to_brl(),
+        }
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, Cell::Money(_))
+    }
+}
+
+/// A financial table shown on `/transparencia` and downloadable as `/transparencia/<slug>.csv`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Table {
+    pub slug: String,
+    pub title: String,
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<Cell>>,
+    /// Totals row, if the table has one.
+    pub footer: Option<Vec<Cell>>,
+}
+
+impl Table {
+    pub fn csv_href(&self) -> String {
+        format!("/transparencia/{}.csv", self.slug)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TransparencyReport {
+    /// Newest fiscal year first.
+    pub documents: Vec<TransparencyDocument>,
+    pub tables: Vec<Table>,
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Default, Deserialize)]
+pub struct TransparencyFile {
+    #[serde(default)]
+    pub documents: Vec<TransparencyDocument>,
+    #[serde(default)]
+    pub received: Vec<FundReceived>,
+    #[serde(default)]
+    pub spent: Vec<FundSpent>,
+}
+
+#[cfg(feature = "ssr")]
+impl TransparencyFile {
+    pub fn document(&self, slug: &str) -> Option<&TransparencyDocument> {
+        self.documents.iter().find(|d| d.slug == slug)
+    }
+
+    pub fn received_table(&self) -> Table {
+        let mut received = self.received.clone();
+        received.sort_by(|a, b| (a.fiscal_year, &a.project, a.date).cmp(&(b.fiscal_year, &b.project, b.date)));
+
+        Table {
+            slug: "recebidos".to_string(),
+            title: "Recursos recebidos".to_string(),
+            headers: vec!["Projeto", "Exercício", "Data", "Origem", "Valor"].into_iter().map(String::from).collect(),
+            footer: Some(total_row(5, received.iter().map(|r| r.amount).sum())),
+            rows: received
+                .into_iter()
+                .map(|r| vec![
+                    Cell::Text(r.project),
+                    Cell::Text(r.fiscal_year.to_string()),
+                    Cell::Text(super::dates::day_month_year(r.date)),
+                    Cell::Text(r.source),
+                    Cell::Money(r.amount),
+                ])
+                .collect(),
+        }
+    }
+
+    pub fn spent_table(&self) -> Table {
+        let mut spent = self.spent.clone();
+        spent.sort_by(|a, b| (a.fiscal_year, &a.project, &a.category).cmp(&(b.fiscal_year, &b.project, &b.category)));
+
+        Table {
+            slug: "aplicados".to_string(),
+            title: "Recursos aplicados".to_string(),
+            headers: vec!["Projeto", "Exercício", "Rubrica", "Valor"].into_iter().map(String::from).collect(),
+            footer: Some(total_row(4, spent.iter().map(|s| s.amount).sum())),
+            rows: spent
+                .into_iter()
+                .map(|s| vec![
+                    Cell::Text(s.project),
+                    Cell::Text(s.fiscal_year.to_string()),
+                    Cell::Text(s.category),
+                    Cell::Money(s.amount),
+                ])
+                .collect(),
+        }
+    }
+
+    /// Received, spent and balance per project and fiscal year.
+    pub fn summary_table(&self) -> Table {
+        use std::collections::BTreeMap;
+
+        let mut totals: BTreeMap<(i32, String), (Money, Money)> = BTreeMap::new();
+        for r in &self.received {
+            let entry = totals.entry((r.fiscal_year, r.project.clone())).or_default();
+            entry.0 = entry.0 + r.amount;
+        }
+        for s in &self.spent {
+            let entry = totals.entry((s.fiscal_year, s.project.clone())).or_default();
+            entry.1 = entry.1 + s.amount;
+        }
+
+        let received: Money = totals.values().map(|t| t.0).sum();
+        let spent: Money = totals.values().map(|t| t.1).sum();
+
+        Table {
+            slug: "resumo".to_string(),
+            title: "Resumo por projeto".to_string(),
+            headers: vec!["Projeto", "Exercício", "Recebido", "Aplicado", "Saldo"].into_iter().map(String::from).collect(),
+            rows: totals
+                .into_iter()
+                .map(|((year, project), (received, spent))| vec![
+                    Cell::Text(project),
+                    Cell::Text(year.to_string()),
+                    Cell::Money(received),
+                    Cell::Money(spent),
+                    Cell::Money(Money(received.0 - spent.0)),
+                ])
+                .collect(),
+            footer: Some(vec![
+                Cell::Text("Total".to_string()),
+                Cell::Text(String::new()),
+                Cell::Money(received),
+                Cell::Money(spent),
+                Cell::Money(Money(received.0 - spent.0)),
+            ]),
+        }
+    }
+
+    pub fn tables(&self) -> Vec<Table> {
+        vec![self.summary_table(), self.received_table(), self.spent_table()]
+    }
+
+    pub fn report(&self) -> TransparencyReport {
+        let mut documents = self.documents.clone();
+        documents.sort_by(|a, b| b.fiscal_year.cmp(&a.fiscal_year).then_with(|| a.title.cmp(&b.title)));
+        TransparencyReport { documents, tables: self.tables() }
+    }
+}
+
+/// "Total" in the first column, `total` in the last, blanks in between.
+#[cfg(feature = "ssr")]
+fn total_row(columns: usize, total: Money) -> Vec<Cell> {
+    let mut row = vec![Cell::Text("Total".to_string())];
+    row.extend((2..columns).map(|_| Cell::Text(String::new())));
+    row.push(Cell::Money(total));
+    row
+}
+
+/// Loads `content/transparency.toml`.
+#[cfg(feature = "ssr")]
+pub fn load_transparency() -> Result<TransparencyFile, super::ContentError> {
+    super::load_toml("transparency.toml")
+}
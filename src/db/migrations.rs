@@ -0,0 +1,105 @@
+//! Versioned schema migrations, embedded in the binary and applied at startup.
+//!
+//! Each migration runs once, inside its own transaction, and is recorded in
+//! `schema_migrations`. Never edit a migration that has shipped; add a new one.
+
+use rusqlite::{params, Connection};
+
+pub struct Migration {
+    pub version: i64,
+    pub name: &'static str,
+    pub sql: &'static str,
+}
+
+/// Every migration, in ascending version order.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "enrollments",
+        sql: include_str!("../../migrations/0001_enrollments.sql"),
+    },
+    Migration {
+        version: 2,
+        name: "attendance",
+        sql: include_str!("../../migrations/0002_attendance.sql"),
+    },
+    Migration {
+        version: 3,
+        name: "graduations",
+        sql: include_str!("../../migrations/0003_graduations.sql"),
+    },
+    Migration {
+        version: 4,
+        name: "users",
+        sql: include_str!("../../migrations/0004_users.sql"),
+    },
+    Migration {
+        version: 5,
+        name: "content_drafts",
+        sql: include_str!("../../migrations/0005_content_drafts.sql"),
+    },
+    Migration {
+        version: 6,
+        name: "audit_log",
+        sql: include_str!("../../migrations/0006_audit_log.sql"),
+    },
+    Migration {
+        version: 7,
+        name: "newsletter",
+        sql: include_str!("../../migrations/0007_newsletter.sql"),
+    },
+    Migration {
+        version: 8,
+        name: "sign_in_throttle",
+        sql: include_str!("../../migrations/0008_sign_in_throttle.sql"),
+    },
+];
+
+/// Highest version recorded in the database, 0 for a fresh one.
+pub fn current_version(conn: &Connection) -> rusqlite::Result<i64> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS schema_migrations (
+            version     INTEGER PRIMARY KEY,
+            name        TEXT NOT NULL,
+            applied_at  TEXT NOT NULL DEFAULT (datetime('now'))
+        );",
+    )?;
+    conn.query_row("SELECT COALESCE(MAX(version), 0) FROM schema_migrations", [], |row| row.get(0))
+}
+
+/// Applies the pending migrations and returns how many ran.
+pub fn migrate(conn: &mut Connection) -> rusqlite::Result<usize> {
+    let current = current_version(conn)?;
+    let mut applied = 0;
+
+    for migration in MIGRATIONS.iter().filter(|m| m.version > current) {
+        let tx = conn.transaction()?;
+        tx.execute_batch(migration.sql)?;
+        tx.execute(
+            "INSERT INTO schema_migrations (version, name) VALUES (?1, ?2)",
+            params![migration.version, migration.name],
+        )?;
+        tx.commit()?;
+        applied += 1;
+    }
+
+    Ok(applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions_are_strictly_increasing() {
+        assert!(MIGRATIONS.windows(2).all(|w| w[0].version < w[1].version));
+    }
+
+    #[test]
+    fn migrates_fresh_database_once() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        assert_eq!(migrate(&mut conn).unwrap(), MIGRATIONS.len());
+        assert_eq!(migrate(&mut conn).unwrap(), 0);
+        assert_eq!(current_version(&conn).unwrap(), MIGRATIONS.last().unwrap().version);
+    }
+}
@@ -0,0 +1,109 @@
+//! Local SQLite database for the data the site collects (matrículas, etc.).
+//!
+//! `main` opens a [`Pool`] once, runs the [migrations] and hands the pool to
+//! the actix handlers (`app_data`) and to the Leptos server functions
+//! (context). Server functions go through [`with_conn`] or [`transaction`],
+//! which run the blocking SQLite calls on actix's thread pool.
+
+pub mod migrations;
+
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::{Connection, Transaction};
+
+/// Default database file (see `settings`), relative to the working directory. Not versioned (see `.gitignore`).
+pub const DATABASE_PATH: &str = "data/camara.db";
+
+pub type Pool = r2d2::Pool<SqliteConnectionManager>;
+
+#[derive(Debug)]
+pub enum DbError {
+    Sqlite(rusqlite::Error),
+    Pool(r2d2::Error),
+    /// The blocking task was cancelled or panicked.
+    Blocking,
+    /// No [`Pool`] in the Leptos context; `main` did not provide one.
+    MissingPool,
+}
+
+impl std::fmt::Display for DbError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DbError::Sqlite(e) => write!(f, "database error: {e}"),
+            DbError::Pool(e) => write!(f, "could not get a database connection: {e}"),
+            DbError::Blocking => write!(f, "database task was interrupted"),
+            DbError::MissingPool => write!(f, "database pool not available in this context"),
+        }
+    }
+}
+
+impl std::error::Error for DbError {}
+
+impl From<rusqlite::Error> for DbError {
+    fn from(e: rusqlite::Error) -> Self {
+        DbError::Sqlite(e)
+    }
+}
+
+impl From<r2d2::Error> for DbError {
+    fn from(e: r2d2::Error) -> Self {
+        DbError::Pool(e)
+    }
+}
+
+/// Opens the database at `path`, creating it if needed, and applies pending migrations.
+pub fn open(path: &std::path::Path) -> Result<Pool, DbError> {
+    if let Some(dir) = path.parent() {
+        // Opening reports a clearer error than this would if the directory is unusable.
+        let _ = std::fs::create_dir_all(dir);
+    }
+
+    let manager = SqliteConnectionManager::file(path).with_init(|conn| {
+        conn.execute_batch("PRAGMA foreign_keys = ON; PRAGMA busy_timeout = 5000; PRAGMA journal_mode = WAL;")
+    });
+    let pool = r2d2::Pool::new(manager)?;
+
+    migrations::migrate(&mut *pool.get()?)?;
+    Ok(pool)
+}
+
+/// Runs `f` with a pooled connection from the Leptos context, off the async executor.
+pub async fn with_conn<T, E, F>(f: F) -> Result<T, E>
+where
+    F: FnOnce(&mut Connection) -> Result<T, E> + Send + 'static,
+    T: Send + 'static,
+    E: From<DbError> + Send + 'static,
+{
+    let pool = leptos::prelude::use_context::<Pool>().ok_or(DbError::MissingPool)?;
+    with_pool(pool, f).await
+}
+
+/// Runs `f` with a connection from `pool`, for actix handlers holding a `web::Data<Pool>`.
+pub async fn with_pool<T, E, F>(pool: Pool, f: F) -> Result<T, E>
+where
+    F: FnOnce(&mut Connection) -> Result<T, E> + Send + 'static,
+    T: Send + 'static,
+    E: From<DbError> + Send + 'static,
+{
+    actix_web::web::block(move || {
+        let mut conn = pool.get().map_err(DbError::from)?;
+        f(&mut conn)
+    })
+    .await
+    .map_err(|_| DbError::Blocking)?
+}
+
+/// Like [`with_conn`], inside a transaction that is committed only if `f` succeeds.
+pub async fn transaction<T, E, F>(f: F) -> Result<T, E>
+where
+    F: FnOnce(&Transaction) -> Result<T, E> + Send + 'static,
+    T: Send + 'static,
+    E: From<DbError> + Send + 'static,
+{
+    with_conn(move |conn| {
+        let tx = conn.transaction().map_err(DbError::from)?;
+        let value = f(&tx)?;
+        tx.commit().map_err(DbError::from)?;
+        Ok(value)
+    })
+    .await
+}
@@ -0,0 +1,447 @@
+//! Matrículas: the online replacement for the paper enrollment form kept at the sede.
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::content::schedule::{ClassSession, Schedule};
+use crate::i18n::Locale;
+
+#[cfg(feature = "ssr")]
+pub mod store;
+
+/// Students younger than this must be enrolled by a legal guardian.
+pub const ADULT_AGE: u32 = 18;
+
+/// Where an enrollment stands: submitted online, accepted by the coordination,
+/// then active once the student starts attending.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnrollmentStatus {
+    Pending,
+    Accepted,
+    Active,
+}
+
+impl EnrollmentStatus {
+    pub const ALL: [EnrollmentStatus; 3] = [EnrollmentStatus::Pending, EnrollmentStatus::Accepted, EnrollmentStatus::Active];
+
+    pub fn label(self, locale: Locale) -> &'static str {
+        let labels = &locale.catalog().labels;
+        match self {
+            EnrollmentStatus::Pending => labels.enrollment_pending,
+            EnrollmentStatus::Accepted => labels.enrollment_accepted,
+            EnrollmentStatus::Active => labels.enrollment_active,
+        }
+    }
+
+    /// Value stored in the database.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EnrollmentStatus::Pending => "pending",
+            EnrollmentStatus::Accepted => "accepted",
+            EnrollmentStatus::Active => "active",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(EnrollmentStatus::Pending),
+            "accepted" => Some(EnrollmentStatus::Accepted),
+            "active" => Some(EnrollmentStatus::Active),
+            _ => None,
+        }
+    }
+
+    /// Button that moves an enrollment out of this status, in `/admin/matriculas`.
+    pub fn advance_label(self) -> Option<&'static str> {
+        match self {
+            EnrollmentStatus::Pending => Some("Aceitar"),
+            EnrollmentStatus::Accepted => Some("Ativar"),
+            EnrollmentStatus::Active => None,
+        }
+    }
+
+    /// The only status this one may move to; the workflow never goes back.
+    pub fn next(self) -> Option<Self> {
+        match self {
+            EnrollmentStatus::Pending => Some(EnrollmentStatus::Accepted),
+            EnrollmentStatus::Accepted => Some(EnrollmentStatus::Active),
+            EnrollmentStatus::Active => None,
+        }
+    }
+}
+
+/// Fields of the `/matricula` form, named `form[...]` in the HTML.
+///
+/// Checkboxes are only sent when ticked, hence the `serde(default)` on the consents.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnrollmentForm {
+    pub student_name: String,
+    pub birth_date: NaiveDate,
+    pub phone: String,
+    #[serde(default)]
+    pub email: String,
+    #[serde(default)]
+    pub address: String,
+    #[serde(default)]
+    pub school: String,
+    #[serde(default)]
+    pub guardian_name: String,
+    #[serde(default)]
+    pub guardian_relationship: String,
+    #[serde(default)]
+    pub guardian_phone: String,
+    #[serde(default)]
+    pub guardian_email: String,
+    /// [`ClassSession::id`](crate::content::schedule::ClassSession::id) of the chosen turma.
+    pub session_id: String,
+    #[serde(default)]
+    pub health_notes: String,
+    /// Processing of personal data for the enrollment (LGPD art. 7º).
+    #[serde(default)]
+    pub consent_data: bool,
+    /// Processing of the health notes, which are sensitive data (LGPD art. 11).
+    #[serde(default)]
+    pub consent_health: bool,
+    /// Use of photos and videos of the student in the association's channels. Optional.
+    #[serde(default)]
+    pub consent_image: bool,
+    /// The person filling the form declares to be the minor's legal guardian (LGPD art. 14).
+    #[serde(default)]
+    pub consent_guardian: bool,
+}
+
+/// A field [`EnrollmentForm::validate`] may find empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequiredField {
+    StudentName,
+    Phone,
+    GuardianName,
+    GuardianRelationship,
+    GuardianPhone,
+}
+
+impl RequiredField {
+    /// The field's label on the form.
+    pub fn label(self, locale: Locale) -> &'static str {
+        let t = &locale.catalog().enrollment;
+        match self {
+            RequiredField::StudentName => t.student_name,
+            RequiredField::Phone => t.phone,
+            RequiredField::GuardianName => t.guardian_name,
+            RequiredField::GuardianRelationship => t.guardian_relationship,
+            RequiredField::GuardianPhone => t.guardian_phone,
+        }
+    }
+}
+
+/// A consent the form cannot go without.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Consent {
+    Guardian,
+    Data,
+    Health,
+}
+
+/// Why an enrollment form was refused; [`message`](Self::message) is shown to whoever filled it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnrollmentError {
+    Missing(RequiredField),
+    BirthDate,
+    UnknownSession,
+    ConsentRequired(Consent),
+}
+
+impl EnrollmentError {
+    pub fn message(&self, locale: Locale) -> String {
+        let t = &locale.catalog().enrollment;
+        match self {
+            EnrollmentError::Missing(field) => t.missing.replace("{}", field.label(locale)),
+            EnrollmentError::BirthDate => t.invalid_birth_date.to_string(),
+            EnrollmentError::UnknownSession => t.unknown_session.to_string(),
+            EnrollmentError::ConsentRequired(consent) => {
+                let what = match consent {
+                    Consent::Guardian => t.consent_guardian_required,
+                    Consent::Data => t.consent_data_required,
+                    Consent::Health => t.consent_health_required,
+                };
+                t.consent_required.replace("{}", what)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for EnrollmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message(Locale::PtBr))
+    }
+}
+
+impl std::error::Error for EnrollmentError {}
+
+impl EnrollmentForm {
+    /// Completed years of age on `date`.
+    pub fn age_on(&self, date: NaiveDate) -> u32 {
+        date.years_since(self.birth_date).unwrap_or(0)
+    }
+
+    pub fn is_minor(&self, today: NaiveDate) -> bool {
+        self.age_on(today) < ADULT_AGE
+    }
+
+    /// Checks required fields, guardian data for minors, the chosen turma and the consents.
+    pub fn validate(&self, today: NaiveDate, schedule: &Schedule) -> Result<(), EnrollmentError> {
+        require(&self.student_name, RequiredField::StudentName)?;
+        require(&self.phone, RequiredField::Phone)?;
+
+        if self.birth_date > today || self.birth_date.year() < today.year() - 120 {
+            return Err(EnrollmentError::BirthDate);
+        }
+
+        if self.is_minor(today) {
+            require(&self.guardian_name, RequiredField::GuardianName)?;
+            require(&self.guardian_relationship, RequiredField::GuardianRelationship)?;
+            require(&self.guardian_phone, RequiredField::GuardianPhone)?;
+            if !self.consent_guardian {
+                return Err(EnrollmentError::ConsentRequired(Consent::Guardian));
+            }
+        }
+
+        if schedule.session(&self.session_id).is_none() {
+            return Err(EnrollmentError::UnknownSession);
+        }
+
+        if !self.consent_data {
+            return Err(EnrollmentError::ConsentRequired(Consent::Data));
+        }
+        if !self.health_notes.trim().is_empty() && !self.consent_health {
+            return Err(EnrollmentError::ConsentRequired(Consent::Health));
+        }
+
+        Ok(())
+    }
+
+    /// Trims every text field; whitespace-only answers count as empty.
+    pub fn normalized(mut self) -> Self {
+        for field in [
+            &mut self.student_name,
+            &mut self.phone,
+            &mut self.email,
+            &mut self.address,
+            &mut self.school,
+            &mut self.guardian_name,
+            &mut self.guardian_relationship,
+            &mut self.guardian_phone,
+            &mut self.guardian_email,
+            &mut self.session_id,
+            &mut self.health_notes,
+        ] {
+            *field = field.trim().to_string();
+        }
+        self
+    }
+}
+
+fn require(value: &str, field: RequiredField) -> Result<(), EnrollmentError> {
+    if value.trim().is_empty() {
+        Err(EnrollmentError::Missing(field))
+    } else {
+        Ok(())
+    }
+}
+
+/// Returned after a successful submission; the protocol lets the family follow it up.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnrollmentReceipt {
+    pub protocol: String,
+    pub status: EnrollmentStatus,
+}
+
+impl EnrollmentReceipt {
+    pub fn href(&self) -> String {
+        format!("/matricula/{}", self.protocol)
+    }
+}
+
+/// What `/matricula/<protocolo>` shows: no personal data beyond the first name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnrollmentSummary {
+    pub protocol: String,
+    pub first_name: String,
+    pub status: EnrollmentStatus,
+    /// The chosen turma, if it is still on the schedule.
+    pub session: Option<ClassSession>,
+    pub submitted: NaiveDate,
+}
+
+/// A turma offered in the attendance picker, labelled in Portuguese like the rest of the admin area.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionOption {
+    pub id: String,
+    pub label: String,
+}
+
+/// One line of `/admin/matriculas`: what the coordination needs to decide and
+/// to get in touch. Health notes and the address stay out of the list.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnrollmentRow {
+    pub protocol: String,
+    pub student_name: String,
+    pub age: u32,
+    /// Name and phone of whoever answers for the student: the guardian of a minor.
+    pub contact_name: String,
+    pub contact_phone: String,
+    /// Title of the chosen turma, if it is still on the schedule.
+    pub session: Option<String>,
+    pub consent_image: bool,
+    pub submitted: NaiveDate,
+    pub status: EnrollmentStatus,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::schedule::Modality;
+    use chrono::{NaiveTime, Weekday};
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 11, 3).unwrap()
+    }
+
+    fn schedule() -> Schedule {
+        Schedule {
+            sessions: vec![ClassSession {
+                id: "adultos-seg".into(),
+                weekday: Weekday::Mon,
+                start: NaiveTime::from_hms_opt(19, 0, 0).unwrap(),
+                end: NaiveTime::from_hms_opt(20, 30, 0).unwrap(),
+                modality: Modality::Capoeira,
+                location: "Sede".into(),
+                age_group: "Jovens e adultos".into(),
+                instructor: "Instrutor".into(),
+            }],
+            ..Default::default()
+        }
+    }
+
+    /// An adult's form that passes every rule.
+    fn adult() -> EnrollmentForm {
+        EnrollmentForm {
+            student_name: "Ana Souza".into(),
+            birth_date: NaiveDate::from_ymd_opt(1995, 4, 12).unwrap(),
+            phone: "(67) 99999-0000".into(),
+            email: String::new(),
+            address: String::new(),
+            school: String::new(),
+            guardian_name: String::new(),
+            guardian_relationship: String::new(),
+            guardian_phone: String::new(),
+            guardian_email: String::new(),
+            session_id: "adultos-seg".into(),
+            health_notes: String::new(),
+            consent_data: true,
+            consent_health: false,
+            consent_image: false,
+            consent_guardian: false,
+        }
+    }
+
+    /// A ten-year-old's form with the guardian filled in.
+    fn minor() -> EnrollmentForm {
+        EnrollmentForm {
+            birth_date: NaiveDate::from_ymd_opt(2016, 5, 1).unwrap(),
+            guardian_name: "Maria Souza".into(),
+            guardian_relationship: "Mãe".into(),
+            guardian_phone: "(67) 98888-0000".into(),
+            consent_guardian: true,
+            ..adult()
+        }
+    }
+
+    fn validate(form: EnrollmentForm) -> Result<(), EnrollmentError> {
+        form.normalized().validate(today(), &schedule())
+    }
+
+    #[test]
+    fn complete_forms_pass() {
+        assert_eq!(validate(adult()), Ok(()));
+        assert_eq!(validate(minor()), Ok(()));
+    }
+
+    #[test]
+    fn missing_fields_are_named() {
+        assert_eq!(
+            validate(EnrollmentForm { student_name: "  ".into(), ..adult() }),
+            Err(EnrollmentError::Missing(RequiredField::StudentName))
+        );
+        assert_eq!(
+            validate(EnrollmentForm { phone: String::new(), ..adult() }),
+            Err(EnrollmentError::Missing(RequiredField::Phone))
+        );
+        // Guardian data is required for minors only.
+        assert_eq!(
+            validate(EnrollmentForm { guardian_name: String::new(), ..minor() }),
+            Err(EnrollmentError::Missing(RequiredField::GuardianName))
+        );
+        assert_eq!(
+            validate(EnrollmentForm { guardian_relationship: String::new(), ..minor() }),
+            Err(EnrollmentError::Missing(RequiredField::GuardianRelationship))
+        );
+        assert_eq!(
+            validate(EnrollmentForm { guardian_phone: String::new(), ..minor() }),
+            Err(EnrollmentError::Missing(RequiredField::GuardianPhone))
+        );
+    }
+
+    #[test]
+    fn birth_dates_must_be_plausible() {
+        assert_eq!(
+            validate(EnrollmentForm { birth_date: today().succ_opt().unwrap(), ..adult() }),
+            Err(EnrollmentError::BirthDate)
+        );
+        assert_eq!(
+            validate(EnrollmentForm { birth_date: NaiveDate::from_ymd_opt(1900, 1, 1).unwrap(), ..adult() }),
+            Err(EnrollmentError::BirthDate)
+        );
+    }
+
+    #[test]
+    fn the_turma_must_be_on_the_schedule() {
+        assert_eq!(
+            validate(EnrollmentForm { session_id: "extinta".into(), ..adult() }),
+            Err(EnrollmentError::UnknownSession)
+        );
+    }
+
+    #[test]
+    fn consents_required_by_the_lgpd() {
+        assert_eq!(
+            validate(EnrollmentForm { consent_guardian: false, ..minor() }),
+            Err(EnrollmentError::ConsentRequired(Consent::Guardian))
+        );
+        assert_eq!(
+            validate(EnrollmentForm { consent_data: false, ..adult() }),
+            Err(EnrollmentError::ConsentRequired(Consent::Data))
+        );
+        // Health notes need their own consent; without notes it is not asked.
+        assert_eq!(
+            validate(EnrollmentForm { health_notes: "Asma".into(), ..adult() }),
+            Err(EnrollmentError::ConsentRequired(Consent::Health))
+        );
+        assert_eq!(validate(EnrollmentForm { health_notes: "Asma".into(), consent_health: true, ..adult() }), Ok(()));
+        // Image use stays a free choice: refusing it never blocks the enrollment.
+        assert_eq!(validate(EnrollmentForm { consent_image: false, ..minor() }), Ok(()));
+        assert_eq!(validate(EnrollmentForm { consent_image: true, ..adult() }), Ok(()));
+    }
+
+    #[test]
+    fn messages_follow_the_visitors_language() {
+        let missing = EnrollmentError::Missing(RequiredField::Phone);
+        assert_eq!(missing.message(Locale::PtBr), "Preencha o campo \"Telefone / WhatsApp\".");
+        assert_eq!(missing.message(Locale::Es), "Completá el campo \"Teléfono / WhatsApp\".");
+        assert_eq!(missing.to_string(), missing.message(Locale::PtBr));
+
+        let consent = EnrollmentError::ConsentRequired(Consent::Data);
+        assert_eq!(consent.message(Locale::En), "You need to authorize the processing of personal data to complete the enrollment.");
+    }
+}
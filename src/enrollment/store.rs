@@ -0,0 +1,202 @@
+//! Reading and writing enrollments in the local database.
+
+use chrono::NaiveDateTime;
+use rusqlite::{params, Connection, OptionalExtension, Row};
+
+use super::{EnrollmentForm, EnrollmentStatus};
+use crate::db::DbError;
+
+/// A stored enrollment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Enrollment {
+    pub id: i64,
+    pub protocol: String,
+    pub status: EnrollmentStatus,
+    pub form: EnrollmentForm,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum StoreError {
+    Db(DbError),
+    NotFound,
+    InvalidTransition { from: EnrollmentStatus, to: EnrollmentStatus },
+}
+
+impl std::fmt::Display for StoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StoreError::Db(e) => e.fmt(f),
+            StoreError::NotFound => write!(f, "enrollment not found"),
+            StoreError::InvalidTransition { from, to } => {
+                write!(f, "cannot move an enrollment from {} to {}", from.as_str(), to.as_str())
+            }
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+impl From<DbError> for StoreError {
+    fn from(e: DbError) -> Self {
+        StoreError::Db(e)
+    }
+}
+
+impl From<rusqlite::Error> for StoreError {
+    fn from(e: rusqlite::Error) -> Self {
+        StoreError::Db(DbError::Sqlite(e))
+    }
+}
+
+const COLUMNS: &str = "id, protocol, status, student_name, birth_date, phone, email, address, school, \
+    guardian_name, guardian_relationship, guardian_phone, guardian_email, session_id, health_notes, \
+    consent_data, consent_health, consent_image, consent_guardian, created_at, updated_at";
+
+/// Stores a new, pending enrollment and returns its protocol.
+pub fn insert(conn: &Connection, form: &EnrollmentForm, now: NaiveDateTime) -> Result<String, StoreError> {
+    let protocol = uuid::Uuid::new_v4().to_string();
+    conn.execute(
+        "INSERT INTO enrollments (protocol, status, student_name, birth_date, phone, email, address, school, \
+            guardian_name, guardian_relationship, guardian_phone, guardian_email, session_id, health_notes, \
+            consent_data, consent_health, consent_image, consent_guardian, created_at, updated_at) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?19)",
+        params![
+            protocol,
+            EnrollmentStatus::Pending.as_str(),
+            form.student_name,
+            form.birth_date,
+            form.phone,
+            form.email,
+            form.address,
+            form.school,
+            form.guardian_name,
+            form.guardian_relationship,
+            form.guardian_phone,
+            form.guardian_email,
+            form.session_id,
+            form.health_notes,
+            form.consent_data,
+            form.consent_health,
+            form.consent_image,
+            form.consent_guardian,
+            now,
+        ],
+    )?;
+    Ok(protocol)
+}
+
+pub fn find(conn: &Connection, protocol: &str) -> Result<Option<Enrollment>, StoreError> {
+    let enrollment = conn
+        .query_row(
+            &format!("SELECT {COLUMNS} FROM enrollments WHERE protocol = ?1"),
+            params![protocol],
+            from_row,
+        )
+        .optional()?;
+    Ok(enrollment)
+}
+
+/// Enrollments oldest first, optionally only those in `status`.
+pub fn list(conn: &Connection, status: Option<EnrollmentStatus>) -> Result<Vec<Enrollment>, StoreError> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {COLUMNS} FROM enrollments WHERE ?1 IS NULL OR status = ?1 ORDER BY created_at, id"
+    ))?;
+    let rows = stmt.query_map(params![status.map(EnrollmentStatus::as_str)], from_row)?;
+    Ok(rows.collect::<Result<_, _>>()?)
+}
+
+/// Moves an enrollment one step forward in the workflow (pending → accepted → active).
+pub fn set_status(
+    conn: &Connection,
+    protocol: &str,
+    to: EnrollmentStatus,
+    now: NaiveDateTime,
+) -> Result<(), StoreError> {
+    let from = find(conn, protocol)?.ok_or(StoreError::NotFound)?.status;
+    if from.next() != Some(to) {
+        return Err(StoreError::InvalidTransition { from, to });
+    }
+
+    conn.execute(
+        "UPDATE enrollments SET status = ?1, updated_at = ?2 WHERE protocol = ?3 AND status = ?4",
+        params![to.as_str(), now, protocol, from.as_str()],
+    )?;
+    Ok(())
+}
+
+fn from_row(row: &Row) -> rusqlite::Result<Enrollment> {
+    let status: String = row.get("status")?;
+    let status = EnrollmentStatus::parse(&status).ok_or_else(|| {
+        rusqlite::Error::FromSqlConversionFailure(2, rusqlite::types::Type::Text, format!("unknown status {status:?}").into())
+    })?;
+
+    Ok(Enrollment {
+        id: row.get("id")?,
+        protocol: row.get("protocol")?,
+        status,
+        form: EnrollmentForm {
+            student_name: row.get("student_name")?,
+            birth_date: row.get("birth_date")?,
+            phone: row.get("phone")?,
+            email: row.get("email")?,
+            address: row.get("address")?,
+            school: row.get("school")?,
+            guardian_name: row.get("guardian_name")?,
+            guardian_relationship: row.get("guardian_relationship")?,
+            guardian_phone: row.get("guardian_phone")?,
+            guardian_email: row.get("guardian_email")?,
+            session_id: row.get("session_id")?,
+            health_notes: row.get("health_notes")?,
+            consent_data: row.get("consent_data")?,
+            consent_health: row.get("consent_health")?,
+            consent_image: row.get("consent_image")?,
+            consent_guardian: row.get("consent_guardian")?,
+        },
+        created_at: row.get("created_at")?,
+        updated_at: row.get("updated_at")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn status_only_moves_forward_one_step() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        crate::db::migrations::migrate(&mut conn).unwrap();
+        let now = NaiveDate::from_ymd_opt(2026, 11, 3).unwrap().and_hms_opt(9, 0, 0).unwrap();
+        let form = EnrollmentForm {
+            student_name: "Ana Souza".into(),
+            birth_date: NaiveDate::from_ymd_opt(1995, 4, 12).unwrap(),
+            phone: "(31) 99999-0000".into(),
+            email: String::new(),
+            address: String::new(),
+            school: String::new(),
+            guardian_name: String::new(),
+            guardian_relationship: String::new(),
+            guardian_phone: String::new(),
+            guardian_email: String::new(),
+            session_id: "adultos-seg".into(),
+            health_notes: String::new(),
+            consent_data: true,
+            consent_health: true,
+            consent_image: false,
+            consent_guardian: false,
+        };
+        let protocol = insert(&conn, &form, now).unwrap();
+
+        assert!(matches!(
+            set_status(&conn, &protocol, EnrollmentStatus::Active, now),
+            Err(StoreError::InvalidTransition { from: EnrollmentStatus::Pending, to: EnrollmentStatus::Active })
+        ));
+        set_status(&conn, &protocol, EnrollmentStatus::Accepted, now).unwrap();
+        assert_eq!(list(&conn, Some(EnrollmentStatus::Pending)).unwrap(), vec![]);
+        set_status(&conn, &protocol, EnrollmentStatus::Active, now).unwrap();
+        assert_eq!(list(&conn, Some(EnrollmentStatus::Active)).unwrap()[0].protocol, protocol);
+        assert!(matches!(set_status(&conn, "desconhecido", EnrollmentStatus::Accepted, now), Err(StoreError::NotFound)));
+    }
+}
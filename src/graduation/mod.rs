@@ -0,0 +1,80 @@
+//! Graduações: which corda each student received, when, at which event and from whom.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::content::cordas::Corda;
+
+#[cfg(feature = "ssr")]
+pub mod store;
+
+/// A recorded change of corda.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Graduation {
+    pub enrollment_id: i64,
+    pub corda: String,
+    pub date: NaiveDate,
+    /// Name of the batizado or event, as shown in the timeline.
+    pub event: String,
+    /// Slug of the agenda event, when the batizado is on `/agenda`.
+    pub event_slug: Option<String>,
+    /// Mestre (or professor) who handed the corda over.
+    pub awarded_by: String,
+}
+
+/// A student on the batizado screen, with the corda they hold today.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StudentCorda {
+    pub enrollment_id: i64,
+    pub student_name: String,
+    pub current: Option<String>,
+}
+
+/// An agenda event that can be picked as the batizado being recorded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatizadoEvent {
+    pub slug: String,
+    pub title: String,
+    pub date: NaiveDate,
+}
+
+/// Everything the bulk screen needs in one request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatizadoSheet {
+    pub cordas: Vec<Corda>,
+    pub events: Vec<BatizadoEvent>,
+    pub students: Vec<StudentCorda>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatizadoEntry {
+    pub enrollment_id: i64,
+    pub corda: String,
+}
+
+/// The results of one batizado, saved together.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatizadoResults {
+    pub event: String,
+    pub event_slug: Option<String>,
+    pub date: NaiveDate,
+    pub awarded_by: String,
+    pub entries: Vec<BatizadoEntry>,
+}
+
+/// One step of a student's timeline, with the corda resolved from the system.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimelineStep {
+    pub corda: Corda,
+    pub date: NaiveDate,
+    pub event: String,
+    pub event_slug: Option<String>,
+    pub awarded_by: String,
+}
+
+/// A student's graduations, oldest first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraduationTimeline {
+    pub student_name: String,
+    pub steps: Vec<TimelineStep>,
+}
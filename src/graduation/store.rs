@@ -0,0 +1,199 @@
+//! Reading and writing graduations in the local database.
+
+use chrono::NaiveDateTime;
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+
+use super::{BatizadoResults, Graduation, StudentCorda};
+use crate::audit::Fields;
+use crate::db::DbError;
+
+/// Students with an accepted or active enrollment and their latest corda.
+pub fn current_cordas(conn: &Connection) -> Result<Vec<StudentCorda>, DbError> {
+    let mut stmt = conn.prepare(
+        "SELECT e.id, e.student_name,
+            (SELECT g.corda FROM graduations g WHERE g.enrollment_id = e.id ORDER BY g.date DESC, g.id DESC LIMIT 1)
+         FROM enrollments e
+         WHERE e.status IN ('accepted', 'active')
+         ORDER BY e.student_name COLLATE NOCASE",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok(StudentCorda {
+            enrollment_id: row.get(0)?,
+            student_name: row.get(1)?,
+            current: row.get(2)?,
+        })
+    })?;
+    Ok(rows.collect::<Result<_, _>>()?)
+}
+
+/// Stores every entry of a batizado and returns how many were saved.
+///
+/// Recording the same corda twice for a student corrects the earlier record
+/// instead of duplicating it.
+pub fn record_batizado(tx: &Transaction, results: &BatizadoResults, now: NaiveDateTime) -> Result<usize, DbError> {
+    let mut saved = 0;
+    for entry in &results.entries {
+        saved += tx.execute(
+            "INSERT INTO graduations (enrollment_id, corda, date, event, event_slug, awarded_by, recorded_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+             ON CONFLICT (enrollment_id, corda) DO UPDATE SET
+                date = excluded.date, event = excluded.event, event_slug = excluded.event_slug,
+                awarded_by = excluded.awarded_by, recorded_at = excluded.recorded_at",
+            params![
+                entry.enrollment_id,
+                entry.corda,
+                results.date,
+                results.event,
+                results.event_slug,
+                results.awarded_by,
+                now,
+            ],
+        )?;
+    }
+    Ok(saved)
+}
+
+/// The current corda of the given students as audit fields: "Name (#id)" → corda.
+pub fn cordas_snapshot(conn: &Connection, enrollment_ids: &[i64]) -> Result<Fields, DbError> {
+    Ok(current_cordas(conn)?
+        .into_iter()
+        .filter(|s| enrollment_ids.contains(&s.enrollment_id))
+        .filter_map(|s| Some((format!("{} (#{})", s.student_name, s.enrollment_id), s.current?)))
+        .collect())
+}
+
+/// Name of the student and their graduations, oldest first.
+pub fn history(conn: &Connection, enrollment_id: i64) -> Result<Option<(String, Vec<Graduation>)>, DbError> {
+    let Some(name) = conn
+        .query_row("SELECT student_name FROM enrollments WHERE id = ?1", params![enrollment_id], |row| row.get(0))
+        .optional()?
+    else {
+        return Ok(None);
+    };
+
+    let mut stmt = conn.prepare(
+        "SELECT enrollment_id, corda, date, event, event_slug, awarded_by
+         FROM graduations WHERE enrollment_id = ?1 ORDER BY date, id",
+    )?;
+    let graduations = stmt
+        .query_map(params![enrollment_id], |row| {
+            Ok(Graduation {
+                enrollment_id: row.get(0)?,
+                corda: row.get(1)?,
+                date: row.get(2)?,
+                event: row.get(3)?,
+                event_slug: row.get(4)?,
+                awarded_by: row.get(5)?,
+            })
+        })?
+        .collect::<Result<_, _>>()?;
+    Ok(Some((name, graduations)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    use crate::enrollment::store as enrollments;
+    use crate::enrollment::{EnrollmentForm, EnrollmentStatus};
+    use crate::graduation::BatizadoEntry;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    /// Enrolls `name` and moves the enrollment up to `status`, returning its id.
+    fn enroll(conn: &Connection, name: &str, status: EnrollmentStatus) -> i64 {
+        let now = date(2025, 2, 1).and_hms_opt(9, 0, 0).unwrap();
+        let form = EnrollmentForm {
+            student_name: name.into(),
+            birth_date: date(1990, 1, 1),
+            phone: "(67) 99999-0000".into(),
+            email: String::new(),
+            address: String::new(),
+            school: String::new(),
+            guardian_name: String::new(),
+            guardian_relationship: String::new(),
+            guardian_phone: String::new(),
+            guardian_email: String::new(),
+            session_id: "adultos-seg".into(),
+            health_notes: String::new(),
+            consent_data: true,
+            consent_health: false,
+            consent_image: false,
+            consent_guardian: false,
+        };
+        let protocol = enrollments::insert(conn, &form, now).unwrap();
+        let mut current = EnrollmentStatus::Pending;
+        while current != status {
+            current = current.next().unwrap();
+            enrollments::set_status(conn, &protocol, current, now).unwrap();
+        }
+        enrollments::find(conn, &protocol).unwrap().unwrap().id
+    }
+
+    fn batizado(date: NaiveDate, event: &str, entries: &[(i64, &str)]) -> BatizadoResults {
+        BatizadoResults {
+            event: event.into(),
+            event_slug: None,
+            date,
+            awarded_by: "Mestre".into(),
+            entries: entries.iter().map(|(id, corda)| BatizadoEntry { enrollment_id: *id, corda: corda.to_string() }).collect(),
+        }
+    }
+
+    fn record(conn: &mut Connection, results: &BatizadoResults) -> usize {
+        let now = results.date.and_hms_opt(18, 0, 0).unwrap();
+        let tx = conn.transaction().unwrap();
+        let saved = record_batizado(&tx, results, now).unwrap();
+        tx.commit().unwrap();
+        saved
+    }
+
+    #[test]
+    fn a_batizado_records_every_student_at_once() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        crate::db::migrations::migrate(&mut conn).unwrap();
+        let bruno = enroll(&conn, "Bruno", EnrollmentStatus::Active);
+        let ana = enroll(&conn, "ana", EnrollmentStatus::Accepted);
+        enroll(&conn, "Carla", EnrollmentStatus::Pending);
+
+        let saved = record(&mut conn, &batizado(date(2025, 11, 29), "Batizado 2025", &[(bruno, "crua"), (ana, "crua")]));
+        assert_eq!(saved, 2);
+
+        // Pending enrollments stay off the screen; names sort without regard to case.
+        let students = current_cordas(&conn).unwrap();
+        let names: Vec<_> = students.iter().map(|s| (s.student_name.as_str(), s.current.as_deref())).collect();
+        assert_eq!(names, [("ana", Some("crua")), ("Bruno", Some("crua"))]);
+
+        // Recording the same corda again corrects it instead of adding a second one.
+        record(&mut conn, &batizado(date(2025, 11, 30), "Batizado 2025 (segundo dia)", &[(ana, "crua")]));
+        let (_, steps) = history(&conn, ana).unwrap().unwrap();
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].date, date(2025, 11, 30));
+
+        let snapshot = cordas_snapshot(&conn, &[bruno]).unwrap();
+        assert_eq!(snapshot, Fields::from([(format!("Bruno (#{bruno})"), "crua".to_string())]));
+    }
+
+    #[test]
+    fn the_timeline_follows_the_dates_not_the_order_of_recording() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        crate::db::migrations::migrate(&mut conn).unwrap();
+        let bruno = enroll(&conn, "Bruno", EnrollmentStatus::Active);
+
+        record(&mut conn, &batizado(date(2026, 11, 28), "Batizado 2026", &[(bruno, "crua-amarela")]));
+        // An older graduation typed in afterwards, from the paper records.
+        record(&mut conn, &batizado(date(2025, 11, 29), "Batizado 2025", &[(bruno, "crua")]));
+
+        let (name, steps) = history(&conn, bruno).unwrap().unwrap();
+        assert_eq!(name, "Bruno");
+        let cordas: Vec<_> = steps.iter().map(|s| (s.corda.as_str(), s.event.as_str())).collect();
+        assert_eq!(cordas, [("crua", "Batizado 2025"), ("crua-amarela", "Batizado 2026")]);
+        // The current corda is the most recent one, not the last typed.
+        assert_eq!(current_cordas(&conn).unwrap()[0].current.as_deref(), Some("crua-amarela"));
+
+        assert_eq!(history(&conn, bruno + 100).unwrap(), None);
+    }
+}
@@ -0,0 +1,272 @@
+/// Every text of the public site, in one language.
+///
+/// Plain structs rather than message keys: a translation that misses a text
+/// does not compile.
+#[derive(Debug)]
+pub struct Catalog {
+    pub site_name: &'static str,
+    pub logo_alt: &'static str,
+    /// Label of the language switcher.
+    pub language: &'static str,
+    pub nav: Nav,
+    pub footer: Footer,
+    pub home: Home,
+    pub newsletter: Newsletter,
+    pub pages: Pages,
+    pub dates: Dates,
+    pub labels: Labels,
+    pub agenda: Agenda,
+    pub schedule: Schedule,
+    pub donate: Donate,
+    pub songs: Songs,
+    pub enrollment: Enrollment,
+}
+
+impl Catalog {
+    /// Document title of a page: "Agenda | Associação Camará Capoeira".
+    pub fn title(&self, page: &str) -> String {
+        format!("{} | {}", page, self.site_name)
+    }
+}
+
+#[derive(Debug)]
+pub struct Nav {
+    pub navigation: &'static str,
+    pub toggle_menu: &'static str,
+    pub home: &'static str,
+    pub projects: &'static str,
+    pub ponto_de_cultura: &'static str,
+    pub schedule: &'static str,
+    pub enrollment: &'static str,
+    pub agenda: &'static str,
+    pub news: &'static str,
+    pub songs: &'static str,
+    pub berimbau: &'static str,
+    pub gallery: &'static str,
+    pub press: &'static str,
+    pub donate: &'static str,
+    pub transparency: &'static str,
+    pub about: &'static str,
+    pub contact: &'static str,
+}
+
+#[derive(Debug)]
+pub struct Footer {
+    pub contact: &'static str,
+    pub rights: &'static str,
+}
+
+#[derive(Debug)]
+pub struct Home {
+    pub tagline: &'static str,
+    pub lead: &'static str,
+    pub cta: &'static str,
+    pub about: &'static str,
+    pub about_quote: &'static str,
+    pub pillars: &'static str,
+    pub pillar_cultura_viva: &'static str,
+    pub pillar_child_labour: &'static str,
+    pub pillar_inclusion: &'static str,
+    pub press: &'static str,
+    pub all_press: &'static str,
+    pub partners: &'static str,
+    pub past_partners: &'static str,
+}
+
+#[derive(Debug)]
+pub struct Newsletter {
+    pub title: &'static str,
+    pub lead: &'static str,
+    pub placeholder: &'static str,
+    pub subscribe: &'static str,
+    pub sent: &'static str,
+    pub failed: &'static str,
+    pub fine_print: &'static str,
+    pub email_label: &'static str,
+    pub invalid_email: &'static str,
+    pub see_news: &'static str,
+    pub confirming: &'static str,
+    pub confirmed: &'static str,
+    pub next_issue: &'static str,
+    pub link_expired: &'static str,
+    pub confirm_failed: &'static str,
+    /// Title of the unsubscribe page and label of its button.
+    pub unsubscribe: &'static str,
+    pub unsubscribe_question: &'static str,
+    pub unsubscribed: &'static str,
+    pub no_more_issues: &'static str,
+    pub unknown_link: &'static str,
+    pub unsubscribe_failed: &'static str,
+}
+
+/// Title banner of an inner page (`PageLayout`).
+#[derive(Debug)]
+pub struct Page {
+    pub title: &'static str,
+    pub subtitle: &'static str,
+}
+
+#[derive(Debug)]
+pub struct Pages {
+    pub agenda: Page,
+    pub news: Page,
+    pub gallery: Page,
+    pub songs: Page,
+    pub berimbau: Page,
+    pub schedule: Page,
+    pub press: Page,
+    pub transparency: Page,
+    pub donate: Page,
+    pub enrollment: Page,
+}
+
+/// Names used by `content::dates`; the formats themselves are in that module.
+#[derive(Debug)]
+pub struct Dates {
+    /// Monday first: "segunda-feira".
+    pub weekdays: [&'static str; 7],
+    /// Monday first, for the schedule grid: "Segunda".
+    pub weekdays_short: [&'static str; 7],
+    /// January first.
+    pub months: [&'static str; 12],
+}
+
+/// Labels of the enums shown on public pages (`EventKind`, `Modality`, `EnrollmentStatus`).
+#[derive(Debug)]
+pub struct Labels {
+    pub batizado: &'static str,
+    pub roda_aberta: &'static str,
+    pub oficina: &'static str,
+    pub apresentacao: &'static str,
+    pub capoeira: &'static str,
+    pub percussao: &'static str,
+    pub maculele: &'static str,
+    pub enrollment_pending: &'static str,
+    pub enrollment_accepted: &'static str,
+    pub enrollment_active: &'static str,
+}
+
+#[derive(Debug)]
+pub struct Agenda {
+    pub loading: &'static str,
+    pub failed: &'static str,
+    pub upcoming: &'static str,
+    pub no_upcoming: &'static str,
+    pub past: &'static str,
+    pub no_past: &'static str,
+    pub subscribe_lead: &'static str,
+    /// Followed by the IANA name of the time zone, in parentheses.
+    pub timezone: &'static str,
+    pub subscribe: &'static str,
+    pub loading_event: &'static str,
+    pub event_failed: &'static str,
+    pub not_found: &'static str,
+    pub back: &'static str,
+    pub date: &'static str,
+    pub time: &'static str,
+    pub local_time: &'static str,
+    pub location: &'static str,
+    pub add_to_calendar: &'static str,
+}
+
+#[derive(Debug)]
+pub struct Schedule {
+    pub loading: &'static str,
+    pub failed: &'static str,
+    pub empty: &'static str,
+    pub cancellations: &'static str,
+    pub extras: &'static str,
+    /// Followed by the date: "Sem aula em 15/11".
+    pub no_class_on: &'static str,
+}
+
+#[derive(Debug)]
+pub struct Donate {
+    pub loading: &'static str,
+    pub failed: &'static str,
+    pub unavailable: &'static str,
+    pub choose: &'static str,
+    pub any_amount: &'static str,
+    pub other_amount: &'static str,
+    pub generate: &'static str,
+    pub scan: &'static str,
+    pub copy: &'static str,
+    pub copied: &'static str,
+}
+
+#[derive(Debug)]
+pub struct Songs {
+    pub loading: &'static str,
+    pub failed: &'static str,
+    pub none_found: &'static str,
+    pub all: &'static str,
+    pub search_placeholder: &'static str,
+    pub search_label: &'static str,
+    pub search: &'static str,
+    pub songbook_link: &'static str,
+    pub has_recording: &'static str,
+    pub loading_song: &'static str,
+    pub song_failed: &'static str,
+    pub not_found: &'static str,
+    pub back: &'static str,
+    pub no_audio: &'static str,
+    pub coro_hint: &'static str,
+    pub songbook: &'static str,
+    pub songbook_lead: &'static str,
+    pub print: &'static str,
+}
+
+#[derive(Debug)]
+pub struct Enrollment {
+    pub loading: &'static str,
+    pub sessions_failed: &'static str,
+    pub submit_failed: &'static str,
+    pub save_failed: &'static str,
+    pub student: &'static str,
+    pub student_name: &'static str,
+    pub birth_date: &'static str,
+    pub phone: &'static str,
+    pub email: &'static str,
+    pub address: &'static str,
+    pub school: &'static str,
+    pub guardian: &'static str,
+    /// `{}` is replaced by the age of majority.
+    pub guardian_hint: &'static str,
+    pub guardian_name: &'static str,
+    pub guardian_relationship: &'static str,
+    pub relationship_placeholder: &'static str,
+    pub guardian_phone: &'static str,
+    pub guardian_email: &'static str,
+    pub session: &'static str,
+    pub choose_session: &'static str,
+    pub select: &'static str,
+    pub see_schedule: &'static str,
+    pub health: &'static str,
+    pub health_hint: &'static str,
+    pub consents: &'static str,
+    pub consent_data: &'static str,
+    pub consent_health: &'static str,
+    pub consent_image: &'static str,
+    pub consent_guardian: &'static str,
+    pub send: &'static str,
+    pub sending: &'static str,
+    pub received: &'static str,
+    pub keep_protocol: &'static str,
+    /// Link to the status page and its title.
+    pub track: &'static str,
+    pub status_loading: &'static str,
+    pub status_failed: &'static str,
+    pub protocol_not_found: &'static str,
+    /// `{}` is replaced by the student's first name.
+    pub hello: &'static str,
+    pub submitted_on: &'static str,
+    /// `{}` is replaced by the label of the field left empty.
+    pub missing: &'static str,
+    pub invalid_birth_date: &'static str,
+    pub unknown_session: &'static str,
+    /// `{}` is replaced by one of the `consent_*_required` texts.
+    pub consent_required: &'static str,
+    pub consent_guardian_required: &'static str,
+    pub consent_data_required: &'static str,
+    pub consent_health_required: &'static str,
+}
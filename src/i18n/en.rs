@@ -0,0 +1,248 @@
+use super::catalog::*;
+
+pub const CATALOG: Catalog = Catalog {
+    site_name: "Associação Camará Capoeira",
+    logo_alt: "Associação Camará Capoeira logo",
+    language: "Language",
+    nav: Nav {
+        navigation: "Navigation",
+        toggle_menu: "Open or close the projects menu",
+        home: "Home",
+        projects: "Projects",
+        ponto_de_cultura: "Ponto de Cultura",
+        schedule: "Class schedule",
+        enrollment: "Enrollment",
+        agenda: "Events",
+        news: "News",
+        songs: "Songs",
+        berimbau: "Berimbau rhythms",
+        gallery: "Gallery",
+        press: "In the press",
+        donate: "Support us",
+        transparency: "Transparency",
+        about: "About us",
+        contact: "Contact us",
+    },
+    footer: Footer {
+        contact: "Contact",
+        rights: "© 2024 Associação de Capoeira Camará. All rights reserved.",
+    },
+    home: Home {
+        tagline: "Culture, education and citizenship in Ponta Porã, Brazil",
+        lead: "Promoting social inclusion and preserving Afro-Brazilian culture on the border through the art of capoeira.",
+        cta: "Discover our projects",
+        about: "Officially recognized as a Ponto de Cultura (community culture hub), Camará Capoeira is a beacon of social change in Mato Grosso do Sul. Our work goes beyond the roda: we fight child labour, promote heritage education and strengthen our community's cultural identity through ongoing programs.",
+        about_quote: "More than a sport: a tool for social inclusion and full citizenship.",
+        pillars: "Our pillars",
+        pillar_cultura_viva: "Cultura Viva programme",
+        pillar_child_labour: "Fighting child labour",
+        pillar_inclusion: "Social inclusion and education",
+        press: "In the press",
+        all_press: "See all articles →",
+        partners: "Partners",
+        past_partners: "Past supporters",
+    },
+    newsletter: Newsletter {
+        title: "Camará newsletter",
+        lead: "News, rodas and events from the group in your inbox, once a month.",
+        placeholder: "you@email.com",
+        subscribe: "Subscribe",
+        sent: "Almost there! We sent a confirmation link to your e-mail.",
+        failed: "We could not complete your subscription. Please try again.",
+        fine_print: "We only send after you confirm through the link. You can unsubscribe at any time.",
+        email_label: "E-mail",
+        invalid_email: "Please check the e-mail address.",
+        see_news: "See the group's news",
+        confirming: "Confirming...",
+        confirmed: "Subscription confirmed. Axé!",
+        next_issue: "The next newsletter will arrive in your inbox.",
+        link_expired: "This link has expired or was already used. If you are not getting the newsletter yet, subscribe again.",
+        confirm_failed: "We could not confirm your subscription right now.",
+        unsubscribe: "Unsubscribe",
+        unsubscribe_question: "Do you want to stop receiving the Camará newsletter?",
+        unsubscribed: "You have been unsubscribed.",
+        no_more_issues: "You will not receive the newsletter any more.",
+        unknown_link: "This link does not match any subscription.",
+        unsubscribe_failed: "We could not unsubscribe you right now. Please try again.",
+    },
+    pages: Pages {
+        agenda: Page {
+            title: "Events",
+            subtitle: "Batizados, open rodas, workshops and performances by the group.",
+        },
+        news: Page {
+            title: "News",
+            subtitle: "Updates on Camará's classes, events and projects.",
+        },
+        gallery: Page {
+            title: "Gallery",
+            subtitle: "Photos of Camará's classes, rodas and events.",
+        },
+        songs: Page {
+            title: "Songs",
+            subtitle: "The songs we sing in the roda, to study at home and follow along in class. Lyrics are in Portuguese.",
+        },
+        berimbau: Page {
+            title: "Berimbau rhythms",
+            subtitle: "Pick a toque, set the tempo and follow the dong, the ding and the buzz on the timeline.",
+        },
+        schedule: Page {
+            title: "Class schedule",
+            subtitle: "Free capoeira, percussion and maculelê classes for all ages.",
+        },
+        press: Page {
+            title: "In the press",
+            subtitle: "Articles and reports about the work of Camará Capoeira.",
+        },
+        transparency: Page {
+            title: "Transparency",
+            subtitle: "Accounts of the public funds received by the Camará Ponto de Cultura.",
+        },
+        donate: Page {
+            title: "Support Camará",
+            subtitle: "Your donation keeps the free capoeira, percussion and maculelê workshops running in Parque dos Ipês II.",
+        },
+        enrollment: Page {
+            title: "Enrollment",
+            subtitle: "Sign up for the free classes. The coordinators will get in touch to confirm your place.",
+        },
+    },
+    dates: Dates {
+        weekdays: ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"],
+        weekdays_short: ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"],
+        months: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+    },
+    labels: Labels {
+        batizado: "Batizado",
+        roda_aberta: "Open roda",
+        oficina: "Workshop",
+        apresentacao: "Performance",
+        capoeira: "Capoeira",
+        percussao: "Percussion",
+        maculele: "Maculelê",
+        enrollment_pending: "Under review",
+        enrollment_accepted: "Accepted",
+        enrollment_active: "Active",
+    },
+    agenda: Agenda {
+        loading: "Loading events...",
+        failed: "We could not load the events.",
+        upcoming: "Upcoming events",
+        no_upcoming: "No events scheduled at the moment.",
+        past: "Past events",
+        no_past: "No past events recorded.",
+        subscribe_lead: "Add the group's events to your phone's calendar and get updates automatically.",
+        timezone: "Times are in the Mato Grosso do Sul time zone",
+        subscribe: "Subscribe to the calendar",
+        loading_event: "Loading event...",
+        event_failed: "We could not load the event.",
+        not_found: "Event not found",
+        back: "← Back to events",
+        date: "Date",
+        time: "Time",
+        local_time: "(MS time)",
+        location: "Place",
+        add_to_calendar: "Add to calendar (.ics)",
+    },
+    schedule: Schedule {
+        loading: "Loading the timetable...",
+        failed: "We could not load the timetable.",
+        empty: "No classes listed at the moment.",
+        cancellations: "Notices: classes that will not take place",
+        extras: "Extra classes",
+        no_class_on: "No class on",
+    },
+    donate: Donate {
+        loading: "Loading...",
+        failed: "We could not generate the PIX code. Please try again later.",
+        unavailable: "PIX donations will be available soon. In the meantime, write to us: contato@camaracapoeira.org.br",
+        choose: "Choose an amount",
+        any_amount: "Any amount",
+        other_amount: "Other amount (R$)",
+        generate: "Generate PIX",
+        scan: "Point your banking app's camera at the code or use PIX copy and paste:",
+        copy: "Copy PIX code",
+        copied: "Copied!",
+    },
+    songs: Songs {
+        loading: "Loading songs...",
+        failed: "We could not load the songs.",
+        none_found: "No songs found.",
+        all: "All",
+        search_placeholder: "Search lyrics, title or author",
+        search_label: "Search songs",
+        search: "Search",
+        songbook_link: "Printable songbook",
+        has_recording: "With recording",
+        loading_song: "Loading song...",
+        song_failed: "We could not load the song.",
+        not_found: "Song not found",
+        back: "← Back to the songs",
+        no_audio: "Your browser does not play audio.",
+        coro_hint: "In bold, the chorus's answer.",
+        songbook: "Songbook",
+        songbook_lead: "Associação Camará Capoeira · in bold, the chorus's answer",
+        print: "Print",
+    },
+    enrollment: Enrollment {
+        loading: "Loading classes...",
+        sessions_failed: "We could not load the classes. Please try again later.",
+        submit_failed: "We could not send the enrollment. Check the fields and try again.",
+        save_failed: "We could not save the enrollment. Please try again later.",
+        student: "Student",
+        student_name: "Full name",
+        birth_date: "Date of birth",
+        phone: "Phone / WhatsApp",
+        email: "E-mail",
+        address: "Address",
+        school: "School",
+        guardian: "Guardian",
+        guardian_hint: "Required for students under {}.",
+        guardian_name: "Guardian's name",
+        guardian_relationship: "Relationship",
+        relationship_placeholder: "Mother, father, grandmother...",
+        guardian_phone: "Guardian's phone",
+        guardian_email: "Guardian's e-mail",
+        session: "Class",
+        choose_session: "Choose the class",
+        select: "Select...",
+        see_schedule: "See the timetable",
+        health: "Health",
+        health_hint: "Allergies, regular medication, restrictions on physical activity or anything else the teachers should know.",
+        consents: "Consent (LGPD)",
+        consent_data: "I authorize the Associação Camará Capoeira to process the data in this form for enrollment, attendance records and contact, under Brazil's General Data Protection Law (Law 13,709/2018).",
+        consent_health: "I authorize the processing of the health information above, used only by the teachers for the student's safety.",
+        consent_image: "I authorize the use of photos and videos of the student in classes, rodas and events on the association's channels (website, social media and project reports). Optional.",
+        consent_guardian: "For minors: I declare that I am the student's legal guardian and authorize their participation in the activities.",
+        send: "Send enrollment",
+        sending: "Sending...",
+        received: "Enrollment received!",
+        keep_protocol: "Keep the protocol number to follow its status:",
+        track: "Follow the enrollment",
+        status_loading: "Loading...",
+        status_failed: "We could not look up the enrollment. Please try again later.",
+        protocol_not_found: "Protocol not found. Check the number you received when sending the enrollment.",
+        hello: "Hello, {}!",
+        submitted_on: "Sent on",
+        missing: "Please fill in \"{}\".",
+        invalid_birth_date: "Please check the date of birth.",
+        unknown_session: "Choose one of the classes on the list.",
+        consent_required: "You need to authorize {} to complete the enrollment.",
+        consent_guardian_required: "the legal guardian declaration",
+        consent_data_required: "the processing of personal data",
+        consent_health_required: "the processing of health information",
+    },
+};
@@ -0,0 +1,248 @@
+use super::catalog::*;
+
+pub const CATALOG: Catalog = Catalog {
+    site_name: "Associação Camará Capoeira",
+    logo_alt: "Logotipo de la Associação Camará Capoeira",
+    language: "Idioma",
+    nav: Nav {
+        navigation: "Navegación",
+        toggle_menu: "Abrir o cerrar el menú de proyectos",
+        home: "Inicio",
+        projects: "Proyectos",
+        ponto_de_cultura: "Punto de Cultura",
+        schedule: "Horarios de entrenamiento",
+        enrollment: "Inscripción",
+        agenda: "Agenda",
+        news: "Noticias",
+        songs: "Cantos",
+        berimbau: "Toques de berimbau",
+        gallery: "Galería",
+        press: "En los medios",
+        donate: "Apoyá",
+        transparency: "Transparencia",
+        about: "Quiénes somos",
+        contact: "Contacto",
+    },
+    footer: Footer {
+        contact: "Contacto",
+        rights: "© 2024 Associação de Capoeira Camará. Todos los derechos reservados.",
+    },
+    home: Home {
+        tagline: "Cultura, educación y ciudadanía en Ponta Porã - MS",
+        lead: "Promovemos la inclusión social y preservamos la cultura afrobrasileña en la frontera a través del arte de la capoeira.",
+        cta: "Conocé nuestros proyectos",
+        about: "Reconocida oficialmente como Punto de Cultura, la organización Camará Capoeira es un faro de transformación social en Mato Grosso do Sul. Nuestro trabajo va más allá de la roda: combatimos el trabajo infantil, promovemos la educación patrimonial y fortalecemos la identidad cultural de nuestra comunidad con acciones continuas.",
+        about_quote: "Más que un deporte, una herramienta de inclusión social y ciudadanía plena.",
+        pillars: "Nuestros pilares",
+        pillar_cultura_viva: "Proyecto Cultura Viva",
+        pillar_child_labour: "Lucha contra el trabajo infantil",
+        pillar_inclusion: "Inclusión social y educación",
+        press: "Reconocimiento en los medios",
+        all_press: "Ver todas las notas →",
+        partners: "Aliados",
+        past_partners: "Ya apoyaron al Camará",
+    },
+    newsletter: Newsletter {
+        title: "Boletín del Camará",
+        lead: "Noticias, rodas y eventos del grupo en tu correo, una vez por mes.",
+        placeholder: "tu@correo.com",
+        subscribe: "Suscribirme",
+        sent: "¡Casi listo! Te enviamos un enlace de confirmación por correo.",
+        failed: "No se pudo completar la suscripción. Intentá de nuevo.",
+        fine_print: "Solo enviamos después de que confirmes con el enlace. Podés darte de baja cuando quieras.",
+        email_label: "Correo electrónico",
+        invalid_email: "Revisá la dirección de correo.",
+        see_news: "Ver las noticias del grupo",
+        confirming: "Confirmando...",
+        confirmed: "Suscripción confirmada. ¡Axé!",
+        next_issue: "El próximo boletín llega a tu correo.",
+        link_expired: "Este enlace venció o ya fue usado. Si todavía no recibís el boletín, suscribite de nuevo.",
+        confirm_failed: "No se pudo confirmar la suscripción ahora.",
+        unsubscribe: "Cancelar suscripción",
+        unsubscribe_question: "¿Querés dejar de recibir el boletín del Camará?",
+        unsubscribed: "Suscripción cancelada.",
+        no_more_issues: "Ya no vas a recibir el boletín.",
+        unknown_link: "Este enlace no corresponde a ninguna suscripción.",
+        unsubscribe_failed: "No se pudo cancelar ahora. Intentá de nuevo.",
+    },
+    pages: Pages {
+        agenda: Page {
+            title: "Agenda",
+            subtitle: "Batizados, rodas abiertas, talleres y presentaciones del grupo.",
+        },
+        news: Page {
+            title: "Noticias",
+            subtitle: "Novedades de las clases, eventos y proyectos del Camará.",
+        },
+        gallery: Page {
+            title: "Galería",
+            subtitle: "Fotos de entrenamientos, rodas y eventos del Camará.",
+        },
+        songs: Page {
+            title: "Cantos",
+            subtitle: "Las canciones que cantamos en la roda, para estudiar en casa y acompañar en los entrenamientos. Las letras están en portugués.",
+        },
+        berimbau: Page {
+            title: "Toques de berimbau",
+            subtitle: "Elegí un toque, ajustá el tempo y seguí el dom, el dim y el chiado en la línea de tiempo.",
+        },
+        schedule: Page {
+            title: "Horarios de entrenamiento",
+            subtitle: "Clases gratuitas de capoeira, percusión y maculelê para todas las edades.",
+        },
+        press: Page {
+            title: "En los medios",
+            subtitle: "Notas y reportajes sobre el trabajo del Camará Capoeira.",
+        },
+        transparency: Page {
+            title: "Transparencia",
+            subtitle: "Rendición de cuentas de los recursos públicos recibidos por el Punto de Cultura Camará.",
+        },
+        donate: Page {
+            title: "Apoyá al Camará",
+            subtitle: "Tu donación mantiene los talleres gratuitos de capoeira, percusión y maculelê en el Parque dos Ipês II.",
+        },
+        enrollment: Page {
+            title: "Inscripción",
+            subtitle: "Inscribite en las clases gratuitas. La coordinación se comunica para confirmar tu lugar.",
+        },
+    },
+    dates: Dates {
+        weekdays: ["lunes", "martes", "miércoles", "jueves", "viernes", "sábado", "domingo"],
+        weekdays_short: ["Lunes", "Martes", "Miércoles", "Jueves", "Viernes", "Sábado", "Domingo"],
+        months: [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+    },
+    labels: Labels {
+        batizado: "Batizado",
+        roda_aberta: "Roda abierta",
+        oficina: "Taller",
+        apresentacao: "Presentación",
+        capoeira: "Capoeira",
+        percussao: "Percusión",
+        maculele: "Maculelê",
+        enrollment_pending: "En análisis",
+        enrollment_accepted: "Aceptada",
+        enrollment_active: "Activa",
+    },
+    agenda: Agenda {
+        loading: "Cargando la agenda...",
+        failed: "No se pudo cargar la agenda.",
+        upcoming: "Próximos eventos",
+        no_upcoming: "No hay eventos programados por ahora.",
+        past: "Eventos anteriores",
+        no_past: "No hay eventos anteriores registrados.",
+        subscribe_lead: "Agregá la agenda del grupo al calendario de tu celular y recibí las novedades automáticamente.",
+        timezone: "Horarios en el huso de Mato Grosso do Sul",
+        subscribe: "Suscribirse al calendario",
+        loading_event: "Cargando el evento...",
+        event_failed: "No se pudo cargar el evento.",
+        not_found: "Evento no encontrado",
+        back: "← Volver a la agenda",
+        date: "Fecha",
+        time: "Horario",
+        local_time: "(hora de MS)",
+        location: "Lugar",
+        add_to_calendar: "Agregar al calendario (.ics)",
+    },
+    schedule: Schedule {
+        loading: "Cargando los horarios...",
+        failed: "No se pudieron cargar los horarios.",
+        empty: "No hay clases registradas por ahora.",
+        cancellations: "Avisos: clases que no se darán",
+        extras: "Clases extra",
+        no_class_on: "Sin clase el",
+    },
+    donate: Donate {
+        loading: "Cargando...",
+        failed: "No se pudo generar el código PIX. Intentá de nuevo más tarde.",
+        unavailable: "Las donaciones por PIX van a estar disponibles pronto. Mientras tanto, escribinos: contato@camaracapoeira.org.br",
+        choose: "Elegí un monto",
+        any_amount: "Monto libre",
+        other_amount: "Otro monto (R$)",
+        generate: "Generar PIX",
+        scan: "Apuntá la cámara de la app de tu banco o usá el PIX copia y pega:",
+        copy: "Copiar código PIX",
+        copied: "¡Copiado!",
+    },
+    songs: Songs {
+        loading: "Cargando los cantos...",
+        failed: "No se pudieron cargar los cantos.",
+        none_found: "No se encontró ningún canto.",
+        all: "Todos",
+        search_placeholder: "Buscar en la letra, el título o el autor",
+        search_label: "Buscar cantos",
+        search: "Buscar",
+        songbook_link: "Cuaderno para imprimir",
+        has_recording: "Con grabación",
+        loading_song: "Cargando el canto...",
+        song_failed: "No se pudo cargar el canto.",
+        not_found: "Canto no encontrado",
+        back: "← Volver a los cantos",
+        no_audio: "Tu navegador no reproduce audio.",
+        coro_hint: "En negrita, la respuesta del coro.",
+        songbook: "Cuaderno de cantos",
+        songbook_lead: "Associação Camará Capoeira · en negrita, la respuesta del coro",
+        print: "Imprimir",
+    },
+    enrollment: Enrollment {
+        loading: "Cargando las clases...",
+        sessions_failed: "No se pudieron cargar las clases. Intentá de nuevo más tarde.",
+        submit_failed: "No se pudo enviar la inscripción. Revisá los campos e intentá de nuevo.",
+        save_failed: "No se pudo guardar la inscripción. Intentá de nuevo más tarde.",
+        student: "Alumno/a",
+        student_name: "Nombre completo",
+        birth_date: "Fecha de nacimiento",
+        phone: "Teléfono / WhatsApp",
+        email: "Correo electrónico",
+        address: "Dirección",
+        school: "Escuela",
+        guardian: "Responsable",
+        guardian_hint: "Obligatorio para menores de {} años.",
+        guardian_name: "Nombre del/de la responsable",
+        guardian_relationship: "Parentesco",
+        relationship_placeholder: "Madre, padre, abuela...",
+        guardian_phone: "Teléfono del/de la responsable",
+        guardian_email: "Correo del/de la responsable",
+        session: "Clase",
+        choose_session: "Elegí la clase",
+        select: "Seleccioná...",
+        see_schedule: "Ver el cuadro de horarios",
+        health: "Salud",
+        health_hint: "Alergias, medicamentos de uso continuo, restricciones a la actividad física u otra información que los profesores deban conocer.",
+        consents: "Consentimientos (LGPD)",
+        consent_data: "Autorizo a la Associação Camará Capoeira a tratar los datos de esta ficha para la inscripción, el control de asistencia y el contacto, según la Ley General de Protección de Datos de Brasil (Ley nº 13.709/2018).",
+        consent_health: "Autorizo el tratamiento de la información de salud indicada arriba, usada solo por los profesores para la seguridad del alumno o la alumna.",
+        consent_image: "Autorizo el uso de fotos y videos del alumno o la alumna en clases, rodas y eventos en los canales de la asociación (sitio, redes sociales e informes de proyectos). Opcional.",
+        consent_guardian: "Para menores de edad: declaro ser el o la responsable legal del alumno o la alumna y autorizo su participación en las actividades.",
+        send: "Enviar inscripción",
+        sending: "Enviando...",
+        received: "¡Inscripción recibida!",
+        keep_protocol: "Guardá el número de protocolo para seguir el estado:",
+        track: "Seguir la inscripción",
+        status_loading: "Cargando...",
+        status_failed: "No se pudo consultar la inscripción. Intentá de nuevo más tarde.",
+        protocol_not_found: "Protocolo no encontrado. Revisá el número que recibiste al enviar la inscripción.",
+        hello: "¡Hola, {}!",
+        submitted_on: "Enviada el",
+        missing: "Completá el campo \"{}\".",
+        invalid_birth_date: "Revisá la fecha de nacimiento.",
+        unknown_session: "Elegí una de las clases de la lista.",
+        consent_required: "Es necesario autorizar {} para completar la inscripción.",
+        consent_guardian_required: "la declaración de responsable legal",
+        consent_data_required: "el tratamiento de los datos personales",
+        consent_health_required: "el tratamiento de la información de salud",
+    },
+};
@@ -0,0 +1,281 @@
+//! Idiomas do site: português (padrão), espanhol e inglês.
+//!
+//! Every page exists once per [`Locale`]: unprefixed in Portuguese, under
+//! `/es/` and `/en/` in the others. `App` reads the locale from the request
+//! path and hands its prefix to the `Router` as `base`, so the route tree is
+//! shared; links written by hand go through [`Locale::href`].
+//!
+//! What the public pages write themselves comes from the [`Catalog`]: labels,
+//! form messages, the names of weekdays and months (`content::dates` takes a
+//! [`Locale`]). What editors write in `content/*.toml` (event titles, news,
+//! lyrics, class locations) is shown as written, in Portuguese, and so is the
+//! admin area, including the exports and share cards it produces.
+//!
+//! Visitors arriving without a preference are sent to the best match for
+//! their `Accept-Language` (`server::locale`); after that the [`COOKIE`]
+//! remembers the language they last viewed.
+
+mod catalog;
+mod en;
+mod es;
+mod pt_br;
+
+pub use catalog::{Agenda, Catalog, Dates, Donate, Enrollment, Footer, Home, Labels, Nav, Newsletter, Page, Pages, Schedule, Songs};
+
+use leptos::prelude::*;
+use leptos_meta::Link;
+use leptos_router::hooks::use_location;
+
+/// Remembers the visitor's language between visits.
+pub const COOKIE: &str = "idioma";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    PtBr,
+    Es,
+    En,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 3] = [Locale::PtBr, Locale::Es, Locale::En];
+
+    /// BCP 47 tag, for `<html lang>` and `hreflang`.
+    pub fn tag(self) -> &'static str {
+        match self {
+            Locale::PtBr => "pt-BR",
+            Locale::Es => "es",
+            Locale::En => "en",
+        }
+    }
+
+    pub fn parse(tag: &str) -> Option<Locale> {
+        Locale::ALL.into_iter().find(|locale| locale.tag().eq_ignore_ascii_case(tag))
+    }
+
+    /// Name of the language in the language itself, for the switcher.
+    pub fn name(self) -> &'static str {
+        match self {
+            Locale::PtBr => "Português",
+            Locale::Es => "Español",
+            Locale::En => "English",
+        }
+    }
+
+    /// Path prefix of the locale's pages, `""` for Portuguese.
+    pub fn base(self) -> &'static str {
+        match self {
+            Locale::PtBr => "",
+            Locale::Es => "/es",
+            Locale::En => "/en",
+        }
+    }
+
+    pub fn catalog(self) -> &'static Catalog {
+        match self {
+            Locale::PtBr => &pt_br::CATALOG,
+            Locale::Es => &es::CATALOG,
+            Locale::En => &en::CATALOG,
+        }
+    }
+
+    /// The locale of a request path and the path without its prefix.
+    pub fn from_path(path: &str) -> (Locale, &str) {
+        for locale in [Locale::Es, Locale::En] {
+            if let Some(rest) = path.strip_prefix(locale.base()) {
+                if rest.is_empty() {
+                    return (locale, "/");
+                }
+                if rest.starts_with('/') {
+                    return (locale, rest);
+                }
+            }
+        }
+        (Locale::PtBr, path)
+    }
+
+    /// `path` (site-absolute, as in Portuguese) for this locale's version of the site.
+    pub fn href(self, path: &str) -> String {
+        match (self.base(), path) {
+            ("", _) => path.to_string(),
+            (base, "/") => base.to_string(),
+            (base, _) if path.starts_with("/?") => format!("{base}{}", &path[1..]),
+            (base, _) => format!("{base}{path}"),
+        }
+    }
+
+    /// Best locale for an `Accept-Language` header; Portuguese when nothing matches.
+    pub fn negotiate(accept_language: &str) -> Locale {
+        let mut ranges: Vec<(&str, f32)> = accept_language
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split(';');
+                let tag = parts.next()?.trim();
+                let quality = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok())?;
+                (!tag.is_empty() && quality > 0.0).then_some((tag, quality))
+            })
+            .collect();
+        // Stable, so equal weights keep the visitor's order.
+        ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        ranges
+            .into_iter()
+            .find_map(|(tag, _)| {
+                let language = tag.split('-').next().unwrap_or(tag);
+                match language.to_ascii_lowercase().as_str() {
+                    "pt" => Some(Locale::PtBr),
+                    "es" => Some(Locale::Es),
+                    "en" => Some(Locale::En),
+                    _ => None,
+                }
+            })
+            .unwrap_or(Locale::PtBr)
+    }
+}
+
+/// Puts the locale of the current request in context and returns it.
+///
+/// The locale comes from the path: `RequestUrl` on the server, the address bar
+/// in the browser. A locale already in context wins, which is how `main`
+/// generates the route list once per locale.
+pub fn provide_locale() -> Locale {
+    let locale = use_context::<Locale>().unwrap_or_else(|| Locale::from_path(&request_path()).0);
+    provide_context(locale);
+    locale
+}
+
+/// Locale of the page being rendered (see [`provide_locale`]).
+pub fn use_locale() -> Locale {
+    use_context::<Locale>().unwrap_or(Locale::PtBr)
+}
+
+/// Texts of the page being rendered.
+pub fn use_catalog() -> &'static Catalog {
+    use_locale().catalog()
+}
+
+fn request_path() -> String {
+    #[cfg(feature = "ssr")]
+    {
+        use_context::<leptos_router::location::RequestUrl>()
+            .and_then(|url| url.parse().ok())
+            .map(|url| url.path().to_string())
+            .unwrap_or_default()
+    }
+    #[cfg(all(feature = "hydrate", not(feature = "ssr")))]
+    {
+        window().location().pathname().unwrap_or_default()
+    }
+    #[cfg(not(any(feature = "ssr", feature = "hydrate")))]
+    {
+        String::new()
+    }
+}
+
+/// Public address of the site, for the absolute links search engines require.
+pub(crate) fn site_origin() -> String {
+    #[cfg(feature = "ssr")]
+    {
+        crate::mail::config().map(|mail| mail.site_url).unwrap_or_default()
+    }
+    #[cfg(all(feature = "hydrate", not(feature = "ssr")))]
+    {
+        window().location().origin().unwrap_or_default()
+    }
+    #[cfg(not(any(feature = "ssr", feature = "hydrate")))]
+    {
+        String::new()
+    }
+}
+
+/// `<link rel="alternate" hreflang>` para as versões da página atual nos outros idiomas.
+#[component]
+pub fn LanguageAlternates() -> impl IntoView {
+    let location = use_location();
+    let origin = site_origin();
+
+    move || {
+        let pathname = location.pathname.get();
+        let (_, path) = Locale::from_path(&pathname);
+        let link = |hreflang: &'static str, locale: Locale| {
+            view! { <Link rel="alternate" hreflang=hreflang href=format!("{}{}", origin, locale.href(path))/> }
+        };
+        view! {
+            {Locale::ALL.map(|locale| link(locale.tag(), locale))}
+            {link("x-default", Locale::PtBr)}
+        }
+    }
+}
+
+/// Links para a página atual em cada idioma, usados no cabeçalho.
+///
+/// Plain `<a>`: the router only knows the current locale's prefix, so changing
+/// language is a full page load (which also updates the [`COOKIE`]).
+#[component]
+pub fn LanguageSwitcher() -> impl IntoView {
+    let location = use_location();
+    let current = use_locale();
+    let accent_color = "#f2e300";
+
+    view! {
+        <nav aria-label=current.catalog().language style="display: flex; gap: 0.75rem; justify-content: flex-end; font-size: 0.8rem; font-weight: 700; letter-spacing: 0.05em;">
+            {Locale::ALL.map(|locale| {
+                let href = move || {
+                    let pathname = location.pathname.get();
+                    let query = location.search.get();
+                    let path = locale.href(Locale::from_path(&pathname).1);
+                    if query.is_empty() { path } else { format!("{path}?{query}") }
+                };
+                let color = if locale == current { accent_color } else { "rgba(255,255,255,0.75)" };
+                view! {
+                    <a
+                        href=href
+                        hreflang=locale.tag()
+                        lang=locale.tag()
+                        title=locale.name()
+                        aria-current=(locale == current).then_some("true")
+                        style=format!("color: {}; text-decoration: none; text-transform: uppercase;", color)
+                    >
+                        {locale.tag().split('-').next().unwrap_or_default().to_string()}
+                    </a>
+                }
+            })}
+        </nav>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths_and_prefixes() {
+        assert_eq!(Locale::from_path("/agenda"), (Locale::PtBr, "/agenda"));
+        assert_eq!(Locale::from_path("/es"), (Locale::Es, "/"));
+        assert_eq!(Locale::from_path("/es/agenda/roda"), (Locale::Es, "/agenda/roda"));
+        assert_eq!(Locale::from_path("/en/"), (Locale::En, "/"));
+        // Only whole segments are prefixes.
+        assert_eq!(Locale::from_path("/escola"), (Locale::PtBr, "/escola"));
+
+        assert_eq!(Locale::PtBr.href("/agenda"), "/agenda");
+        assert_eq!(Locale::Es.href("/agenda"), "/es/agenda");
+        assert_eq!(Locale::En.href("/"), "/en");
+        assert_eq!(Locale::En.href("/?ano=2024"), "/en?ano=2024");
+        for locale in Locale::ALL {
+            assert_eq!(Locale::from_path(&locale.href("/noticias")), (locale, "/noticias"));
+            assert_eq!(Locale::parse(locale.tag()), Some(locale));
+        }
+    }
+
+    #[test]
+    fn negotiates_accept_language() {
+        assert_eq!(Locale::negotiate("es-PY,es;q=0.9,gn;q=0.8"), Locale::Es);
+        assert_eq!(Locale::negotiate("gn, en-US;q=0.7, pt;q=0.5"), Locale::En);
+        assert_eq!(Locale::negotiate("en;q=0.4, pt-BR;q=0.8"), Locale::PtBr);
+        assert_eq!(Locale::negotiate("fr-FR, de;q=0.5"), Locale::PtBr);
+        assert_eq!(Locale::negotiate("es;q=0, en;q=0.1"), Locale::En);
+        assert_eq!(Locale::negotiate("*"), Locale::PtBr);
+        assert_eq!(Locale::negotiate(""), Locale::PtBr);
+    }
+}
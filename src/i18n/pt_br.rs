@@ -0,0 +1,256 @@
+use super::catalog::*;
+
+pub const CATALOG: Catalog = Catalog {
+    site_name: "Associação Camará Capoeira",
+    logo_alt: "Logotipo da Associação Camará Capoeira",
+    language: "Idioma",
+    nav: Nav {
+        navigation: "Navegação",
+        toggle_menu: "Abrir ou fechar o menu de projetos",
+        home: "Início",
+        projects: "Projetos",
+        ponto_de_cultura: "Ponto de Cultura",
+        schedule: "Horários de Treino",
+        enrollment: "Matrícula",
+        agenda: "Agenda",
+        news: "Notícias",
+        songs: "Cantigas",
+        berimbau: "Toques de Berimbau",
+        gallery: "Galeria",
+        press: "Na Mídia",
+        donate: "Apoie",
+        transparency: "Transparência",
+        about: "Quem Somos",
+        contact: "Fale Conosco",
+    },
+    footer: Footer {
+        contact: "Contato",
+        rights: "© 2024 Associação de Capoeira Camará. Todos os direitos reservados.",
+    },
+    home: Home {
+        tagline: "Cultura, Educação e Cidadania em Ponta Porã - MS",
+        lead: "Promovendo inclusão social e preservando a cultura afro-brasileira na fronteira através da arte da Capoeira.",
+        cta: "Conheça Nossos Projetos",
+        about: "Reconhecida oficialmente como um Ponto de Cultura, a organização Camará Capoeira atua como um farol de transformação social em Mato Grosso do Sul. Nosso trabalho vai além da roda: combatemos o trabalho infantil, promovemos a educação patrimonial e fortalecemos a identidade cultural de nossa comunidade através de ações continuadas.",
+        about_quote: "Mais do que um esporte, uma ferramenta de inclusão social e cidadania plena.",
+        pillars: "Nossos Pilares",
+        pillar_cultura_viva: "Projeto Cultura Viva",
+        pillar_child_labour: "Combate ao Trabalho Infantil",
+        pillar_inclusion: "Inclusão Social e Educação",
+        press: "Reconhecimento na Mídia",
+        all_press: "Ver todas as matérias →",
+        partners: "Parceiros",
+        past_partners: "Já apoiaram o Camará",
+    },
+    newsletter: Newsletter {
+        title: "Boletim do Camará",
+        lead: "Notícias, rodas e eventos do grupo no seu e-mail, uma vez por mês.",
+        placeholder: "seu@email.com",
+        subscribe: "Inscrever",
+        sent: "Quase lá! Enviamos um link de confirmação para o seu e-mail.",
+        failed: "Não foi possível concluir a inscrição. Tente novamente.",
+        fine_print: "Só enviamos depois que você confirmar pelo link. Dá para cancelar a qualquer momento.",
+        email_label: "E-mail",
+        invalid_email: "Confira o endereço de e-mail.",
+        see_news: "Ver as notícias do grupo",
+        confirming: "Confirmando...",
+        confirmed: "Inscrição confirmada. Axé!",
+        next_issue: "O próximo boletim chega no seu e-mail.",
+        link_expired: "Este link expirou ou já foi usado. Se ainda não recebe o boletim, inscreva-se de novo.",
+        confirm_failed: "Não foi possível confirmar a inscrição agora.",
+        unsubscribe: "Cancelar inscrição",
+        unsubscribe_question: "Deseja deixar de receber o boletim do Camará?",
+        unsubscribed: "Inscrição cancelada.",
+        no_more_issues: "Você não receberá mais o boletim.",
+        unknown_link: "Este link não corresponde a nenhuma inscrição.",
+        unsubscribe_failed: "Não foi possível cancelar agora. Tente novamente.",
+    },
+    pages: Pages {
+        agenda: Page {
+            title: "Agenda",
+            subtitle: "Batizados, rodas abertas, oficinas e apresentações do grupo.",
+        },
+        news: Page {
+            title: "Notícias",
+            subtitle: "Novidades das turmas, eventos e projetos do Camará.",
+        },
+        gallery: Page {
+            title: "Galeria",
+            subtitle: "Fotos de treinos, rodas e eventos do Camará.",
+        },
+        songs: Page {
+            title: "Cantigas",
+            subtitle: "As músicas que cantamos na roda, para estudar em casa e acompanhar nos treinos.",
+        },
+        berimbau: Page {
+            title: "Toques de Berimbau",
+            subtitle: "Escolha um toque, ajuste o andamento e acompanhe o dom, o dim e o chiado na linha do tempo.",
+        },
+        schedule: Page {
+            title: "Horários de Treino",
+            subtitle: "Aulas gratuitas de capoeira, percussão e maculelê para todas as idades.",
+        },
+        press: Page {
+            title: "Na Mídia",
+            subtitle: "Matérias e reportagens sobre o trabalho do Camará Capoeira.",
+        },
+        transparency: Page {
+            title: "Transparência",
+            subtitle: "Prestação de contas dos recursos públicos recebidos pelo Ponto de Cultura Camará.",
+        },
+        donate: Page {
+            title: "Apoie o Camará",
+            subtitle: "Sua doação mantém as oficinas gratuitas de capoeira, percussão e maculelê no Parque dos Ipês II.",
+        },
+        enrollment: Page {
+            title: "Matrícula",
+            subtitle: "Inscreva-se nas aulas gratuitas. A coordenação entra em contato para confirmar a vaga.",
+        },
+    },
+    dates: Dates {
+        weekdays: [
+            "segunda-feira",
+            "terça-feira",
+            "quarta-feira",
+            "quinta-feira",
+            "sexta-feira",
+            "sábado",
+            "domingo",
+        ],
+        weekdays_short: ["Segunda", "Terça", "Quarta", "Quinta", "Sexta", "Sábado", "Domingo"],
+        months: [
+            "janeiro",
+            "fevereiro",
+            "março",
+            "abril",
+            "maio",
+            "junho",
+            "julho",
+            "agosto",
+            "setembro",
+            "outubro",
+            "novembro",
+            "dezembro",
+        ],
+    },
+    labels: Labels {
+        batizado: "Batizado",
+        roda_aberta: "Roda Aberta",
+        oficina: "Oficina",
+        apresentacao: "Apresentação",
+        capoeira: "Capoeira",
+        percussao: "Percussão",
+        maculele: "Maculelê",
+        enrollment_pending: "Em análise",
+        enrollment_accepted: "Aceita",
+        enrollment_active: "Ativa",
+    },
+    agenda: Agenda {
+        loading: "Carregando agenda...",
+        failed: "Não foi possível carregar a agenda.",
+        upcoming: "Próximos eventos",
+        no_upcoming: "Nenhum evento agendado no momento.",
+        past: "Eventos anteriores",
+        no_past: "Nenhum evento anterior registrado.",
+        subscribe_lead: "Adicione a agenda do grupo ao calendário do seu celular e receba as novidades automaticamente.",
+        timezone: "Horários no fuso de Mato Grosso do Sul",
+        subscribe: "Assinar calendário",
+        loading_event: "Carregando evento...",
+        event_failed: "Não foi possível carregar o evento.",
+        not_found: "Evento não encontrado",
+        back: "← Voltar para a agenda",
+        date: "Data",
+        time: "Horário",
+        local_time: "(horário de MS)",
+        location: "Local",
+        add_to_calendar: "Adicionar ao calendário (.ics)",
+    },
+    schedule: Schedule {
+        loading: "Carregando horários...",
+        failed: "Não foi possível carregar os horários.",
+        empty: "Nenhuma turma cadastrada no momento.",
+        cancellations: "Avisos: aulas que não acontecerão",
+        extras: "Aulas extras",
+        no_class_on: "Sem aula em",
+    },
+    donate: Donate {
+        loading: "Carregando...",
+        failed: "Não foi possível gerar o código PIX. Tente novamente mais tarde.",
+        unavailable: "As doações por PIX estarão disponíveis em breve. Enquanto isso, fale com a gente: contato@camaracapoeira.org.br",
+        choose: "Escolha um valor",
+        any_amount: "Valor livre",
+        other_amount: "Outro valor (R$)",
+        generate: "Gerar PIX",
+        scan: "Aponte a câmera do app do seu banco ou use o PIX copia e cola:",
+        copy: "Copiar código PIX",
+        copied: "Copiado!",
+    },
+    songs: Songs {
+        loading: "Carregando cantigas...",
+        failed: "Não foi possível carregar as cantigas.",
+        none_found: "Nenhuma cantiga encontrada.",
+        all: "Todas",
+        search_placeholder: "Buscar na letra, título ou autor",
+        search_label: "Buscar cantigas",
+        search: "Buscar",
+        songbook_link: "Caderno para imprimir",
+        has_recording: "Com gravação",
+        loading_song: "Carregando cantiga...",
+        song_failed: "Não foi possível carregar a cantiga.",
+        not_found: "Cantiga não encontrada",
+        back: "← Voltar para as cantigas",
+        no_audio: "Seu navegador não reproduz áudio.",
+        coro_hint: "Em negrito, a resposta do coro.",
+        songbook: "Caderno de cantigas",
+        songbook_lead: "Associação Camará Capoeira · em negrito, a resposta do coro",
+        print: "Imprimir",
+    },
+    enrollment: Enrollment {
+        loading: "Carregando turmas...",
+        sessions_failed: "Não foi possível carregar as turmas. Tente novamente mais tarde.",
+        submit_failed: "Não foi possível enviar a matrícula. Confira os campos e tente novamente.",
+        save_failed: "Não foi possível salvar a matrícula. Tente novamente mais tarde.",
+        student: "Aluno(a)",
+        student_name: "Nome completo",
+        birth_date: "Data de nascimento",
+        phone: "Telefone / WhatsApp",
+        email: "E-mail",
+        address: "Endereço",
+        school: "Escola",
+        guardian: "Responsável",
+        guardian_hint: "Obrigatório para menores de {} anos.",
+        guardian_name: "Nome do(a) responsável",
+        guardian_relationship: "Parentesco",
+        relationship_placeholder: "Mãe, pai, avó...",
+        guardian_phone: "Telefone do(a) responsável",
+        guardian_email: "E-mail do(a) responsável",
+        session: "Turma",
+        choose_session: "Escolha a turma",
+        select: "Selecione...",
+        see_schedule: "Ver quadro de horários",
+        health: "Saúde",
+        health_hint: "Alergias, medicamentos de uso contínuo, restrições a atividade física ou outras informações que os professores devam conhecer.",
+        consents: "Consentimentos (LGPD)",
+        consent_data: "Autorizo a Associação Camará Capoeira a tratar os dados desta ficha para fins de matrícula, controle de frequência e contato, conforme a Lei Geral de Proteção de Dados (Lei nº 13.709/2018).",
+        consent_health: "Autorizo o tratamento das informações de saúde acima, usadas apenas pelos professores para a segurança do(a) aluno(a).",
+        consent_image: "Autorizo o uso de fotos e vídeos do(a) aluno(a) em aulas, rodas e eventos nos canais da associação (site, redes sociais e relatórios de projetos). Opcional.",
+        consent_guardian: "Para menores de idade: declaro ser o(a) responsável legal pelo(a) aluno(a) e autorizo sua participação nas atividades.",
+        send: "Enviar matrícula",
+        sending: "Enviando...",
+        received: "Matrícula recebida!",
+        keep_protocol: "Guarde o número de protocolo para acompanhar a situação:",
+        track: "Acompanhar matrícula",
+        status_loading: "Carregando...",
+        status_failed: "Não foi possível consultar a matrícula. Tente novamente mais tarde.",
+        protocol_not_found: "Protocolo não encontrado. Confira o número recebido ao enviar a matrícula.",
+        hello: "Olá, {}!",
+        submitted_on: "Enviada em",
+        missing: "Preencha o campo \"{}\".",
+        invalid_birth_date: "Confira a data de nascimento.",
+        unknown_session: "Escolha uma das turmas da lista.",
+        consent_required: "É preciso autorizar {} para concluir a matrícula.",
+        consent_guardian_required: "a declaração de responsável legal",
+        consent_data_required: "o tratamento dos dados pessoais",
+        consent_health_required: "o tratamento das informações de saúde",
+    },
+};
@@ -1,6 +1,28 @@
+pub mod admin;
 pub mod app;
+pub mod attendance;
+pub mod audit;
+pub mod auth;
+pub mod berimbau;
 pub mod components;
+pub mod content;
+#[cfg(feature = "ssr")]
+pub mod db;
+pub mod enrollment;
+pub mod graduation;
+pub mod i18n;
+#[cfg(feature = "ssr")]
+pub mod logging;
+#[cfg(feature = "ssr")]
+pub mod mail;
+pub mod newsletter;
+#[cfg(feature = "ssr")]
+pub mod pix;
 pub mod routes;
+#[cfg(feature = "ssr")]
+pub mod server;
+#[cfg(feature = "ssr")]
+pub mod settings;
 #[cfg(feature = "hydrate")]
 #[wasm_bindgen::prelude::wasm_bindgen]
 pub fn hydrate() {
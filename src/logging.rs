@@ -0,0 +1,74 @@
+//! Server log: `tracing` events on standard output, as text or JSON lines.
+//!
+//! `main` calls [`init`] right after loading the settings, so everything the
+//! server does from then on (including what actix and other crates report
+//! through `log`) goes through the same subscriber. Each request runs in a
+//! span opened by `server::request_id`, which puts its id on every event of
+//! the request, server functions included. The level starts at the
+//! `log_level` setting and can be changed while the server runs through
+//! [`LogControl`] (`server::log_level`).
+
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::{SubscriberInitExt, TryInitError};
+use tracing_subscriber::{fmt, reload, Registry};
+
+use crate::settings::{LogFormat, LogLevel, Settings};
+
+/// Changes the level of the subscriber installed by [`init`].
+#[derive(Debug, Clone)]
+pub struct LogControl {
+    handle: reload::Handle<LevelFilter, Registry>,
+}
+
+impl LogControl {
+    /// The level in force, `None` if the subscriber is gone.
+    pub fn level(&self) -> Option<LogLevel> {
+        self.handle.clone_current().and_then(from_filter)
+    }
+
+    pub fn set_level(&self, level: LogLevel) -> Result<(), reload::Error> {
+        self.handle.reload(filter(level))
+    }
+}
+
+/// Installs the global subscriber. Fails if one is already installed.
+pub fn init(settings: &Settings) -> Result<LogControl, TryInitError> {
+    let (level, handle) = reload::Layer::new(filter(settings.log_level));
+    let json = settings.log_format == LogFormat::Json;
+
+    tracing_subscriber::registry()
+        .with(level)
+        .with(json.then(|| fmt::layer().json().with_current_span(true).with_span_list(false)))
+        .with((!json).then(fmt::layer))
+        .try_init()?;
+    Ok(LogControl { handle })
+}
+
+fn filter(level: LogLevel) -> LevelFilter {
+    match level {
+        LogLevel::Error => LevelFilter::ERROR,
+        LogLevel::Warn => LevelFilter::WARN,
+        LogLevel::Info => LevelFilter::INFO,
+        LogLevel::Debug => LevelFilter::DEBUG,
+        LogLevel::Trace => LevelFilter::TRACE,
+    }
+}
+
+/// `None` for [`LevelFilter::OFF`], which [`filter`] never produces.
+fn from_filter(current: LevelFilter) -> Option<LogLevel> {
+    LogLevel::ALL.into_iter().find(|level| filter(*level) == current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels_round_trip() {
+        for level in LogLevel::ALL {
+            assert_eq!(from_filter(filter(level)), Some(level));
+        }
+        assert_eq!(from_filter(LevelFilter::OFF), None);
+    }
+}
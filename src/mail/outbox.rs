@@ -0,0 +1,181 @@
+//! The queue of e-mails waiting to be delivered, and the worker that drains it.
+
+use std::time::Duration;
+
+use chrono::NaiveDateTime;
+use lettre::message::Mailbox;
+use rusqlite::{params, Connection};
+
+use super::{MailConfig, Message, Transport};
+use crate::db::{DbError, Pool};
+
+/// After this many failed attempts a message is marked as failed and left alone.
+pub const MAX_ATTEMPTS: u32 = 6;
+
+/// How often the worker looks for due messages.
+const POLL_INTERVAL: Duration = Duration::from_secs(10);
+/// Messages sent per pass, so a large digest does not hold a connection for long.
+const BATCH_SIZE: usize = 50;
+
+/// Wait before the next attempt after `attempts` failures: 1, 4, 16, 64 and 256 minutes.
+pub fn retry_delay(attempts: u32) -> chrono::Duration {
+    chrono::Duration::minutes(4_i64.pow(attempts.saturating_sub(1).min(4)))
+}
+
+pub fn enqueue(conn: &Connection, message: &Message, now: NaiveDateTime) -> Result<i64, DbError> {
+    conn.execute(
+        "INSERT INTO mail_outbox (recipient, subject, text_body, html_body, unsubscribe_url, created_at, next_attempt_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?6)",
+        params![message.to, message.subject, message.text, message.html, message.unsubscribe_url, now],
+    )?;
+    Ok(conn.last_insert_rowid())
+}
+
+/// Messages by state, for the admin.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Counts {
+    pub queued: usize,
+    pub sent: usize,
+    pub failed: usize,
+}
+
+pub fn counts(conn: &Connection) -> Result<Counts, DbError> {
+    Ok(conn.query_row(
+        "SELECT COUNT(*) FILTER (WHERE sent_at IS NULL AND failed_at IS NULL),
+                COUNT(*) FILTER (WHERE sent_at IS NOT NULL),
+                COUNT(*) FILTER (WHERE failed_at IS NOT NULL)
+         FROM mail_outbox",
+        [],
+        |row| {
+            Ok(Counts {
+                queued: row.get::<_, i64>(0)? as usize,
+                sent: row.get::<_, i64>(1)? as usize,
+                failed: row.get::<_, i64>(2)? as usize,
+            })
+        },
+    )?)
+}
+
+/// Sends up to `limit` messages that are due at `now` and returns how many went out.
+/// Failures are rescheduled (see [`retry_delay`]) or, after [`MAX_ATTEMPTS`], given up.
+pub fn deliver_due(
+    conn: &Connection,
+    transport: &Transport,
+    from: &Mailbox,
+    now: NaiveDateTime,
+    limit: usize,
+) -> Result<usize, DbError> {
+    let mut stmt = conn.prepare(
+        "SELECT id, recipient, subject, text_body, html_body, unsubscribe_url, attempts FROM mail_outbox
+         WHERE sent_at IS NULL AND failed_at IS NULL AND next_attempt_at <= ?1
+         ORDER BY next_attempt_at, id LIMIT ?2",
+    )?;
+    let due = stmt
+        .query_map(params![now, limit as i64], |row| {
+            let message = Message {
+                to: row.get(1)?,
+                subject: row.get(2)?,
+                text: row.get(3)?,
+                html: row.get(4)?,
+                unsubscribe_url: row.get(5)?,
+            };
+            Ok((row.get::<_, i64>(0)?, message, row.get::<_, u32>(6)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut sent = 0;
+    for (id, message, attempts) in due {
+        match transport.send(from, &message) {
+            Ok(()) => {
+                conn.execute(
+                    "UPDATE mail_outbox SET sent_at = ?2, attempts = attempts + 1, last_error = NULL WHERE id = ?1",
+                    params![id, now],
+                )?;
+                sent += 1;
+            }
+            Err(e) => {
+                let attempts = attempts + 1;
+                let failed_at = (attempts >= MAX_ATTEMPTS).then_some(now);
+                conn.execute(
+                    "UPDATE mail_outbox SET attempts = ?2, last_error = ?3, next_attempt_at = ?4, failed_at = ?5 WHERE id = ?1",
+                    params![id, attempts, e.to_string(), now + retry_delay(attempts), failed_at],
+                )?;
+            }
+        }
+    }
+    Ok(sent)
+}
+
+/// Starts the thread that delivers queued messages for as long as the server runs.
+pub fn spawn_worker(pool: Pool, config: MailConfig) -> Result<(), super::MailError> {
+    let transport = config.connect()?;
+
+    std::thread::Builder::new()
+        .name("mail-outbox".to_string())
+        .spawn(move || loop {
+            let now = crate::content::events::now_local();
+            let result = pool
+                .get()
+                .map_err(DbError::from)
+                .and_then(|conn| deliver_due(&conn, &transport, &config.from, now, BATCH_SIZE));
+            match result {
+                // A full batch means there may be more waiting.
+                Ok(sent) if sent == BATCH_SIZE => continue,
+                Ok(_) => {}
+                Err(e) => tracing::error!(error = %e, "mail outbox: delivery failed"),
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        })
+        .map_err(|e| super::MailError::Config(format!("could not start the outbox worker: {e}")))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lettre::FileTransport;
+
+    fn at(hour: u32, minute: u32) -> NaiveDateTime {
+        chrono::NaiveDate::from_ymd_opt(2026, 11, 3).unwrap().and_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn message(to: &str) -> Message {
+        Message {
+            to: to.to_string(),
+            subject: "Boletim de novembro".to_string(),
+            text: "Roda de Natal no dia 20.".to_string(),
+            html: Some("<p>Roda de Natal no dia 20.</p>".to_string()),
+            unsubscribe_url: Some("https://camaracapoeira.org.br/newsletter/sair/abc".to_string()),
+        }
+    }
+
+    #[test]
+    fn delivers_to_file_transport_and_retries_failures() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        crate::db::migrations::migrate(&mut conn).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let transport = Transport::File(FileTransport::new(dir.path()));
+        let from: Mailbox = "Camará <contato@camaracapoeira.org.br>".parse().unwrap();
+
+        enqueue(&conn, &message("aluna@example.com"), at(9, 0)).unwrap();
+        enqueue(&conn, &message("não é um endereço"), at(9, 0)).unwrap();
+
+        assert_eq!(deliver_due(&conn, &transport, &from, at(9, 0), 10).unwrap(), 1);
+        let files: Vec<_> = std::fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(files.len(), 1);
+        let eml = std::fs::read_to_string(files[0].as_ref().unwrap().path()).unwrap();
+        assert!(eml.contains("To: aluna@example.com"));
+        assert!(eml.contains("List-Unsubscribe: <https://camaracapoeira.org.br/newsletter/sair/abc>"));
+        assert!(eml.contains("List-Unsubscribe-Post: List-Unsubscribe=One-Click"));
+        assert_eq!(counts(&conn).unwrap(), Counts { queued: 1, sent: 1, failed: 0 });
+
+        // The bad address waits a minute before the second attempt, and gives up after the last.
+        assert_eq!(deliver_due(&conn, &transport, &from, at(9, 0), 10).unwrap(), 0);
+        let mut now = at(9, 0);
+        for attempt in 1..MAX_ATTEMPTS {
+            now += retry_delay(attempt);
+            deliver_due(&conn, &transport, &from, now, 10).unwrap();
+        }
+        assert_eq!(counts(&conn).unwrap(), Counts { queued: 0, sent: 1, failed: 1 });
+    }
+}
@@ -2,60 +2,159 @@
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     use actix_files::Files;
+    use actix_session::{config::PersistentSession, storage::CookieSessionStore, SessionMiddleware};
+    use actix_web::cookie::{time::Duration, Key, SameSite};
     use actix_web::*;
     use leptos::prelude::*;
     use leptos::config::get_configuration;
-    use leptos_meta::MetaTags;
-    use leptos_actix::{generate_route_list, LeptosRoutes, handle_server_fns}; // Imported handle_server_fns
+    use leptos_actix::{generate_route_list, generate_route_list_with_exclusions_and_ssg_and_context, LeptosRoutes, handle_server_fns_with_context};
     use camaracapoeira::app::*;
+    use camaracapoeira::{auth, content, db, i18n, logging, mail, settings};
+    use camaracapoeira::server::{agenda, attendance, health, locale, log_level, newsletter, prerender, request_id, robots, security_headers, share_image, sitemap, transparency};
 
-    let conf = get_configuration(None).unwrap();
-    let addr = conf.leptos_options.site_addr;
+    let mut conf = get_configuration(None).unwrap();
+    let settings = settings::load(&conf.leptos_options).map_err(std::io::Error::other)?;
+    content::set_dir(settings.content_dir.clone());
+    conf.leptos_options.site_addr = settings.bind;
+    let log_control = logging::init(&settings).map_err(std::io::Error::other)?;
 
-    HttpServer::new(move || {
+    let pool = db::open(&settings.database)
+        .map_err(|e| std::io::Error::other(format!("could not open {}: {e}", settings.database.display())))?;
+    match auth::bootstrap_admin(&pool) {
+        Ok(Some(email)) => tracing::info!(%email, "created administrator account"),
+        Ok(None) => {}
+        Err(e) => return Err(std::io::Error::other(format!("could not create the administrator account: {e}"))),
+    }
+
+    let mail = settings.mail.clone();
+
+    // `camaracapoeira prerender`: static copy of the public pages, then exit.
+    if std::env::args().nth(1).as_deref() == Some("prerender") {
+        let site_index = sitemap::SiteIndex::new(&mail.site_url, &generate_route_list(App));
+        return prerender::run(conf.leptos_options, pool, settings, &site_index).await;
+    }
+
+    if settings.features.mail_worker {
+        mail::outbox::spawn_worker(pool.clone(), mail.clone())
+            .map_err(|e| std::io::Error::other(format!("could not start the mail worker: {e}")))?;
+    }
+
+    // Session cookies are encrypted with this key; without a fixed one every
+    // restart signs everybody out.
+    let session_key = match &settings.security.session_key {
+        Some(key) => Key::from(key.as_bytes()),
+        None => {
+            tracing::warn!("session_key not set; using a random session key");
+            Key::generate()
+        }
+    };
+    let secure_cookies = conf.leptos_options.env == leptos::config::Env::PROD;
+    let security = security_headers::SecurityHeaders::new(&settings.security, &conf.leptos_options);
+    let share_images = share_image::ShareImages::new(&settings.share_images_cache, &settings.media_root);
+    if settings.features.share_images {
+        match share_images.prune() {
+            Ok(0) => {}
+            Ok(removed) => tracing::info!(removed, "share images: removed cards no longer in use"),
+            Err(e) => tracing::warn!(error = %e, "share images: could not check the saved cards"),
+        }
+    }
+    let (bind, workers) = (settings.bind, settings.workers);
+
+    let server = HttpServer::new(move || {
         // Generate the list of routes in your Leptos App
-        let routes = generate_route_list(App);
+        let mut routes = generate_route_list(App);
+        let site_index = sitemap::SiteIndex::new(&mail.site_url, &routes);
+        // …and again under `/es` and `/en`, with the locale in context so the
+        // router takes its prefix as base (see `i18n`).
+        for locale in [i18n::Locale::Es, i18n::Locale::En] {
+            let (localized, _) = generate_route_list_with_exclusions_and_ssg_and_context(App, None, move || provide_context(locale));
+            routes.extend(localized);
+        }
         let leptos_options = &conf.leptos_options;
         let site_root = leptos_options.site_root.clone().to_string();
 
-        println!("listening on http://{}", &addr);
+        // Server functions and SSR both reach the database through
+        // `use_context::<db::Pool>()`, the mail settings through `mail::config()`
+        // and the rest through `settings::config()`. Pages also get their CSP here,
+        // where the nonce of the render is known.
+        let provide_state = {
+            let pool = pool.clone();
+            let mail = mail.clone();
+            let settings = settings.clone();
+            let security = security.clone();
+            move || {
+                provide_context(pool.clone());
+                provide_context(mail.clone());
+                provide_context(settings.clone());
+                security_headers::provide_policy(&security);
+            }
+        };
 
         App::new()
             // Explicitly handle server functions (like /api/optimize_image)
-            .route("/api/{tail:.*}", handle_server_fns())
+            .route("/api/{tail:.*}", handle_server_fns_with_context(provide_state.clone()))
+            // Para o deploy e o monitoramento: vivo, pronto e qual versão
+            .service(health::healthz)
+            .service(health::readyz)
+            .service(health::version)
             // serve JS/WASM/CSS from `pkg`
             .service(Files::new("/pkg", format!("{site_root}/pkg")))
-            // serve other assets from the `assets` directory
-            .service(Files::new("/assets", &site_root))
+            // serve photos, logos and recordings from the media root
+            .service(Files::new("/assets", &settings.media_root))
             // serve the favicon from /favicon.ico
             .service(favicon)
-            .leptos_routes(routes, {
+            // iCalendar feed and per-event downloads (before the Leptos `/agenda/:slug` route)
+            .service(agenda::agenda_feed)
+            .service(agenda::event_ics)
+            // Imagens para pré-visualização de links de notícias e eventos
+            .service(share_image::article_image)
+            .service(share_image::event_image)
+            // Prestação de contas: planilhas e documentos registrados
+            .service(transparency::table_csv)
+            .service(transparency::document)
+            // Relatórios mensais de frequência (CSV/ODS)
+            .service(attendance::summary_csv)
+            .service(attendance::detail_csv)
+            .service(attendance::report_ods)
+            // Cancelamento do boletim em um clique (List-Unsubscribe-Post)
+            .service(newsletter::one_click_unsubscribe)
+            // Nível do log, para administradores
+            .service(log_level::current)
+            .service(log_level::change)
+            // Para buscadores: páginas públicas e regras de indexação
+            .service(sitemap::sitemap)
+            .service(robots::robots)
+            .leptos_routes_with_context(routes, provide_state, {
                 let leptos_options = leptos_options.clone();
-                move || {
-                    view! {
-                        <!DOCTYPE html>
-                        <html lang="pt-BR">
-                            <head>
-                                <meta charset="utf-8"/>
-                                <meta name="viewport" content="width=device-width, initial-scale=1"/>
-                                <AutoReload options=leptos_options.clone() />
-                                <HydrationScripts options=leptos_options.clone()/>
-                                <MetaTags/>
-                            </head>
-                            <body>
-                                <App/>
-                            </body>
-                        </html>
-                    }
-                }
+                move || shell(leptos_options.clone())
             })
             .app_data(web::Data::new(leptos_options.to_owned()))
+            .app_data(web::Data::new(pool.clone()))
+            .app_data(web::Data::new(site_index))
+            .app_data(web::Data::new(share_images.clone()))
+            .app_data(web::Data::new(settings.clone()))
+            .app_data(web::Data::new(log_control.clone()))
+            .app_data(web::Data::new(security.clone()))
+        .wrap(
+            SessionMiddleware::builder(CookieSessionStore::default(), session_key.clone())
+                .cookie_name("camara_sessao".to_string())
+                .cookie_http_only(true)
+                .cookie_same_site(SameSite::Lax)
+                .cookie_secure(secure_cookies)
+                .session_lifecycle(PersistentSession::default().session_ttl(Duration::days(14)))
+                .build(),
+        )
+        .wrap(middleware::from_fn(locale::negotiate))
         .wrap(middleware::Compress::default())
+        .wrap(middleware::from_fn(security_headers::apply))
+        // Outermost, so the id is set before anything else runs (see `audit`).
+        .wrap(middleware::from_fn(request_id::assign))
     })
-    .bind(&addr)?
-    .workers(1)
-    .run()
-    .await
+    .bind(bind)?
+    .workers(workers);
+
+    tracing::info!(%bind, workers, "listening on http://{bind}");
+    server.run().await
 }
 
 #[cfg(feature = "ssr")]
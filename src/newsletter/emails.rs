@@ -0,0 +1,149 @@
+//! The e-mails of the newsletter: confirmation and digest, as plain text and HTML.
+
+use std::fmt::Write as _;
+
+use super::{Digest, DigestItem};
+use crate::mail::{escape_html, MailConfig, Message};
+
+pub fn confirm_path(token: &str) -> String {
+    format!("/newsletter/confirmar/{token}")
+}
+
+pub fn unsubscribe_path(token: &str) -> String {
+    format!("/newsletter/sair/{token}")
+}
+
+pub fn confirmation(config: &MailConfig, email: &str, token: &str) -> Message {
+    let link = config.url(&confirm_path(token));
+    Message {
+        to: email.to_string(),
+        subject: "Confirme sua inscrição no boletim do Camará".to_string(),
+        text: format!(
+            "Olá!\n\n\
+             Recebemos um pedido para inscrever este endereço no boletim da Associação Camará Capoeira.\n\n\
+             Para confirmar, abra o link abaixo (válido por 7 dias):\n{link}\n\n\
+             Se não foi você, ignore esta mensagem: sem a confirmação, nada será enviado.\n"
+        ),
+        html: None,
+        unsubscribe_url: None,
+    }
+}
+
+/// The copy of `digest` for one subscriber.
+pub fn digest(config: &MailConfig, digest: &Digest, email: &str, unsubscribe_token: &str) -> Message {
+    let unsubscribe = config.url(&unsubscribe_path(unsubscribe_token));
+    Message {
+        to: email.to_string(),
+        subject: digest.subject.clone(),
+        text: digest_text(config, digest, &unsubscribe),
+        html: Some(digest_html(config, digest, &unsubscribe)),
+        unsubscribe_url: Some(unsubscribe),
+    }
+}
+
+fn digest_text(config: &MailConfig, digest: &Digest, unsubscribe: &str) -> String {
+    let mut out = String::new();
+    if !digest.intro.trim().is_empty() {
+        let _ = writeln!(out, "{}\n", digest.intro.trim());
+    }
+    for (heading, items) in [("NOTÍCIAS", &digest.news), ("AGENDA", &digest.events)] {
+        if items.is_empty() {
+            continue;
+        }
+        let _ = writeln!(out, "{heading}\n");
+        for item in items {
+            let _ = writeln!(out, "{}\n{}\n{}\n{}\n", item.title, item.when, item.detail, config.url(&item.path));
+        }
+    }
+    let _ = write!(
+        out,
+        "--\nVocê recebe este boletim porque se inscreveu em {}.\nPara cancelar a inscrição: {}\n",
+        config.site_url, unsubscribe
+    );
+    out
+}
+
+fn digest_html(config: &MailConfig, digest: &Digest, unsubscribe: &str) -> String {
+    let primary_bg = "#332175";
+    let accent_color = "#f2e300";
+
+    let mut out = format!(
+        "<!DOCTYPE html><html lang=\"pt-BR\"><body style=\"margin: 0; padding: 0; background: #f5f5f5; font-family: Arial, sans-serif; color: #222;\">\
+         <div style=\"max-width: 600px; margin: 0 auto; background: white;\">\
+         <div style=\"background: {primary_bg}; border-bottom: 6px solid {accent_color}; padding: 24px 32px;\">\
+         <h1 style=\"margin: 0; color: white; font-size: 22px;\">{}</h1></div><div style=\"padding: 24px 32px;\">",
+        escape_html(&digest.subject)
+    );
+    for paragraph in digest.intro.split("\n\n").map(str::trim).filter(|p| !p.is_empty()) {
+        let _ = write!(out, "<p style=\"line-height: 1.6;\">{}</p>", escape_html(paragraph));
+    }
+    for (heading, items) in [("Notícias", &digest.news), ("Agenda", &digest.events)] {
+        if items.is_empty() {
+            continue;
+        }
+        let _ = write!(out, "<h2 style=\"color: {primary_bg}; font-size: 18px; margin: 28px 0 12px;\">{heading}</h2>");
+        for item in items {
+            out.push_str(&item_html(config, item));
+        }
+    }
+    let _ = write!(
+        out,
+        "</div><div style=\"padding: 16px 32px 32px; font-size: 12px; color: #777; border-top: 1px solid #eee;\">\
+         Você recebe este boletim porque se inscreveu em {site}. \
+         <a href=\"{unsubscribe}\" style=\"color: #777;\">Cancelar inscrição</a>.</div></div></body></html>",
+        site = escape_html(&config.site_url),
+        unsubscribe = escape_html(unsubscribe),
+    );
+    out
+}
+
+fn item_html(config: &MailConfig, item: &DigestItem) -> String {
+    format!(
+        "<div style=\"margin-bottom: 18px;\">\
+         <a href=\"{href}\" style=\"color: #222; font-weight: bold; font-size: 16px;\">{title}</a>\
+         <div style=\"color: #666; font-size: 13px; margin: 4px 0;\">{when}</div>\
+         <div style=\"line-height: 1.5;\">{detail}</div></div>",
+        href = escape_html(&config.url(&item.path)),
+        title = escape_html(&item.title),
+        when = escape_html(&item.when),
+        detail = escape_html(&item.detail),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mail::TransportConfig;
+
+    #[test]
+    fn digest_links_back_and_escapes_content() {
+        let config = MailConfig {
+            site_url: "https://camaracapoeira.org.br".to_string(),
+            from: "Camará <contato@camaracapoeira.org.br>".parse().unwrap(),
+            transport: TransportConfig::File("data/mail".into()),
+        };
+        let digest = Digest {
+            subject: "Boletim de novembro".to_string(),
+            intro: "Axé!".to_string(),
+            news: vec![DigestItem {
+                slug: "roda".to_string(),
+                title: "Roda <aberta> & batizado".to_string(),
+                when: "20 de novembro de 2026".to_string(),
+                detail: "Na praça.".to_string(),
+                path: "/noticias/roda".to_string(),
+            }],
+            events: Vec::new(),
+        };
+
+        let message = super::digest(&config, &digest, "aluna@example.com", "tok");
+        let unsubscribe = "https://camaracapoeira.org.br/newsletter/sair/tok";
+        assert_eq!(message.unsubscribe_url.as_deref(), Some(unsubscribe));
+        assert!(message.text.contains("https://camaracapoeira.org.br/noticias/roda"));
+        assert!(message.text.contains(unsubscribe));
+        assert!(!message.text.contains("AGENDA"));
+
+        let html = message.html.unwrap();
+        assert!(html.contains("Roda &lt;aberta&gt; &amp; batizado"));
+        assert!(html.contains(&format!("href=\"{unsubscribe}\"")));
+    }
+}
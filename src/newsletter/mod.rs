@@ -0,0 +1,143 @@
+//! Boletim por e-mail: inscrição com confirmação (double opt-in), cancelamento
+//! em um clique e o boletim montado no painel a partir das notícias e da agenda.
+//!
+//! A subscriber starts `pending` and only becomes `active` by following the
+//! link (a v4 UUID) sent to the address. Every digest carries the
+//! subscriber's own unsubscribe link, also exposed as a `List-Unsubscribe`
+//! header. Mail goes through the outbox (`crate::mail`).
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+pub mod emails;
+#[cfg(feature = "ssr")]
+pub mod store;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriberStatus {
+    /// Signed up, confirmation link not followed yet.
+    Pending,
+    Active,
+    Unsubscribed,
+}
+
+impl SubscriberStatus {
+    /// Value stored in the database.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SubscriberStatus::Pending => "pending",
+            SubscriberStatus::Active => "active",
+            SubscriberStatus::Unsubscribed => "unsubscribed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        [SubscriberStatus::Pending, SubscriberStatus::Active, SubscriberStatus::Unsubscribed]
+            .into_iter()
+            .find(|s| s.as_str() == value)
+    }
+}
+
+/// Trims and lowercases `input` if it looks like an e-mail address.
+///
+/// Deliberately loose: the confirmation e-mail is the real check.
+pub fn normalize_email(input: &str) -> Option<String> {
+    let email = input.trim().to_lowercase();
+    let (local, domain) = email.split_once('@')?;
+    let valid = !local.is_empty()
+        && email.len() <= 254
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && domain.contains('.')
+        && !domain.contains('@')
+        && !email.chars().any(|c| c.is_whitespace() || c.is_control() || matches!(c, '<' | '>' | ',' | ';' | '"'));
+    valid.then_some(email)
+}
+
+/// A news article or event that can go into a digest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DigestItem {
+    /// Slug of the article or event.
+    pub slug: String,
+    pub title: String,
+    /// "21 de novembro de 2026", or date and time for events.
+    pub when: String,
+    /// Summary of the article, or place of the event.
+    pub detail: String,
+    /// Site path ("/noticias/…", "/agenda/…").
+    pub path: String,
+}
+
+/// A digest as composed in the admin, one copy per subscriber.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Digest {
+    pub subject: String,
+    pub intro: String,
+    pub news: Vec<DigestItem>,
+    pub events: Vec<DigestItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SentDigest {
+    pub subject: String,
+    pub sent_at: NaiveDateTime,
+    pub recipients: usize,
+}
+
+/// What the composer at `/admin/newsletter` starts from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewsletterOverview {
+    pub active: usize,
+    pub pending: usize,
+    pub queued: usize,
+    pub failed: usize,
+    pub last_digest: Option<SentDigest>,
+    /// Most recent first; `true` when published since the last digest.
+    pub news: Vec<(DigestItem, bool)>,
+    /// Upcoming, soonest first; `true` for the ones in the next weeks.
+    pub events: Vec<(DigestItem, bool)>,
+}
+
+/// Unsubscribes the holder of `token` and records it in the audit log.
+/// `false` for a token that matches no subscriber.
+#[cfg(feature = "ssr")]
+pub fn unsubscribe(
+    conn: &rusqlite::Connection,
+    recorder: &crate::audit::Recorder,
+    token: &str,
+    now: NaiveDateTime,
+) -> Result<bool, crate::db::DbError> {
+    use crate::audit::{Action, Fields};
+
+    match store::unsubscribe(conn, token, now)? {
+        None => Ok(false),
+        Some((id, previous)) => {
+            if previous != SubscriberStatus::Unsubscribed {
+                let status = |s: SubscriberStatus| Fields::from([("status".to_string(), s.as_str().to_string())]);
+                recorder.record(
+                    conn,
+                    Action::NewsletterUnsubscribed,
+                    &format!("Inscrição #{id}"),
+                    &status(previous),
+                    &status(SubscriberStatus::Unsubscribed),
+                )?;
+            }
+            Ok(true)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_plausible_addresses_only() {
+        assert_eq!(normalize_email("  Mestre@Camara.org.br "), Some("mestre@camara.org.br".to_string()));
+        for invalid in ["", "capoeira", "@camara.org", "a@b", "a@.org", "a@b.org.", "a b@c.org", "a@b@c.org", "<a@b.org>"] {
+            assert_eq!(normalize_email(invalid), None, "{invalid:?}");
+        }
+    }
+}
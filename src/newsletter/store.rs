@@ -0,0 +1,176 @@
+//! Subscribers and sent digests in the local database.
+
+use chrono::{Duration, NaiveDateTime};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use super::{SentDigest, SubscriberStatus};
+use crate::db::DbError;
+
+/// How long a confirmation link stays valid.
+pub const CONFIRMATION_VALIDITY: Duration = Duration::days(7);
+/// A new confirmation e-mail for the same address is sent at most this often.
+pub const RESEND_INTERVAL: Duration = Duration::minutes(10);
+
+/// A subscriber who must receive a confirmation e-mail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingConfirmation {
+    pub id: i64,
+    pub email: String,
+    pub confirm_token: String,
+}
+
+/// Signs `email` up (or up again) and returns who needs a confirmation
+/// e-mail: nobody when the address is already active or one was sent recently.
+pub fn subscribe(conn: &Connection, email: &str, now: NaiveDateTime) -> Result<Option<PendingConfirmation>, DbError> {
+    let token = uuid::Uuid::new_v4().to_string();
+    let existing: Option<(i64, String, NaiveDateTime)> = conn
+        .query_row(
+            "SELECT id, status, confirm_sent_at FROM newsletter_subscribers WHERE email = ?1",
+            params![email],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .optional()?;
+
+    let id = match existing {
+        None => {
+            conn.execute(
+                "INSERT INTO newsletter_subscribers (email, status, confirm_token, unsubscribe_token, created_at, confirm_sent_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?5)",
+                params![email, SubscriberStatus::Pending.as_str(), token, uuid::Uuid::new_v4().to_string(), now],
+            )?;
+            conn.last_insert_rowid()
+        }
+        Some((_, status, _)) if status == SubscriberStatus::Active.as_str() => return Ok(None),
+        Some((_, status, sent_at)) if status == SubscriberStatus::Pending.as_str() && now - sent_at < RESEND_INTERVAL => {
+            return Ok(None)
+        }
+        Some((id, _, _)) => {
+            conn.execute(
+                "UPDATE newsletter_subscribers
+                 SET status = ?2, confirm_token = ?3, confirm_sent_at = ?4, unsubscribed_at = NULL
+                 WHERE id = ?1",
+                params![id, SubscriberStatus::Pending.as_str(), token, now],
+            )?;
+            id
+        }
+    };
+
+    Ok(Some(PendingConfirmation { id, email: email.to_string(), confirm_token: token }))
+}
+
+/// Activates the subscriber holding `token`, if the link is still valid. Returns their id.
+pub fn confirm(conn: &Connection, token: &str, now: NaiveDateTime) -> Result<Option<i64>, DbError> {
+    Ok(conn
+        .query_row(
+            "UPDATE newsletter_subscribers SET status = ?2, confirmed_at = ?3, confirm_token = NULL
+             WHERE confirm_token = ?1 AND status = ?4 AND confirm_sent_at >= ?5
+             RETURNING id",
+            params![
+                token,
+                SubscriberStatus::Active.as_str(),
+                now,
+                SubscriberStatus::Pending.as_str(),
+                now - CONFIRMATION_VALIDITY
+            ],
+            |row| row.get(0),
+        )
+        .optional()?)
+}
+
+/// Unsubscribes whoever holds `token`. Returns their id and the status they
+/// had; `None` for an unknown token.
+pub fn unsubscribe(conn: &Connection, token: &str, now: NaiveDateTime) -> Result<Option<(i64, SubscriberStatus)>, DbError> {
+    let Some((id, status)): Option<(i64, String)> = conn
+        .query_row(
+            "SELECT id, status FROM newsletter_subscribers WHERE unsubscribe_token = ?1",
+            params![token],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?
+    else {
+        return Ok(None);
+    };
+    let previous = SubscriberStatus::parse(&status).unwrap_or(SubscriberStatus::Unsubscribed);
+
+    if previous != SubscriberStatus::Unsubscribed {
+        conn.execute(
+            "UPDATE newsletter_subscribers SET status = ?2, confirm_token = NULL, unsubscribed_at = ?3 WHERE id = ?1",
+            params![id, SubscriberStatus::Unsubscribed.as_str(), now],
+        )?;
+    }
+    Ok(Some((id, previous)))
+}
+
+/// E-mail and unsubscribe token of every active subscriber.
+pub fn active_subscribers(conn: &Connection) -> Result<Vec<(String, String)>, DbError> {
+    let mut stmt = conn.prepare("SELECT email, unsubscribe_token FROM newsletter_subscribers WHERE status = ?1 ORDER BY id")?;
+    let rows = stmt.query_map(params![SubscriberStatus::Active.as_str()], |row| Ok((row.get(0)?, row.get(1)?)))?;
+    Ok(rows.collect::<Result<_, _>>()?)
+}
+
+pub fn count(conn: &Connection, status: SubscriberStatus) -> Result<usize, DbError> {
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM newsletter_subscribers WHERE status = ?1",
+        params![status.as_str()],
+        |row| row.get(0),
+    )?;
+    Ok(count as usize)
+}
+
+pub fn record_digest(conn: &Connection, subject: &str, sent_by: i64, recipients: usize, now: NaiveDateTime) -> Result<(), DbError> {
+    conn.execute(
+        "INSERT INTO newsletter_digests (subject, sent_by, sent_at, recipients) VALUES (?1, ?2, ?3, ?4)",
+        params![subject, sent_by, now, recipients as i64],
+    )?;
+    Ok(())
+}
+
+pub fn last_digest(conn: &Connection) -> Result<Option<SentDigest>, DbError> {
+    Ok(conn
+        .query_row(
+            "SELECT subject, sent_at, recipients FROM newsletter_digests ORDER BY sent_at DESC, id DESC LIMIT 1",
+            [],
+            |row| {
+                Ok(SentDigest {
+                    subject: row.get(0)?,
+                    sent_at: row.get(1)?,
+                    recipients: row.get::<_, i64>(2)? as usize,
+                })
+            },
+        )
+        .optional()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn double_opt_in_and_unsubscribe() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        crate::db::migrations::migrate(&mut conn).unwrap();
+        let now = chrono::NaiveDate::from_ymd_opt(2026, 11, 3).unwrap().and_hms_opt(9, 0, 0).unwrap();
+
+        let pending = subscribe(&conn, "aluna@example.com", now).unwrap().unwrap();
+        // No second e-mail right away, and nothing to send while pending.
+        assert_eq!(subscribe(&conn, "aluna@example.com", now + Duration::minutes(1)).unwrap(), None);
+        assert!(active_subscribers(&conn).unwrap().is_empty());
+
+        assert_eq!(confirm(&conn, &pending.confirm_token, now + Duration::hours(1)).unwrap(), Some(pending.id));
+        assert_eq!(confirm(&conn, &pending.confirm_token, now + Duration::hours(1)).unwrap(), None);
+        let active = active_subscribers(&conn).unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(subscribe(&conn, "aluna@example.com", now + Duration::days(1)).unwrap(), None);
+
+        let token = &active[0].1;
+        assert_eq!(unsubscribe(&conn, token, now).unwrap(), Some((pending.id, SubscriberStatus::Active)));
+        assert_eq!(unsubscribe(&conn, token, now).unwrap(), Some((pending.id, SubscriberStatus::Unsubscribed)));
+        assert_eq!(unsubscribe(&conn, "desconhecido", now).unwrap(), None);
+        assert_eq!(count(&conn, SubscriberStatus::Active).unwrap(), 0);
+
+        // Signing up again needs a new confirmation; an expired link does nothing.
+        let again = subscribe(&conn, "aluna@example.com", now + Duration::days(2)).unwrap().unwrap();
+        assert_eq!(again.id, pending.id);
+        assert_eq!(confirm(&conn, &again.confirm_token, now + Duration::days(10)).unwrap(), None);
+    }
+}
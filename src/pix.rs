@@ -0,0 +1,329 @@
+//! PIX "BR Code" payloads for static QR codes.
+//!
+//! Follows the EMV® QRCPS Merchant-Presented Mode layout as profiled by the
+//! Banco Central do Brasil in the "Manual de Padrões para Iniciação do Pix":
+//! every field is `ID (2 digits) + length (2 digits) + value`, and the
+//! payload ends with a CRC16-CCITT-FALSE over everything before it.
+
+use crate::content::money::Money;
+
+const GUI: &str = "br.gov.bcb.pix";
+/// Merchant Category Code; "0000" when not informed.
+const MCC: &str = "0000";
+/// ISO 4217 code for the Brazilian real.
+const CURRENCY_BRL: &str = "986";
+const COUNTRY: &str = "BR";
+/// txid used by static codes that do not identify the transaction.
+const NO_TXID: &str = "***";
+
+pub const MAX_NAME_LEN: usize = 25;
+pub const MAX_CITY_LEN: usize = 15;
+pub const MAX_TXID_LEN: usize = 25;
+/// Field 54 holds at most 13 characters ("9999999999.99").
+pub const MAX_AMOUNT: Money = Money(999_999_999_999);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BrCodeError {
+    EmptyKey,
+    InvalidAmount,
+    InvalidTxid,
+    /// The named field does not fit the two-digit length of its EMV template.
+    TooLong(&'static str),
+}
+
+impl std::fmt::Display for BrCodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BrCodeError::EmptyKey => write!(f, "PIX key is empty"),
+            BrCodeError::InvalidAmount => write!(f, "amount must be positive and at most {}", MAX_AMOUNT.to_brl()),
+            BrCodeError::InvalidTxid => write!(f, "txid must be 1 to {MAX_TXID_LEN} ASCII letters or digits"),
+            BrCodeError::TooLong(field) => write!(f, "{field} is too long for a BR Code"),
+        }
+    }
+}
+
+impl std::error::Error for BrCodeError {}
+
+/// A static PIX charge, rendered with [`BrCode::payload`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrCode {
+    key: String,
+    merchant_name: String,
+    merchant_city: String,
+    description: Option<String>,
+    amount: Option<Money>,
+    txid: Option<String>,
+}
+
+impl BrCode {
+    /// Name and city are transliterated to ASCII and truncated to the
+    /// lengths the specification allows.
+    pub fn new(key: &str, merchant_name: &str, merchant_city: &str) -> Self {
+        BrCode {
+            key: key.trim().to_string(),
+            merchant_name: to_ascii(merchant_name, MAX_NAME_LEN),
+            merchant_city: to_ascii(merchant_city, MAX_CITY_LEN),
+            description: None,
+            amount: None,
+            txid: None,
+        }
+    }
+
+    /// Message shown to the payer by their bank app (field 26.02).
+    pub fn description(mut self, description: &str) -> Self {
+        let description = to_ascii(description, 72);
+        self.description = (!description.is_empty()).then_some(description);
+        self
+    }
+
+    /// Fixed amount; without it the payer types the value.
+    pub fn amount(mut self, amount: Money) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    pub fn txid(mut self, txid: &str) -> Self {
+        self.txid = Some(txid.to_string());
+        self
+    }
+
+    pub fn payload(&self) -> Result<String, BrCodeError> {
+        if self.key.is_empty() {
+            return Err(BrCodeError::EmptyKey);
+        }
+
+        let mut account = field("00", GUI)?;
+        account.push_str(&field("01", &self.key).map_err(|_| BrCodeError::TooLong("PIX key"))?);
+        if let Some(description) = &self.description {
+            account.push_str(&field("02", description)?);
+        }
+
+        let mut payload = field("00", "01")?;
+        payload.push_str(&field("26", &account).map_err(|_| BrCodeError::TooLong("merchant account"))?);
+        payload.push_str(&field("52", MCC)?);
+        payload.push_str(&field("53", CURRENCY_BRL)?);
+        if let Some(amount) = self.amount {
+            payload.push_str(&field("54", &format_amount(amount)?)?);
+        }
+        payload.push_str(&field("58", COUNTRY)?);
+        payload.push_str(&field("59", &self.merchant_name)?);
+        payload.push_str(&field("60", &self.merchant_city)?);
+
+        let txid = match &self.txid {
+            Some(txid) if is_valid_txid(txid) => txid.as_str(),
+            Some(_) => return Err(BrCodeError::InvalidTxid),
+            None => NO_TXID,
+        };
+        payload.push_str(&field("62", &field("05", txid)?)?);
+
+        // The CRC covers its own ID and length ("6304").
+        payload.push_str("6304");
+        let crc = crc16(payload.as_bytes());
+        payload.push_str(&format!("{crc:04X}"));
+
+        Ok(payload)
+    }
+}
+
+/// Suggested donation values: positive, within the BR Code limit, sorted and unique.
+pub fn suggested_amounts(values: &[Money]) -> Vec<Money> {
+    let mut amounts: Vec<Money> = values
+        .iter()
+        .copied()
+        .filter(|a| a.0 > 0 && *a <= MAX_AMOUNT)
+        .collect();
+    amounts.sort();
+    amounts.dedup();
+    amounts
+}
+
+/// CRC16-CCITT-FALSE: polynomial 0x1021, initial value 0xFFFF, no reflection.
+pub fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 };
+        }
+    }
+    crc
+}
+
+fn field(id: &str, value: &str) -> Result<String, BrCodeError> {
+    if value.len() > 99 {
+        return Err(BrCodeError::TooLong(id_name(id)));
+    }
+    Ok(format!("{id}{:02}{value}", value.len()))
+}
+
+fn id_name(id: &str) -> &'static str {
+    match id {
+        "54" => "amount",
+        "59" => "merchant name",
+        "60" => "merchant city",
+        "62" => "additional data",
+        _ => "field",
+    }
+}
+
+/// "1500.00"
+fn format_amount(amount: Money) -> Result<String, BrCodeError> {
+    if amount.0 <= 0 || amount > MAX_AMOUNT {
+        return Err(BrCodeError::InvalidAmount);
+    }
+    Ok(format!("{}.{:02}", amount.0 / 100, amount.0 % 100))
+}
+
+fn is_valid_txid(txid: &str) -> bool {
+    !txid.is_empty() && txid.len() <= MAX_TXID_LEN && txid.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// Drops diacritics (the BR Code character set is ASCII) and truncates to `max` characters.
+fn to_ascii(text: &str, max: usize) -> String {
+    text.trim()
+        .chars()
+        .filter_map(|c| match c {
+            'á' | 'à' | 'â' | 'ã' | 'ä' => Some('a'),
+            'Á' | 'À' | 'Â' | 'Ã' | 'Ä' => Some('A'),
+            'é' | 'è' | 'ê' | 'ë' => Some('e'),
+            'É' | 'È' | 'Ê' | 'Ë' => Some('E'),
+            'í' | 'ì' | 'î' | 'ï' => Some('i'),
+            'Í' | 'Ì' | 'Î' | 'Ï' => Some('I'),
+            'ó' | 'ò' | 'ô' | 'õ' | 'ö' => Some('o'),
+            'Ó' | 'Ò' | 'Ô' | 'Õ' | 'Ö' => Some('O'),
+            'ú' | 'ù' | 'û' | 'ü' => Some('u'),
+            'Ú' | 'Ù' | 'Û' | 'Ü' => Some('U'),
+            'ç' => Some('c'),
+            'Ç' => Some('C'),
+            'ñ' => Some('n'),
+            'Ñ' => Some('N'),
+            c if c.is_ascii() && !c.is_ascii_control() => Some(c),
+            _ => None,
+        })
+        .take(max)
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc16_matches_ccitt_false_check_value() {
+        assert_eq!(crc16(b"123456789"), 0x29B1);
+    }
+
+    #[test]
+    fn payload_matches_manual_example() {
+        // Exemplo do Manual de Padrões para Iniciação do Pix (chave aleatória, sem valor).
+        let code = BrCode::new("123e4567-e12b-12d1-a456-426655440000", "Fulano de Tal", "BRASILIA");
+        assert_eq!(
+            code.payload().unwrap(),
+            "00020126580014br.gov.bcb.pix0136123e4567-e12b-12d1-a456-426655440000\
+             5204000053039865802BR5913Fulano de Tal6008BRASILIA62070503***63041D3D"
+        );
+    }
+
+    #[test]
+    fn payload_ends_with_crc_of_preceding_content() {
+        let payload = BrCode::new("contato@example.org", "Camara", "Ponta Pora")
+            .amount(Money(5000))
+            .payload()
+            .unwrap();
+        let (body, crc) = payload.split_at(payload.len() - 4);
+        assert!(body.ends_with("6304"));
+        assert_eq!(crc, format!("{:04X}", crc16(body.as_bytes())));
+    }
+
+    #[test]
+    fn amount_is_encoded_with_dot_and_two_decimals() {
+        let payload = BrCode::new("chave", "Camara", "Ponta Pora").amount(Money(2550)).payload().unwrap();
+        assert!(payload.contains("540525.50"));
+
+        let payload = BrCode::new("chave", "Camara", "Ponta Pora").amount(Money(100_000)).payload().unwrap();
+        assert!(payload.contains("54071000.00"));
+    }
+
+    #[test]
+    fn amount_is_omitted_when_not_set() {
+        let payload = BrCode::new("chave", "Camara", "Ponta Pora").payload().unwrap();
+        // Currency (53) is followed directly by country (58).
+        assert!(payload.contains("53039865802BR"));
+    }
+
+    #[test]
+    fn rejects_non_positive_and_oversized_amounts() {
+        let code = BrCode::new("chave", "Camara", "Ponta Pora");
+        assert_eq!(code.clone().amount(Money(0)).payload(), Err(BrCodeError::InvalidAmount));
+        assert_eq!(code.clone().amount(Money(-100)).payload(), Err(BrCodeError::InvalidAmount));
+        assert_eq!(
+            code.amount(Money(MAX_AMOUNT.0 + 1)).payload(),
+            Err(BrCodeError::InvalidAmount)
+        );
+    }
+
+    #[test]
+    fn name_and_city_are_transliterated_and_truncated() {
+        let payload = BrCode::new("chave", "Associação Camará Capoeira de Ponta Porã", "Ponta Porã")
+            .payload()
+            .unwrap();
+        assert!(payload.contains("5925Associacao Camara Capoeir"));
+        assert!(payload.contains("6010Ponta Pora"));
+    }
+
+    #[test]
+    fn txid_defaults_to_three_asterisks_and_is_validated() {
+        let code = BrCode::new("chave", "Camara", "Ponta Pora");
+        assert!(code.payload().unwrap().contains("62070503***"));
+        assert!(code.clone().txid("APOIE2026").payload().unwrap().contains("62130509APOIE2026"));
+        assert_eq!(code.clone().txid("com espaço").payload(), Err(BrCodeError::InvalidTxid));
+        assert_eq!(code.txid(&"X".repeat(26)).payload(), Err(BrCodeError::InvalidTxid));
+    }
+
+    #[test]
+    fn description_is_placed_in_merchant_account_template() {
+        let payload = BrCode::new("chave", "Camara", "Ponta Pora")
+            .description("Doação")
+            .payload()
+            .unwrap();
+        assert!(payload.starts_with("00020126370014br.gov.bcb.pix"));
+        assert!(payload.contains("0105chave0206Doacao52"));
+    }
+
+    #[test]
+    fn empty_key_is_rejected() {
+        assert_eq!(BrCode::new("  ", "Camara", "Ponta Pora").payload(), Err(BrCodeError::EmptyKey));
+    }
+
+    #[test]
+    fn oversized_key_is_rejected() {
+        let key = "k".repeat(90);
+        assert_eq!(
+            BrCode::new(&key, "Camara", "Ponta Pora").payload(),
+            Err(BrCodeError::TooLong("merchant account"))
+        );
+    }
+
+    #[test]
+    fn suggested_amounts_are_sorted_unique_and_positive() {
+        let amounts = suggested_amounts(&[Money(10_000), Money(2_000), Money(0), Money(5_000), Money(2_000), Money(-1)]);
+        assert_eq!(amounts, vec![Money(2_000), Money(5_000), Money(10_000)]);
+    }
+
+    #[test]
+    fn suggested_amounts_respect_br_code_limit() {
+        let amounts = suggested_amounts(&[MAX_AMOUNT, Money(MAX_AMOUNT.0 + 1)]);
+        assert_eq!(amounts, vec![MAX_AMOUNT]);
+    }
+
+    #[test]
+    fn every_suggested_amount_produces_a_valid_payload() {
+        let code = BrCode::new("chave", "Camara", "Ponta Pora");
+        for amount in suggested_amounts(&[Money(2_000), Money(5_000), Money(10_000), Money(20_000)]) {
+            let payload = code.clone().amount(amount).payload().unwrap();
+            assert!(payload.contains(&format!("54{:02}{}", format_amount(amount).unwrap().len(), format_amount(amount).unwrap())));
+        }
+    }
+}
@@ -0,0 +1,302 @@
+use leptos::form::ActionForm;
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::components::A;
+use crate::auth::{Account, Role};
+use crate::components::form::{LabeledField, INPUT_STYLE};
+use crate::components::page_layout::PageLayout;
+use crate::components::stacks::hstack::{HStack, AlignItems as HAlign, JustifyContent as HJustify, FlexWrap};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::dates;
+use crate::routes::auth::use_auth;
+
+const SUCCESS_COLOR: &str = "#1f7a4d";
+const ERROR_COLOR: &str = "#b5431f";
+
+/// Contas do painel: criar, mudar o papel e desativar. Ninguém altera a própria
+/// conta por aqui, então sempre resta ao menos um administrador.
+#[component]
+pub fn AccountsAdminPage() -> impl IntoView {
+    let create = ServerAction::<CreateAccount>::new();
+    let change_role = ServerAction::<ChangeAccountRole>::new();
+    let set_active = ServerAction::<SetAccountActive>::new();
+    let accounts = Resource::new(
+        move || (create.version().get(), change_role.version().get(), set_active.version().get()),
+        |_| list_accounts(),
+    );
+
+    // Whichever action ran last says how it went.
+    let latest = RwSignal::new(None::<Result<String, ServerFnError>>);
+    Effect::new(move |_| create.value().get().map(|result| latest.set(Some(result.map(|_| "Conta criada.".to_string())))));
+    Effect::new(move |_| change_role.value().get().map(|result| latest.set(Some(result.map(|_| "Papel alterado.".to_string())))));
+    Effect::new(move |_| {
+        set_active.value().get().map(|result| {
+            latest.set(Some(result.map(|active| if active { "Conta reativada." } else { "Conta desativada." }.to_string())))
+        })
+    });
+    let outcome = move || match latest.get() {
+        Some(Ok(message)) => Some((message, SUCCESS_COLOR)),
+        Some(Err(ServerFnError::ServerError(message))) => Some((message, ERROR_COLOR)),
+        Some(Err(_)) => Some(("Não foi possível alterar a conta.".to_string(), ERROR_COLOR)),
+        None => None,
+    };
+
+    view! {
+        <Title text="Contas | Associação Camará Capoeira"/>
+        <PageLayout title="Contas" subtitle="Quem entra no painel e o que cada pessoa pode fazer.">
+            <VStack spacing="2rem".to_string() align=VAlign::Stretch style="max-width: 860px; margin: 0 auto;">
+                <A href="/admin" attr:style="color: #332175; font-weight: 600;">"← Painel"</A>
+                {move || outcome().map(|(message, color)| view! { <p style=format!("margin: 0; color: {};", color)>{message}</p> })}
+                <Suspense fallback=move || view! { <p>"Carregando contas..."</p> }>
+                    {move || accounts.get().map(|result| match result {
+                        Ok(accounts) => view! {
+                            <VStack spacing="0.75rem".to_string() align=VAlign::Stretch>
+                                {accounts.into_iter().map(|account| view! {
+                                    <AccountItem account=account change_role=change_role set_active=set_active />
+                                }).collect_view()}
+                            </VStack>
+                        }.into_any(),
+                        Err(_) => view! { <p>"Não foi possível carregar as contas."</p> }.into_any(),
+                    })}
+                </Suspense>
+                <NewAccountForm create=create />
+            </VStack>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn AccountItem(
+    account: Account,
+    change_role: ServerAction<ChangeAccountRole>,
+    set_active: ServerAction<SetAccountActive>,
+) -> impl IntoView {
+    let primary_bg = "#332175";
+    let auth = use_auth();
+    let Account { user, active, last_login_at } = account;
+    let id = user.id;
+    let is_self = move || auth.user.get().and_then(|r| r.ok()).flatten().is_some_and(|me| me.id == id);
+
+    let on_role = move |ev| {
+        if let Some(role) = Role::parse(&event_target_value(&ev)) {
+            change_role.dispatch(ChangeAccountRole { id, role });
+        }
+    };
+    let name = user.name.clone();
+    let on_toggle = move |_| {
+        let question = format!("Desativar a conta de {}? Ela deixa de entrar no painel.", name);
+        if active && !window().confirm_with_message(&question).unwrap_or(false) {
+            return;
+        }
+        set_active.dispatch(SetAccountActive { id, active: !active });
+    };
+    let last_login = match last_login_at {
+        Some(at) => format!("Último acesso em {}", dates::day_month_year(at.date())),
+        None => "Nunca entrou".to_string(),
+    };
+    let border = if active { "#f2e300" } else { "#ccc" };
+
+    view! {
+        <HStack
+            justify=HJustify::SpaceBetween
+            align=HAlign::Center
+            wrap=FlexWrap::Wrap
+            spacing="1rem".to_string()
+            style=format!("background: white; padding: 0.9rem 1.25rem; border-radius: 12px; box-shadow: 0 2px 8px rgba(0,0,0,0.04); border-left: 4px solid {}; opacity: {};", border, if active { "1" } else { "0.7" })
+        >
+            <VStack spacing="0.3rem".to_string() align=VAlign::FlexStart style="flex: 1 1 280px;">
+                <span style="font-weight: 700; color: #222;">
+                    {user.name}
+                    {move || is_self().then_some(" (você)")}
+                    {(!active).then_some(" · desativada")}
+                </span>
+                <span style="color: #555; font-size: 0.9rem;">{user.email}</span>
+                <span style="color: #666; font-size: 0.8rem;">{last_login}</span>
+            </VStack>
+            <HStack align=HAlign::Center spacing="0.75rem".to_string()>
+                <select on:change=on_role disabled=is_self style=format!("{} width: auto;", INPUT_STYLE)>
+                    {Role::ALL.into_iter().map(|role| view! {
+                        <option value=role.as_str() selected=role == user.role>{role.label()}</option>
+                    }).collect_view()}
+                </select>
+                <button
+                    on:click=on_toggle
+                    disabled=is_self
+                    style=format!("padding: 0.5rem 1.2rem; border: 1px solid {}; border-radius: 8px; background: white; color: {}; font-weight: 700; cursor: pointer;", primary_bg, primary_bg)
+                >
+                    {if active { "Desativar" } else { "Reativar" }}
+                </button>
+            </HStack>
+        </HStack>
+    }
+}
+
+#[component]
+fn NewAccountForm(create: ServerAction<CreateAccount>) -> impl IntoView {
+    let primary_bg = "#332175";
+
+    view! {
+        <ActionForm action=create>
+            <VStack
+                spacing="1.25rem".to_string()
+                align=VAlign::Stretch
+                style="background: white; padding: 2rem; border-radius: 12px; box-shadow: 0 4px 15px rgba(0,0,0,0.05);"
+            >
+                <h2 style=format!("margin: 0; color: {}; font-size: 1.25rem;", primary_bg)>"Nova conta"</h2>
+                <LabeledField label="Nome" required=true>
+                    <input type="text" name="name" required style=INPUT_STYLE />
+                </LabeledField>
+                <LabeledField label="E-mail" required=true>
+                    <input type="email" name="email" required autocomplete="off" style=INPUT_STYLE />
+                </LabeledField>
+                <LabeledField label="Papel" required=true>
+                    <select name="role" style=INPUT_STYLE>
+                        {Role::ALL.into_iter().map(|role| view! {
+                            <option value=role.as_str() selected=role == Role::Voluntario>{role.label()}</option>
+                        }).collect_view()}
+                    </select>
+                </LabeledField>
+                <LabeledField
+                    label="Senha inicial"
+                    required=true
+                    hint=Some("Pelo menos 10 caracteres. Combine com a pessoa uma forma segura de passar a senha.")
+                >
+                    <input type="password" name="password" required minlength="10" autocomplete="new-password" style=INPUT_STYLE />
+                </LabeledField>
+                <button
+                    type="submit"
+                    disabled=move || create.pending().get()
+                    style=format!("align-self: flex-end; padding: 0.8rem 1.6rem; border: none; border-radius: 8px; background-color: {}; color: white; font-weight: 700; font-size: 1rem; cursor: pointer;", primary_bg)
+                >
+                    "Criar conta"
+                </button>
+            </VStack>
+        </ActionForm>
+    }
+}
+
+#[cfg(feature = "ssr")]
+fn role_fields(role: Role) -> crate::audit::Fields {
+    crate::audit::Fields::from([("papel".to_string(), role.as_str().to_string())])
+}
+
+#[cfg(feature = "ssr")]
+fn active_fields(active: bool) -> crate::audit::Fields {
+    crate::audit::Fields::from([("ativa".to_string(), if active { "sim" } else { "não" }.to_string())])
+}
+
+#[server(ListAccounts, "/api/list_accounts")]
+pub async fn list_accounts() -> Result<Vec<Account>, ServerFnError> {
+    use crate::auth::{require_role, store};
+
+    require_role(Role::Admin).await?;
+    crate::db::with_conn(|conn| store::list(conn)).await.map_err(ServerFnError::new)
+}
+
+#[server(CreateAccount, "/api/create_account")]
+pub async fn create_account(name: String, email: String, role: Role, password: String) -> Result<(), ServerFnError> {
+    use crate::audit::{Action, Fields, Recorder};
+    use crate::auth::{password, require_role, store};
+    use crate::content::events::now_local;
+
+    let user = require_role(Role::Admin).await?;
+
+    let (name, email) = (name.trim().to_string(), email.trim().to_string());
+    if name.is_empty() {
+        return Err(ServerFnError::new("Informe o nome da pessoa."));
+    }
+    if !email.contains('@') {
+        return Err(ServerFnError::new("Informe um e-mail válido."));
+    }
+    if password.chars().count() < password::MIN_LENGTH {
+        return Err(ServerFnError::new(format!("A senha precisa ter pelo menos {} caracteres.", password::MIN_LENGTH)));
+    }
+
+    // Argon2 takes a while; keep it off the async executor like the database.
+    let hash = actix_web::web::block(move || password::hash(&password))
+        .await
+        .map_err(ServerFnError::new)?
+        .map_err(ServerFnError::new)?;
+
+    let recorder = Recorder::new(Some(&user)).await;
+    let now = now_local();
+    let created = crate::db::transaction(move |tx| {
+        if store::email_taken(tx, &email)? {
+            return Ok(false);
+        }
+        store::create(tx, &email, &name, &hash, role, now)?;
+        let mut after = role_fields(role);
+        after.insert("nome".to_string(), name.clone());
+        recorder.record(tx, Action::UserCreated, &email, &Fields::new(), &after)?;
+        Ok::<_, crate::db::DbError>(true)
+    })
+    .await
+    .map_err(ServerFnError::new)?;
+
+    if !created {
+        return Err(ServerFnError::new("Já existe uma conta com este e-mail."));
+    }
+    Ok(())
+}
+
+#[server(ChangeAccountRole, "/api/change_account_role")]
+pub async fn change_account_role(id: i64, role: Role) -> Result<(), ServerFnError> {
+    use crate::audit::{Action, Recorder};
+    use crate::auth::{require_role, store};
+
+    let user = require_role(Role::Admin).await?;
+    if user.id == id {
+        return Err(ServerFnError::new("Peça a outra pessoa da administração para mudar o seu papel."));
+    }
+
+    let recorder = Recorder::new(Some(&user)).await;
+    let found = crate::db::transaction(move |tx| {
+        let Some(account) = store::find(tx, id)? else {
+            return Ok(false);
+        };
+        if account.user.role != role {
+            store::set_role(tx, id, role)?;
+            recorder.record(tx, Action::UserRoleChanged, &account.user.email, &role_fields(account.user.role), &role_fields(role))?;
+        }
+        Ok::<_, crate::db::DbError>(true)
+    })
+    .await
+    .map_err(ServerFnError::new)?;
+
+    if !found {
+        return Err(ServerFnError::new("Conta não encontrada."));
+    }
+    Ok(())
+}
+
+/// Returns whether the account is now active.
+#[server(SetAccountActive, "/api/set_account_active")]
+pub async fn set_account_active(id: i64, active: bool) -> Result<bool, ServerFnError> {
+    use crate::audit::{Action, Recorder};
+    use crate::auth::{require_role, store};
+
+    let user = require_role(Role::Admin).await?;
+    if user.id == id {
+        return Err(ServerFnError::new("Você não pode desativar a sua própria conta."));
+    }
+
+    let recorder = Recorder::new(Some(&user)).await;
+    let found = crate::db::transaction(move |tx| {
+        let Some(account) = store::find(tx, id)? else {
+            return Ok(false);
+        };
+        if account.active != active {
+            store::set_active(tx, id, active)?;
+            let action = if active { Action::UserReactivated } else { Action::UserDeactivated };
+            recorder.record(tx, action, &account.user.email, &active_fields(account.active), &active_fields(active))?;
+        }
+        Ok::<_, crate::db::DbError>(true)
+    })
+    .await
+    .map_err(ServerFnError::new)?;
+
+    if !found {
+        return Err(ServerFnError::new("Conta não encontrada."));
+    }
+    Ok(active)
+}
@@ -0,0 +1,216 @@
+use chrono::NaiveDate;
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::components::A;
+use leptos_router::hooks::use_query_map;
+use crate::audit::{Action, AuditEntry, AuditFilter, AuditPage, FieldChange};
+use crate::components::form::INPUT_STYLE;
+use crate::components::page_layout::PageLayout;
+use crate::components::stacks::hstack::{HStack, AlignItems as HAlign, JustifyContent as HJustify, FlexWrap};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::dates;
+
+#[cfg(feature = "ssr")]
+const PAGE_SIZE: usize = 50;
+
+fn filter_from_query(get: impl Fn(&str) -> Option<String>) -> AuditFilter {
+    let get = |key: &str| get(key).filter(|v| !v.trim().is_empty());
+    AuditFilter {
+        user_id: get("usuario").and_then(|v| v.parse().ok()),
+        action: get("acao").as_deref().and_then(Action::parse),
+        from: get("de").and_then(|v| v.parse().ok()),
+        until: get("ate").and_then(|v| v.parse().ok()),
+        search: get("busca"),
+    }
+}
+
+fn page_href(filter: &AuditFilter, page: usize) -> String {
+    let mut query = form_urlencoded::Serializer::new(String::new());
+    if let Some(user_id) = filter.user_id {
+        query.append_pair("usuario", &user_id.to_string());
+    }
+    if let Some(action) = filter.action {
+        query.append_pair("acao", action.as_str());
+    }
+    if let Some(from) = filter.from {
+        query.append_pair("de", &from.to_string());
+    }
+    if let Some(until) = filter.until {
+        query.append_pair("ate", &until.to_string());
+    }
+    if let Some(search) = &filter.search {
+        query.append_pair("busca", search);
+    }
+    if page > 0 {
+        query.append_pair("pagina", &page.to_string());
+    }
+    match query.finish() {
+        query if query.is_empty() => "/admin/auditoria".to_string(),
+        query => format!("/admin/auditoria?{}", query),
+    }
+}
+
+/// Registro de auditoria: o que cada conta fez no painel, com filtros por
+/// pessoa, ação, período e texto (`?usuario=&acao=&de=&ate=&busca=&pagina=`).
+#[component]
+pub fn AuditLogPage() -> impl IntoView {
+    let query = use_query_map();
+    let filter = move || query.with(|q| filter_from_query(|key| q.get(key)));
+    let page = move || query.read().get("pagina").and_then(|p| p.parse::<usize>().ok()).unwrap_or(0);
+
+    let log = Resource::new(move || (filter(), page()), |(filter, page)| get_audit_log(filter, page));
+
+    view! {
+        <Title text="Auditoria | Associação Camará Capoeira"/>
+        <PageLayout title="Auditoria" subtitle="Quem alterou o quê no painel, quando e de onde.">
+            <VStack spacing="2rem".to_string() align=VAlign::Stretch style="max-width: 960px; margin: 0 auto;">
+                <A href="/admin" attr:style="color: #332175; font-weight: 600;">"← Painel"</A>
+                <Suspense fallback=move || view! { <p>"Carregando registro..."</p> }>
+                    {move || log.get().map(|result| match result {
+                        Ok(log) => view! { <AuditLog log=log filter=filter() page=page() /> }.into_any(),
+                        Err(_) => view! { <p>"Não foi possível carregar o registro de auditoria."</p> }.into_any(),
+                    })}
+                </Suspense>
+            </VStack>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn AuditLog(log: AuditPage, filter: AuditFilter, page: usize) -> impl IntoView {
+    let AuditPage { entries, actors, has_more } = log;
+    let newer = (page > 0).then(|| page_href(&filter, page - 1));
+    let older = has_more.then(|| page_href(&filter, page + 1));
+
+    view! {
+        <AuditFilters actors=actors filter=filter />
+        {if entries.is_empty() {
+            view! { <p style="color: #666;">"Nenhuma ação registrada com esses filtros."</p> }.into_any()
+        } else {
+            view! {
+                <VStack spacing="0.75rem".to_string() align=VAlign::Stretch>
+                    {entries.into_iter().map(|entry| view! { <AuditItem entry=entry /> }).collect_view()}
+                </VStack>
+            }.into_any()
+        }}
+        <HStack justify=HJustify::SpaceBetween spacing="1rem".to_string()>
+            <span>{newer.map(|href| view! { <A href=href attr:style="color: #332175; font-weight: 600;">"← Mais recentes"</A> })}</span>
+            <span>{older.map(|href| view! { <A href=href attr:style="color: #332175; font-weight: 600;">"Mais antigas →"</A> })}</span>
+        </HStack>
+    }
+}
+
+/// Filters as a plain GET form, like the roll call picker.
+#[component]
+fn AuditFilters(actors: Vec<(i64, String)>, filter: AuditFilter) -> impl IntoView {
+    let primary_bg = "#332175";
+    let date_value = |date: Option<NaiveDate>| date.map(|d| d.to_string()).unwrap_or_default();
+
+    view! {
+        <form method="get" action="/admin/auditoria" style="display: flex; gap: 0.75rem; flex-wrap: wrap; align-items: center;">
+            <select name="usuario" style=format!("{} flex: 1 1 180px; width: auto;", INPUT_STYLE)>
+                <option value="">"Todas as pessoas"</option>
+                {actors.into_iter().map(|(id, name)| {
+                    let is_selected = filter.user_id == Some(id);
+                    view! { <option value=id.to_string() selected=is_selected>{name}</option> }
+                }).collect_view()}
+            </select>
+            <select name="acao" style=format!("{} flex: 1 1 180px; width: auto;", INPUT_STYLE)>
+                <option value="">"Todas as ações"</option>
+                {Action::ALL.into_iter().map(|action| {
+                    let is_selected = filter.action == Some(action);
+                    view! { <option value=action.as_str() selected=is_selected>{action.label()}</option> }
+                }).collect_view()}
+            </select>
+            <input type="date" name="de" title="De" value=date_value(filter.from) style=format!("{} flex: 0 1 170px; width: auto;", INPUT_STYLE) />
+            <input type="date" name="ate" title="Até" value=date_value(filter.until) style=format!("{} flex: 0 1 170px; width: auto;", INPUT_STYLE) />
+            <input
+                type="search"
+                name="busca"
+                placeholder="Registro, IP ou ID da requisição"
+                value=filter.search.unwrap_or_default()
+                style=format!("{} flex: 2 1 240px; width: auto;", INPUT_STYLE)
+            />
+            <button
+                type="submit"
+                style=format!("padding: 0.8rem 1.6rem; border: none; border-radius: 8px; background-color: {}; color: white; font-weight: 700; font-size: 1rem; cursor: pointer;", primary_bg)
+            >
+                "Filtrar"
+            </button>
+        </form>
+    }
+}
+
+#[component]
+fn AuditItem(entry: AuditEntry) -> impl IntoView {
+    let AuditEntry { request_id, occurred_at, actor, ip, action, target, changes, .. } = entry;
+    let when = format!("{}, {}", dates::day_month_year(occurred_at.date()), occurred_at.format("%H:%M:%S"));
+    let accent = match action {
+        Action::SignInFailed | Action::UserDeactivated | Action::ContentDeleted | Action::DraftDiscarded => "#b5431f",
+        _ => "#332175",
+    };
+
+    view! {
+        <VStack
+            spacing="0.6rem".to_string()
+            align=VAlign::Stretch
+            style=format!("background: white; padding: 0.9rem 1.25rem; border-radius: 12px; box-shadow: 0 2px 8px rgba(0,0,0,0.04); border-left: 4px solid {};", accent)
+        >
+            <HStack justify=HJustify::SpaceBetween align=HAlign::Center wrap=FlexWrap::Wrap spacing="0.75rem".to_string()>
+                <span style=format!("font-weight: 700; color: {};", accent)>{action.label()}</span>
+                <span style="font-size: 0.85rem; color: #666;">{when}</span>
+            </HStack>
+            <span style="color: #222;">{target}</span>
+            <HStack wrap=FlexWrap::Wrap spacing="1rem".to_string() style="font-size: 0.8rem; color: #666;">
+                <span>{actor.unwrap_or_else(|| "Visitante do site".to_string())}</span>
+                <span>{ip.unwrap_or_else(|| "IP desconhecido".to_string())}</span>
+                <code title="ID da requisição">{request_id}</code>
+            </HStack>
+            {(!changes.is_empty()).then(|| view! { <ChangesTable changes=changes /> })}
+        </VStack>
+    }
+}
+
+#[component]
+fn ChangesTable(changes: Vec<FieldChange>) -> impl IntoView {
+    let cell = "padding: 0.35rem 0.6rem; border-top: 1px solid #eee; vertical-align: top; white-space: pre-wrap; word-break: break-word;";
+    let empty = || view! { <span style="color: #aaa;">"—"</span> };
+
+    view! {
+        <div style="overflow-x: auto;">
+            <table style="width: 100%; border-collapse: collapse; font-size: 0.85rem;">
+                <thead>
+                    <tr style="text-align: left; color: #666;">
+                        <th style="padding: 0.35rem 0.6rem;">"Campo"</th>
+                        <th style="padding: 0.35rem 0.6rem;">"Antes"</th>
+                        <th style="padding: 0.35rem 0.6rem;">"Depois"</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {changes.into_iter().map(|FieldChange { field, before, after }| view! {
+                        <tr>
+                            <td style=format!("{} font-weight: 600;", cell)>{field}</td>
+                            <td style=format!("{} color: #b5431f;", cell)>{before.map(|v| v.into_any()).unwrap_or_else(|| empty().into_any())}</td>
+                            <td style=format!("{} color: #1f7a4d;", cell)>{after.map(|v| v.into_any()).unwrap_or_else(|| empty().into_any())}</td>
+                        </tr>
+                    }).collect_view()}
+                </tbody>
+            </table>
+        </div>
+    }
+}
+
+#[server(GetAuditLog, "/api/get_audit_log")]
+pub async fn get_audit_log(#[server(default)] filter: AuditFilter, #[server(default)] page: usize) -> Result<AuditPage, ServerFnError> {
+    use crate::audit::store;
+    use crate::auth::{require_role, Role};
+
+    require_role(Role::Admin).await?;
+
+    crate::db::with_conn(move |conn| {
+        let (entries, has_more) = store::list(conn, &filter, PAGE_SIZE, page * PAGE_SIZE)?;
+        Ok(AuditPage { entries, actors: store::actors(conn)?, has_more })
+    })
+    .await
+    .map_err(|e: crate::db::DbError| ServerFnError::new(e))
+}
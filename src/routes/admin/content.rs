@@ -0,0 +1,612 @@
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::components::A;
+use leptos_router::hooks::{use_navigate, use_params_map, use_query_map};
+use crate::admin::{Collection, CollectionListing, DraftRow, EntryDocument, EntryRow, FieldKind, FieldSpec, SavedEntry, Values, PUBLISH_ROLE};
+use crate::components::form::{LabeledField, INPUT_STYLE};
+use crate::components::page_layout::PageLayout;
+use crate::components::stacks::hstack::{HStack, AlignItems as HAlign, JustifyContent as HJustify, FlexWrap};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::dates;
+use crate::routes::auth::use_auth;
+
+const ROW_STYLE: &str = "background: white; padding: 0.9rem 1.25rem; border-radius: 12px; box-shadow: 0 2px 8px rgba(0,0,0,0.04);";
+
+fn use_collection() -> impl Fn() -> Option<Collection> + Copy {
+    let params = use_params_map();
+    move || params.read().get("colecao").as_deref().and_then(Collection::parse)
+}
+
+fn page_title(collection: Option<Collection>) -> String {
+    collection.map(Collection::label).unwrap_or("Conteúdo").to_string()
+}
+
+fn editor_href(collection: Collection, index: Option<usize>, draft: Option<i64>) -> String {
+    match (index, draft) {
+        (_, Some(draft)) => format!("{}/editar?rascunho={}", collection.href(), draft),
+        (Some(index), None) => format!("{}/editar?indice={}", collection.href(), index),
+        (None, None) => format!("{}/editar", collection.href()),
+    }
+}
+
+fn pill(label: &'static str, color: &'static str) -> impl IntoView {
+    view! {
+        <span style=format!("font-size: 0.75rem; font-weight: 700; padding: 0.15rem 0.6rem; border-radius: 50px; background: {}; color: #222;", color)>
+            {label}
+        </span>
+    }
+}
+
+/// Lista as entradas publicadas e os rascunhos de uma coleção.
+#[component]
+pub fn CollectionPage() -> impl IntoView {
+    let collection = use_collection();
+    let listing = Resource::new(collection, |collection| async move {
+        match collection {
+            Some(collection) => get_collection(collection).await.map(Some),
+            None => Ok(None),
+        }
+    });
+
+    view! {
+        <Title text="Conteúdo | Associação Camará Capoeira"/>
+        <PageLayout title=page_title(untrack(collection))>
+            <Suspense fallback=move || view! { <p>"Carregando..."</p> }>
+                {move || listing.get().map(|result| match result {
+                    Ok(Some(listing)) => view! { <CollectionEntries listing=listing /> }.into_any(),
+                    Ok(None) => view! { <p>"Coleção não encontrada."</p> }.into_any(),
+                    Err(_) => view! { <p>"Não foi possível carregar o conteúdo."</p> }.into_any(),
+                })}
+            </Suspense>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn CollectionEntries(listing: CollectionListing) -> impl IntoView {
+    let primary_bg = "#332175";
+    let CollectionListing { collection, entries, drafts } = listing;
+
+    view! {
+        <VStack spacing="2rem".to_string() align=VAlign::Stretch style="max-width: 860px; margin: 0 auto;">
+            <HStack justify=HJustify::SpaceBetween align=HAlign::Center wrap=FlexWrap::Wrap spacing="1rem".to_string()>
+                <HStack spacing="1.25rem".to_string() align=HAlign::Center>
+                    <A href="/admin" attr:style=format!("color: {}; font-weight: 600;", primary_bg)>"← Painel"</A>
+                    <a href=collection.public_href() target="_blank" style="color: #555;">"Ver no site"</a>
+                </HStack>
+                <A
+                    href=editor_href(collection, None, None)
+                    attr:style=format!("padding: 0.7rem 1.5rem; border-radius: 50px; background-color: {}; color: white; font-weight: 700; text-decoration: none;", primary_bg)
+                >
+                    "Nova entrada"
+                </A>
+            </HStack>
+
+            {(!drafts.is_empty()).then(|| view! {
+                <VStack spacing="0.75rem".to_string() align=VAlign::Stretch>
+                    <h2 style=format!("margin: 0; color: {}; font-size: 1.3rem;", primary_bg)>"Rascunhos de novas entradas"</h2>
+                    {drafts.into_iter().map(|draft| view! { <DraftItem collection=collection draft=draft /> }).collect_view()}
+                </VStack>
+            })}
+
+            <VStack spacing="0.75rem".to_string() align=VAlign::Stretch>
+                <h2 style=format!("margin: 0; color: {}; font-size: 1.3rem;", primary_bg)>"Publicadas"</h2>
+                {if entries.is_empty() {
+                    view! { <p style="color: #666;">"Nenhuma entrada publicada."</p> }.into_any()
+                } else {
+                    entries.into_iter().map(|entry| view! { <EntryItem collection=collection entry=entry /> }).collect_view().into_any()
+                }}
+            </VStack>
+        </VStack>
+    }
+}
+
+#[component]
+fn EntryItem(collection: Collection, entry: EntryRow) -> impl IntoView {
+    let href = editor_href(collection, Some(entry.index), entry.draft_id);
+
+    view! {
+        <A href=href attr:style="text-decoration: none; color: inherit;">
+            <HStack justify=HJustify::SpaceBetween align=HAlign::Center spacing="1rem".to_string() style=ROW_STYLE>
+                <VStack spacing="0.2rem".to_string() align=VAlign::FlexStart>
+                    <span style="font-weight: 700; color: #222;">{entry.title}</span>
+                    <span style="font-size: 0.85rem; color: #666;">{entry.detail}</span>
+                </VStack>
+                {entry.draft_id.map(|_| pill("Rascunho", "#f2e300"))}
+            </HStack>
+        </A>
+    }
+}
+
+#[component]
+fn DraftItem(collection: Collection, draft: DraftRow) -> impl IntoView {
+    let href = editor_href(collection, None, Some(draft.id));
+    let detail = format!("{} · {}", draft.author, dates::day_month_year(draft.updated_at.date()));
+
+    view! {
+        <A href=href attr:style="text-decoration: none; color: inherit;">
+            <HStack justify=HJustify::SpaceBetween align=HAlign::Center spacing="1rem".to_string() style=ROW_STYLE>
+                <VStack spacing="0.2rem".to_string() align=VAlign::FlexStart>
+                    <span style="font-weight: 700; color: #222;">{draft.title}</span>
+                    <span style="font-size: 0.85rem; color: #666;">{detail}</span>
+                </VStack>
+                {pill("Rascunho", "#f2e300")}
+            </HStack>
+        </A>
+    }
+}
+
+/// Formulário de edição de uma entrada: `?indice=` (publicada), `?rascunho=` ou em branco (nova).
+#[component]
+pub fn EntryEditorPage() -> impl IntoView {
+    let collection = use_collection();
+    let query = use_query_map();
+    let index = move || query.read().get("indice").and_then(|i| i.parse::<usize>().ok());
+    let draft = move || query.read().get("rascunho").and_then(|d| d.parse::<i64>().ok());
+
+    let save = ServerAction::<SaveEntry>::new();
+    let delete = ServerAction::<DeleteEntry>::new();
+    let discard = ServerAction::<DiscardDraft>::new();
+
+    // Reloaded after every save, so the form always starts from what is stored.
+    let document = Resource::new(
+        move || (collection(), index(), draft(), save.version().get()),
+        |(collection, index, draft, _)| async move {
+            match collection {
+                Some(collection) => get_entry(collection, index, draft).await.map(Some),
+                None => Ok(None),
+            }
+        },
+    );
+
+    let navigate = use_navigate();
+    Effect::new(move || {
+        let Some(collection) = collection() else { return };
+        let target = match save.value().get() {
+            Some(Ok(SavedEntry::Published(index))) => editor_href(collection, Some(index), None),
+            Some(Ok(SavedEntry::Draft(id))) => editor_href(collection, None, Some(id)),
+            _ => return,
+        };
+        navigate(&target, Default::default());
+    });
+    let navigate = use_navigate();
+    Effect::new(move || {
+        let done = matches!(delete.value().get(), Some(Ok(()))) || matches!(discard.value().get(), Some(Ok(())));
+        if let (true, Some(collection)) = (done, collection()) {
+            navigate(&collection.href(), Default::default());
+        }
+    });
+
+    view! {
+        <Title text="Editar conteúdo | Associação Camará Capoeira"/>
+        <PageLayout title=page_title(untrack(collection))>
+            <Suspense fallback=move || view! { <p>"Carregando..."</p> }>
+                {move || document.get().map(|result| match result {
+                    Ok(Some(document)) => view! {
+                        <EntryEditor document=document save=save delete=delete discard=discard />
+                    }.into_any(),
+                    Ok(None) => view! { <p>"Coleção não encontrada."</p> }.into_any(),
+                    Err(ServerFnError::ServerError(message)) => view! { <p>{message}</p> }.into_any(),
+                    Err(_) => view! { <p>"Não foi possível carregar a entrada."</p> }.into_any(),
+                })}
+            </Suspense>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn EntryEditor(
+    document: EntryDocument,
+    save: ServerAction<SaveEntry>,
+    delete: ServerAction<DeleteEntry>,
+    discard: ServerAction<DiscardDraft>,
+) -> impl IntoView {
+    let primary_bg = "#332175";
+    let auth = use_auth();
+    let can_publish = move || auth.user.get().and_then(|r| r.ok()).flatten().is_some_and(|u| u.has_role(PUBLISH_ROLE));
+    let EntryDocument { collection, index, draft_id, values, base, stale } = document;
+    let values = RwSignal::new(values);
+    let base = StoredValue::new(base);
+
+    let submit = move |publish: bool| {
+        save.dispatch(SaveEntry {
+            collection,
+            index,
+            draft_id,
+            base: base.get_value(),
+            values: values.get_untracked(),
+            publish,
+        });
+    };
+    let on_delete = move |_| {
+        let confirmed = window().confirm_with_message("Excluir esta entrada do site?").unwrap_or(false);
+        if let (true, Some(index), Some(base)) = (confirmed, index, base.get_value()) {
+            delete.dispatch(DeleteEntry { collection, index, base });
+        }
+    };
+
+    let busy = move || save.pending().get() || delete.pending().get() || discard.pending().get();
+    let error = move || {
+        [save.value().get().and_then(|r| r.err()), delete.value().get().and_then(|r| r.err()), discard.value().get().and_then(|r| r.err())]
+            .into_iter()
+            .flatten()
+            .next()
+            .map(|e| match e {
+                ServerFnError::ServerError(message) => message,
+                _ => "Não foi possível salvar. Tente novamente.".to_string(),
+            })
+    };
+
+    let status = match (index, draft_id) {
+        (Some(_), Some(_)) => "Publicada, com alterações em rascunho",
+        (Some(_), None) => "Publicada",
+        (None, Some(_)) => "Rascunho, ainda não publicada",
+        (None, None) => "Nova entrada",
+    };
+    let button = move |background: &str, color: &str| format!(
+        "padding: 0.8rem 1.6rem; border: 2px solid {}; border-radius: 50px; background-color: {}; color: {}; font-weight: 700; font-size: 1rem; cursor: pointer;",
+        primary_bg, background, color
+    );
+
+    view! {
+        <VStack spacing="1.5rem".to_string() align=VAlign::Stretch style="max-width: 760px; margin: 0 auto;">
+            <HStack justify=HJustify::SpaceBetween align=HAlign::Center wrap=FlexWrap::Wrap spacing="1rem".to_string()>
+                <A href=collection.href() attr:style=format!("color: {}; font-weight: 600;", primary_bg)>{format!("← {}", collection.label())}</A>
+                <span style="font-size: 0.9rem; color: #555; font-weight: 600;">{status}</span>
+            </HStack>
+
+            {stale.then(|| view! {
+                <p style="margin: 0; padding: 0.8rem 1rem; border-radius: 8px; background: #fff6d6; color: #6b5200;">
+                    "A versão publicada mudou depois que este rascunho foi salvo. Publicar vai falhar: "
+                    "copie suas alterações, descarte o rascunho e edite a versão atual."
+                </p>
+            })}
+
+            <VStack
+                spacing="1.25rem".to_string()
+                align=VAlign::Stretch
+                style="background: white; padding: 1.75rem; border-radius: 12px; box-shadow: 0 4px 15px rgba(0,0,0,0.05);"
+            >
+                {collection.fields().iter().map(|spec| view! { <FieldInput spec=*spec values=values /> }).collect_view()}
+            </VStack>
+
+            <HStack align=HAlign::Center wrap=FlexWrap::Wrap spacing="1rem".to_string()>
+                {move || can_publish().then(|| view! {
+                    <button on:click=move |_| submit(true) disabled=busy style=button(primary_bg, "white")>"Publicar"</button>
+                })}
+                <button on:click=move |_| submit(false) disabled=busy style=button("white", primary_bg)>"Salvar rascunho"</button>
+                {draft_id.map(|id| view! {
+                    <button on:click=move |_| { discard.dispatch(DiscardDraft { id }); } disabled=busy style=button("white", primary_bg)>
+                        "Descartar rascunho"
+                    </button>
+                })}
+                {move || (index.is_some() && can_publish()).then(|| view! {
+                    <button
+                        on:click=on_delete
+                        disabled=busy
+                        style="padding: 0.8rem 1.6rem; border: none; background: none; color: #b5431f; font-weight: 700; font-size: 1rem; cursor: pointer;"
+                    >
+                        "Excluir"
+                    </button>
+                })}
+            </HStack>
+            {move || (!can_publish()).then(|| view! {
+                <p style="margin: 0; color: #555; font-size: 0.9rem;">
+                    "Seu rascunho fica guardado no painel; um(a) administrador(a) revisa e publica."
+                </p>
+            })}
+            {move || error().map(|message| view! {
+                <p style="margin: 0; color: #b5431f; font-weight: 600;">{message}</p>
+            })}
+        </VStack>
+    }
+}
+
+/// Shows stored `HH:MM:SS` times as the `HH:MM` time inputs expect.
+fn display_value(kind: FieldKind, value: String) -> String {
+    match kind {
+        FieldKind::Time | FieldKind::DateTime if value.ends_with(":00") && value.len() > 5 => {
+            value[..value.len() - 3].to_string()
+        }
+        _ => value,
+    }
+}
+
+#[component]
+fn FieldInput(spec: FieldSpec, values: RwSignal<Values>) -> impl IntoView {
+    let name = spec.name;
+    let current = move || display_value(spec.kind, values.with(|v| v.get(name).cloned().unwrap_or_default()));
+    let set = move |ev| {
+        let value = event_target_value(&ev);
+        values.update(|v| {
+            v.insert(name.to_string(), value);
+        });
+    };
+
+    let control = match spec.kind {
+        FieldKind::LongText | FieldKind::Lines => view! {
+            <textarea rows="6" prop:value=current on:input=set style=INPUT_STYLE></textarea>
+        }.into_any(),
+        FieldKind::Choice(options) => view! {
+            <select prop:value=current on:change=set style=INPUT_STYLE>
+                <option value="">"Selecione..."</option>
+                {options.iter().map(|(value, label)| view! { <option value=*value>{*label}</option> }).collect_view()}
+            </select>
+        }.into_any(),
+        kind => {
+            let input_type = match kind {
+                FieldKind::Date => "date",
+                FieldKind::DateTime => "datetime-local",
+                FieldKind::Time => "time",
+                FieldKind::Url => "url",
+                _ => "text",
+            };
+            view! { <input type=input_type prop:value=current on:input=set style=INPUT_STYLE /> }.into_any()
+        }
+    };
+
+    view! {
+        <LabeledField label=spec.label hint=spec.hint required=spec.required>
+            {control}
+        </LabeledField>
+    }
+}
+
+#[cfg(feature = "ssr")]
+fn edit_error(e: crate::admin::files::EditError) -> ServerFnError {
+    if e.is_user_facing() {
+        ServerFnError::new(e)
+    } else {
+        tracing::error!(error = %e, "content edit failed");
+        ServerFnError::new("Não foi possível gravar o conteúdo.")
+    }
+}
+
+/// The published entries of `collection`, read off the async executor like the database.
+#[cfg(feature = "ssr")]
+async fn published_entries(collection: Collection) -> Result<Vec<Values>, ServerFnError> {
+    actix_web::web::block(move || crate::admin::files::entries(collection))
+        .await
+        .map_err(ServerFnError::new)?
+        .map_err(edit_error)
+}
+
+/// A published change fails either on the file or on the database around it.
+#[cfg(feature = "ssr")]
+#[derive(Debug)]
+enum ChangeError {
+    Edit(crate::admin::files::EditError),
+    Db(crate::db::DbError),
+}
+
+#[cfg(feature = "ssr")]
+impl From<crate::admin::files::EditError> for ChangeError {
+    fn from(e: crate::admin::files::EditError) -> Self {
+        ChangeError::Edit(e)
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<crate::db::DbError> for ChangeError {
+    fn from(e: crate::db::DbError) -> Self {
+        ChangeError::Db(e)
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<rusqlite::Error> for ChangeError {
+    fn from(e: rusqlite::Error) -> Self {
+        ChangeError::Db(e.into())
+    }
+}
+
+#[cfg(feature = "ssr")]
+fn change_error(e: ChangeError) -> ServerFnError {
+    match e {
+        ChangeError::Edit(e) => edit_error(e),
+        ChangeError::Db(e) => ServerFnError::new(e),
+    }
+}
+
+/// Runs `change` (which rewrites a content file) after `record` has written
+/// the audit entry, in one transaction: a file that cannot be written leaves
+/// no entry behind, and an entry that cannot be written leaves the file alone.
+/// Only a failed commit after the file changed goes unrecorded, and is logged.
+#[cfg(feature = "ssr")]
+async fn audited_change<T, R, C>(target: String, record: R, change: C) -> Result<T, ServerFnError>
+where
+    T: Send + 'static,
+    R: FnOnce(&rusqlite::Transaction) -> Result<(), crate::db::DbError> + Send + 'static,
+    C: FnOnce() -> Result<T, crate::admin::files::EditError> + Send + 'static,
+{
+    crate::db::with_conn(move |conn| {
+        let tx = conn.transaction()?;
+        record(&tx)?;
+        let value = change()?;
+        tx.commit().map_err(|e| {
+            tracing::error!(error = %e, %target, "content file changed but its audit entry was not saved");
+            e
+        })?;
+        Ok::<_, ChangeError>(value)
+    })
+    .await
+    .map_err(change_error)
+}
+
+#[server(GetCollection, "/api/get_collection")]
+pub async fn get_collection(collection: Collection) -> Result<CollectionListing, ServerFnError> {
+    use crate::admin::{drafts, EDIT_ROLE};
+    use crate::auth::require_role;
+
+    require_role(EDIT_ROLE).await?;
+
+    let published = published_entries(collection).await?;
+    let all_drafts = crate::db::with_conn(move |conn| drafts::list(conn, collection)).await.map_err(ServerFnError::new)?;
+
+    let entries = published
+        .iter()
+        .enumerate()
+        .map(|(index, values)| {
+            let (title, detail) = collection.summarize(values);
+            let draft_id = all_drafts.iter().find(|d| d.entry_index == Some(index)).map(|d| d.id);
+            EntryRow { index, title, detail, draft_id }
+        })
+        .collect();
+    let drafts = all_drafts
+        .into_iter()
+        .filter(|d| d.entry_index.is_none())
+        .map(|d| DraftRow { id: d.id, title: collection.summarize(&d.values).0, author: d.author, updated_at: d.updated_at })
+        .collect();
+
+    Ok(CollectionListing { collection, entries, drafts })
+}
+
+#[server(GetEntry, "/api/get_entry")]
+pub async fn get_entry(collection: Collection, index: Option<usize>, draft: Option<i64>) -> Result<EntryDocument, ServerFnError> {
+    use crate::admin::{drafts, EDIT_ROLE};
+    use crate::auth::require_role;
+
+    require_role(EDIT_ROLE).await?;
+
+    let published = published_entries(collection).await?;
+    let found = crate::db::with_conn(move |conn| match (draft, index) {
+        (Some(id), _) => drafts::find(conn, id),
+        (None, Some(index)) => drafts::for_entry(conn, collection, index),
+        (None, None) => Ok(None),
+    })
+    .await
+    .map_err(ServerFnError::new)?;
+
+    match found.filter(|d| d.collection == collection) {
+        Some(draft) => {
+            let stale = draft.entry_index.is_some_and(|i| published.get(i) != draft.base.as_ref());
+            Ok(EntryDocument {
+                collection,
+                index: draft.entry_index,
+                draft_id: Some(draft.id),
+                values: draft.values,
+                base: draft.base,
+                stale,
+            })
+        }
+        None if draft.is_some() => Err(ServerFnError::new("Rascunho não encontrado; talvez já tenha sido publicado.")),
+        None => {
+            let values = match index {
+                Some(index) => published.get(index).cloned().ok_or_else(|| ServerFnError::new("Entrada não encontrada."))?,
+                None => Values::new(),
+            };
+            Ok(EntryDocument {
+                collection,
+                index,
+                draft_id: None,
+                base: index.map(|_| values.clone()),
+                values,
+                stale: false,
+            })
+        }
+    }
+}
+
+#[server(SaveEntry, "/api/save_entry")]
+pub async fn save_entry(
+    collection: Collection,
+    index: Option<usize>,
+    draft_id: Option<i64>,
+    base: Option<Values>,
+    values: Values,
+    publish: bool,
+) -> Result<SavedEntry, ServerFnError> {
+    use crate::admin::{drafts, files, EDIT_ROLE};
+    use crate::audit::{Action, Recorder};
+    use crate::auth::require_role;
+    use crate::content::events::now_local;
+
+    let user = require_role(if publish { PUBLISH_ROLE } else { EDIT_ROLE }).await?;
+    let recorder = Recorder::new(Some(&user)).await;
+    let target = audit_target(collection, &values);
+
+    if publish {
+        let audit_target = target.clone();
+        let before = base.clone().unwrap_or_default();
+        let published = values.clone();
+        let position = audited_change(
+            target,
+            move |tx| {
+                if let Some(id) = draft_id {
+                    drafts::delete(tx, id)?;
+                }
+                recorder.record(tx, Action::ContentPublished, &audit_target, &before, &published)
+            },
+            move || files::publish(collection, index, base.as_ref(), &values),
+        )
+        .await?;
+        return Ok(SavedEntry::Published(position));
+    }
+
+    let now = now_local();
+    let id = crate::db::transaction(move |tx| {
+        let previous = match draft_id {
+            Some(id) => drafts::find(tx, id)?.map(|d| d.values),
+            None => None,
+        };
+        let id = drafts::save(tx, draft_id, collection, index, base.as_ref(), &values, user.id, now)?;
+        let before = previous.or(base).unwrap_or_default();
+        recorder.record(tx, Action::DraftSaved, &target, &before, &values)?;
+        Ok::<_, crate::db::DbError>(id)
+    })
+    .await
+    .map_err(ServerFnError::new)?;
+    Ok(SavedEntry::Draft(id))
+}
+
+#[server(DeleteEntry, "/api/delete_entry")]
+pub async fn delete_entry(collection: Collection, index: usize, base: Values) -> Result<(), ServerFnError> {
+    use crate::admin::{drafts, files};
+    use crate::audit::{Action, Recorder};
+    use crate::auth::require_role;
+
+    let user = require_role(PUBLISH_ROLE).await?;
+    let recorder = Recorder::new(Some(&user)).await;
+
+    // The entry is recorded as it was loaded in the editor; `files::delete`
+    // refuses to remove it if the file no longer matches.
+    let target = audit_target(collection, &base);
+    let audit_target = target.clone();
+    let removed = base.clone();
+    audited_change(
+        target,
+        move |tx| {
+            drafts::entry_deleted(tx, collection, index)?;
+            recorder.record(tx, Action::ContentDeleted, &audit_target, &removed, &Values::new())
+        },
+        move || files::delete(collection, index, &base).map(|_| ()),
+    )
+    .await
+}
+
+#[server(DiscardDraft, "/api/discard_draft")]
+pub async fn discard_draft(id: i64) -> Result<(), ServerFnError> {
+    use crate::admin::{drafts, EDIT_ROLE};
+    use crate::audit::{Action, Recorder};
+    use crate::auth::require_role;
+
+    let user = require_role(EDIT_ROLE).await?;
+    let recorder = Recorder::new(Some(&user)).await;
+
+    crate::db::transaction(move |tx| {
+        let Some(draft) = drafts::find(tx, id)? else {
+            return Ok(());
+        };
+        drafts::delete(tx, id)?;
+        let target = audit_target(draft.collection, &draft.values);
+        recorder.record(tx, Action::DraftDiscarded, &target, &draft.values, &Values::new())
+    })
+    .await
+    .map_err(ServerFnError::new)
+}
+
+/// "Notícias: Roda de Natal", as shown in the audit log.
+#[cfg(feature = "ssr")]
+fn audit_target(collection: Collection, values: &Values) -> String {
+    format!("{}: {}", collection.label(), collection.summarize(values).0)
+}
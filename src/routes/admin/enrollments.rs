@@ -0,0 +1,231 @@
+use leptos::form::ActionForm;
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::components::A;
+use leptos_router::hooks::use_query_map;
+use crate::components::page_layout::PageLayout;
+use crate::components::stacks::hstack::{HStack, AlignItems as HAlign, JustifyContent as HJustify, FlexWrap};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::dates;
+use crate::i18n::Locale;
+use crate::enrollment::{EnrollmentRow, EnrollmentStatus};
+
+const SUCCESS_COLOR: &str = "#1f7a4d";
+const ERROR_COLOR: &str = "#b5431f";
+
+fn status_href(status: Option<EnrollmentStatus>) -> String {
+    match status {
+        Some(status) => format!("/admin/matriculas?status={}", status.as_str()),
+        None => "/admin/matriculas".to_string(),
+    }
+}
+
+/// Matrículas enviadas pelo site: a coordenação aceita as que estão em
+/// análise e ativa as aceitas quando o aluno começa a treinar
+/// (`?status=pending|accepted|active` filtra a lista).
+#[component]
+pub fn EnrollmentsAdminPage() -> impl IntoView {
+    let query = use_query_map();
+    let status = move || query.read().get("status").as_deref().and_then(EnrollmentStatus::parse);
+
+    let accept = ServerAction::<AcceptEnrollment>::new();
+    let activate = ServerAction::<ActivateEnrollment>::new();
+    let enrollments = Resource::new(
+        move || (status(), accept.version().get(), activate.version().get()),
+        |(status, _, _)| list_enrollments(status),
+    );
+
+    // Whichever of the two actions ran last says how it went.
+    let latest = RwSignal::new(None::<Result<EnrollmentStatus, ServerFnError>>);
+    Effect::new(move |_| accept.value().get().map(|result| latest.set(Some(result))));
+    Effect::new(move |_| activate.value().get().map(|result| latest.set(Some(result))));
+    let outcome = move || match latest.get() {
+        Some(Ok(status)) => Some((format!("Matrícula {}.", status.label(Locale::PtBr).to_lowercase()), SUCCESS_COLOR)),
+        Some(Err(ServerFnError::ServerError(message))) => Some((message, ERROR_COLOR)),
+        Some(Err(_)) => Some(("Não foi possível atualizar a matrícula.".to_string(), ERROR_COLOR)),
+        None => None,
+    };
+
+    view! {
+        <Title text="Matrículas | Associação Camará Capoeira"/>
+        <PageLayout title="Matrículas" subtitle="Matrículas enviadas pelo site, da análise até o aluno ativo.">
+            <VStack spacing="2rem".to_string() align=VAlign::Stretch style="max-width: 960px; margin: 0 auto;">
+                <A href="/admin" attr:style="color: #332175; font-weight: 600;">"← Painel"</A>
+                <StatusTabs current=Signal::derive(status) />
+                {move || outcome().map(|(message, color)| view! { <p style=format!("margin: 0; color: {};", color)>{message}</p> })}
+                <Suspense fallback=move || view! { <p>"Carregando matrículas..."</p> }>
+                    {move || enrollments.get().map(|result| match result {
+                        Ok(rows) if rows.is_empty() => view! { <p style="color: #666;">"Nenhuma matrícula nesta situação."</p> }.into_any(),
+                        Ok(rows) => view! {
+                            <VStack spacing="0.75rem".to_string() align=VAlign::Stretch>
+                                {rows.into_iter().map(|row| view! { <EnrollmentItem row=row accept=accept activate=activate /> }).collect_view()}
+                            </VStack>
+                        }.into_any(),
+                        Err(_) => view! { <p>"Não foi possível carregar as matrículas."</p> }.into_any(),
+                    })}
+                </Suspense>
+            </VStack>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn StatusTabs(current: Signal<Option<EnrollmentStatus>>) -> impl IntoView {
+    let tab = move |status: Option<EnrollmentStatus>, label: &'static str| {
+        let style = move || {
+            if current.get() == status {
+                "padding: 0.4rem 0.9rem; border-radius: 999px; background: #332175; color: white; font-weight: 600; text-decoration: none;"
+            } else {
+                "padding: 0.4rem 0.9rem; border-radius: 999px; background: white; color: #332175; font-weight: 600; text-decoration: none; border: 1px solid #ddd;"
+            }
+        };
+        view! { <A href=status_href(status) attr:style=style>{label}</A> }
+    };
+
+    view! {
+        <HStack wrap=FlexWrap::Wrap spacing="0.5rem".to_string()>
+            {tab(None, "Todas")}
+            {EnrollmentStatus::ALL.into_iter().map(|status| tab(Some(status), status.label(Locale::PtBr))).collect_view()}
+        </HStack>
+    }
+}
+
+#[component]
+fn EnrollmentItem(
+    row: EnrollmentRow,
+    accept: ServerAction<AcceptEnrollment>,
+    activate: ServerAction<ActivateEnrollment>,
+) -> impl IntoView {
+    let primary_bg = "#332175";
+    let EnrollmentRow { protocol, student_name, age, contact_name, contact_phone, session, consent_image, submitted, status } = row;
+    let button_style = format!("padding: 0.5rem 1.2rem; border: none; border-radius: 8px; background-color: {}; color: white; font-weight: 700; cursor: pointer;", primary_bg);
+    let href = format!("/matricula/{}", protocol);
+    let hidden = protocol.clone();
+    let advance = match status {
+        EnrollmentStatus::Pending => Some(view! {
+            <ActionForm action=accept>
+                <input type="hidden" name="protocol" value=hidden />
+                <button type="submit" style=button_style.clone()>{status.advance_label()}</button>
+            </ActionForm>
+        }.into_any()),
+        EnrollmentStatus::Accepted => Some(view! {
+            <ActionForm action=activate>
+                <input type="hidden" name="protocol" value=hidden />
+                <button type="submit" style=button_style.clone()>{status.advance_label()}</button>
+            </ActionForm>
+        }.into_any()),
+        EnrollmentStatus::Active => None,
+    };
+
+    view! {
+        <HStack
+            justify=HJustify::SpaceBetween
+            align=HAlign::Center
+            wrap=FlexWrap::Wrap
+            spacing="1rem".to_string()
+            style="background: white; padding: 0.9rem 1.25rem; border-radius: 12px; box-shadow: 0 2px 8px rgba(0,0,0,0.04); border-left: 4px solid #f2e300;"
+        >
+            <VStack spacing="0.3rem".to_string() align=VAlign::FlexStart style="flex: 1 1 320px;">
+                <span style="font-weight: 700; color: #222;">{student_name}{format!(", {} anos", age)}</span>
+                <span style="color: #555; font-size: 0.9rem;">
+                    {session.unwrap_or_else(|| "Turma fora da agenda".to_string())}
+                </span>
+                <span style="color: #555; font-size: 0.9rem;">{format!("{} · {}", contact_name, contact_phone)}</span>
+                <HStack wrap=FlexWrap::Wrap spacing="1rem".to_string() style="font-size: 0.8rem; color: #666;">
+                    <span>{format!("Enviada em {}", dates::day_month_year(submitted))}</span>
+                    <span>{if consent_image { "Autoriza uso de imagem" } else { "Não autoriza uso de imagem" }}</span>
+                    <A href=href attr:style="color: #666;"><code>{protocol}</code></A>
+                </HStack>
+            </VStack>
+            <HStack align=HAlign::Center spacing="1rem".to_string()>
+                <span style=format!("font-weight: 600; color: {};", primary_bg)>{status.label(Locale::PtBr)}</span>
+                {advance}
+            </HStack>
+        </HStack>
+    }
+}
+
+#[server(ListEnrollments, "/api/list_enrollments")]
+pub async fn list_enrollments(status: Option<EnrollmentStatus>) -> Result<Vec<EnrollmentRow>, ServerFnError> {
+    use crate::auth::{require_role, Role};
+    use crate::content::events::now_local;
+    use crate::content::schedule::load_schedule;
+    use crate::enrollment::store;
+
+    require_role(Role::Mestre).await?;
+
+    let schedule = load_schedule().map_err(ServerFnError::new)?;
+    let enrollments = crate::db::with_conn(move |conn| store::list(conn, status))
+        .await
+        .map_err(ServerFnError::new)?;
+
+    let today = now_local().date();
+    Ok(enrollments
+        .into_iter()
+        .map(|e| {
+            let (contact_name, contact_phone) = if e.form.is_minor(today) {
+                (e.form.guardian_name.clone(), e.form.guardian_phone.clone())
+            } else {
+                (e.form.student_name.clone(), e.form.phone.clone())
+            };
+            EnrollmentRow {
+                age: e.form.age_on(today),
+                session: schedule.session(&e.form.session_id).map(|s| s.title(Locale::PtBr)),
+                consent_image: e.form.consent_image,
+                submitted: e.created_at.date(),
+                student_name: e.form.student_name,
+                protocol: e.protocol,
+                status: e.status,
+                contact_name,
+                contact_phone,
+            }
+        })
+        .collect())
+}
+
+#[server(AcceptEnrollment, "/api/accept_enrollment")]
+pub async fn accept_enrollment(protocol: String) -> Result<EnrollmentStatus, ServerFnError> {
+    advance(protocol, EnrollmentStatus::Accepted).await
+}
+
+#[server(ActivateEnrollment, "/api/activate_enrollment")]
+pub async fn activate_enrollment(protocol: String) -> Result<EnrollmentStatus, ServerFnError> {
+    advance(protocol, EnrollmentStatus::Active).await
+}
+
+/// Moves the enrollment to `to` and records who did it, in one transaction.
+#[cfg(feature = "ssr")]
+async fn advance(protocol: String, to: EnrollmentStatus) -> Result<EnrollmentStatus, ServerFnError> {
+    use crate::audit::{Action, Fields, Recorder};
+    use crate::auth::{require_role, Role};
+    use crate::content::events::now_local;
+    use crate::enrollment::store::{self, StoreError};
+
+    let user = require_role(Role::Mestre).await?;
+    let action = match to {
+        EnrollmentStatus::Accepted => Action::EnrollmentAccepted,
+        _ => Action::EnrollmentActivated,
+    };
+
+    let recorder = Recorder::new(Some(&user)).await;
+    let now = now_local();
+    crate::db::transaction(move |tx| {
+        let from = store::find(tx, &protocol)?.ok_or(StoreError::NotFound)?.status;
+        store::set_status(tx, &protocol, to, now)?;
+        let field = |status: EnrollmentStatus| Fields::from([("status".to_string(), status.as_str().to_string())]);
+        recorder.record(tx, action, &protocol, &field(from), &field(to))?;
+        Ok::<_, StoreError>(())
+    })
+    .await
+    .map_err(|e| match e {
+        StoreError::NotFound => ServerFnError::new("Matrícula não encontrada."),
+        StoreError::InvalidTransition { from, .. } => {
+            ServerFnError::new(format!("Esta matrícula já está com a situação \"{}\".", from.label(Locale::PtBr)))
+        }
+        StoreError::Db(e) => {
+            tracing::error!(error = %e, "could not update the enrollment");
+            ServerFnError::new("Não foi possível atualizar a matrícula. Tente novamente mais tarde.")
+        }
+    })?;
+    Ok(to)
+}
@@ -0,0 +1,129 @@
+pub mod accounts;
+pub mod audit;
+pub mod content;
+pub mod enrollments;
+pub mod newsletter;
+
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::components::A;
+use crate::admin::{CollectionOverview, EDIT_ROLE};
+use crate::auth::Role;
+use crate::components::page_layout::PageLayout;
+use crate::components::stacks::hstack::{HStack, JustifyContent as HJustify, FlexWrap};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::routes::auth::use_auth;
+
+const CARD_STYLE: &str = "flex: 1 1 240px; max-width: 340px; background: white; padding: 1.25rem 1.5rem; border-radius: 12px; box-shadow: 0 4px 15px rgba(0,0,0,0.05); text-decoration: none; color: inherit;";
+
+/// Página inicial do painel: atalhos para a coordenação e para o conteúdo do site.
+#[component]
+pub fn AdminDashboard() -> impl IntoView {
+    let auth = use_auth();
+    let role = move || auth.user.get().and_then(|r| r.ok()).flatten().map(|u| u.role);
+
+    view! {
+        <Title text="Painel | Associação Camará Capoeira"/>
+        <PageLayout title="Painel" subtitle="Coordenação das turmas e conteúdo do site.">
+            <VStack spacing="3rem".to_string() align=VAlign::Stretch>
+                <DashboardSection title="Coordenação">
+                    <ToolCard href="/admin/frequencia" title="Frequência" detail="Chamada das turmas e relatórios mensais." />
+                    {move || role().is_some_and(|r| r >= Role::Mestre).then(|| view! {
+                        <ToolCard href="/admin/graduacoes" title="Graduações" detail="Batizado e troca de cordas." />
+                        <ToolCard href="/admin/matriculas" title="Matrículas" detail="Aceite e ativação das matrículas enviadas pelo site." />
+                    })}
+                </DashboardSection>
+                {move || role().is_some_and(|r| r >= EDIT_ROLE).then(|| view! { <ContentOverview /> })}
+                {move || role().is_some_and(|r| r >= Role::Admin).then(|| view! {
+                    <DashboardSection title="Administração">
+                        <ToolCard href="/admin/contas" title="Contas" detail="Quem entra no painel e com qual papel." />
+                        <ToolCard href="/admin/auditoria" title="Auditoria" detail="Quem alterou o quê, quando e de onde." />
+                        <ToolCard href="/admin/newsletter" title="Boletim" detail="Inscritos e envio do boletim por e-mail." />
+                    </DashboardSection>
+                })}
+            </VStack>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn DashboardSection(title: &'static str, children: Children) -> impl IntoView {
+    let primary_bg = "#332175";
+
+    view! {
+        <VStack spacing="1.25rem".to_string() align=VAlign::Stretch>
+            <h2 style=format!("margin: 0; color: {}; font-size: 1.5rem;", primary_bg)>{title}</h2>
+            <HStack wrap=FlexWrap::Wrap justify=HJustify::FlexStart spacing="1.25rem".to_string()>
+                {children()}
+            </HStack>
+        </VStack>
+    }
+}
+
+#[component]
+fn ToolCard(#[prop(into)] href: String, #[prop(into)] title: String, #[prop(into)] detail: String) -> impl IntoView {
+    let accent_color = "#f2e300";
+
+    view! {
+        <A href=href attr:style=format!("{} border-top: 4px solid {};", CARD_STYLE, accent_color)>
+            <h3 style="margin: 0 0 0.4rem; color: #222; font-size: 1.15rem;">{title}</h3>
+            <p style="margin: 0; color: #666; font-size: 0.9rem; line-height: 1.5;">{detail}</p>
+        </A>
+    }
+}
+
+#[component]
+fn ContentOverview() -> impl IntoView {
+    let overview = Resource::new(|| (), |_| get_content_overview());
+
+    view! {
+        <DashboardSection title="Conteúdo do site">
+            <Suspense fallback=move || view! { <p>"Carregando..."</p> }>
+                {move || overview.get().map(|result| match result {
+                    Ok(collections) => collections
+                        .into_iter()
+                        .map(|CollectionOverview { collection, entries, drafts }| {
+                            let detail = match drafts {
+                                0 => format!("{} publicados", entries),
+                                1 => format!("{} publicados · 1 rascunho", entries),
+                                n => format!("{} publicados · {} rascunhos", entries, n),
+                            };
+                            view! { <ToolCard href=collection.href() title=collection.label() detail=detail /> }
+                        })
+                        .collect_view()
+                        .into_any(),
+                    Err(_) => view! { <p>"Não foi possível carregar o conteúdo."</p> }.into_any(),
+                })}
+            </Suspense>
+        </DashboardSection>
+    }
+}
+
+#[server(GetContentOverview, "/api/get_content_overview")]
+pub async fn get_content_overview() -> Result<Vec<CollectionOverview>, ServerFnError> {
+    use crate::admin::{drafts, files, Collection};
+    use crate::auth::require_role;
+
+    require_role(EDIT_ROLE).await?;
+
+    // Reading the files blocks, so off the async executor like the database.
+    let published = actix_web::web::block(|| {
+        Collection::ALL
+            .into_iter()
+            .map(|collection| Ok((collection, files::entries(collection)?.len())))
+            .collect::<Result<Vec<_>, files::EditError>>()
+    })
+    .await
+    .map_err(ServerFnError::new)?
+    .map_err(ServerFnError::new)?;
+    crate::db::with_conn(move |conn| {
+        published
+            .into_iter()
+            .map(|(collection, entries)| {
+                Ok(CollectionOverview { collection, entries, drafts: drafts::count(conn, collection)? })
+            })
+            .collect::<Result<Vec<_>, crate::db::DbError>>()
+    })
+    .await
+    .map_err(ServerFnError::new)
+}
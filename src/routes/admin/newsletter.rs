@@ -0,0 +1,316 @@
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::components::A;
+use crate::components::form::{LabeledField, INPUT_STYLE};
+use crate::components::page_layout::PageLayout;
+use crate::components::stacks::hstack::{HStack, AlignItems as HAlign, JustifyContent as HJustify, FlexWrap};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::dates;
+use crate::i18n::Locale;
+use crate::newsletter::{DigestItem, NewsletterOverview};
+
+const SUCCESS_COLOR: &str = "#1f7a4d";
+const ERROR_COLOR: &str = "#b5431f";
+
+/// Recent news published since the last digest (or in this many days, for the first one) come preselected.
+#[cfg(feature = "ssr")]
+const NEWS_WINDOW_DAYS: i64 = 30;
+/// Events starting within this many days come preselected.
+#[cfg(feature = "ssr")]
+const EVENTS_WINDOW_DAYS: i64 = 45;
+/// How many news articles and upcoming events the composer offers.
+#[cfg(feature = "ssr")]
+const MAX_CANDIDATES: usize = 8;
+
+/// Boletim: inscritos, fila de envio e o editor do boletim com as notícias e eventos recentes.
+#[component]
+pub fn NewsletterAdminPage() -> impl IntoView {
+    let send = ServerAction::<SendDigest>::new();
+    let overview = Resource::new(move || send.version().get(), |_| get_newsletter_overview());
+
+    view! {
+        <Title text="Boletim | Associação Camará Capoeira"/>
+        <PageLayout title="Boletim" subtitle="Monte o boletim com as notícias e eventos recentes e envie aos inscritos.">
+            <VStack spacing="2rem".to_string() align=VAlign::Stretch style="max-width: 860px; margin: 0 auto;">
+                <A href="/admin" attr:style="color: #332175; font-weight: 600;">"← Painel"</A>
+                <Suspense fallback=move || view! { <p>"Carregando..."</p> }>
+                    {move || overview.get().map(|result| match result {
+                        Ok(overview) => view! { <DigestComposer overview=overview send=send /> }.into_any(),
+                        Err(_) => view! { <p>"Não foi possível carregar o boletim."</p> }.into_any(),
+                    })}
+                </Suspense>
+            </VStack>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn DigestComposer(overview: NewsletterOverview, send: ServerAction<SendDigest>) -> impl IntoView {
+    let primary_bg = "#332175";
+    let NewsletterOverview { active, pending, queued, failed, last_digest, news, events } = overview;
+
+    let subject = RwSignal::new(String::new());
+    let intro = RwSignal::new(String::new());
+    let picked = |items: &[(DigestItem, bool)]| items.iter().filter(|(_, on)| *on).map(|(i, _)| i.slug.clone()).collect::<Vec<_>>();
+    let news_selected = RwSignal::new(picked(&news));
+    let events_selected = RwSignal::new(picked(&events));
+
+    let on_send = move |_| {
+        let question = format!("Enviar o boletim para {} inscrito(s)?", active);
+        if window().confirm_with_message(&question).unwrap_or(false) {
+            send.dispatch(SendDigest {
+                subject: subject.get_untracked(),
+                intro: intro.get_untracked(),
+                news: news_selected.get_untracked(),
+                events: events_selected.get_untracked(),
+            });
+        }
+    };
+    let status = move || match send.value().get() {
+        _ if send.pending().get() => Some(("Colocando na fila de envio...".to_string(), "#666")),
+        Some(Ok(count)) => Some((format!("Boletim na fila de envio para {} inscrito(s).", count), SUCCESS_COLOR)),
+        Some(Err(ServerFnError::ServerError(message))) => Some((message, ERROR_COLOR)),
+        Some(Err(_)) => Some(("Não foi possível enviar o boletim.".to_string(), ERROR_COLOR)),
+        None => None,
+    };
+
+    view! {
+        <HStack wrap=FlexWrap::Wrap spacing="1rem".to_string()>
+            <Stat label="Inscritos ativos" value=active.to_string() />
+            <Stat label="Aguardando confirmação" value=pending.to_string() />
+            <Stat label="E-mails na fila" value=queued.to_string() />
+            {(failed > 0).then(|| view! { <Stat label="Envios que falharam" value=failed.to_string() /> })}
+        </HStack>
+        <p style="margin: 0; color: #666;">
+            {match last_digest {
+                Some(last) => format!(
+                    "Último boletim: \"{}\", em {}, para {} inscrito(s).",
+                    last.subject,
+                    dates::day_month_year(last.sent_at.date()),
+                    last.recipients
+                ),
+                None => "Nenhum boletim enviado ainda.".to_string(),
+            }}
+        </p>
+
+        <VStack
+            spacing="1.25rem".to_string()
+            align=VAlign::Stretch
+            style="background: white; padding: 2rem; border-radius: 12px; box-shadow: 0 4px 15px rgba(0,0,0,0.05);"
+        >
+            <LabeledField label="Assunto" required=true>
+                <input type="text" required style=INPUT_STYLE prop:value=subject on:input=move |ev| subject.set(event_target_value(&ev)) />
+            </LabeledField>
+            <LabeledField label="Apresentação" hint=Some("Parágrafos separados por uma linha em branco.")>
+                <textarea rows="5" style=INPUT_STYLE prop:value=intro on:input=move |ev| intro.set(event_target_value(&ev))></textarea>
+            </LabeledField>
+            <ItemPicker title="Notícias" empty="Nenhuma notícia publicada." items=news selected=news_selected />
+            <ItemPicker title="Agenda" empty="Nenhum evento futuro na agenda." items=events selected=events_selected />
+
+            <HStack justify=HJustify::SpaceBetween align=HAlign::Center wrap=FlexWrap::Wrap spacing="1rem".to_string()>
+                <span>{move || status().map(|(message, color)| view! { <span style=format!("color: {};", color)>{message}</span> })}</span>
+                <button
+                    on:click=on_send
+                    disabled=move || send.pending().get() || active == 0
+                    style=format!("padding: 0.8rem 1.6rem; border: none; border-radius: 8px; background-color: {}; color: white; font-weight: 700; font-size: 1rem; cursor: pointer;", primary_bg)
+                >
+                    "Enviar boletim"
+                </button>
+            </HStack>
+        </VStack>
+    }
+}
+
+#[component]
+fn Stat(label: &'static str, value: String) -> impl IntoView {
+    view! {
+        <VStack
+            spacing="0.2rem".to_string()
+            align=VAlign::FlexStart
+            style="flex: 1 1 160px; background: white; padding: 1rem 1.25rem; border-radius: 12px; box-shadow: 0 2px 8px rgba(0,0,0,0.04); border-top: 4px solid #f2e300;"
+        >
+            <span style="font-size: 1.6rem; font-weight: 700; color: #332175;">{value}</span>
+            <span style="font-size: 0.85rem; color: #666;">{label}</span>
+        </VStack>
+    }
+}
+
+#[component]
+fn ItemPicker(
+    title: &'static str,
+    empty: &'static str,
+    items: Vec<(DigestItem, bool)>,
+    selected: RwSignal<Vec<String>>,
+) -> impl IntoView {
+    let primary_bg = "#332175";
+
+    view! {
+        <VStack spacing="0.6rem".to_string() align=VAlign::Stretch>
+            <h3 style=format!("margin: 0; color: {}; font-size: 1.1rem;", primary_bg)>{title}</h3>
+            {if items.is_empty() {
+                view! { <p style="margin: 0; color: #666;">{empty}</p> }.into_any()
+            } else {
+                items.into_iter().map(|(item, _)| {
+                    let slug = item.slug.clone();
+                    let checked = {
+                        let slug = slug.clone();
+                        move || selected.with(|s| s.contains(&slug))
+                    };
+                    let toggle = move |ev| {
+                        let on = event_target_checked(&ev);
+                        selected.update(|s| {
+                            s.retain(|x| x != &slug);
+                            if on {
+                                s.push(slug.clone());
+                            }
+                        });
+                    };
+                    view! {
+                        <label style="display: flex; gap: 0.75rem; align-items: flex-start; cursor: pointer;">
+                            <input type="checkbox" prop:checked=checked on:change=toggle style="margin-top: 0.3rem;" />
+                            <span>
+                                <strong>{item.title}</strong>
+                                <span style="color: #666; font-size: 0.9rem;">{format!(" · {}", item.when)}</span>
+                                <br/>
+                                <span style="color: #555; font-size: 0.9rem;">{item.detail}</span>
+                            </span>
+                        </label>
+                    }
+                }).collect_view().into_any()
+            }}
+        </VStack>
+    }
+}
+
+#[cfg(feature = "ssr")]
+fn news_item(article: &crate::content::news::NewsArticle) -> DigestItem {
+    DigestItem {
+        slug: article.slug.clone(),
+        title: article.title.clone(),
+        when: article.date_label(Locale::PtBr),
+        detail: article.summary.clone(),
+        path: article.href(),
+    }
+}
+
+#[cfg(feature = "ssr")]
+fn event_item(event: &crate::content::events::Event) -> DigestItem {
+    DigestItem {
+        slug: event.slug.clone(),
+        title: event.title.clone(),
+        when: format!("{}, {}", event.date_label(Locale::PtBr), event.time_label(Locale::PtBr)),
+        detail: event.location.clone(),
+        path: format!("/agenda/{}", event.slug),
+    }
+}
+
+#[server(GetNewsletterOverview, "/api/get_newsletter_overview")]
+pub async fn get_newsletter_overview() -> Result<NewsletterOverview, ServerFnError> {
+    use chrono::Duration;
+    use crate::auth::{require_role, Role};
+    use crate::content::events::{load_events, now_local, Agenda};
+    use crate::content::news::load_news;
+    use crate::mail::outbox;
+    use crate::newsletter::{store, SubscriberStatus};
+
+    require_role(Role::Admin).await?;
+
+    let (active, pending, counts, last_digest) = crate::db::with_conn(|conn| {
+        Ok::<_, crate::db::DbError>((
+            store::count(conn, SubscriberStatus::Active)?,
+            store::count(conn, SubscriberStatus::Pending)?,
+            outbox::counts(conn)?,
+            store::last_digest(conn)?,
+        ))
+    })
+    .await
+    .map_err(ServerFnError::new)?;
+
+    let now = now_local();
+    let news_since = last_digest.as_ref().map(|d| d.sent_at.date()).unwrap_or(now.date() - Duration::days(NEWS_WINDOW_DAYS));
+    let news = load_news()
+        .map_err(ServerFnError::new)?
+        .iter()
+        .take(MAX_CANDIDATES)
+        .map(|article| (news_item(article), article.date >= news_since))
+        .collect();
+    let events = Agenda::split(load_events().map_err(ServerFnError::new)?, now)
+        .upcoming
+        .iter()
+        .take(MAX_CANDIDATES)
+        .map(|event| (event_item(event), event.start <= now + Duration::days(EVENTS_WINDOW_DAYS)))
+        .collect();
+
+    Ok(NewsletterOverview { active, pending, queued: counts.queued, failed: counts.failed, last_digest, news, events })
+}
+
+/// Queues one copy of the digest per active subscriber and returns how many.
+///
+/// Items are looked up again by slug, so the e-mail always carries the published text.
+#[server(SendDigest, "/api/send_digest")]
+pub async fn send_digest(
+    subject: String,
+    intro: String,
+    #[server(default)] news: Vec<String>,
+    #[server(default)] events: Vec<String>,
+) -> Result<usize, ServerFnError> {
+    use crate::audit::{Action, Fields, Recorder};
+    use crate::auth::{require_role, Role};
+    use crate::content::events::{load_events, now_local};
+    use crate::content::news::load_news;
+    use crate::mail::outbox;
+    use crate::newsletter::{emails, store, Digest};
+
+    let user = require_role(Role::Admin).await?;
+
+    let subject = subject.trim().to_string();
+    if subject.is_empty() {
+        return Err(ServerFnError::new("Informe o assunto do boletim."));
+    }
+    let news: Vec<DigestItem> = load_news()
+        .map_err(ServerFnError::new)?
+        .iter()
+        .filter(|a| news.contains(&a.slug))
+        .map(news_item)
+        .collect();
+    let events: Vec<DigestItem> = load_events()
+        .map_err(ServerFnError::new)?
+        .iter()
+        .filter(|e| events.contains(&e.slug))
+        .map(event_item)
+        .collect();
+    if intro.trim().is_empty() && news.is_empty() && events.is_empty() {
+        return Err(ServerFnError::new("Escreva uma apresentação ou escolha ao menos uma notícia ou evento."));
+    }
+    let digest = Digest { subject, intro: intro.trim().to_string(), news, events };
+
+    let config = crate::mail::config().map_err(ServerFnError::new)?;
+    let recorder = Recorder::new(Some(&user)).await;
+    let now = now_local();
+    let recipients = crate::db::transaction(move |tx| {
+        let subscribers = store::active_subscribers(tx)?;
+        if subscribers.is_empty() {
+            return Ok(0);
+        }
+        for (email, token) in &subscribers {
+            outbox::enqueue(tx, &emails::digest(&config, &digest, email, token), now)?;
+        }
+        store::record_digest(tx, &digest.subject, user.id, subscribers.len(), now)?;
+
+        let titles = |items: &[DigestItem]| items.iter().map(|i| i.title.as_str()).collect::<Vec<_>>().join("\n");
+        let after = Fields::from([
+            ("destinatarios".to_string(), subscribers.len().to_string()),
+            ("noticias".to_string(), titles(&digest.news)),
+            ("eventos".to_string(), titles(&digest.events)),
+        ]);
+        recorder.record(tx, Action::DigestSent, &digest.subject, &Fields::new(), &after)?;
+        Ok::<_, crate::db::DbError>(subscribers.len())
+    })
+    .await
+    .map_err(ServerFnError::new)?;
+
+    if recipients == 0 {
+        return Err(ServerFnError::new("Nenhum inscrito ativo para receber o boletim."));
+    }
+    Ok(recipients)
+}
@@ -0,0 +1,48 @@
+use chrono::Datelike;
+use leptos::prelude::*;
+use leptos_router::components::A;
+use crate::components::stacks::hstack::{HStack, AlignItems as HAlign};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::dates;
+use crate::content::events::Event;
+use crate::i18n::use_locale;
+
+/// Compact listing entry: date badge on the left, summary on the right.
+#[component]
+pub fn EventCard(event: Event) -> impl IntoView {
+    let primary_bg = "#332175";
+    let accent_color = "#f2e300";
+
+    let locale = use_locale();
+    let href = locale.href(&format!("/agenda/{}", event.slug));
+    let day = event.start.day();
+    let month = dates::month_abbr(event.start.month(), locale);
+
+    view! {
+        <A href=href attr:style="text-decoration: none; color: inherit;">
+            <HStack
+                align=HAlign::Center
+                spacing="1.5rem".to_string()
+                style="background: white; padding: 1.25rem 1.5rem; border-radius: 12px; box-shadow: 0 4px 15px rgba(0,0,0,0.05);"
+            >
+                <VStack
+                    align=VAlign::Center
+                    style=format!("min-width: 72px; padding: 0.6rem 0; border-radius: 10px; background-color: {}; color: white;", primary_bg)
+                >
+                    <span style="font-size: 1.8rem; font-weight: 800; line-height: 1;">{day}</span>
+                    <span style=format!("font-size: 0.8rem; font-weight: 700; letter-spacing: 0.1em; color: {};", accent_color)>{month}</span>
+                </VStack>
+
+                <VStack spacing="0.35rem".to_string() align=VAlign::FlexStart style="flex: 1;">
+                    <span style=format!("font-size: 0.75rem; font-weight: 700; text-transform: uppercase; letter-spacing: 0.05em; color: {};", primary_bg)>
+                        {event.kind.label(locale)}
+                    </span>
+                    <h3 style="margin: 0; font-size: 1.2rem; color: #222;">{event.title.clone()}</h3>
+                    <span style="font-size: 0.9rem; color: #666;">
+                        {event.date_label(locale)}" · "{event.time_label(locale)}" · "{event.location.clone()}
+                    </span>
+                </VStack>
+            </HStack>
+        </A>
+    }
+}
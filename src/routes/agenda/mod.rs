@@ -0,0 +1,174 @@
+pub mod event_card;
+
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::hooks::use_params_map;
+use leptos_router::components::A;
+use crate::components::page_layout::PageLayout;
+use crate::components::seo::{self, Seo};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::components::stacks::hstack::{HStack, AlignItems as HAlign, FlexWrap};
+use crate::content::events::{Agenda, Event, TIMEZONE_NAME};
+use crate::i18n::{self, use_catalog, use_locale};
+
+use self::event_card::EventCard;
+
+#[component]
+pub fn AgendaPage() -> impl IntoView {
+    let t = use_catalog();
+    let page = &t.pages.agenda;
+    let agenda = Resource::new(|| (), |_| get_agenda());
+
+    view! {
+        <Seo title=page.title description=page.subtitle/>
+        <PageLayout title=page.title subtitle=page.subtitle>
+            <VStack spacing="3rem".to_string() align=VAlign::Stretch>
+                <SubscribeBox />
+
+                <Suspense fallback=move || view! { <p>{t.agenda.loading}</p> }>
+                    {move || agenda.get().map(|result| match result {
+                        Ok(Agenda { upcoming, past }) => view! {
+                            <EventList title=t.agenda.upcoming empty=t.agenda.no_upcoming events=upcoming />
+                            <EventList title=t.agenda.past empty=t.agenda.no_past events=past />
+                        }.into_any(),
+                        Err(_) => view! { <p>{t.agenda.failed}</p> }.into_any(),
+                    })}
+                </Suspense>
+            </VStack>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn EventList(title: &'static str, empty: &'static str, events: Vec<Event>) -> impl IntoView {
+    let primary_bg = "#332175";
+
+    view! {
+        <VStack spacing="1.5rem".to_string() align=VAlign::Stretch>
+            <h2 style=format!("color: {}; font-size: 1.8rem; font-weight: 700; margin: 0;", primary_bg)>{title}</h2>
+            {if events.is_empty() {
+                view! { <p style="color: #666;">{empty}</p> }.into_any()
+            } else {
+                events
+                    .into_iter()
+                    .map(|event| view! { <EventCard event=event /> })
+                    .collect_view()
+                    .into_any()
+            }}
+        </VStack>
+    }
+}
+
+#[component]
+fn SubscribeBox() -> impl IntoView {
+    let primary_bg = "#332175";
+    let accent_color = "#f2e300";
+    let t = &use_catalog().agenda;
+
+    view! {
+        <div style=format!("background: white; padding: 1.5rem 2rem; border-radius: 12px; border-left: 5px solid {}; box-shadow: 0 4px 15px rgba(0,0,0,0.05);", accent_color)>
+            <HStack wrap=FlexWrap::Wrap spacing="1rem".to_string() align=HAlign::Center>
+                <p style="flex: 1; min-width: 260px; margin: 0; color: #444; line-height: 1.5;">
+                    {t.subscribe_lead}" "{t.timezone}" ("{TIMEZONE_NAME}")."
+                </p>
+                <a
+                    href="/agenda.ics"
+                    rel="external"
+                    style=format!("padding: 0.8rem 1.5rem; background-color: {}; color: white; font-weight: 700; text-decoration: none; border-radius: 50px;", primary_bg)
+                >
+                    {t.subscribe}
+                </a>
+            </HStack>
+        </div>
+    }
+}
+
+#[component]
+pub fn EventPage() -> impl IntoView {
+    let t = use_catalog();
+    let params = use_params_map();
+    let slug = move || params.read().get("slug").unwrap_or_default();
+    let event = Resource::new(slug, get_event);
+
+    view! {
+        <Suspense fallback=move || view! { <p>{t.agenda.loading_event}</p> }>
+            {move || event.get().map(|result| match result {
+                Ok(Some(event)) => view! { <EventDetail event=event /> }.into_any(),
+                Ok(None) => view! {
+                    <Title text=t.title(t.agenda.not_found)/>
+                    <PageLayout title=t.agenda.not_found>
+                        <A href=use_locale().href("/agenda")>{t.agenda.back}</A>
+                    </PageLayout>
+                }.into_any(),
+                Err(_) => view! {
+                    <PageLayout title=t.pages.agenda.title>
+                        <p>{t.agenda.event_failed}</p>
+                    </PageLayout>
+                }.into_any(),
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+fn EventDetail(event: Event) -> impl IntoView {
+    let primary_bg = "#332175";
+    let locale = use_locale();
+    let t = &locale.catalog().agenda;
+    let ics_href = format!("/agenda/{}.ics", event.slug);
+    let description = format!("{}, {}. {}", event.date_label(locale), event.time_label(locale), event.location);
+    let json_ld = seo::event(&i18n::site_origin(), &event);
+
+    view! {
+        <Seo title=event.title.clone() description=description image=Some(event.share_image()) json_ld=json_ld/>
+        <PageLayout title=event.title.clone() subtitle=event.kind.label(locale)>
+            <VStack spacing="1.5rem".to_string() align=VAlign::Stretch style="background: white; padding: 2.5rem; border-radius: 16px; box-shadow: 0 15px 35px -5px rgba(0,0,0,0.1);">
+                <dl style="display: grid; grid-template-columns: max-content 1fr; gap: 0.6rem 1.5rem; margin: 0;">
+                    <dt style="font-weight: 700; color: #333;">{t.date}</dt>
+                    <dd style="margin: 0;">{event.date_label(locale)}</dd>
+                    <dt style="font-weight: 700; color: #333;">{t.time}</dt>
+                    <dd style="margin: 0;">{event.time_label(locale)}" "{t.local_time}</dd>
+                    <dt style="font-weight: 700; color: #333;">{t.location}</dt>
+                    <dd style="margin: 0;">
+                        {event.location.clone()}
+                        {event.address.clone().map(|a| view! { <br/><span style="color: #666;">{a}</span> })}
+                    </dd>
+                </dl>
+
+                {event.description.split("\n\n").filter(|p| !p.trim().is_empty()).map(|p| view! {
+                    <p style="font-size: 1.05rem; color: #444; line-height: 1.7; margin: 0;">{p.trim().to_string()}</p>
+                }).collect_view()}
+
+                <HStack wrap=FlexWrap::Wrap spacing="1rem".to_string() align=HAlign::Center>
+                    <a
+                        href=ics_href
+                        rel="external"
+                        download=""
+                        style=format!("padding: 0.8rem 1.5rem; background-color: {}; color: white; font-weight: 700; text-decoration: none; border-radius: 50px;", primary_bg)
+                    >
+                        {t.add_to_calendar}
+                    </a>
+                    <A href=locale.href("/agenda") attr:style=format!("color: {}; font-weight: 600;", primary_bg)>
+                        {t.back}
+                    </A>
+                </HStack>
+            </VStack>
+        </PageLayout>
+    }
+}
+
+#[server(GetAgenda, "/api/get_agenda")]
+pub async fn get_agenda() -> Result<Agenda, ServerFnError> {
+    use crate::content::events::{load_events, now_local};
+
+    let events = load_events().map_err(ServerFnError::new)?;
+    Ok(Agenda::split(events, now_local()))
+}
+
+#[server(GetEvent, "/api/get_event")]
+pub async fn get_event(slug: String) -> Result<Option<Event>, ServerFnError> {
+    use crate::content::events::load_events;
+
+    let events = load_events().map_err(ServerFnError::new)?;
+    Ok(events.into_iter().find(|e| e.slug == slug))
+}
@@ -0,0 +1,127 @@
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::components::A;
+use leptos_router::hooks::use_params_map;
+use crate::components::page_layout::PageLayout;
+use crate::components::seo::Seo;
+use crate::components::stacks::hstack::{HStack, JustifyContent as HJustify, FlexWrap};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::albums::Album;
+use crate::i18n::{use_catalog, use_locale};
+
+/// Álbuns de fotos do acervo.
+#[component]
+pub fn AlbumsPage() -> impl IntoView {
+    let t = use_catalog();
+    let page = &t.pages.gallery;
+    let albums = Resource::new(|| (), |_| get_albums());
+
+    view! {
+        <Seo title=page.title description=page.subtitle/>
+        <PageLayout title=page.title subtitle=page.subtitle>
+            <Suspense fallback=move || view! { <p>"Carregando álbuns..."</p> }>
+                {move || albums.get().map(|result| match result {
+                    Ok(albums) if albums.is_empty() => view! {
+                        <p style="color: #666;">"Nenhum álbum publicado ainda."</p>
+                    }.into_any(),
+                    Ok(albums) => view! {
+                        <HStack wrap=FlexWrap::Wrap justify=HJustify::Center spacing="2rem".to_string()>
+                            {albums.into_iter().map(|album| view! { <AlbumCard album=album /> }).collect_view()}
+                        </HStack>
+                    }.into_any(),
+                    Err(_) => view! { <p>"Não foi possível carregar os álbuns."</p> }.into_any(),
+                })}
+            </Suspense>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn AlbumCard(album: Album) -> impl IntoView {
+    let primary_bg = "#332175";
+    let count = match album.photos.len() {
+        1 => "1 foto".to_string(),
+        n => format!("{} fotos", n),
+    };
+
+    view! {
+        <A href=use_locale().href(&album.href()) attr:style="text-decoration: none; color: inherit; width: 300px;">
+            <VStack spacing="0".to_string() align=VAlign::Stretch style="background: white; border-radius: 12px; overflow: hidden; box-shadow: 0 4px 15px rgba(0,0,0,0.05);">
+                {match album.cover() {
+                    Some(src) => view! { <img src=src.to_string() alt="" loading="lazy" style="width: 100%; aspect-ratio: 4 / 3; object-fit: cover;"/> }.into_any(),
+                    None => view! { <div style=format!("width: 100%; aspect-ratio: 4 / 3; background: {};", primary_bg)></div> }.into_any(),
+                }}
+                <VStack spacing="0.3rem".to_string() align=VAlign::FlexStart style="padding: 1rem 1.25rem;">
+                    <h2 style=format!("margin: 0; font-size: 1.1rem; color: {};", primary_bg)>{album.title.clone()}</h2>
+                    <span style="font-size: 0.85rem; color: #666;">{album.date_label(use_locale())}" · "{count}</span>
+                </VStack>
+            </VStack>
+        </A>
+    }
+}
+
+#[component]
+pub fn AlbumPage() -> impl IntoView {
+    let params = use_params_map();
+    let slug = move || params.read().get("slug").unwrap_or_default();
+    let album = Resource::new(slug, get_album);
+
+    view! {
+        <Suspense fallback=move || view! { <p>"Carregando álbum..."</p> }>
+            {move || album.get().map(|result| match result {
+                Ok(Some(album)) => view! { <AlbumDetail album=album /> }.into_any(),
+                Ok(None) => view! {
+                    <Title text="Álbum não encontrado | Associação Camará Capoeira"/>
+                    <PageLayout title="Álbum não encontrado">
+                        <A href=use_locale().href("/galeria")>"← Voltar para a galeria"</A>
+                    </PageLayout>
+                }.into_any(),
+                Err(_) => view! {
+                    <PageLayout title="Galeria">
+                        <p>"Não foi possível carregar o álbum."</p>
+                    </PageLayout>
+                }.into_any(),
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+fn AlbumDetail(album: Album) -> impl IntoView {
+    let primary_bg = "#332175";
+    let locale = use_locale();
+    let description = if album.description.is_empty() { album.date_label(locale) } else { album.description.clone() };
+
+    view! {
+        <Seo title=album.title.clone() description=description image=album.cover().map(str::to_string)/>
+        <PageLayout title=album.title.clone() subtitle=album.date_label(locale)>
+            <VStack spacing="2rem".to_string() align=VAlign::Stretch>
+                {(!album.description.is_empty()).then(|| view! {
+                    <p style="margin: 0 auto; max-width: 760px; color: #444; line-height: 1.7; font-size: 1.05rem;">{album.description.clone()}</p>
+                })}
+                <div style="display: grid; grid-template-columns: repeat(auto-fill, minmax(220px, 1fr)); gap: 1rem;">
+                    {album.photos.iter().map(|src| view! {
+                        <a href=src.clone() target="_blank" rel="external">
+                            <img src=src.clone() alt="" loading="lazy" style="width: 100%; aspect-ratio: 1; object-fit: cover; border-radius: 8px; display: block;"/>
+                        </a>
+                    }).collect_view()}
+                </div>
+                <A href=use_locale().href("/galeria") attr:style=format!("color: {}; font-weight: 600;", primary_bg)>"← Voltar para a galeria"</A>
+            </VStack>
+        </PageLayout>
+    }
+}
+
+#[server(GetAlbums, "/api/get_albums")]
+pub async fn get_albums() -> Result<Vec<Album>, ServerFnError> {
+    use crate::content::albums::load_albums;
+
+    load_albums().map_err(ServerFnError::new)
+}
+
+#[server(GetAlbum, "/api/get_album")]
+pub async fn get_album(slug: String) -> Result<Option<Album>, ServerFnError> {
+    use crate::content::albums::load_albums;
+
+    Ok(load_albums().map_err(ServerFnError::new)?.into_iter().find(|a| a.slug == slug))
+}
@@ -0,0 +1,179 @@
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::components::A;
+use leptos_router::hooks::use_params_map;
+use crate::attendance::{ClassAttendance, StudentAttendance};
+use crate::components::page_layout::PageLayout;
+use crate::components::stacks::hstack::{HStack, AlignItems as HAlign, JustifyContent as HJustify};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::dates;
+use crate::i18n::Locale;
+
+use super::{ABSENT_COLOR, PRESENT_COLOR};
+
+/// Frequência de um(a) aluno(a) em todas as turmas.
+#[component]
+pub fn StudentAttendancePage() -> impl IntoView {
+    let params = use_params_map();
+    let enrollment_id = move || params.read().get("id").and_then(|id| id.parse::<i64>().ok()).unwrap_or_default();
+    let history = Resource::new(enrollment_id, get_student_attendance);
+
+    view! {
+        <Title text="Frequência do(a) aluno(a) | Associação Camará Capoeira"/>
+        <PageLayout title="Frequência do(a) aluno(a)">
+            <Suspense fallback=move || view! { <p>"Carregando..."</p> }>
+                {move || history.get().map(|result| match result {
+                    Ok(Some(history)) => view! { <StudentHistory history=history /> }.into_any(),
+                    Ok(None) => view! { <p>"Aluno(a) não encontrado(a)."</p> }.into_any(),
+                    Err(_) => view! { <p>"Não foi possível carregar a frequência."</p> }.into_any(),
+                })}
+            </Suspense>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn StudentHistory(history: StudentAttendance) -> impl IntoView {
+    let primary_bg = "#332175";
+    let summary = format!(
+        "{} presenças em {} chamadas ({})",
+        history.presences(),
+        history.marks.len(),
+        history.rate_label()
+    );
+
+    view! {
+        <VStack spacing="1rem".to_string() align=VAlign::Stretch style="max-width: 720px; margin: 0 auto;">
+            <BackLink />
+            <h2 style=format!("margin: 0; color: {};", primary_bg)>{history.student_name}</h2>
+            <p style="margin: 0; color: #555;">{summary}</p>
+            {history.marks.into_iter().map(|mark| {
+                let (label, color) = if mark.present { ("Presente", PRESENT_COLOR) } else { ("Falta", ABSENT_COLOR) };
+                view! {
+                    <HistoryRow
+                        title=dates::day_month_year(mark.date)
+                        detail=mark.session_title
+                        value=label.to_string()
+                        color=color
+                    />
+                }
+            }).collect_view()}
+        </VStack>
+    }
+}
+
+/// Presentes e faltas de uma turma, encontro por encontro.
+#[component]
+pub fn ClassAttendancePage() -> impl IntoView {
+    let params = use_params_map();
+    let session_id = move || params.read().get("id").unwrap_or_default();
+    let history = Resource::new(session_id, get_class_attendance);
+
+    view! {
+        <Title text="Frequência da turma | Associação Camará Capoeira"/>
+        <PageLayout title="Frequência da turma">
+            <Suspense fallback=move || view! { <p>"Carregando..."</p> }>
+                {move || history.get().map(|result| match result {
+                    Ok(Some(history)) => view! { <ClassHistory history=history /> }.into_any(),
+                    Ok(None) => view! { <p>"Turma não encontrada."</p> }.into_any(),
+                    Err(_) => view! { <p>"Não foi possível carregar a frequência."</p> }.into_any(),
+                })}
+            </Suspense>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn ClassHistory(history: ClassAttendance) -> impl IntoView {
+    let primary_bg = "#332175";
+
+    view! {
+        <VStack spacing="1rem".to_string() align=VAlign::Stretch style="max-width: 720px; margin: 0 auto;">
+            <BackLink />
+            <h2 style=format!("margin: 0; color: {};", primary_bg)>{history.session_title}</h2>
+            {if history.days.is_empty() {
+                view! { <p style="color: #666;">"Nenhuma chamada registrada."</p> }.into_any()
+            } else {
+                history.days.into_iter().map(|day| view! {
+                    <HistoryRow
+                        title=dates::day_month_year(day.date)
+                        detail=format!("{} presentes, {} faltas", day.present, day.absent)
+                        value=day.rate_label()
+                        color=primary_bg
+                    />
+                }).collect_view().into_any()
+            }}
+        </VStack>
+    }
+}
+
+#[component]
+fn HistoryRow(title: String, detail: String, value: String, color: &'static str) -> impl IntoView {
+    view! {
+        <HStack
+            justify=HJustify::SpaceBetween
+            align=HAlign::Center
+            spacing="1rem".to_string()
+            style="background: white; padding: 0.8rem 1.2rem; border-radius: 10px; box-shadow: 0 2px 8px rgba(0,0,0,0.04);"
+        >
+            <VStack spacing="0.15rem".to_string() align=VAlign::FlexStart>
+                <span style="font-weight: 700; color: #222;">{title}</span>
+                <span style="font-size: 0.85rem; color: #666;">{detail}</span>
+            </VStack>
+            <span style=format!("font-weight: 800; color: {};", color)>{value}</span>
+        </HStack>
+    }
+}
+
+#[component]
+fn BackLink() -> impl IntoView {
+    view! {
+        <A href="/admin/frequencia" attr:style="color: #332175; font-weight: 600;">"← Chamada"</A>
+    }
+}
+
+#[server(GetStudentAttendance, "/api/get_student_attendance")]
+pub async fn get_student_attendance(enrollment_id: i64) -> Result<Option<StudentAttendance>, ServerFnError> {
+    use crate::attendance::{store, StudentMark};
+    use crate::content::schedule::load_schedule;
+
+    crate::auth::require_role(crate::auth::Role::Voluntario).await?;
+
+    let schedule = load_schedule().map_err(ServerFnError::new)?;
+    let found = crate::db::with_conn(move |conn| store::student_records(conn, enrollment_id))
+        .await
+        .map_err(ServerFnError::new)?;
+
+    Ok(found.map(|(student_name, records)| StudentAttendance {
+        student_name,
+        marks: records
+            .into_iter()
+            .map(|record| StudentMark {
+                session_title: schedule
+                    .session(&record.session_id)
+                    .map_or(record.session_id, |s| s.title(Locale::PtBr)),
+                date: record.date,
+                present: record.present,
+            })
+            .collect(),
+    }))
+}
+
+#[server(GetClassAttendance, "/api/get_class_attendance")]
+pub async fn get_class_attendance(session_id: String) -> Result<Option<ClassAttendance>, ServerFnError> {
+    use crate::attendance::{class_days, store};
+    use crate::content::schedule::load_schedule;
+
+    crate::auth::require_role(crate::auth::Role::Voluntario).await?;
+
+    let schedule = load_schedule().map_err(ServerFnError::new)?;
+    let Some(session) = schedule.session(&session_id) else {
+        return Ok(None);
+    };
+
+    let records = crate::db::with_conn(move |conn| store::class_records(conn, &session_id))
+        .await
+        .map_err(ServerFnError::new)?;
+
+    Ok(Some(ClassAttendance { session_title: session.title(Locale::PtBr), days: class_days(&records) }))
+}
@@ -0,0 +1,359 @@
+pub mod history;
+
+use chrono::NaiveDate;
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::components::A;
+use leptos_router::hooks::use_query_map;
+use crate::attendance::{Mark, MonthlyReport, Roll, RollEntry};
+use crate::components::page_layout::PageLayout;
+use crate::components::stacks::hstack::{HStack, AlignItems as HAlign, JustifyContent as HJustify, FlexWrap};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::dates;
+use crate::i18n::Locale;
+use crate::enrollment::SessionOption;
+
+const PRESENT_COLOR: &str = "#1f7a4d";
+const ABSENT_COLOR: &str = "#b5431f";
+
+/// Chamada das oficinas, pensada para o celular do(a) professor(a):
+/// `?turma=<id>&data=AAAA-MM-DD` abre a lista de alunos da turma.
+#[component]
+pub fn AttendancePage() -> impl IntoView {
+    let query = use_query_map();
+    let session_id = move || query.read().get("turma").filter(|t| !t.is_empty());
+    let date = move || query.read().get("data").and_then(|d| d.parse::<NaiveDate>().ok());
+    let month = move || query.read().get("mes");
+
+    let options = Resource::new(|| (), |_| get_attendance_options());
+    let roll = Resource::new(
+        move || (session_id(), date()),
+        |(session_id, date)| async move {
+            match session_id {
+                Some(session_id) => get_roll(session_id, date).await.map(Some),
+                None => Ok(None),
+            }
+        },
+    );
+    let report = Resource::new(month, get_monthly_report);
+
+    view! {
+        <Title text="Frequência | Associação Camará Capoeira"/>
+        <PageLayout title="Frequência" subtitle="Chamada das oficinas e relatórios mensais.">
+            <VStack spacing="3rem".to_string() align=VAlign::Stretch>
+                <Suspense fallback=move || view! { <p>"Carregando turmas..."</p> }>
+                    {move || options.get().map(|result| match result {
+                        Ok((sessions, today)) => view! {
+                            <RollPicker sessions=sessions selected=session_id() date=date().unwrap_or(today) />
+                        }.into_any(),
+                        Err(_) => view! { <p>"Não foi possível carregar as turmas."</p> }.into_any(),
+                    })}
+                </Suspense>
+
+                <Suspense fallback=move || view! { <p>"Carregando chamada..."</p> }>
+                    {move || roll.get().map(|result| match result {
+                        Ok(Some(roll)) => view! { <RollCall roll=roll /> }.into_any(),
+                        Ok(None) => ().into_any(),
+                        Err(_) => view! { <p>"Não foi possível carregar a chamada."</p> }.into_any(),
+                    })}
+                </Suspense>
+
+                <Suspense fallback=move || view! { <p>"Carregando relatório..."</p> }>
+                    {move || report.get().map(|result| match result {
+                        Ok(report) => view! { <MonthlyReportPanel report=report /> }.into_any(),
+                        Err(_) => view! { <p>"Não foi possível carregar o relatório mensal."</p> }.into_any(),
+                    })}
+                </Suspense>
+            </VStack>
+        </PageLayout>
+    }
+}
+
+/// Turma and date selection, as a plain GET form so it also works before hydration.
+#[component]
+fn RollPicker(sessions: Vec<SessionOption>, selected: Option<String>, date: NaiveDate) -> impl IntoView {
+    let primary_bg = "#332175";
+    let input_style = "flex: 1; min-width: 200px; padding: 0.8rem 1rem; border-radius: 8px; border: 1px solid #ccc; font-size: 1rem; font-family: inherit;";
+
+    view! {
+        <form method="get" action="/admin/frequencia" style="display: flex; gap: 0.75rem; flex-wrap: wrap; align-items: center;">
+            <select name="turma" required style=input_style>
+                <option value="">"Escolha a turma"</option>
+                {sessions.into_iter().map(|s| {
+                    let is_selected = selected.as_deref() == Some(s.id.as_str());
+                    view! { <option value=s.id selected=is_selected>{s.label}</option> }
+                }).collect_view()}
+            </select>
+            <input type="date" name="data" value=date.to_string() required style=input_style />
+            <button
+                type="submit"
+                style=format!("padding: 0.8rem 1.6rem; border: none; border-radius: 8px; background-color: {}; color: white; font-weight: 700; font-size: 1rem; cursor: pointer;", primary_bg)
+            >
+                "Abrir chamada"
+            </button>
+        </form>
+    }
+}
+
+#[component]
+fn RollCall(roll: Roll) -> impl IntoView {
+    let primary_bg = "#332175";
+    let Roll { session_id, session_title, date, entries } = roll;
+
+    let marks = RwSignal::new(entries.iter().map(|e| e.present).collect::<Vec<_>>());
+    let save = ServerAction::<SaveRoll>::new();
+    let ids: Vec<i64> = entries.iter().map(|e| e.enrollment_id).collect();
+    let class_href = format!("/admin/frequencia/turma/{}", session_id);
+
+    let on_save = move |_| {
+        let marks = ids
+            .iter()
+            .zip(marks.get_untracked())
+            .filter_map(|(id, present)| present.map(|present| Mark { enrollment_id: *id, present }))
+            .collect();
+        save.dispatch(SaveRoll { session_id: session_id.clone(), date, marks });
+    };
+    let all_present = move |_| marks.update(|marks| marks.iter_mut().for_each(|m| *m = Some(true)));
+    let marked = move || marks.with(|marks| marks.iter().filter(|m| m.is_some()).count());
+    let total = entries.len();
+
+    let status = move || match save.value().get() {
+        _ if save.pending().get() => Some(("Salvando...".to_string(), "#666")),
+        Some(Ok(saved)) => Some((format!("Chamada salva ({} marcações).", saved), PRESENT_COLOR)),
+        Some(Err(_)) => Some(("Não foi possível salvar a chamada. Tente novamente.".to_string(), ABSENT_COLOR)),
+        None => None,
+    };
+
+    view! {
+        <VStack spacing="1.25rem".to_string() align=VAlign::Stretch>
+            <HStack justify=HJustify::SpaceBetween align=HAlign::Center wrap=FlexWrap::Wrap spacing="1rem".to_string()>
+                <VStack spacing="0.25rem".to_string() align=VAlign::FlexStart>
+                    <h2 style=format!("margin: 0; color: {}; font-size: 1.4rem;", primary_bg)>{session_title}</h2>
+                    <span style="color: #666;">{dates::medium_date(date, Locale::PtBr)}</span>
+                </VStack>
+                <A href=class_href attr:style=format!("color: {}; font-weight: 600;", primary_bg)>"Histórico da turma"</A>
+            </HStack>
+
+            {if entries.is_empty() {
+                view! { <p style="color: #666;">"Nenhum(a) aluno(a) com matrícula aceita nesta turma."</p> }.into_any()
+            } else {
+                view! {
+                    <button
+                        on:click=all_present
+                        style=format!("align-self: flex-start; padding: 0.6rem 1.2rem; border: 2px solid {}; border-radius: 50px; background: white; color: {}; font-weight: 700; cursor: pointer;", PRESENT_COLOR, PRESENT_COLOR)
+                    >
+                        "Marcar todos presentes"
+                    </button>
+                    <VStack spacing="0.5rem".to_string() align=VAlign::Stretch>
+                        {entries.into_iter().enumerate().map(|(index, entry)| view! {
+                            <RollRow entry=entry index=index marks=marks />
+                        }).collect_view()}
+                    </VStack>
+                    <HStack align=HAlign::Center wrap=FlexWrap::Wrap spacing="1rem".to_string()>
+                        <button
+                            on:click=on_save
+                            disabled=move || save.pending().get()
+                            style=format!("padding: 0.9rem 2rem; border: none; border-radius: 50px; background-color: {}; color: white; font-weight: 700; font-size: 1.05rem; cursor: pointer;", primary_bg)
+                        >
+                            "Salvar chamada"
+                        </button>
+                        <span style="color: #666;">{move || format!("{} de {} marcados", marked(), total)}</span>
+                    </HStack>
+                    {move || status().map(|(message, color)| view! {
+                        <p style=format!("margin: 0; font-weight: 600; color: {};", color)>{message}</p>
+                    })}
+                }.into_any()
+            }}
+        </VStack>
+    }
+}
+
+/// One student with large presente/falta toggles, easy to hit on a phone.
+#[component]
+fn RollRow(entry: RollEntry, index: usize, marks: RwSignal<Vec<Option<bool>>>) -> impl IntoView {
+    let student_href = format!("/admin/frequencia/aluno/{}", entry.enrollment_id);
+    let current = move || marks.with(|marks| marks.get(index).copied().flatten());
+    let set = move |value: bool| marks.update(|marks| marks[index] = Some(value));
+
+    let toggle_style = move |value: bool, color: &str| {
+        let active = current() == Some(value);
+        format!(
+            "min-width: 3.2rem; min-height: 3.2rem; border-radius: 10px; border: 2px solid {color}; font-weight: 800; font-size: 1.1rem; cursor: pointer; background: {}; color: {};",
+            if active { color } else { "white" },
+            if active { "white" } else { color },
+        )
+    };
+
+    view! {
+        <HStack
+            justify=HJustify::SpaceBetween
+            align=HAlign::Center
+            spacing="0.75rem".to_string()
+            style="background: white; padding: 0.6rem 0.6rem 0.6rem 1rem; border-radius: 12px; box-shadow: 0 2px 8px rgba(0,0,0,0.04);"
+        >
+            <A href=student_href attr:style="flex: 1; color: #222; font-weight: 600; text-decoration: none;">{entry.student_name}</A>
+            <HStack spacing="0.5rem".to_string()>
+                <button title="Presente" on:click=move |_| set(true) style=move || toggle_style(true, PRESENT_COLOR)>"P"</button>
+                <button title="Falta" on:click=move |_| set(false) style=move || toggle_style(false, ABSENT_COLOR)>"F"</button>
+            </HStack>
+        </HStack>
+    }
+}
+
+#[component]
+fn MonthlyReportPanel(report: MonthlyReport) -> impl IntoView {
+    let primary_bg = "#332175";
+    let previous_href = format!("/admin/frequencia?mes={}", report.month.previous());
+    let links = [
+        ("Resumo (CSV)", report.summary_csv_href()),
+        ("Chamadas (CSV)", report.detail_csv_href()),
+        ("Planilha (ODS)", report.ods_href()),
+    ];
+    let cell = "padding: 0.7rem 1rem; border-bottom: 1px solid #eee; text-align: left;";
+
+    view! {
+        <VStack spacing="1rem".to_string() align=VAlign::Stretch>
+            <HStack justify=HJustify::SpaceBetween align=HAlign::Center wrap=FlexWrap::Wrap spacing="1rem".to_string()>
+                <h2 style=format!("margin: 0; color: {}; font-size: 1.4rem;", primary_bg)>
+                    "Relatório de "{report.month.label()}
+                </h2>
+                <A href=previous_href attr:style=format!("color: {}; font-weight: 600;", primary_bg)>"Mês anterior"</A>
+            </HStack>
+            <HStack wrap=FlexWrap::Wrap spacing="0.75rem".to_string()>
+                {links.into_iter().map(|(label, href)| view! {
+                    <a
+                        href=href
+                        rel="external"
+                        download=""
+                        style=format!("padding: 0.5rem 1.2rem; border: 2px solid {}; color: {}; font-weight: 700; text-decoration: none; border-radius: 50px; font-size: 0.85rem;", primary_bg, primary_bg)
+                    >
+                        {label}
+                    </a>
+                }).collect_view()}
+            </HStack>
+            {if report.rows.is_empty() {
+                view! { <p style="color: #666;">"Nenhuma chamada registrada neste mês."</p> }.into_any()
+            } else {
+                view! {
+                    <div style="overflow-x: auto; background: white; border-radius: 12px; box-shadow: 0 4px 15px rgba(0,0,0,0.05);">
+                        <table style="width: 100%; border-collapse: collapse; font-size: 0.95rem;">
+                            <thead>
+                                <tr style=format!("background-color: {}; color: white;", primary_bg)>
+                                    {["Modalidade", "Faixa etária", "Encontros", "Alunos", "Presenças", "Faltas", "Frequência"]
+                                        .into_iter()
+                                        .map(|h| view! { <th style=cell>{h}</th> })
+                                        .collect_view()}
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {report.rows.into_iter().map(|row| view! {
+                                    <tr>
+                                        <td style=cell>{row.modality.label(Locale::PtBr)}</td>
+                                        <td style=cell>{row.age_group.clone()}</td>
+                                        <td style=cell>{row.meetings}</td>
+                                        <td style=cell>{row.students}</td>
+                                        <td style=cell>{row.presences}</td>
+                                        <td style=cell>{row.absences}</td>
+                                        <td style=cell>{row.rate_label()}</td>
+                                    </tr>
+                                }).collect_view()}
+                            </tbody>
+                        </table>
+                    </div>
+                }.into_any()
+            }}
+        </VStack>
+    }
+}
+
+/// Turmas for the picker and today's date in the association's timezone.
+#[server(GetAttendanceOptions, "/api/get_attendance_options")]
+pub async fn get_attendance_options() -> Result<(Vec<SessionOption>, NaiveDate), ServerFnError> {
+    use crate::content::events::now_local;
+    use crate::routes::enrollment::get_enrollment_sessions;
+
+    crate::auth::require_role(crate::auth::Role::Voluntario).await?;
+
+    let options = get_enrollment_sessions()
+        .await?
+        .into_iter()
+        .map(|s| SessionOption { label: s.option_label(Locale::PtBr), id: s.id })
+        .collect();
+    Ok((options, now_local().date()))
+}
+
+#[server(GetRoll, "/api/get_roll")]
+pub async fn get_roll(session_id: String, date: Option<NaiveDate>) -> Result<Roll, ServerFnError> {
+    use crate::attendance::store;
+    use crate::content::events::now_local;
+    use crate::content::schedule::load_schedule;
+
+    crate::auth::require_role(crate::auth::Role::Voluntario).await?;
+
+    let schedule = load_schedule().map_err(ServerFnError::new)?;
+    let session = schedule
+        .session(&session_id)
+        .ok_or_else(|| ServerFnError::new("Turma não encontrada."))?;
+    let date = date.unwrap_or_else(|| now_local().date());
+
+    let id = session_id.clone();
+    let entries = crate::db::with_conn(move |conn| store::roster(conn, &id, date))
+        .await
+        .map_err(ServerFnError::new)?;
+
+    Ok(Roll { session_id, session_title: session.title(Locale::PtBr), date, entries })
+}
+
+#[server(SaveRoll, "/api/save_roll")]
+pub async fn save_roll(session_id: String, date: NaiveDate, marks: Vec<Mark>) -> Result<usize, ServerFnError> {
+    use crate::attendance::store;
+    use crate::audit::{Action, Recorder};
+    use crate::content::events::now_local;
+    use crate::content::schedule::load_schedule;
+
+    let user = crate::auth::require_role(crate::auth::Role::Voluntario).await?;
+
+    let schedule = load_schedule().map_err(ServerFnError::new)?;
+    let Some(session) = schedule.session(&session_id) else {
+        return Err(ServerFnError::new("Turma não encontrada."));
+    };
+    let target = format!("{}, {}", session.title(Locale::PtBr), dates::day_month_year(date));
+
+    let now = now_local();
+    let recorder = Recorder::new(Some(&user)).await;
+    crate::db::transaction(move |tx| {
+        let before = store::roll_snapshot(tx, &session_id, date)?;
+        let saved = store::save_roll(tx, &session_id, date, &marks, now)?;
+        let after = store::roll_snapshot(tx, &session_id, date)?;
+        recorder.record(tx, Action::RollSaved, &target, &before, &after)?;
+        Ok::<_, crate::db::DbError>(saved)
+    })
+    .await
+    .map_err(ServerFnError::new)
+}
+
+/// Summary of `month` (`AAAA-MM`), the current month by default.
+#[server(GetMonthlyReport, "/api/get_monthly_report")]
+pub async fn get_monthly_report(month: Option<String>) -> Result<MonthlyReport, ServerFnError> {
+    use crate::attendance::{store, summarize, Month};
+    use crate::content::events::now_local;
+    use crate::content::schedule::load_schedule;
+
+    crate::auth::require_role(crate::auth::Role::Voluntario).await?;
+
+    let month = month
+        .as_deref()
+        .and_then(Month::parse)
+        .unwrap_or_else(|| Month::of(now_local().date()));
+    let (from, until) = month
+        .first_day()
+        .zip(month.end())
+        .ok_or_else(|| ServerFnError::new("Mês inválido."))?;
+
+    let schedule = load_schedule().map_err(ServerFnError::new)?;
+    let records = crate::db::with_conn(move |conn| store::records_between(conn, from, until))
+        .await
+        .map_err(ServerFnError::new)?;
+
+    Ok(MonthlyReport { month, rows: summarize(&records, &schedule) })
+}
@@ -0,0 +1,218 @@
+use leptos::form::ActionForm;
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::components::{Outlet, Redirect};
+use leptos_router::hooks::{use_location, use_query_map};
+use crate::auth::{CurrentUser, Role};
+use crate::components::form::{LabeledField, INPUT_STYLE};
+use crate::components::page_layout::PageLayout;
+use crate::components::stacks::hstack::{HStack, AlignItems as HAlign, JustifyContent as HJustify, FlexWrap};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+
+/// Where a successful sign-in lands when no `?next=` is given.
+pub const DEFAULT_NEXT: &str = "/admin";
+
+/// Signed-in user and the actions that change it, shared through context by [`provide_auth`].
+#[derive(Clone, Copy)]
+pub struct Auth {
+    pub login: ServerAction<Login>,
+    pub logout: ServerAction<Logout>,
+    /// Reloaded after each sign-in or sign-out.
+    pub user: Resource<Result<Option<CurrentUser>, ServerFnError>>,
+}
+
+/// Called once in `App`; components read it back with [`use_auth`].
+pub fn provide_auth() {
+    let login = ServerAction::<Login>::new();
+    let logout = ServerAction::<Logout>::new();
+    let user = Resource::new(
+        move || (login.version().get(), logout.version().get()),
+        |_| get_current_user(),
+    );
+    provide_context(Auth { login, logout, user });
+}
+
+pub fn use_auth() -> Auth {
+    expect_context::<Auth>()
+}
+
+/// Only local paths are accepted as `?next=`, so the login form cannot send anyone off-site.
+#[cfg(feature = "ssr")]
+fn safe_next(next: Option<String>) -> String {
+    next.filter(|n| n.starts_with('/') && !n.starts_with("//") && !n.contains('\\'))
+        .unwrap_or_else(|| DEFAULT_NEXT.to_string())
+}
+
+/// Tela de acesso ao painel administrativo.
+#[component]
+pub fn LoginPage() -> impl IntoView {
+    let primary_bg = "#332175";
+    let auth = use_auth();
+    let query = use_query_map();
+    let next = move || query.read().get("next");
+
+    let error = move || auth.login.value().get().and_then(|result| result.err()).map(|e| match e {
+        ServerFnError::ServerError(message) => message,
+        _ => "Não foi possível entrar. Tente novamente.".to_string(),
+    });
+
+    view! {
+        <Title text="Entrar | Associação Camará Capoeira"/>
+        <PageLayout title="Entrar" subtitle="Acesso da coordenação, professores e voluntários.">
+            <ActionForm action=auth.login attr:style="max-width: 420px; margin: 0 auto;">
+                <VStack
+                    spacing="1.25rem".to_string()
+                    align=VAlign::Stretch
+                    style="background: white; padding: 2rem; border-radius: 12px; box-shadow: 0 4px 15px rgba(0,0,0,0.05);"
+                >
+                    <input type="hidden" name="next" value=move || next().unwrap_or_default() />
+                    <LabeledField label="E-mail">
+                        <input type="email" name="email" required autocomplete="username" style=INPUT_STYLE />
+                    </LabeledField>
+                    <LabeledField label="Senha">
+                        <input type="password" name="password" required autocomplete="current-password" style=INPUT_STYLE />
+                    </LabeledField>
+                    {move || error().map(|message| view! {
+                        <p style="margin: 0; color: #b5431f; font-weight: 600;">{message}</p>
+                    })}
+                    <button
+                        type="submit"
+                        disabled=move || auth.login.pending().get()
+                        style=format!("padding: 0.9rem 2rem; border: none; border-radius: 50px; background-color: {}; color: white; font-weight: 700; font-size: 1.05rem; cursor: pointer;", primary_bg)
+                    >
+                        {move || if auth.login.pending().get() { "Entrando..." } else { "Entrar" }}
+                    </button>
+                </VStack>
+            </ActionForm>
+        </PageLayout>
+    }
+}
+
+/// Envolve as rotas `/admin/*`: exige conta de voluntário(a) ou acima e mostra quem está conectado.
+///
+/// Each admin server function checks its own role as well; this only keeps
+/// visitors from landing on an empty panel. Voluntário(a) is also
+/// `admin::EDIT_ROLE`: everyone here can keep content drafts, only
+/// `admin::PUBLISH_ROLE` puts them on the site.
+#[component]
+pub fn AdminArea() -> impl IntoView {
+    let auth = use_auth();
+    let location = use_location();
+    let login_href = move || {
+        let mut here = location.pathname.get_untracked();
+        let search = location.search.get_untracked();
+        if !search.is_empty() {
+            here = format!("{here}?{search}");
+        }
+        format!("/entrar?next={}", form_urlencoded::byte_serialize(here.as_bytes()).collect::<String>())
+    };
+
+    view! {
+        <Suspense fallback=move || view! { <p style="padding: 2rem;">"Verificando acesso..."</p> }>
+            {move || Suspend::new(async move {
+                match auth.user.await {
+                    Ok(Some(user)) if user.has_role(Role::Voluntario) => view! {
+                        <AdminBar user=user />
+                        <Outlet />
+                    }.into_any(),
+                    Ok(Some(user)) => view! {
+                        <AdminBar user=user />
+                        <PageLayout title="Acesso restrito">
+                            <p>"Sua conta não tem acesso a esta área."</p>
+                        </PageLayout>
+                    }.into_any(),
+                    _ => view! { <Redirect path=login_href() /> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+fn AdminBar(user: CurrentUser) -> impl IntoView {
+    let auth = use_auth();
+
+    view! {
+        <HStack
+            justify=HJustify::FlexEnd
+            align=HAlign::Center
+            wrap=FlexWrap::Wrap
+            spacing="1rem".to_string()
+            style="background: #1d1242; color: white; padding: 0.4rem 2rem; font-size: 0.85rem;"
+        >
+            <span>{user.name}" · "{user.role.label()}</span>
+            <ActionForm action=auth.logout>
+                <button type="submit" style="background: none; border: none; color: #f2e300; font-weight: 700; cursor: pointer; padding: 0;">
+                    "Sair"
+                </button>
+            </ActionForm>
+        </HStack>
+    }
+}
+
+#[server(GetCurrentUser, "/api/get_current_user")]
+pub async fn get_current_user() -> Result<Option<CurrentUser>, ServerFnError> {
+    crate::auth::current_user().await
+}
+
+#[server(Login, "/api/login")]
+pub async fn login(email: String, password: String, next: Option<String>) -> Result<(), ServerFnError> {
+    use actix_session::Session;
+    use crate::audit::{Action, Fields, Recorder};
+    use crate::auth::{store, SESSION_USER_KEY};
+    use crate::content::events::now_local;
+
+    let now = now_local();
+    let target = email.trim().to_string();
+
+    // Too many recent failures for this e-mail or from this address: refuse
+    // before checking the password, and count this attempt as one more.
+    let recorder = Recorder::new(None).await;
+    let (throttle_email, ip) = (target.clone(), recorder.ip().map(str::to_string));
+    let throttled = crate::db::with_conn(move |conn| crate::auth::sign_in_throttled(conn, &throttle_email, ip.as_deref(), now))
+        .await
+        .map_err(ServerFnError::new)?;
+    if throttled {
+        let after = Fields::from([("motivo".to_string(), "muitas tentativas".to_string())]);
+        recorder.record_now(Action::SignInFailed, target, Fields::new(), after).await.map_err(ServerFnError::new)?;
+        if let Some(response) = use_context::<leptos_actix::ResponseOptions>() {
+            response.set_status(actix_web::http::StatusCode::TOO_MANY_REQUESTS);
+        }
+        return Err(ServerFnError::new("Muitas tentativas de acesso. Aguarde alguns minutos e tente de novo."));
+    }
+
+    let user = crate::db::with_conn(move |conn| store::authenticate(conn, &email, &password, now))
+        .await
+        .map_err(ServerFnError::new)?;
+
+    let (action, recorder) = match &user {
+        Some(user) => (Action::SignedIn, Recorder::new(Some(user)).await),
+        None => (Action::SignInFailed, recorder),
+    };
+    recorder.record_now(action, target, Fields::new(), Fields::new()).await.map_err(ServerFnError::new)?;
+    let user = user.ok_or_else(|| ServerFnError::new("E-mail ou senha incorretos."))?;
+
+    let session: Session = leptos_actix::extract().await?;
+    // A fresh session id on every sign-in, so a cookie planted beforehand is worthless.
+    session.renew();
+    session.insert(SESSION_USER_KEY, user.id).map_err(ServerFnError::new)?;
+    leptos_actix::redirect(&safe_next(next));
+    Ok(())
+}
+
+#[server(Logout, "/api/logout")]
+pub async fn logout() -> Result<(), ServerFnError> {
+    use crate::audit::{Action, Fields, Recorder};
+
+    if let Some(user) = crate::auth::current_user().await? {
+        Recorder::new(Some(&user))
+            .await
+            .record_now(Action::SignedOut, user.email.clone(), Fields::new(), Fields::new())
+            .await
+            .map_err(ServerFnError::new)?;
+    }
+    let session: actix_session::Session = leptos_actix::extract().await?;
+    session.purge();
+    leptos_actix::redirect("/");
+    Ok(())
+}
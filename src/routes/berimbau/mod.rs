@@ -0,0 +1,21 @@
+pub mod toque_trainer;
+
+use leptos::prelude::*;
+use crate::components::page_layout::PageLayout;
+use crate::components::seo::Seo;
+use crate::i18n::use_catalog;
+
+use self::toque_trainer::ToqueTrainer;
+
+/// Treinador de toques de berimbau, tocado no próprio navegador.
+#[component]
+pub fn BerimbauPage() -> impl IntoView {
+    let t = use_catalog();
+    let page = &t.pages.berimbau;
+    view! {
+        <Seo title=page.title description=page.subtitle/>
+        <PageLayout title=page.title subtitle=page.subtitle>
+            <ToqueTrainer />
+        </PageLayout>
+    }
+}
@@ -0,0 +1,175 @@
+use leptos::prelude::*;
+use crate::berimbau::{Stroke, Toque, TOQUES};
+use crate::components::stacks::hstack::{HStack, AlignItems as HAlign, JustifyContent as HJustify, FlexWrap};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+
+/// Picks a toque, plays it in a loop and lights up the step being heard.
+///
+/// Server-rendered as a static timeline; sound and highlighting start after
+/// hydration, since both live in the browser (`berimbau::audio`).
+#[component]
+pub fn ToqueTrainer() -> impl IntoView {
+    let primary_bg = "#332175";
+    let accent_color = "#f2e300";
+
+    let selected = RwSignal::new(&TOQUES[0]);
+    let bpm = RwSignal::new(TOQUES[0].tempo.1 as f64);
+    let playing = RwSignal::new(false);
+    let active_step = RwSignal::new(None::<usize>);
+
+    #[cfg(feature = "hydrate")]
+    {
+        let player = StoredValue::new_local(crate::berimbau::audio::Player::default());
+
+        Effect::new(move |_| {
+            let toque = selected.get();
+            if playing.get() {
+                let on_step = move |step| active_step.set(step);
+                let started = player.try_update_value(|p| p.start(toque, bpm.get_untracked(), on_step));
+                if !matches!(started, Some(Ok(()))) {
+                    playing.set(false);
+                }
+            } else {
+                player.try_update_value(|p| p.stop());
+                active_step.set(None);
+            }
+        });
+        Effect::new(move |_| {
+            let bpm = bpm.get();
+            player.try_update_value(|p| p.set_bpm(bpm));
+        });
+        on_cleanup(move || {
+            player.try_update_value(|p| p.stop());
+        });
+    }
+
+    let choose = move |toque: &'static Toque| {
+        active_step.set(None);
+        bpm.set(toque.tempo.1 as f64);
+        selected.set(toque);
+    };
+
+    view! {
+        <VStack spacing="2rem".to_string() align=VAlign::Stretch style="max-width: 860px; margin: 0 auto;">
+            <HStack wrap=FlexWrap::Wrap justify=HJustify::Center spacing="0.6rem".to_string()>
+                {TOQUES.iter().map(|toque| {
+                    let is_selected = move || std::ptr::eq(selected.get(), toque);
+                    view! {
+                        <button
+                            on:click=move |_| choose(toque)
+                            style=move || {
+                                let (bg, fg) = if is_selected() { (primary_bg, "white") } else { ("white", primary_bg) };
+                                format!("padding: 0.5rem 1.2rem; border-radius: 50px; border: 2px solid {}; background-color: {}; color: {}; font-weight: 700; font-size: 0.95rem; cursor: pointer;", primary_bg, bg, fg)
+                            }
+                        >
+                            {toque.name}
+                        </button>
+                    }
+                }).collect_view()}
+            </HStack>
+
+            <VStack
+                spacing="1.5rem".to_string()
+                align=VAlign::Stretch
+                style="background: white; padding: 2rem; border-radius: 16px; box-shadow: 0 15px 35px -5px rgba(0,0,0,0.1);"
+            >
+                <VStack spacing="0.4rem".to_string() align=VAlign::FlexStart>
+                    <h2 style=format!("margin: 0; color: {}; font-size: 1.6rem;", primary_bg)>{move || selected.get().name}</h2>
+                    <p style="margin: 0; color: #555; line-height: 1.6;">{move || selected.get().description}</p>
+                </VStack>
+
+                {move || view! { <Timeline toque=selected.get() active=active_step /> }}
+
+                <HStack wrap=FlexWrap::Wrap spacing="1.5rem".to_string() align=HAlign::Center>
+                    <button
+                        on:click=move |_| playing.update(|on| *on = !*on)
+                        style=format!("padding: 0.8rem 1.8rem; border: none; border-radius: 50px; background-color: {}; color: {}; font-weight: 800; font-size: 1rem; cursor: pointer; min-width: 9rem;", accent_color, primary_bg)
+                    >
+                        {move || if playing.get() { "■ Parar" } else { "▶ Tocar" }}
+                    </button>
+                    <label style="flex: 1; min-width: 240px; display: flex; align-items: center; gap: 1rem; color: #444; font-weight: 600;">
+                        "Andamento"
+                        <input
+                            type="range"
+                            step="1"
+                            min=move || selected.get().tempo.0
+                            max=move || selected.get().tempo.2
+                            value=move || bpm.get()
+                            prop:value=move || bpm.get()
+                            on:input=move |ev| {
+                                if let Ok(value) = event_target_value(&ev).parse::<f64>() {
+                                    bpm.set(value);
+                                }
+                            }
+                            style="flex: 1;"
+                        />
+                        <span style="min-width: 5.5rem; text-align: right;">{move || format!("{} bpm", bpm.get())}</span>
+                    </label>
+                </HStack>
+            </VStack>
+
+            <Legend />
+        </VStack>
+    }
+}
+
+/// Colors of a stroke on the timeline: background, text.
+fn stroke_colors(stroke: Stroke) -> (&'static str, &'static str) {
+    match stroke {
+        Stroke::Dong => ("#332175", "white"),
+        Stroke::Ding => ("#f2e300", "#332175"),
+        Stroke::Chiado => ("#d9d6e8", "#332175"),
+        Stroke::Rest => ("transparent", "#999"),
+    }
+}
+
+/// One cycle of the toque, each step as wide as it lasts.
+#[component]
+fn Timeline(toque: &'static Toque, active: RwSignal<Option<usize>>) -> impl IntoView {
+    view! {
+        <div style="display: flex; gap: 4px; width: 100%;" role="img" aria-label=format!("Toque de {}", toque.name)>
+            {toque.steps.iter().enumerate().map(|(index, step)| {
+                let (bg, fg) = stroke_colors(step.stroke);
+                let border = if step.stroke == Stroke::Rest { "2px dashed #ccc" } else { "2px solid transparent" };
+                view! {
+                    <div style=move || {
+                        let lit = active.get() == Some(index);
+                        format!(
+                            "flex: {} 1 0; min-width: 0; padding: 1.1rem 0; text-align: center; border-radius: 8px; background: {}; color: {}; border: {}; font-weight: 800; font-size: 1rem; transition: transform 0.08s, box-shadow 0.08s; {}",
+                            step.length,
+                            bg,
+                            fg,
+                            border,
+                            if lit { "transform: translateY(-4px); box-shadow: 0 6px 14px rgba(51,33,117,0.35);" } else { "" },
+                        )
+                    }>
+                        {step.stroke.syllable()}
+                    </div>
+                }
+            }).collect_view()}
+        </div>
+    }
+}
+
+#[component]
+fn Legend() -> impl IntoView {
+    let item = |stroke: Stroke, text: &'static str| {
+        let (bg, fg) = stroke_colors(stroke);
+        view! {
+            <HStack spacing="0.6rem".to_string() align=HAlign::Center>
+                <span style=format!("display: inline-block; min-width: 2.8rem; padding: 0.2rem 0; text-align: center; border-radius: 6px; background: {}; color: {}; font-weight: 800;", bg, fg)>
+                    {stroke.syllable()}
+                </span>
+                <span style="color: #555;">{text}</span>
+            </HStack>
+        }
+    };
+
+    view! {
+        <HStack wrap=FlexWrap::Wrap justify=HJustify::Center spacing="1.5rem".to_string()>
+            {item(Stroke::Dong, "corda solta")}
+            {item(Stroke::Ding, "dobrão apertando a corda")}
+            {item(Stroke::Chiado, "dobrão encostado, sem apertar")}
+        </HStack>
+    }
+}
@@ -0,0 +1,321 @@
+use leptos::prelude::*;
+use leptos_meta::{Style, Title};
+use leptos_router::components::A;
+use leptos_router::hooks::{use_params_map, use_query_map};
+use crate::components::form::INPUT_STYLE;
+use crate::components::page_layout::PageLayout;
+use crate::components::seo::Seo;
+use crate::components::stacks::hstack::{HStack, AlignItems as HAlign, JustifyContent as HJustify, FlexWrap};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::cantigas::{Song, SongKind, Voice};
+use crate::i18n::{use_catalog, use_locale};
+
+/// Filters shared by the library and the songbook: `?tipo=corrido&busca=paranaue`.
+fn filter_query(kind: Option<SongKind>, search: &str) -> String {
+    let mut query = form_urlencoded::Serializer::new(String::new());
+    if let Some(kind) = kind {
+        query.append_pair("tipo", kind.as_str());
+    }
+    if !search.trim().is_empty() {
+        query.append_pair("busca", search.trim());
+    }
+    match query.finish() {
+        query if query.is_empty() => String::new(),
+        query => format!("?{}", query),
+    }
+}
+
+/// Cantigas da roda: ladainhas, quadras, chulas e corridos, com busca na letra.
+#[component]
+pub fn CantigasPage() -> impl IntoView {
+    let t = use_catalog();
+    let page = &t.pages.songs;
+    let query = use_query_map();
+    let kind = move || query.read().get("tipo").and_then(|k| SongKind::parse(&k));
+    let search = move || query.read().get("busca").unwrap_or_default();
+    let songs = Resource::new(move || (kind(), search()), |(kind, search)| get_songs(kind, search));
+
+    view! {
+        <Seo title=page.title description=page.subtitle/>
+        <PageLayout title=page.title subtitle=page.subtitle>
+            <VStack spacing="2rem".to_string() align=VAlign::Stretch style="max-width: 860px; margin: 0 auto;">
+                {move || view! { <KindFilter selected=kind() search=search() /> }}
+                {move || view! { <SearchForm kind=kind() search=search() /> }}
+                <Suspense fallback=move || view! { <p>{t.songs.loading}</p> }>
+                    {move || songs.get().map(|result| match result {
+                        Ok(songs) if songs.is_empty() => view! {
+                            <p style="color: #666;">{t.songs.none_found}</p>
+                        }.into_any(),
+                        Ok(songs) => view! {
+                            <VStack spacing="1rem".to_string() align=VAlign::Stretch>
+                                {songs.into_iter().map(|song| view! { <SongCard song=song /> }).collect_view()}
+                            </VStack>
+                        }.into_any(),
+                        Err(_) => view! { <p>{t.songs.failed}</p> }.into_any(),
+                    })}
+                </Suspense>
+            </VStack>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn KindFilter(selected: Option<SongKind>, search: String) -> impl IntoView {
+    let primary_bg = "#332175";
+    let locale = use_locale();
+
+    let chip = move |label: &'static str, kind: Option<SongKind>| {
+        let (bg, fg) = if selected == kind { (primary_bg, "white") } else { ("white", primary_bg) };
+        view! {
+            <A
+                href=locale.href(&format!("/cantigas{}", filter_query(kind, &search)))
+                attr:style=format!("padding: 0.4rem 1.1rem; border-radius: 50px; border: 2px solid {}; background-color: {}; color: {}; font-weight: 700; text-decoration: none; font-size: 0.9rem;", primary_bg, bg, fg)
+            >
+                {label}
+            </A>
+        }
+    };
+
+    view! {
+        <HStack wrap=FlexWrap::Wrap justify=HJustify::Center spacing="0.6rem".to_string()>
+            {chip(locale.catalog().songs.all, None)}
+            {SongKind::ALL.into_iter().map(|kind| chip(kind.plural(), Some(kind))).collect_view()}
+        </HStack>
+    }
+}
+
+/// Plain GET form, so the search works before hydration and the result can be shared.
+#[component]
+fn SearchForm(kind: Option<SongKind>, search: String) -> impl IntoView {
+    let primary_bg = "#332175";
+    let locale = use_locale();
+    let t = &locale.catalog().songs;
+    let songbook_href = locale.href(&format!("/cantigas/caderno{}", filter_query(kind, &search)));
+
+    view! {
+        <form method="get" action=locale.href("/cantigas") style="display: flex; gap: 0.75rem; flex-wrap: wrap; align-items: center;">
+            {kind.map(|kind| view! { <input type="hidden" name="tipo" value=kind.as_str() /> })}
+            <input
+                type="search"
+                name="busca"
+                placeholder=t.search_placeholder
+                aria-label=t.search_label
+                value=search
+                style=format!("{} flex: 1 1 260px; width: auto;", INPUT_STYLE)
+            />
+            <button
+                type="submit"
+                style=format!("padding: 0.8rem 1.6rem; border: none; border-radius: 8px; background-color: {}; color: white; font-weight: 700; font-size: 1rem; cursor: pointer;", primary_bg)
+            >
+                {t.search}
+            </button>
+            <a href=songbook_href rel="external" style=format!("color: {}; font-weight: 600;", primary_bg)>{t.songbook_link}</a>
+        </form>
+    }
+}
+
+#[component]
+fn SongCard(song: Song) -> impl IntoView {
+    let primary_bg = "#332175";
+    let accent_color = "#f2e300";
+    let first_line = song.first_line();
+    let credit = song.credit();
+    let has_audio = song.audio.is_some();
+    let locale = use_locale();
+
+    view! {
+        <A href=locale.href(&song.href()) attr:style="text-decoration: none; color: inherit;">
+            <HStack
+                spacing="1rem".to_string()
+                align=HAlign::Center
+                style=format!("background: white; padding: 1rem 1.5rem; border-radius: 12px; box-shadow: 0 2px 8px rgba(0,0,0,0.04); border-left: 5px solid {};", accent_color)
+            >
+                <VStack spacing="0.25rem".to_string() align=VAlign::FlexStart style="flex: 1;">
+                    <span style=format!("font-weight: 700; font-size: 1.1rem; color: {};", primary_bg)>{song.title.clone()}</span>
+                    <span style="color: #555; font-style: italic;">{first_line}</span>
+                    {credit.map(|credit| view! { <span style="color: #888; font-size: 0.85rem;">{credit}</span> })}
+                </VStack>
+                {has_audio.then(|| view! {
+                    <span title=locale.catalog().songs.has_recording style="font-size: 1.2rem;">"♪"</span>
+                })}
+                <span style="padding: 0.2rem 0.7rem; border-radius: 50px; background: #f4f4f9; color: #555; font-size: 0.8rem; font-weight: 700; text-transform: uppercase;">
+                    {song.kind.label()}
+                </span>
+            </HStack>
+        </A>
+    }
+}
+
+/// Letra, autoria e gravação de uma cantiga.
+#[component]
+pub fn CantigaPage() -> impl IntoView {
+    let t = use_catalog();
+    let params = use_params_map();
+    let slug = move || params.read().get("slug").unwrap_or_default();
+    let song = Resource::new(slug, get_song);
+
+    view! {
+        <Suspense fallback=move || view! { <p>{t.songs.loading_song}</p> }>
+            {move || song.get().map(|result| match result {
+                Ok(Some(song)) => view! { <SongDetail song=song /> }.into_any(),
+                Ok(None) => view! {
+                    <Title text=t.title(t.songs.not_found)/>
+                    <PageLayout title=t.songs.not_found>
+                        <A href=use_locale().href("/cantigas")>{t.songs.back}</A>
+                    </PageLayout>
+                }.into_any(),
+                Err(_) => view! {
+                    <PageLayout title=t.pages.songs.title>
+                        <p>{t.songs.song_failed}</p>
+                    </PageLayout>
+                }.into_any(),
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+fn SongDetail(song: Song) -> impl IntoView {
+    let primary_bg = "#332175";
+    let locale = use_locale();
+    let catalog = locale.catalog();
+    let t = &catalog.songs;
+    let description = match song.credit() {
+        Some(credit) => format!("{} · {}", song.kind.label(), credit),
+        None => song.kind.label().to_string(),
+    };
+
+    view! {
+        <Seo title=format!("{} | {}", song.title, catalog.pages.songs.title) description=description/>
+        <PageLayout title=song.title.clone() subtitle=song.kind.label()>
+            <VStack spacing="1.5rem".to_string() align=VAlign::Stretch style="max-width: 760px; margin: 0 auto; background: white; padding: 2.5rem; border-radius: 16px; box-shadow: 0 15px 35px -5px rgba(0,0,0,0.1);">
+                {song.credit().map(|credit| view! { <p style="margin: 0; color: #666;">{credit}</p> })}
+                {song.audio.clone().map(|src| view! {
+                    <audio controls preload="none" src=src style="width: 100%;">
+                        {t.no_audio}
+                    </audio>
+                })}
+                <Lyrics song=song.clone() />
+                <p style="margin: 0; font-size: 0.85rem; color: #888;">{t.coro_hint}</p>
+                {song.notes.clone().map(|notes| view! {
+                    <p style="margin: 0; padding: 1rem 1.25rem; background: #f4f4f9; border-radius: 8px; color: #444; line-height: 1.6;">{notes}</p>
+                })}
+                <A href=locale.href("/cantigas") attr:style=format!("color: {}; font-weight: 600;", primary_bg)>{t.back}</A>
+            </VStack>
+        </PageLayout>
+    }
+}
+
+/// Stanzas with the soloist's lines plain and the coro's in bold, indented.
+#[component]
+fn Lyrics(song: Song) -> impl IntoView {
+    let primary_bg = "#332175";
+
+    view! {
+        <div style="display: flex; flex-direction: column; gap: 1.25rem; font-size: 1.1rem; line-height: 1.6;">
+            {song.stanzas().into_iter().map(|stanza| view! {
+                <p style="margin: 0;">
+                    {stanza.into_iter().map(|line| match line.voice {
+                        Voice::Solo => view! { <span style="display: block; color: #333;">{line.text}</span> }.into_any(),
+                        Voice::Coro => view! {
+                            <strong style=format!("display: block; padding-left: 1.5rem; color: {};", primary_bg)>{line.text}</strong>
+                        }.into_any(),
+                    }).collect_view()}
+                </p>
+            }).collect_view()}
+        </div>
+    }
+}
+
+/// Caderno de cantigas para imprimir, com os mesmos filtros da biblioteca.
+///
+/// Rendered without the site header and footer; the print stylesheet hides
+/// the toolbar and keeps each song on one page when it fits.
+#[component]
+pub fn SongbookPage() -> impl IntoView {
+    let primary_bg = "#332175";
+    let locale = use_locale();
+    let catalog = locale.catalog();
+    let t = &catalog.songs;
+    let query = use_query_map();
+    let kind = move || query.read().get("tipo").and_then(|k| SongKind::parse(&k));
+    let search = move || query.read().get("busca").unwrap_or_default();
+    let songs = Resource::new(move || (kind(), search()), |(kind, search)| get_songs(kind, search));
+
+    view! {
+        <Title text=catalog.title(t.songbook)/>
+        <Style>
+            ".songbook-song { break-inside: avoid; page-break-inside: avoid; }
+            @media print {
+                .songbook-toolbar { display: none !important; }
+                .songbook { padding: 0 !important; }
+            }
+            @page { margin: 1.5cm; }"
+        </Style>
+        <div class="songbook" style="max-width: 760px; margin: 0 auto; padding: 2rem; color: #222; font-family: Georgia, serif;">
+            <div class="songbook-toolbar" style="display: flex; justify-content: space-between; align-items: center; gap: 1rem; margin-bottom: 2rem; font-family: sans-serif;">
+                <a href=move || locale.href(&format!("/cantigas{}", filter_query(kind(), &search()))) style=format!("color: {}; font-weight: 600;", primary_bg)>
+                    {t.back}
+                </a>
+                <PrintButton />
+            </div>
+            <header style=format!("border-bottom: 3px solid {}; margin-bottom: 2rem;", primary_bg)>
+                <h1 style=format!("margin: 0 0 0.25rem; color: {};", primary_bg)>{t.songbook}</h1>
+                <p style="margin: 0 0 1rem; color: #555;">{t.songbook_lead}</p>
+            </header>
+            <Suspense fallback=move || view! { <p>{t.loading}</p> }>
+                {move || songs.get().map(|result| match result {
+                    Ok(songs) if songs.is_empty() => view! { <p>{t.none_found}</p> }.into_any(),
+                    Ok(songs) => SongKind::ALL.into_iter().filter_map(|kind| {
+                        let of_kind: Vec<Song> = songs.iter().filter(|s| s.kind == kind).cloned().collect();
+                        (!of_kind.is_empty()).then(|| view! {
+                            <section>
+                                <h2 style=format!("color: {}; margin: 2rem 0 1rem;", primary_bg)>{kind.plural()}</h2>
+                                {of_kind.into_iter().map(|song| view! {
+                                    <article class="songbook-song" style="margin-bottom: 2rem;">
+                                        <h3 style="margin: 0 0 0.25rem;">{song.title.clone()}</h3>
+                                        {song.credit().map(|credit| view! { <p style="margin: 0 0 0.75rem; color: #666; font-size: 0.9rem;">{credit}</p> })}
+                                        <Lyrics song=song />
+                                    </article>
+                                }).collect_view()}
+                            </section>
+                        })
+                    }).collect_view().into_any(),
+                    Err(_) => view! { <p>{t.failed}</p> }.into_any(),
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+#[component]
+fn PrintButton() -> impl IntoView {
+    let primary_bg = "#332175";
+
+    view! {
+        <button
+            on:click=move |_| {
+                let _ = window().print();
+            }
+            style=format!("padding: 0.7rem 1.4rem; border: none; border-radius: 8px; background-color: {}; color: white; font-weight: 700; font-size: 1rem; cursor: pointer;", primary_bg)
+        >
+            {use_catalog().songs.print}
+        </button>
+    }
+}
+
+#[server(GetSongs, "/api/get_songs")]
+pub async fn get_songs(kind: Option<SongKind>, search: String) -> Result<Vec<Song>, ServerFnError> {
+    use crate::content::cantigas::{load_songs, search as search_songs};
+
+    let songs = load_songs().map_err(ServerFnError::new)?;
+    Ok(search_songs(songs, kind, &search))
+}
+
+#[server(GetSong, "/api/get_song")]
+pub async fn get_song(slug: String) -> Result<Option<Song>, ServerFnError> {
+    use crate::content::cantigas::load_songs;
+
+    let songs = load_songs().map_err(ServerFnError::new)?;
+    Ok(songs.into_iter().find(|s| s.slug == slug))
+}
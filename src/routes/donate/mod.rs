@@ -0,0 +1,185 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::use_query_map;
+use crate::components::page_layout::PageLayout;
+use crate::components::seo::Seo;
+use crate::components::stacks::hstack::{HStack, AlignItems as HAlign, JustifyContent as HJustify, FlexWrap};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::donations::{Donation, PixCharge};
+use crate::content::money::Money;
+use crate::i18n::{use_catalog, use_locale};
+
+/// Doações via PIX, com valor escolhido em `?valor=`.
+#[component]
+pub fn DonatePage() -> impl IntoView {
+    let t = use_catalog();
+    let page = &t.pages.donate;
+    let query = use_query_map();
+    let amount = move || query.read().get("valor").and_then(|v| Money::parse_reais(&v));
+    let donation = Resource::new(amount, get_donation);
+
+    view! {
+        <Seo title=page.title description=page.subtitle/>
+        <PageLayout title=page.title subtitle=page.subtitle>
+            <Suspense fallback=move || view! { <p>{t.donate.loading}</p> }>
+                {move || donation.get().map(|result| match result {
+                    Ok(donation) => view! { <DonationPanel donation=donation /> }.into_any(),
+                    Err(_) => view! { <p>{t.donate.failed}</p> }.into_any(),
+                })}
+            </Suspense>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn DonationPanel(donation: Donation) -> impl IntoView {
+    let Donation { suggested, amount, charge } = donation;
+    let t = &use_catalog().donate;
+
+    let Some(charge) = charge else {
+        return view! {
+            <p style="color: #555; font-size: 1.1rem; text-align: center;">
+                {t.unavailable}
+            </p>
+        }.into_any();
+    };
+
+    view! {
+        <HStack wrap=FlexWrap::Wrap spacing="3rem".to_string() align=HAlign::FlexStart justify=HJustify::Center>
+            <VStack spacing="1.5rem".to_string() align=VAlign::Stretch style="flex: 1; min-width: 280px; max-width: 480px;">
+                <h2 style="margin: 0; font-size: 1.4rem; color: #333;">{t.choose}</h2>
+                <AmountPicker suggested=suggested selected=amount />
+            </VStack>
+            <PixCard charge=charge amount=amount />
+        </HStack>
+    }.into_any()
+}
+
+#[component]
+fn AmountPicker(suggested: Vec<Money>, selected: Option<Money>) -> impl IntoView {
+    let primary_bg = "#332175";
+    let locale = use_locale();
+    let t = &locale.catalog().donate;
+
+    let chip = move |label: String, href: String, active: bool| {
+        let (bg, fg) = if active { (primary_bg, "white") } else { ("white", primary_bg) };
+        view! {
+            <A
+                href=href
+                attr:style=format!("padding: 0.7rem 1.4rem; border-radius: 50px; border: 2px solid {}; background-color: {}; color: {}; font-weight: 700; text-decoration: none;", primary_bg, bg, fg)
+            >
+                {label}
+            </A>
+        }
+    };
+
+    view! {
+        <HStack wrap=FlexWrap::Wrap spacing="0.75rem".to_string()>
+            {suggested.iter().map(|value| {
+                chip(value.to_brl(), locale.href(&format!("/apoie?valor={}", value.to_decimal(false))), selected == Some(*value))
+            }).collect_view()}
+            {chip(t.any_amount.to_string(), locale.href("/apoie"), selected.is_none())}
+        </HStack>
+
+        <form method="get" action=locale.href("/apoie") style="display: flex; gap: 0.75rem; flex-wrap: wrap; align-items: center;">
+            <label for="valor" style="font-weight: 600; color: #444;">{t.other_amount}</label>
+            <input
+                id="valor"
+                name="valor"
+                inputmode="decimal"
+                placeholder="35,00"
+                style="flex: 1; min-width: 120px; padding: 0.7rem 1rem; border-radius: 8px; border: 1px solid #ccc; font-size: 1rem;"
+            />
+            <button
+                type="submit"
+                style=format!("padding: 0.7rem 1.4rem; border: none; border-radius: 8px; background-color: {}; color: white; font-weight: 700; cursor: pointer;", primary_bg)
+            >
+                {t.generate}
+            </button>
+        </form>
+    }
+}
+
+#[component]
+fn PixCard(charge: PixCharge, amount: Option<Money>) -> impl IntoView {
+    let primary_bg = "#332175";
+    let accent_color = "#f2e300";
+    let t = &use_catalog().donate;
+    let (copied, set_copied) = signal(false);
+
+    let PixCharge { payload, qr_svg } = charge;
+    let to_copy = payload.clone();
+
+    let on_copy = move |_| {
+        #[cfg(feature = "hydrate")]
+        {
+            let clipboard = window().navigator().clipboard();
+            let _ = clipboard.write_text(&to_copy);
+        }
+        #[cfg(not(feature = "hydrate"))]
+        let _ = &to_copy;
+        set_copied.set(true);
+    };
+
+    view! {
+        <VStack
+            spacing="1rem".to_string()
+            align=VAlign::Center
+            style=format!("background: white; padding: 2rem; border-radius: 16px; border-top: 6px solid {}; box-shadow: 0 15px 35px -5px rgba(0,0,0,0.1); width: 320px; max-width: 100%;", accent_color)
+        >
+            <span style=format!("font-weight: 800; color: {}; font-size: 1.3rem;", primary_bg)>
+                {amount.map_or_else(|| t.any_amount.to_string(), Money::to_brl)}
+            </span>
+            <div inner_html=qr_svg style="line-height: 0;"></div>
+            <span style="font-size: 0.85rem; color: #666;">{t.scan}</span>
+            <textarea
+                readonly
+                rows="4"
+                style="width: 100%; font-family: monospace; font-size: 0.75rem; padding: 0.6rem; border-radius: 8px; border: 1px solid #ddd; resize: none; word-break: break-all;"
+            >
+                {payload}
+            </textarea>
+            <button
+                on:click=on_copy
+                style=format!("width: 100%; padding: 0.8rem; border: none; border-radius: 50px; background-color: {}; color: white; font-weight: 700; cursor: pointer;", primary_bg)
+            >
+                {move || if copied.get() { t.copied } else { t.copy }}
+            </button>
+        </VStack>
+    }
+}
+
+#[server(GetDonation, "/api/get_donation")]
+pub async fn get_donation(amount: Option<Money>) -> Result<Donation, ServerFnError> {
+    use crate::content::donations::load_donation_settings;
+    use qrcode::render::svg;
+    use qrcode::{EcLevel, QrCode};
+
+    let settings = load_donation_settings().map_err(ServerFnError::new)?;
+    let suggested = settings.suggested_amounts();
+    let amount = amount.filter(|a| a.0 > 0 && *a <= crate::pix::MAX_AMOUNT);
+
+    if settings.pix_key.trim().is_empty() {
+        return Ok(Donation { suggested, amount, charge: None });
+    }
+
+    let payload = settings.br_code(amount).payload().map_err(ServerFnError::new)?;
+    let qr_svg = QrCode::with_error_correction_level(payload.as_bytes(), EcLevel::M)
+        .map_err(ServerFnError::new)?
+        .render::<svg::Color>()
+        .min_dimensions(240, 240)
+        .dark_color(svg::Color("#1a113d"))
+        .light_color(svg::Color("#ffffff"))
+        .build();
+    // Inline SVG inside HTML does not take an XML prolog.
+    let qr_svg = match qr_svg.find("<svg") {
+        Some(start) => qr_svg[start..].to_string(),
+        None => qr_svg,
+    };
+
+    Ok(Donation {
+        suggested,
+        amount,
+        charge: Some(PixCharge { payload, qr_svg }),
+    })
+}
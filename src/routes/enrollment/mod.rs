@@ -0,0 +1,294 @@
+use leptos::form::ActionForm;
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::components::A;
+use leptos_router::hooks::use_params_map;
+use crate::components::form::INPUT_STYLE;
+use crate::components::page_layout::PageLayout;
+use crate::components::seo::Seo;
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::dates;
+use crate::content::schedule::ClassSession;
+use crate::enrollment::{EnrollmentForm, EnrollmentReceipt, EnrollmentStatus, EnrollmentSummary, ADULT_AGE};
+use crate::i18n::{use_catalog, use_locale, Locale};
+
+/// Ficha de matrícula online, no lugar da ficha em papel da sede.
+#[component]
+pub fn EnrollmentPage() -> impl IntoView {
+    let t = use_catalog();
+    let page = &t.pages.enrollment;
+    let sessions = Resource::new(|| (), |_| get_enrollment_sessions());
+    let submit = ServerAction::<SubmitEnrollment>::new();
+
+    view! {
+        <Seo title=page.title description=page.subtitle/>
+        <PageLayout title=page.title subtitle=page.subtitle>
+            <Suspense fallback=move || view! { <p>{t.enrollment.loading}</p> }>
+                {move || match submit.value().get() {
+                    Some(Ok(receipt)) => view! { <EnrollmentReceived receipt=receipt /> }.into_any(),
+                    _ => sessions.get().map(|result| match result {
+                        Ok(sessions) => view! { <EnrollmentFields sessions=sessions submit=submit /> }.into_any(),
+                        Err(_) => view! { <p>{t.enrollment.sessions_failed}</p> }.into_any(),
+                    }).into_any(),
+                }}
+            </Suspense>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn EnrollmentFields(sessions: Vec<ClassSession>, submit: ServerAction<SubmitEnrollment>) -> impl IntoView {
+    let primary_bg = "#332175";
+    let locale = use_locale();
+    let t = &locale.catalog().enrollment;
+
+    let error = move || submit.value().get().and_then(|result| result.err()).map(|e| match e {
+        ServerFnError::ServerError(message) => message,
+        _ => t.submit_failed.to_string(),
+    });
+
+    view! {
+        <ActionForm action=submit attr:style="max-width: 720px; margin: 0 auto;">
+            // Validation messages come back in the language of the page.
+            <input type="hidden" name="locale" value=locale.tag() />
+            <VStack spacing="2rem".to_string() align=VAlign::Stretch>
+                <FormSection title=t.student>
+                    <FormField label=t.student_name name="student_name" required=true />
+                    <FormField label=t.birth_date name="birth_date" input_type="date" required=true />
+                    <FormField label=t.phone name="phone" input_type="tel" required=true />
+                    <FormField label=t.email name="email" input_type="email" />
+                    <FormField label=t.address name="address" />
+                    <FormField label=t.school name="school" />
+                </FormSection>
+
+                <FormSection
+                    title=t.guardian
+                    hint=t.guardian_hint.replace("{}", &ADULT_AGE.to_string())
+                >
+                    <FormField label=t.guardian_name name="guardian_name" />
+                    <FormField label=t.guardian_relationship name="guardian_relationship" placeholder=t.relationship_placeholder />
+                    <FormField label=t.guardian_phone name="guardian_phone" input_type="tel" />
+                    <FormField label=t.guardian_email name="guardian_email" input_type="email" />
+                </FormSection>
+
+                <FormSection title=t.session>
+                    <label style="display: flex; flex-direction: column; gap: 0.4rem; font-weight: 600; color: #444;">
+                        {t.choose_session}
+                        <select name="form[session_id]" required style=INPUT_STYLE>
+                            <option value="">{t.select}</option>
+                            {sessions.into_iter().map(|s| view! { <option value=s.id.clone()>{s.option_label(locale)}</option> }).collect_view()}
+                        </select>
+                    </label>
+                    <A href=locale.href("/horarios") attr:style=format!("color: {}; font-size: 0.9rem;", primary_bg)>{t.see_schedule}</A>
+                </FormSection>
+
+                <FormSection title=t.health hint=t.health_hint>
+                    <textarea name="form[health_notes]" rows="4" style=INPUT_STYLE></textarea>
+                </FormSection>
+
+                <FormSection title=t.consents>
+                    <ConsentCheckbox name="consent_data" required=true>{t.consent_data}</ConsentCheckbox>
+                    <ConsentCheckbox name="consent_health">{t.consent_health}</ConsentCheckbox>
+                    <ConsentCheckbox name="consent_image">{t.consent_image}</ConsentCheckbox>
+                    <ConsentCheckbox name="consent_guardian">{t.consent_guardian}</ConsentCheckbox>
+                </FormSection>
+
+                {move || error().map(|message| view! {
+                    <p style="margin: 0; padding: 1rem 1.25rem; border-radius: 8px; background: #fdecea; color: #8a1c13; font-weight: 600;">
+                        {message}
+                    </p>
+                })}
+
+                <button
+                    type="submit"
+                    disabled=move || submit.pending().get()
+                    style=format!("align-self: center; padding: 0.9rem 2.5rem; border: none; border-radius: 50px; background-color: {}; color: white; font-weight: 700; font-size: 1.05rem; cursor: pointer;", primary_bg)
+                >
+                    {move || if submit.pending().get() { t.sending } else { t.send }}
+                </button>
+            </VStack>
+        </ActionForm>
+    }
+}
+
+#[component]
+fn FormSection(
+    children: Children,
+    #[prop(into)] title: String,
+    #[prop(optional, into)] hint: Option<String>,
+) -> impl IntoView {
+    view! {
+        <fieldset style="border: none; margin: 0; padding: 1.5rem; background: white; border-radius: 12px; box-shadow: 0 4px 15px rgba(0,0,0,0.05);">
+            <VStack spacing="1rem".to_string() align=VAlign::Stretch>
+                <legend style="float: left; padding: 0; font-size: 1.2rem; font-weight: 700; color: #332175;">{title}</legend>
+                {hint.map(|hint| view! { <p style="margin: 0; font-size: 0.9rem; color: #666;">{hint}</p> })}
+                {children()}
+            </VStack>
+        </fieldset>
+    }
+}
+
+/// A labelled input named `form[<name>]`, which is how the server function reads it.
+#[component]
+fn FormField(
+    #[prop(into)] label: String,
+    name: &'static str,
+    #[prop(default = "text")] input_type: &'static str,
+    #[prop(optional)] required: bool,
+    #[prop(optional)] placeholder: &'static str,
+) -> impl IntoView {
+    view! {
+        <label style="display: flex; flex-direction: column; gap: 0.4rem; font-weight: 600; color: #444;">
+            {label}
+            <input type=input_type name=format!("form[{}]", name) required=required placeholder=placeholder style=INPUT_STYLE />
+        </label>
+    }
+}
+
+#[component]
+fn ConsentCheckbox(children: Children, name: &'static str, #[prop(optional)] required: bool) -> impl IntoView {
+    view! {
+        <label style="display: flex; gap: 0.75rem; align-items: flex-start; color: #333; line-height: 1.5; cursor: pointer;">
+            <input type="checkbox" name=format!("form[{}]", name) value="true" required=required style="margin-top: 0.3rem; flex-shrink: 0;" />
+            <span>{children()}</span>
+        </label>
+    }
+}
+
+#[component]
+fn EnrollmentReceived(receipt: EnrollmentReceipt) -> impl IntoView {
+    let primary_bg = "#332175";
+    let accent_color = "#f2e300";
+    let locale = use_locale();
+    let t = &locale.catalog().enrollment;
+    let href = locale.href(&receipt.href());
+
+    view! {
+        <VStack
+            spacing="1rem".to_string()
+            align=VAlign::Center
+            style=format!("max-width: 560px; margin: 0 auto; background: white; padding: 2.5rem 2rem; border-radius: 16px; border-top: 6px solid {}; box-shadow: 0 15px 35px -5px rgba(0,0,0,0.1); text-align: center;", accent_color)
+        >
+            <h2 style=format!("margin: 0; color: {};", primary_bg)>{t.received}</h2>
+            <p style="margin: 0; color: #555;">{t.keep_protocol}</p>
+            <code style="font-size: 0.95rem; background: #f4f4f9; padding: 0.5rem 1rem; border-radius: 8px; word-break: break-all;">{receipt.protocol}</code>
+            <A href=href attr:style=format!("color: {}; font-weight: 700;", primary_bg)>{t.track}</A>
+        </VStack>
+    }
+}
+
+/// Situação de uma matrícula, consultada pelo protocolo.
+#[component]
+pub fn EnrollmentStatusPage() -> impl IntoView {
+    let catalog = use_catalog();
+    let t = &catalog.enrollment;
+    let params = use_params_map();
+    let protocol = move || params.read().get("protocolo").unwrap_or_default();
+    let summary = Resource::new(protocol, get_enrollment_summary);
+
+    view! {
+        <Title text=catalog.title(t.track)/>
+        <PageLayout title=t.track>
+            <Suspense fallback=move || view! { <p>{t.status_loading}</p> }>
+                {move || summary.get().map(|result| match result {
+                    Ok(Some(summary)) => view! { <EnrollmentProgress summary=summary /> }.into_any(),
+                    Ok(None) => view! { <p>{t.protocol_not_found}</p> }.into_any(),
+                    Err(_) => view! { <p>{t.status_failed}</p> }.into_any(),
+                })}
+            </Suspense>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn EnrollmentProgress(summary: EnrollmentSummary) -> impl IntoView {
+    let primary_bg = "#332175";
+    let locale = use_locale();
+    let t = &locale.catalog().enrollment;
+    let steps = [EnrollmentStatus::Pending, EnrollmentStatus::Accepted, EnrollmentStatus::Active];
+    let reached = steps.iter().position(|s| *s == summary.status).unwrap_or(0);
+
+    view! {
+        <VStack
+            spacing="1.25rem".to_string()
+            align=VAlign::Stretch
+            style="max-width: 560px; margin: 0 auto; background: white; padding: 2rem; border-radius: 16px; box-shadow: 0 4px 15px rgba(0,0,0,0.05);"
+        >
+            <h2 style=format!("margin: 0; color: {};", primary_bg)>{t.hello.replace("{}", &summary.first_name)}</h2>
+            {summary.session.map(|session| view! { <p style="margin: 0; color: #555;">{t.session}": "{session.title(locale)}</p> })}
+            <p style="margin: 0; color: #555;">{t.submitted_on}" "{dates::day_month_year(summary.submitted)}</p>
+            <ol style="margin: 0; padding-left: 1.25rem; display: flex; flex-direction: column; gap: 0.5rem;">
+                {steps.into_iter().enumerate().map(|(i, step)| {
+                    let style = if i <= reached {
+                        format!("color: {}; font-weight: 700;", primary_bg)
+                    } else {
+                        "color: #999;".to_string()
+                    };
+                    view! { <li style=style>{step.label(locale)}</li> }
+                }).collect_view()}
+            </ol>
+        </VStack>
+    }
+}
+
+/// Turmas offered in the form, Monday first.
+#[server(GetEnrollmentSessions, "/api/get_enrollment_sessions")]
+pub async fn get_enrollment_sessions() -> Result<Vec<ClassSession>, ServerFnError> {
+    use crate::content::schedule::{load_schedule, WEEK};
+
+    let schedule = load_schedule().map_err(ServerFnError::new)?;
+    Ok(WEEK.iter().flat_map(|day| schedule.sessions_on(*day)).cloned().collect())
+}
+
+/// `locale` is the tag of the page the form was sent from, for the messages.
+#[server(SubmitEnrollment, "/api/submit_enrollment")]
+pub async fn submit_enrollment(form: EnrollmentForm, locale: String) -> Result<EnrollmentReceipt, ServerFnError> {
+    use crate::audit::{Action, Fields, Recorder};
+    use crate::content::events::now_local;
+    use crate::content::schedule::load_schedule;
+    use crate::enrollment::store;
+
+    let locale = Locale::parse(&locale).unwrap_or(Locale::PtBr);
+    let form = form.normalized();
+    let now = now_local();
+    let schedule = load_schedule().map_err(ServerFnError::new)?;
+    form.validate(now.date(), &schedule).map_err(|e| ServerFnError::new(e.message(locale)))?;
+
+    // Only what identifies the enrollment: the personal data stays in its own table.
+    let recorder = Recorder::new(None).await;
+    let protocol = crate::db::transaction(move |tx| {
+        let protocol = store::insert(tx, &form, now)?;
+        let after = Fields::from([
+            ("turma".to_string(), form.session_id.clone()),
+            ("status".to_string(), EnrollmentStatus::Pending.as_str().to_string()),
+        ]);
+        recorder.record(tx, Action::EnrollmentSubmitted, &protocol, &Fields::new(), &after)?;
+        Ok::<_, store::StoreError>(protocol)
+    })
+    .await
+    .map_err(|e| {
+        tracing::error!(error = %e, "could not save the enrollment");
+        ServerFnError::new(locale.catalog().enrollment.save_failed)
+    })?;
+
+    Ok(EnrollmentReceipt { protocol, status: EnrollmentStatus::Pending })
+}
+
+#[server(GetEnrollmentSummary, "/api/get_enrollment_summary")]
+pub async fn get_enrollment_summary(protocol: String) -> Result<Option<EnrollmentSummary>, ServerFnError> {
+    use crate::content::schedule::load_schedule;
+    use crate::enrollment::store;
+
+    let schedule = load_schedule().map_err(ServerFnError::new)?;
+    let enrollment = crate::db::with_conn(move |conn| store::find(conn, &protocol))
+        .await
+        .map_err(ServerFnError::new)?;
+
+    Ok(enrollment.map(|e| EnrollmentSummary {
+        first_name: e.form.student_name.split_whitespace().next().unwrap_or_default().to_string(),
+        session: schedule.session(&e.form.session_id).cloned(),
+        submitted: e.created_at.date(),
+        protocol: e.protocol,
+        status: e.status,
+    }))
+}
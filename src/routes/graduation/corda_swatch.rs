@@ -0,0 +1,21 @@
+use leptos::prelude::*;
+use crate::content::cordas::Corda;
+
+/// A short bar in the colors of a corda, with its name beside it.
+#[component]
+pub fn CordaSwatch(corda: Corda, #[prop(optional)] large: bool) -> impl IntoView {
+    let (width, height) = if large { ("3.5rem", "0.9rem") } else { ("2.2rem", "0.6rem") };
+
+    view! {
+        <span style="display: inline-flex; align-items: center; gap: 0.5rem;">
+            <span
+                title=corda.name.clone()
+                style=format!(
+                    "display: inline-block; width: {}; height: {}; border-radius: 999px; background: {}; box-shadow: inset 0 0 0 1px rgba(0,0,0,0.15);",
+                    width, height, corda.background()
+                )
+            ></span>
+            <span style="font-weight: 600; color: #333;">{corda.name}</span>
+        </span>
+    }
+}
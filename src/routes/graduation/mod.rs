@@ -0,0 +1,307 @@
+pub mod corda_swatch;
+pub mod timeline;
+
+use chrono::NaiveDate;
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::components::A;
+use crate::components::form::INPUT_STYLE;
+use crate::components::page_layout::PageLayout;
+use crate::components::stacks::hstack::{HStack, AlignItems as HAlign, JustifyContent as HJustify, FlexWrap};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::cordas::{Corda, CordaSystem};
+use crate::graduation::{BatizadoEntry, BatizadoEvent, BatizadoResults, BatizadoSheet};
+
+use self::corda_swatch::CordaSwatch;
+
+/// Registro em lote das graduações de um batizado.
+#[component]
+pub fn BatizadoPage() -> impl IntoView {
+    let sheet = Resource::new(|| (), |_| get_batizado_sheet());
+
+    view! {
+        <Title text="Batizado | Associação Camará Capoeira"/>
+        <PageLayout title="Graduações" subtitle="Registre as cordas entregues em um batizado.">
+            <Suspense fallback=move || view! { <p>"Carregando alunos..."</p> }>
+                {move || sheet.get().map(|result| match result {
+                    Ok(sheet) if sheet.cordas.is_empty() => view! {
+                        <p>"Nenhuma corda cadastrada. Liste as cordas do grupo em content/cordas.toml para registrar batizados."</p>
+                    }.into_any(),
+                    Ok(sheet) => view! { <BatizadoForm sheet=sheet /> }.into_any(),
+                    Err(_) => view! { <p>"Não foi possível carregar os alunos e as cordas."</p> }.into_any(),
+                })}
+            </Suspense>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn BatizadoForm(sheet: BatizadoSheet) -> impl IntoView {
+    let primary_bg = "#332175";
+    let BatizadoSheet { cordas, events, students } = sheet;
+    let system = CordaSystem { cordas };
+
+    let event = RwSignal::new(String::new());
+    let event_slug = RwSignal::new(None::<String>);
+    let date = RwSignal::new(String::new());
+    let awarded_by = RwSignal::new(String::new());
+    let choices = RwSignal::new(vec![String::new(); students.len()]);
+    let error = RwSignal::new(None::<&'static str>);
+    let save = ServerAction::<RecordBatizado>::new();
+
+    let picked_events = events.clone();
+    let on_pick_event = move |ev| {
+        let slug = event_target_value(&ev);
+        match picked_events.iter().find(|e| e.slug == slug) {
+            Some(BatizadoEvent { slug, title, date: day }) => {
+                event.set(title.clone());
+                date.set(day.to_string());
+                event_slug.set(Some(slug.clone()));
+            }
+            None => event_slug.set(None),
+        }
+    };
+
+    let suggestions: Vec<String> = students
+        .iter()
+        .map(|s| system.next_after(s.current.as_deref()).map(|c| c.slug.clone()).unwrap_or_default())
+        .collect();
+    let suggest_all = move |_| {
+        choices.update(|choices| {
+            for (choice, suggestion) in choices.iter_mut().zip(&suggestions) {
+                if choice.is_empty() {
+                    *choice = suggestion.clone();
+                }
+            }
+        })
+    };
+
+    let ids: Vec<i64> = students.iter().map(|s| s.enrollment_id).collect();
+    let on_save = move |_| {
+        let Ok(day) = date.get_untracked().parse::<NaiveDate>() else {
+            error.set(Some("Informe a data do batizado."));
+            return;
+        };
+        let entries: Vec<BatizadoEntry> = ids
+            .iter()
+            .zip(choices.get_untracked())
+            .filter(|(_, corda)| !corda.is_empty())
+            .map(|(id, corda)| BatizadoEntry { enrollment_id: *id, corda })
+            .collect();
+        if entries.is_empty() {
+            error.set(Some("Escolha a nova corda de pelo menos um(a) aluno(a)."));
+            return;
+        }
+        error.set(None);
+        save.dispatch(RecordBatizado {
+            results: BatizadoResults {
+                event: event.get_untracked(),
+                event_slug: event_slug.get_untracked(),
+                date: day,
+                awarded_by: awarded_by.get_untracked(),
+                entries,
+            },
+        });
+    };
+
+    let status = move || {
+        if let Some(message) = error.get() {
+            return Some((message.to_string(), "#b5431f"));
+        }
+        match save.value().get() {
+            Some(Ok(saved)) => Some((format!("{} graduações registradas.", saved), "#1f7a4d")),
+            Some(Err(ServerFnError::ServerError(message))) => Some((message, "#b5431f")),
+            Some(Err(_)) => Some(("Não foi possível salvar o batizado.".to_string(), "#b5431f")),
+            None => None,
+        }
+    };
+
+    view! {
+        <VStack spacing="2rem".to_string() align=VAlign::Stretch style="max-width: 860px; margin: 0 auto;">
+            <VStack
+                spacing="1rem".to_string()
+                align=VAlign::Stretch
+                style="background: white; padding: 1.5rem; border-radius: 12px; box-shadow: 0 4px 15px rgba(0,0,0,0.05);"
+            >
+                <h2 style=format!("margin: 0; color: {}; font-size: 1.3rem;", primary_bg)>"Evento"</h2>
+                {(!events.is_empty()).then(|| view! {
+                    <select on:change=on_pick_event style=INPUT_STYLE>
+                        <option value="">"Escolher batizado da agenda (opcional)"</option>
+                        {events.into_iter().map(|e| view! { <option value=e.slug>{e.title}</option> }).collect_view()}
+                    </select>
+                })}
+                <HStack wrap=FlexWrap::Wrap spacing="1rem".to_string()>
+                    <label style="flex: 2; min-width: 220px; display: flex; flex-direction: column; gap: 0.4rem; font-weight: 600; color: #444;">
+                        "Nome do evento"
+                        <input
+                            prop:value=move || event.get()
+                            on:input=move |ev| event.set(event_target_value(&ev))
+                            placeholder="Batizado e Troca de Cordas 2026"
+                            style=INPUT_STYLE
+                        />
+                    </label>
+                    <label style="flex: 1; min-width: 160px; display: flex; flex-direction: column; gap: 0.4rem; font-weight: 600; color: #444;">
+                        "Data"
+                        <input
+                            type="date"
+                            prop:value=move || date.get()
+                            on:input=move |ev| date.set(event_target_value(&ev))
+                            style=INPUT_STYLE
+                        />
+                    </label>
+                </HStack>
+                <label style="display: flex; flex-direction: column; gap: 0.4rem; font-weight: 600; color: #444;">
+                    "Mestre que entregou as cordas"
+                    <input
+                        prop:value=move || awarded_by.get()
+                        on:input=move |ev| awarded_by.set(event_target_value(&ev))
+                        style=INPUT_STYLE
+                    />
+                </label>
+            </VStack>
+
+            {if students.is_empty() {
+                view! { <p style="color: #666;">"Nenhum(a) aluno(a) com matrícula aceita."</p> }.into_any()
+            } else {
+                view! {
+                    <VStack spacing="0.75rem".to_string() align=VAlign::Stretch>
+                        <HStack justify=HJustify::SpaceBetween align=HAlign::Center wrap=FlexWrap::Wrap spacing="1rem".to_string()>
+                            <h2 style=format!("margin: 0; color: {}; font-size: 1.3rem;", primary_bg)>"Alunos"</h2>
+                            <button
+                                on:click=suggest_all
+                                style=format!("padding: 0.5rem 1.1rem; border: 2px solid {}; border-radius: 50px; background: white; color: {}; font-weight: 700; cursor: pointer;", primary_bg, primary_bg)
+                            >
+                                "Sugerir a próxima corda"
+                            </button>
+                        </HStack>
+                        {students.into_iter().enumerate().map(|(index, student)| {
+                            let current = student.current.as_deref().and_then(|slug| system.get(slug)).cloned();
+                            view! {
+                                <StudentRow
+                                    name=student.student_name
+                                    timeline_href=format!("/admin/graduacoes/aluno/{}", student.enrollment_id)
+                                    current=current
+                                    cordas=system.cordas.clone()
+                                    index=index
+                                    choices=choices
+                                />
+                            }
+                        }).collect_view()}
+                    </VStack>
+                }.into_any()
+            }}
+
+            <HStack align=HAlign::Center wrap=FlexWrap::Wrap spacing="1rem".to_string()>
+                <button
+                    on:click=on_save
+                    disabled=move || save.pending().get()
+                    style=format!("padding: 0.9rem 2rem; border: none; border-radius: 50px; background-color: {}; color: white; font-weight: 700; font-size: 1.05rem; cursor: pointer;", primary_bg)
+                >
+                    {move || if save.pending().get() { "Salvando..." } else { "Registrar batizado" }}
+                </button>
+                {move || status().map(|(message, color)| view! {
+                    <span style=format!("font-weight: 600; color: {};", color)>{message}</span>
+                })}
+            </HStack>
+        </VStack>
+    }
+}
+
+#[component]
+fn StudentRow(
+    name: String,
+    timeline_href: String,
+    current: Option<Corda>,
+    cordas: Vec<Corda>,
+    index: usize,
+    choices: RwSignal<Vec<String>>,
+) -> impl IntoView {
+    view! {
+        <HStack
+            justify=HJustify::SpaceBetween
+            align=HAlign::Center
+            wrap=FlexWrap::Wrap
+            spacing="0.75rem".to_string()
+            style="background: white; padding: 0.8rem 1rem; border-radius: 12px; box-shadow: 0 2px 8px rgba(0,0,0,0.04);"
+        >
+            <VStack spacing="0.3rem".to_string() align=VAlign::FlexStart style="flex: 1; min-width: 200px;">
+                <A href=timeline_href attr:style="color: #222; font-weight: 700; text-decoration: none;">{name}</A>
+                {match current {
+                    Some(corda) => view! { <CordaSwatch corda=corda /> }.into_any(),
+                    None => view! { <span style="font-size: 0.85rem; color: #888;">"Sem corda"</span> }.into_any(),
+                }}
+            </VStack>
+            <select
+                prop:value=move || choices.with(|c| c.get(index).cloned().unwrap_or_default())
+                on:change=move |ev| {
+                    let value = event_target_value(&ev);
+                    choices.update(|c| c[index] = value);
+                }
+                style="min-width: 200px; padding: 0.6rem 0.8rem; border-radius: 8px; border: 1px solid #ccc; font-size: 1rem;"
+            >
+                <option value="">"— sem troca —"</option>
+                {cordas.into_iter().map(|c| view! { <option value=c.slug>{c.name}</option> }).collect_view()}
+            </select>
+        </HStack>
+    }
+}
+
+#[server(GetBatizadoSheet, "/api/get_batizado_sheet")]
+pub async fn get_batizado_sheet() -> Result<BatizadoSheet, ServerFnError> {
+    use crate::content::cordas::load_cordas;
+    use crate::content::events::{load_events, EventKind};
+    use crate::graduation::store;
+
+    crate::auth::require_role(crate::auth::Role::Mestre).await?;
+
+    let system = load_cordas().map_err(ServerFnError::new)?;
+    let mut events: Vec<BatizadoEvent> = load_events()
+        .map_err(ServerFnError::new)?
+        .into_iter()
+        .filter(|e| e.kind == EventKind::Batizado)
+        .map(|e| BatizadoEvent { date: e.start.date(), slug: e.slug, title: e.title })
+        .collect();
+    events.reverse();
+
+    let students = crate::db::with_conn(|conn| store::current_cordas(conn)).await.map_err(ServerFnError::new)?;
+    Ok(BatizadoSheet { cordas: system.cordas, events, students })
+}
+
+#[server(RecordBatizado, "/api/record_batizado")]
+pub async fn record_batizado(results: BatizadoResults) -> Result<usize, ServerFnError> {
+    use crate::audit::{Action, Recorder};
+    use crate::content::cordas::load_cordas;
+    use crate::content::events::now_local;
+    use crate::graduation::store;
+
+    let user = crate::auth::require_role(crate::auth::Role::Mestre).await?;
+
+    let mut results = results;
+    results.event = results.event.trim().to_string();
+    results.awarded_by = results.awarded_by.trim().to_string();
+    if results.event.is_empty() {
+        return Err(ServerFnError::new("Informe o nome do evento."));
+    }
+    if results.awarded_by.is_empty() {
+        return Err(ServerFnError::new("Informe o mestre que entregou as cordas."));
+    }
+
+    let system = load_cordas().map_err(ServerFnError::new)?;
+    if results.entries.iter().any(|e| system.get(&e.corda).is_none()) {
+        return Err(ServerFnError::new("Corda desconhecida; recarregue a página."));
+    }
+
+    let now = now_local();
+    let recorder = Recorder::new(Some(&user)).await;
+    crate::db::transaction(move |tx| {
+        let students: Vec<i64> = results.entries.iter().map(|e| e.enrollment_id).collect();
+        let before = store::cordas_snapshot(tx, &students)?;
+        let saved = store::record_batizado(tx, &results, now)?;
+        let after = store::cordas_snapshot(tx, &students)?;
+        let target = format!("{}, {} ({})", results.event, crate::content::dates::day_month_year(results.date), results.awarded_by);
+        recorder.record(tx, Action::BatizadoRecorded, &target, &before, &after)?;
+        Ok::<_, crate::db::DbError>(saved)
+    })
+    .await
+    .map_err(ServerFnError::new)
+}
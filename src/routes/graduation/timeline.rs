@@ -0,0 +1,116 @@
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::components::A;
+use leptos_router::hooks::use_params_map;
+use crate::components::page_layout::PageLayout;
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::dates;
+use crate::i18n::Locale;
+use crate::graduation::{GraduationTimeline, TimelineStep};
+
+use super::corda_swatch::CordaSwatch;
+
+/// Linha do tempo das graduações de um(a) aluno(a).
+#[component]
+pub fn GraduationTimelinePage() -> impl IntoView {
+    let params = use_params_map();
+    let enrollment_id = move || params.read().get("id").and_then(|id| id.parse::<i64>().ok()).unwrap_or_default();
+    let timeline = Resource::new(enrollment_id, get_graduation_timeline);
+
+    view! {
+        <Title text="Graduações do(a) aluno(a) | Associação Camará Capoeira"/>
+        <PageLayout title="Graduações do(a) aluno(a)">
+            <Suspense fallback=move || view! { <p>"Carregando..."</p> }>
+                {move || timeline.get().map(|result| match result {
+                    Ok(Some(timeline)) => view! { <Timeline timeline=timeline /> }.into_any(),
+                    Ok(None) => view! { <p>"Aluno(a) não encontrado(a)."</p> }.into_any(),
+                    Err(_) => view! { <p>"Não foi possível carregar as graduações."</p> }.into_any(),
+                })}
+            </Suspense>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn Timeline(timeline: GraduationTimeline) -> impl IntoView {
+    let primary_bg = "#332175";
+
+    view! {
+        <VStack spacing="1.5rem".to_string() align=VAlign::Stretch style="max-width: 640px; margin: 0 auto;">
+            <A href="/admin/graduacoes" attr:style=format!("color: {}; font-weight: 600;", primary_bg)>"← Batizado"</A>
+            <h2 style=format!("margin: 0; color: {};", primary_bg)>{timeline.student_name}</h2>
+            {if timeline.steps.is_empty() {
+                view! { <p style="color: #666;">"Nenhuma graduação registrada."</p> }.into_any()
+            } else {
+                view! {
+                    <ol style=format!("list-style: none; margin: 0; padding: 0 0 0 1.5rem; border-left: 3px solid {};", primary_bg)>
+                        {timeline.steps.into_iter().map(|step| view! { <TimelineItem step=step /> }).collect_view()}
+                    </ol>
+                }.into_any()
+            }}
+        </VStack>
+    }
+}
+
+#[component]
+fn TimelineItem(step: TimelineStep) -> impl IntoView {
+    let accent_color = "#f2e300";
+    let TimelineStep { corda, date, event, event_slug, awarded_by } = step;
+
+    view! {
+        <li style="position: relative; margin-bottom: 1.5rem;">
+            <span style=format!(
+                "position: absolute; left: -2.05rem; top: 0.35rem; width: 0.9rem; height: 0.9rem; border-radius: 50%; background: {}; border: 3px solid #332175;",
+                accent_color
+            )></span>
+            <VStack
+                spacing="0.4rem".to_string()
+                align=VAlign::FlexStart
+                style="background: white; padding: 1rem 1.25rem; border-radius: 12px; box-shadow: 0 2px 8px rgba(0,0,0,0.04);"
+            >
+                <span style="font-size: 0.85rem; color: #666;">{dates::medium_date(date, Locale::PtBr)}</span>
+                <CordaSwatch corda=corda large=true />
+                {match event_slug {
+                    Some(slug) => view! {
+                        <A href=format!("/agenda/{}", slug) attr:style="color: #333;">{event}</A>
+                    }.into_any(),
+                    None => view! { <span style="color: #333;">{event}</span> }.into_any(),
+                }}
+                <span style="font-size: 0.9rem; color: #555;">"Entregue por "{awarded_by}</span>
+            </VStack>
+        </li>
+    }
+}
+
+#[server(GetGraduationTimeline, "/api/get_graduation_timeline")]
+pub async fn get_graduation_timeline(enrollment_id: i64) -> Result<Option<GraduationTimeline>, ServerFnError> {
+    use crate::content::cordas::{load_cordas, Corda, Stage};
+    use crate::graduation::store;
+
+    crate::auth::require_role(crate::auth::Role::Mestre).await?;
+
+    let system = load_cordas().map_err(ServerFnError::new)?;
+    let found = crate::db::with_conn(move |conn| store::history(conn, enrollment_id))
+        .await
+        .map_err(ServerFnError::new)?;
+
+    Ok(found.map(|(student_name, graduations)| GraduationTimeline {
+        student_name,
+        steps: graduations
+            .into_iter()
+            .map(|g| TimelineStep {
+                // A corda removed from the system still shows, in gray, under its slug.
+                corda: system.get(&g.corda).cloned().unwrap_or(Corda {
+                    name: g.corda.clone(),
+                    slug: g.corda,
+                    colors: Vec::new(),
+                    stage: Stage::Aluno,
+                }),
+                date: g.date,
+                event: g.event,
+                event_slug: g.event_slug,
+                awarded_by: g.awarded_by,
+            })
+            .collect(),
+    }))
+}
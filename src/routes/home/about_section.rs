@@ -1,6 +1,7 @@
 use leptos::prelude::*;
 use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
 use crate::components::stacks::hstack::{HStack, AlignItems as HAlign, JustifyContent as HJustify, FlexWrap};
+use crate::i18n::use_catalog;
 
 #[component]
 pub fn AboutSection() -> impl IntoView {
@@ -8,6 +9,7 @@ pub fn AboutSection() -> impl IntoView {
     let accent_color = "#f2e300";
     let gray_light = "#f4f4f9";
     let white = "#ffffff";
+    let t = use_catalog();
 
     view! {
         <div id="sobre" style=format!("position: sticky; top: 0; z-index: 2; background-color: {}; padding: 5rem 2rem;", white)>
@@ -21,14 +23,14 @@ pub fn AboutSection() -> impl IntoView {
                 <div style="flex: 1; min-width: 300px; max-width: 600px;">
                     <VStack spacing="1.5rem".to_string() align=VAlign::FlexStart>
                         <h3 style=format!("color: {}; font-size: 2.2rem; font-weight: 700;", primary_bg)>
-                            "Ponto de Cultura"
+                            {t.nav.ponto_de_cultura}
                         </h3>
                         <p style="font-size: 1.1rem; color: #444; line-height: 1.6; text-align: justify;">
-                            "Reconhecida oficialmente como um Ponto de Cultura, a organização Camará Capoeira atua como um farol de transformação social em Mato Grosso do Sul. Nosso trabalho vai além da roda: combatemos o trabalho infantil, promovemos a educação patrimonial e fortalecemos a identidade cultural de nossa comunidade através de ações continuadas."
+                            {t.home.about}
                         </p>
                         <div style=format!("padding: 1.5rem; border-left: 5px solid {}; background: #f9f9f9; border-radius: 0 8px 8px 0;", accent_color)>
                             <p style="font-style: italic; color: #555; font-weight: 500;">
-                                "Mais do que um esporte, uma ferramenta de inclusão social e cidadania plena."
+                                {t.home.about_quote}
                             </p>
                         </div>
                     </VStack>
@@ -47,20 +49,20 @@ pub fn AboutSection() -> impl IntoView {
                         ", gray_light)
                     >
                         <h4 style=format!("font-weight: 800; color: {}; font-size: 1.2rem; text-transform: uppercase;", primary_bg)>
-                            "Nossos Pilares"
+                            {t.home.pillars}
                         </h4>
                         <ul style="list-style: none; padding: 0; display: flex; flex-direction: column; gap: 1rem;">
                             <li style="display: flex; align-items: center; gap: 12px; font-size: 1.05rem;">
                                 <div style=format!("width: 24px; height: 24px; background: {}; border-radius: 50%; display: flex; align-items: center; justify-content: center; color: white; font-size: 0.8rem;", primary_bg)>"✓"</div>
-                                "Projeto Cultura Viva"
+                                {t.home.pillar_cultura_viva}
                             </li>
                             <li style="display: flex; align-items: center; gap: 12px; font-size: 1.05rem;">
                                 <div style=format!("width: 24px; height: 24px; background: {}; border-radius: 50%; display: flex; align-items: center; justify-content: center; color: white; font-size: 0.8rem;", primary_bg)>"✓"</div>
-                                "Combate ao Trabalho Infantil"
+                                {t.home.pillar_child_labour}
                             </li>
                             <li style="display: flex; align-items: center; gap: 12px; font-size: 1.05rem;">
                                 <div style=format!("width: 24px; height: 24px; background: {}; border-radius: 50%; display: flex; align-items: center; justify-content: center; color: white; font-size: 0.8rem;", primary_bg)>"✓"</div>
-                                "Inclusão Social e Educação"
+                                {t.home.pillar_inclusion}
                             </li>
                         </ul>
                     </VStack>
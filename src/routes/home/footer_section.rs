@@ -3,10 +3,31 @@ use leptos_meta::{Script, Link};
 use wasm_bindgen::JsValue; // Importação necessária
 use crate::components::stacks::hstack::{HStack, AlignItems as HAlign, FlexWrap};
 use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::components::seo::{ADDRESS, EMAIL};
+use crate::i18n::use_locale;
+use crate::routes::newsletter::NewsletterSignup;
 
 #[component]
 pub fn FooterSection() -> impl IntoView {
-    
+    let locale = use_locale();
+    let t = locale.catalog();
+    let links = [
+        ("/", t.nav.home),
+        ("/projetos", t.nav.projects),
+        ("/horarios", t.nav.schedule),
+        ("/matricula", t.nav.enrollment),
+        ("/agenda", t.nav.agenda),
+        ("/noticias", t.nav.news),
+        ("/cantigas", t.nav.songs),
+        ("/berimbau", t.nav.berimbau),
+        ("/galeria", t.nav.gallery),
+        ("/na-midia", t.nav.press),
+        ("/apoie", t.nav.donate),
+        ("/transparencia", t.nav.transparency),
+        ("/sobre", t.nav.about),
+        ("/contato", t.nav.contact),
+    ];
+
     view! {
         <div style="position: sticky; top: 0; z-index: 5; background-color: #1a113d; color: #e0e0e0; padding: 0;">
             <Link rel="stylesheet" href="https://unpkg.com/leaflet@1.9.4/dist/leaflet.css" integrity="sha256-p4NxAoJBhIIN+hmNHrzRCf9tD/miZyoHS5obTRR9BMY=" crossorigin=""/>
@@ -19,13 +40,14 @@ pub fn FooterSection() -> impl IntoView {
                         <VStack spacing="1rem".to_string() align=VAlign::FlexStart>
                             <h5 style="color: white; font-size: 1.2rem; font-weight: 700;">"Associação Camará Capoeira"</h5>
                             <p style="font-size: 0.95rem; line-height: 1.6; color: rgba(255,255,255,0.7);">
-                                "Rua São Judas Tadeu, nº 663"<br/>
-                                "Parque dos Ipês II"<br/>
-                                "Ponta Porã - MS, 79900-000"
+                                {ADDRESS.street}<br/>
+                                {ADDRESS.neighbourhood}<br/>
+                                {format!("{} - {}, {}", ADDRESS.city, ADDRESS.region, ADDRESS.postal_code)}
                             </p>
                             <p style="font-size: 0.95rem; margin-top: 1rem;">
-                                "Contato: contato@camaracapoeira.org.br"
+                                {t.footer.contact}": "{EMAIL}
                             </p>
+                            <NewsletterSignup dark=true />
                         </VStack>
                     </div>
 
@@ -34,24 +56,52 @@ pub fn FooterSection() -> impl IntoView {
                     </div>
 
                     <div style="flex: 1; min-width: 200px;">
-                         <h5 style="color: white; font-size: 1.2rem; font-weight: 700; margin-bottom: 1rem;">"Navegação"</h5>
+                         <h5 style="color: white; font-size: 1.2rem; font-weight: 700; margin-bottom: 1rem;">{t.nav.navigation}</h5>
                          <nav style="display: flex; flex-direction: column; gap: 0.8rem;">
-                            <a href="/" style="color: rgba(255,255,255,0.7); text-decoration: none; transition: color 0.2s;">"Início"</a>
-                            <a href="/projetos" style="color: rgba(255,255,255,0.7); text-decoration: none; transition: color 0.2s;">"Projetos"</a>
-                            <a href="/sobre" style="color: rgba(255,255,255,0.7); text-decoration: none; transition: color 0.2s;">"Quem Somos"</a>
-                            <a href="/contato" style="color: rgba(255,255,255,0.7); text-decoration: none; transition: color 0.2s;">"Fale Conosco"</a>
+                            {links.map(|(path, label)| view! {
+                                <a href=locale.href(path) style="color: rgba(255,255,255,0.7); text-decoration: none; transition: color 0.2s;">{label}</a>
+                            })}
                          </nav>
                     </div>
                 </HStack>
 
                 <div style="margin-top: 3rem; padding-top: 1.5rem; border-top: 1px solid rgba(255,255,255,0.1); text-align: center; font-size: 0.8rem; opacity: 0.5;">
-                    <p>"© 2024 Associação de Capoeira Camará. Todos os direitos reservados."</p>
+                    <p>{t.footer.rights}</p>
                 </div>
             </div>
         </div>
     }
 }
 
+/// Os poucos pedaços da API do Leaflet usados pelo mapa, chamados direto do
+/// Rust; sem `eval`, o site funciona com a CSP de `server::security_headers`.
+#[cfg(feature = "hydrate")]
+mod leaflet {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        pub type Map;
+        pub type Layer;
+
+        #[wasm_bindgen(js_namespace = L, js_name = map)]
+        pub fn map(id: &str) -> Map;
+        #[wasm_bindgen(method, js_name = setView)]
+        pub fn set_view(this: &Map, center: &JsValue, zoom: u8) -> Map;
+
+        #[wasm_bindgen(js_namespace = L, js_name = tileLayer)]
+        pub fn tile_layer(url_template: &str, options: &JsValue) -> Layer;
+        #[wasm_bindgen(js_namespace = L, js_name = marker)]
+        pub fn marker(position: &JsValue) -> Layer;
+        #[wasm_bindgen(method, js_name = addTo)]
+        pub fn add_to(this: &Layer, map: &Map) -> Layer;
+        #[wasm_bindgen(method, js_name = bindPopup)]
+        pub fn bind_popup(this: &Layer, content: &str) -> Layer;
+        #[wasm_bindgen(method, js_name = openPopup)]
+        pub fn open_popup(this: &Layer) -> Layer;
+    }
+}
+
 #[component]
 fn LeafletMap() -> impl IntoView {
     let map_id = "footer-map";
@@ -68,17 +118,20 @@ fn LeafletMap() -> impl IntoView {
                     let document = window.document().unwrap();
                     if let Some(element) = document.get_element_by_id(map_id) {
                         if element.child_element_count() == 0 {
-                            let script = format!("
-                                var map = L.map('{}').setView([-22.507296, -55.737256], 15);
-                                L.tileLayer('https://{{s}}.tile.openstreetmap.org/{{z}}/{{x}}/{{y}}.png', {{
-                                    attribution: '&copy; <a href=\"https://www.openstreetmap.org/copyright\">OpenStreetMap</a> contributors'
-                                }}).addTo(map);
-                                L.marker([-22.507296, -55.737256]).addTo(map)
-                                    .bindPopup('Sede Camará Capoeira')
-                                    .openPopup();
-                            ", map_id);
-                            
-                            let _ = js_sys::eval(&script);
+                            let position = js_sys::Array::of2(&ADDRESS.latitude.into(), &ADDRESS.longitude.into());
+                            let tile_options = js_sys::Object::new();
+                            let _ = js_sys::Reflect::set(
+                                &tile_options,
+                                &JsValue::from_str("attribution"),
+                                &JsValue::from_str("&copy; <a href=\"https://www.openstreetmap.org/copyright\">OpenStreetMap</a> contributors"),
+                            );
+
+                            let map = leaflet::map(map_id).set_view(&position, 15);
+                            leaflet::tile_layer("https://{s}.tile.openstreetmap.org/{z}/{x}/{y}.png", &tile_options).add_to(&map);
+                            leaflet::marker(&position)
+                                .add_to(&map)
+                                .bind_popup("Sede Camará Capoeira")
+                                .open_popup();
                         }
                     }
                 }
@@ -89,4 +142,4 @@ fn LeafletMap() -> impl IntoView {
     view! {
         <div id=map_id style="width: 100%; height: 100%; background: #ddd;"></div>
     }
-}
\ No newline at end of file
+}
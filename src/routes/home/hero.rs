@@ -1,5 +1,6 @@
 use leptos::prelude::*;
 use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::i18n::use_catalog;
 use super::webgl_slideshow::WebGLSlideshow;
 
 #[component]
@@ -10,6 +11,7 @@ pub fn HeroSection(
     let primary_bg = "#332175";
     let white = "#ffffff";
     let accent_color = "#f2e300";
+    let t = use_catalog();
 
     view! {
         <div style=format!("
@@ -59,10 +61,10 @@ pub fn HeroSection(
                     </h1>
                     <div style=format!("width: 100px; height: 8px; background-color: {}; margin: 0.5rem 0; box-shadow: 0 2px 4px rgba(0,0,0,0.3);", accent_color)></div>
                     <h2 style="font-size: 1.8rem; font-weight: 400; opacity: 0.95; text-shadow: 0 2px 4px rgba(0,0,0,0.5);">
-                        "Cultura, Educação e Cidadania em Ponta Porã - MS"
+                        {t.home.tagline}
                     </h2>
                     <p style="font-size: 1.2rem; max-width: 700px; margin-top: 1rem; line-height: 1.6; text-shadow: 0 1px 3px rgba(0,0,0,0.8);">
-                        "Promovendo inclusão social e preservando a cultura afro-brasileira na fronteira através da arte da Capoeira."
+                        {t.home.lead}
                     </p>
                     <a 
                         href="#sobre"
@@ -78,7 +80,7 @@ pub fn HeroSection(
                             display: inline-block;
                         ", accent_color, primary_bg)
                     >
-                        "Conheça Nossos Projetos"
+                        {t.home.cta}
                     </a>
                 </VStack>
             </div>
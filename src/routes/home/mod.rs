@@ -1,18 +1,25 @@
 pub mod hero;
 pub mod about_section;
 pub mod media_section;
+pub mod partners_section;
 pub mod footer_section;
 pub mod webgl_slideshow;
 
 use leptos::prelude::*;
 use crate::components::header::Header;
+use crate::components::seo::Seo;
 use crate::components::stacking_container::StackingContainer;
+use crate::i18n::use_catalog;
 
 use self::hero::HeroSection;
 use self::about_section::AboutSection;
 use self::media_section::MediaSection;
+use self::partners_section::PartnersSection;
 use self::footer_section::FooterSection;
 
+/// Photos of the hero slideshow, under the media root (`/assets/`).
+pub const SLIDESHOW_DIR: &str = "acervo/inicio";
+
 #[component]
 pub fn HomePage() -> impl IntoView {
     let container_ref = NodeRef::new();
@@ -24,6 +31,7 @@ pub fn HomePage() -> impl IntoView {
     );
 
     view! {
+        <Seo description=use_catalog().home.lead/>
         <StackingContainer node_ref=container_ref>
             // 1. Header (Fixo no topo, acima de tudo)
             <div style="position: sticky; top: 0; z-index: 1000;">
@@ -39,7 +47,9 @@ pub fn HomePage() -> impl IntoView {
             // 4. Na Mídia (Snippets Reais)
             <MediaSection />
 
-            // 5. Parceiros (Adicionado para corrigir o aviso de unused import e exibir a seção)
+            // 5. Parceiros (atuais e anteriores)
+            <PartnersSection />
+
             // 6. Footer (Mapa + Nav)
             <FooterSection />
 
@@ -52,23 +62,30 @@ pub fn HomePage() -> impl IntoView {
 pub async fn get_random_images() -> Result<Vec<String>, ServerFnError> {
     use std::fs;
     use rand::seq::SliceRandom;
-    use rand::rng;
+    use rand::{rng, rngs::StdRng, SeedableRng};
+    use crate::server::prerender::Prerender;
 
-    let target_dir = "assets/acervo/inicio";
+    let media_root = crate::settings::config().map_err(ServerFnError::new)?.media_root;
     let mut images = Vec::new();
 
-    match fs::read_dir(target_dir) {
+    let dir = media_root.join(SLIDESHOW_DIR);
+    match fs::read_dir(&dir) {
         Ok(entries) => {
             for entry in entries {
-                if let Ok(entry) = entry {
-                    let path = entry.path();
-                    if path.is_file() {
-                        if let Some(ext) = path.extension() {
-                            let ext_str = ext.to_string_lossy().to_lowercase();
-                            if ["jpg", "jpeg", "png", "webp"].contains(&ext_str.as_str()) {
-                                if let Some(name) = path.file_name() {
-                                    images.push(format!("/{}/{}", target_dir, name.to_string_lossy()));
-                                }
+                let entry = match entry {
+                    Ok(entry) => entry,
+                    Err(e) => {
+                        tracing::warn!(dir = %dir.display(), error = %e, "slideshow: skipping unreadable entry");
+                        continue;
+                    }
+                };
+                let path = entry.path();
+                if path.is_file() {
+                    if let Some(ext) = path.extension() {
+                        let ext_str = ext.to_string_lossy().to_lowercase();
+                        if ["jpg", "jpeg", "png", "webp"].contains(&ext_str.as_str()) {
+                            if let Some(name) = path.file_name() {
+                                images.push(format!("/assets/{}/{}", SLIDESHOW_DIR, name.to_string_lossy()));
                             }
                         }
                     }
@@ -76,13 +93,18 @@ pub async fn get_random_images() -> Result<Vec<String>, ServerFnError> {
             }
         }
         Err(e) => {
-            eprintln!("Error reading image directory: {}", e);
-            return Ok(vec![]); 
+            // The home page still works without the slideshow.
+            tracing::warn!(dir = %dir.display(), error = %e, "slideshow: could not read the photo directory");
+            return Ok(vec![]);
         }
     }
 
-    let mut rng = rng();
-    images.shuffle(&mut rng);
+    // `read_dir` order varies between machines; a pre-rendered copy must not.
+    images.sort();
+    match use_context::<Prerender>() {
+        Some(prerender) => images.shuffle(&mut StdRng::seed_from_u64(prerender.seed)),
+        None => images.shuffle(&mut rng()),
+    }
     images.truncate(10);
 
     Ok(images)
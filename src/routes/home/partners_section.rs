@@ -0,0 +1,114 @@
+use leptos::prelude::*;
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::components::stacks::hstack::{HStack, AlignItems as HAlign, JustifyContent as HJustify, FlexWrap};
+use crate::i18n::use_catalog;
+use crate::content::partners::{Partner, PartnerCategory, PartnerWall};
+
+#[component]
+pub fn PartnersSection() -> impl IntoView {
+    let white = "#ffffff";
+    let primary_bg = "#332175";
+    let t = use_catalog();
+
+    let partners = Resource::new(|| (), |_| get_partners());
+
+    view! {
+        <div id="parceiros" style=format!("position: sticky; top: 0; z-index: 4; background-color: {}; padding: 5rem 2rem;", white)>
+            <VStack spacing="3rem".to_string() align=VAlign::Center>
+                <h3 style=format!("font-size: 2.5rem; font-weight: 700; text-align: center; color: {};", primary_bg)>
+                    {t.home.partners}
+                </h3>
+
+                <Suspense fallback=|| ()>
+                    {move || partners.get().map(|result| {
+                        let wall = result.unwrap_or_default();
+                        view! {
+                            {PartnerCategory::ALL.into_iter().map(|category| {
+                                let partners = wall.active_in(category);
+                                (!partners.is_empty()).then(|| view! { <LogoWall category=category partners=partners /> })
+                            }).collect_view()}
+                            <PastSupporters partners=wall.past />
+                        }
+                    })}
+                </Suspense>
+            </VStack>
+        </div>
+    }
+}
+
+#[component]
+fn LogoWall(category: PartnerCategory, partners: Vec<Partner>) -> impl IntoView {
+    view! {
+        <VStack spacing="1.5rem".to_string() align=VAlign::Center style="width: 100%; max-width: 1100px;">
+            <h4 style="font-size: 0.9rem; font-weight: 700; letter-spacing: 0.1em; text-transform: uppercase; color: #777; margin: 0;">
+                {category.label()}
+            </h4>
+            <HStack wrap=FlexWrap::Wrap justify=HJustify::Center align=HAlign::Center spacing="2rem".to_string()>
+                {partners.into_iter().map(|partner| view! { <PartnerLogo partner=partner /> }).collect_view()}
+            </HStack>
+        </VStack>
+    }
+}
+
+#[component]
+fn PartnerLogo(partner: Partner) -> impl IntoView {
+    let Partner { name, logo, url, .. } = partner;
+    let title = name.clone();
+
+    let content = match logo {
+        Some(src) => view! {
+            <img src=src alt=format!("Logotipo de {}", name) style="max-width: 100%; max-height: 100%; object-fit: contain;" />
+        }.into_any(),
+        None => view! {
+            <span style="font-size: 0.9rem; font-weight: 700; color: #444; line-height: 1.3;">{name}</span>
+        }.into_any(),
+    };
+
+    let tile = view! {
+        <div
+            title=title
+            style="width: 180px; height: 100px; padding: 1rem; box-sizing: border-box; display: flex; align-items: center; justify-content: center; background: #f9f9fb; border-radius: 12px; border: 1px solid #eee;"
+        >
+            {content}
+        </div>
+    };
+
+    match url {
+        Some(href) => view! {
+            <a href=href target="_blank" rel="noopener" style="text-decoration: none;">{tile}</a>
+        }.into_any(),
+        None => tile.into_any(),
+    }
+}
+
+/// Apoiadores de convênios já encerrados, que os termos de fomento exigem manter creditados.
+#[component]
+fn PastSupporters(partners: Vec<Partner>) -> impl IntoView {
+    let t = use_catalog();
+    (!partners.is_empty()).then(|| view! {
+        <VStack spacing="0.8rem".to_string() align=VAlign::Center style="max-width: 800px;">
+            <h4 style="font-size: 0.9rem; font-weight: 700; letter-spacing: 0.1em; text-transform: uppercase; color: #777; margin: 0;">
+                {t.home.past_partners}
+            </h4>
+            <p style="font-size: 0.95rem; color: #555; line-height: 1.8; margin: 0;">
+                {partners
+                    .into_iter()
+                    .map(|p| match p.period_label() {
+                        Some(period) => format!("{} ({})", p.name, period),
+                        None => p.name,
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" · ")}
+            </p>
+        </VStack>
+    })
+}
+
+#[server(GetPartners, "/api/get_partners")]
+pub async fn get_partners() -> Result<PartnerWall, ServerFnError> {
+    use crate::content::events::now_local;
+    use crate::content::partners::load_partners;
+
+    let partners = load_partners().map_err(ServerFnError::new)?;
+    Ok(PartnerWall::split(partners, now_local().date()))
+}
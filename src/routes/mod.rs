@@ -1 +1,16 @@
-pub mod home;
\ No newline at end of file
+pub mod admin;
+pub mod agenda;
+pub mod albums;
+pub mod attendance;
+pub mod auth;
+pub mod berimbau;
+pub mod cantigas;
+pub mod donate;
+pub mod enrollment;
+pub mod graduation;
+pub mod home;
+pub mod news;
+pub mod newsletter;
+pub mod press;
+pub mod schedule;
+pub mod transparency;
\ No newline at end of file
@@ -0,0 +1,131 @@
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::components::A;
+use leptos_router::hooks::use_params_map;
+use crate::components::page_layout::PageLayout;
+use crate::components::seo::{self, PageKind, Seo};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::news::NewsArticle;
+use crate::routes::newsletter::NewsletterSignup;
+use crate::i18n::{self, use_catalog, use_locale};
+
+/// Lista de notícias do grupo, mais recentes primeiro.
+#[component]
+pub fn NewsPage() -> impl IntoView {
+    let t = use_catalog();
+    let page = &t.pages.news;
+    let articles = Resource::new(|| (), |_| get_news());
+
+    view! {
+        <Seo title=page.title description=page.subtitle/>
+        <PageLayout title=page.title subtitle=page.subtitle>
+            <Suspense fallback=move || view! { <p>"Carregando notícias..."</p> }>
+                {move || articles.get().map(|result| match result {
+                    Ok(articles) if articles.is_empty() => view! {
+                        <p style="color: #666;">"Nenhuma notícia publicada ainda."</p>
+                    }.into_any(),
+                    Ok(articles) => view! {
+                        <VStack spacing="1.5rem".to_string() align=VAlign::Stretch style="max-width: 860px; margin: 0 auto;">
+                            {articles.into_iter().map(|article| view! { <NewsCard article=article /> }).collect_view()}
+                        </VStack>
+                    }.into_any(),
+                    Err(_) => view! { <p>"Não foi possível carregar as notícias."</p> }.into_any(),
+                })}
+            </Suspense>
+            <div style="max-width: 860px; margin: 3rem auto 0; background: white; padding: 2rem; border-radius: 12px; box-shadow: 0 4px 15px rgba(0,0,0,0.05); border-left: 6px solid #f2e300;">
+                <NewsletterSignup />
+            </div>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn NewsCard(article: NewsArticle) -> impl IntoView {
+    let primary_bg = "#332175";
+
+    view! {
+        <A href=use_locale().href(&article.href()) attr:style="text-decoration: none; color: inherit;">
+            <article style="display: flex; flex-wrap: wrap; gap: 1.5rem; background: white; padding: 1.5rem; border-radius: 12px; box-shadow: 0 4px 15px rgba(0,0,0,0.05);">
+                {article.cover.clone().map(|src| view! {
+                    <img src=src alt="" loading="lazy" style="width: 220px; max-width: 100%; aspect-ratio: 4 / 3; object-fit: cover; border-radius: 8px;"/>
+                })}
+                <VStack spacing="0.5rem".to_string() align=VAlign::FlexStart style="flex: 1; min-width: 240px;">
+                    <span style="font-size: 0.85rem; color: #666;">{article.date_label(use_locale())}</span>
+                    <h2 style=format!("margin: 0; font-size: 1.35rem; color: {};", primary_bg)>{article.title.clone()}</h2>
+                    <p style="margin: 0; color: #444; line-height: 1.6;">{article.summary.clone()}</p>
+                </VStack>
+            </article>
+        </A>
+    }
+}
+
+#[component]
+pub fn NewsArticlePage() -> impl IntoView {
+    let params = use_params_map();
+    let slug = move || params.read().get("slug").unwrap_or_default();
+    let article = Resource::new(slug, get_news_article);
+
+    view! {
+        <Suspense fallback=move || view! { <p>"Carregando notícia..."</p> }>
+            {move || article.get().map(|result| match result {
+                Ok(Some(article)) => view! { <ArticleDetail article=article /> }.into_any(),
+                Ok(None) => view! {
+                    <Title text="Notícia não encontrada | Associação Camará Capoeira"/>
+                    <PageLayout title="Notícia não encontrada">
+                        <A href=use_locale().href("/noticias")>"← Voltar para as notícias"</A>
+                    </PageLayout>
+                }.into_any(),
+                Err(_) => view! {
+                    <PageLayout title="Notícias">
+                        <p>"Não foi possível carregar a notícia."</p>
+                    </PageLayout>
+                }.into_any(),
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+fn ArticleDetail(article: NewsArticle) -> impl IntoView {
+    let primary_bg = "#332175";
+    let json_ld = seo::news_article(&i18n::site_origin(), &article);
+
+    view! {
+        <Seo
+            title=article.title.clone()
+            description=article.summary.clone()
+            image=Some(article.share_image())
+            kind=PageKind::Article
+            json_ld=json_ld
+        />
+        <PageLayout title=article.title.clone() subtitle=article.date_label(use_locale())>
+            <VStack spacing="1.5rem".to_string() align=VAlign::Stretch style="max-width: 760px; margin: 0 auto; background: white; padding: 2.5rem; border-radius: 16px; box-shadow: 0 15px 35px -5px rgba(0,0,0,0.1);">
+                {article.cover.clone().map(|src| view! {
+                    <img src=src alt="" style="width: 100%; max-height: 420px; object-fit: cover; border-radius: 12px;"/>
+                })}
+                <p style="font-size: 1.15rem; color: #333; line-height: 1.7; margin: 0; font-weight: 600;">{article.summary.clone()}</p>
+                {article.paragraphs().map(|p| view! {
+                    <p style="font-size: 1.05rem; color: #444; line-height: 1.7; margin: 0;">{p.to_string()}</p>
+                }).collect_view()}
+                <div style="border-top: 1px solid #eee; padding-top: 1.5rem;">
+                    <NewsletterSignup />
+                </div>
+                <A href=use_locale().href("/noticias") attr:style=format!("color: {}; font-weight: 600;", primary_bg)>"← Voltar para as notícias"</A>
+            </VStack>
+        </PageLayout>
+    }
+}
+
+#[server(GetNews, "/api/get_news")]
+pub async fn get_news() -> Result<Vec<NewsArticle>, ServerFnError> {
+    use crate::content::news::load_news;
+
+    load_news().map_err(ServerFnError::new)
+}
+
+#[server(GetNewsArticle, "/api/get_news_article")]
+pub async fn get_news_article(slug: String) -> Result<Option<NewsArticle>, ServerFnError> {
+    use crate::content::news::load_news;
+
+    Ok(load_news().map_err(ServerFnError::new)?.into_iter().find(|a| a.slug == slug))
+}
@@ -0,0 +1,231 @@
+use leptos::form::ActionForm;
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::components::A;
+use leptos_router::hooks::use_params_map;
+use crate::components::page_layout::PageLayout;
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::i18n::{use_catalog, use_locale};
+
+const SUCCESS_COLOR: &str = "#1f7a4d";
+const ERROR_COLOR: &str = "#b5431f";
+
+/// Bloco de inscrição no boletim, usado no rodapé (`dark`) e nas páginas de notícias.
+#[component]
+pub fn NewsletterSignup(#[prop(optional)] dark: bool) -> impl IntoView {
+    let primary_bg = "#332175";
+    let accent_color = "#f2e300";
+    let subscribe = ServerAction::<SubscribeNewsletter>::new();
+    let locale = use_locale();
+    let t = &locale.catalog().newsletter;
+
+    let (title_color, text_color, success_color, error_color) = match dark {
+        true => ("white", "rgba(255,255,255,0.7)", accent_color, "#ffb59e"),
+        false => (primary_bg, "#555", SUCCESS_COLOR, ERROR_COLOR),
+    };
+    let status = move || match subscribe.value().get() {
+        Some(Ok(())) => Some((t.sent.to_string(), success_color)),
+        Some(Err(ServerFnError::ServerError(message))) => Some((message, error_color)),
+        Some(Err(_)) => Some((t.failed.to_string(), error_color)),
+        None => None,
+    };
+    let (button_bg, button_fg) = if dark { (accent_color, "#222") } else { (primary_bg, "white") };
+
+    view! {
+        <ActionForm action=subscribe attr:style="width: 100%;">
+            <input type="hidden" name="locale" value=locale.tag() />
+            <VStack spacing="0.75rem".to_string() align=VAlign::Stretch>
+                <h5 style=format!("margin: 0; color: {}; font-size: 1.2rem; font-weight: 700;", title_color)>{t.title}</h5>
+                <p style=format!("margin: 0; font-size: 0.95rem; line-height: 1.5; color: {};", text_color)>
+                    {t.lead}
+                </p>
+                <div style="display: flex; gap: 0.5rem; flex-wrap: wrap;">
+                    <input
+                        type="email"
+                        name="email"
+                        required
+                        placeholder=t.placeholder
+                        autocomplete="email"
+                        aria-label=t.email_label
+                        style="flex: 1 1 180px; min-width: 0; padding: 0.7rem 1rem; border-radius: 8px; border: 1px solid #ccc; font-size: 1rem; font-family: inherit;"
+                    />
+                    <button
+                        type="submit"
+                        disabled=move || subscribe.pending().get()
+                        style=format!("padding: 0.7rem 1.3rem; border: none; border-radius: 8px; background-color: {}; color: {}; font-weight: 700; font-size: 1rem; cursor: pointer;", button_bg, button_fg)
+                    >
+                        {t.subscribe}
+                    </button>
+                </div>
+                {move || status().map(|(message, color)| view! {
+                    <p style=format!("margin: 0; font-size: 0.9rem; color: {};", color)>{message}</p>
+                })}
+                <p style=format!("margin: 0; font-size: 0.8rem; color: {};", text_color)>
+                    {t.fine_print}
+                </p>
+            </VStack>
+        </ActionForm>
+    }
+}
+
+/// Destino do link do e-mail de confirmação: ativa a inscrição.
+#[component]
+pub fn NewsletterConfirmPage() -> impl IntoView {
+    let catalog = use_catalog();
+    let t = &catalog.newsletter;
+    let params = use_params_map();
+    let token = move || params.read().get("token").unwrap_or_default();
+    let confirmed = Resource::new(token, confirm_newsletter);
+
+    view! {
+        <Title text=catalog.title(t.title)/>
+        <PageLayout title=t.title>
+            <NewsletterNotice>
+                <Suspense fallback=move || view! { <p>{t.confirming}</p> }>
+                    {move || confirmed.get().map(|result| match result {
+                        Ok(true) => view! {
+                            <p style=format!("margin: 0; font-size: 1.1rem; color: {};", SUCCESS_COLOR)>{t.confirmed}</p>
+                            <p style="margin: 0; color: #555;">{t.next_issue}</p>
+                        }.into_any(),
+                        Ok(false) => view! {
+                            <p style="margin: 0; color: #555;">{t.link_expired}</p>
+                        }.into_any(),
+                        Err(_) => view! { <p style=format!("margin: 0; color: {};", ERROR_COLOR)>{t.confirm_failed}</p> }.into_any(),
+                    })}
+                </Suspense>
+            </NewsletterNotice>
+        </PageLayout>
+    }
+}
+
+/// Destino do link "cancelar inscrição" de cada boletim.
+///
+/// A GET never unsubscribes (mail scanners follow links); the button does,
+/// and mail clients use the one-click POST handled in `server::newsletter`.
+#[component]
+pub fn NewsletterUnsubscribePage() -> impl IntoView {
+    let primary_bg = "#332175";
+    let catalog = use_catalog();
+    let t = &catalog.newsletter;
+    let params = use_params_map();
+    let token = move || params.read().get("token").unwrap_or_default();
+    let unsubscribe = ServerAction::<UnsubscribeNewsletter>::new();
+
+    view! {
+        <Title text=catalog.title(t.unsubscribe)/>
+        <PageLayout title=t.title>
+            <NewsletterNotice>
+                {move || match unsubscribe.value().get() {
+                    Some(Ok(true)) => view! {
+                        <p style=format!("margin: 0; font-size: 1.1rem; color: {};", SUCCESS_COLOR)>{t.unsubscribed}</p>
+                        <p style="margin: 0; color: #555;">{t.no_more_issues}</p>
+                    }.into_any(),
+                    Some(Ok(false)) => view! {
+                        <p style="margin: 0; color: #555;">{t.unknown_link}</p>
+                    }.into_any(),
+                    result => view! {
+                        <p style="margin: 0; color: #555;">{t.unsubscribe_question}</p>
+                        <ActionForm action=unsubscribe>
+                            <input type="hidden" name="token" value=token() />
+                            <button
+                                type="submit"
+                                disabled=move || unsubscribe.pending().get()
+                                style=format!("padding: 0.8rem 1.6rem; border: none; border-radius: 8px; background-color: {}; color: white; font-weight: 700; font-size: 1rem; cursor: pointer;", primary_bg)
+                            >
+                                {t.unsubscribe}
+                            </button>
+                        </ActionForm>
+                        {result.is_some().then(|| view! {
+                            <p style=format!("margin: 0; color: {};", ERROR_COLOR)>{t.unsubscribe_failed}</p>
+                        })}
+                    }.into_any(),
+                }}
+            </NewsletterNotice>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn NewsletterNotice(children: Children) -> impl IntoView {
+    let primary_bg = "#332175";
+    let locale = use_locale();
+
+    view! {
+        <VStack
+            spacing="1.25rem".to_string()
+            align=VAlign::FlexStart
+            style="max-width: 560px; margin: 0 auto; background: white; padding: 2rem; border-radius: 12px; box-shadow: 0 4px 15px rgba(0,0,0,0.05);"
+        >
+            {children()}
+            <A href=locale.href("/noticias") attr:style=format!("color: {}; font-weight: 600;", primary_bg)>{locale.catalog().newsletter.see_news}</A>
+        </VStack>
+    }
+}
+
+/// `locale` is the tag of the page the form was sent from, for the messages.
+#[server(SubscribeNewsletter, "/api/subscribe_newsletter")]
+pub async fn subscribe_newsletter(email: String, locale: String) -> Result<(), ServerFnError> {
+    use crate::audit::{Action, Fields, Recorder};
+    use crate::content::events::now_local;
+    use crate::mail::outbox;
+    use crate::newsletter::{emails, normalize_email, store, SubscriberStatus};
+
+    let Some(email) = normalize_email(&email) else {
+        let locale = crate::i18n::Locale::parse(&locale).unwrap_or(crate::i18n::Locale::PtBr);
+        return Err(ServerFnError::new(locale.catalog().newsletter.invalid_email));
+    };
+    let config = crate::mail::config().map_err(ServerFnError::new)?;
+    let recorder = Recorder::new(None).await;
+    let now = now_local();
+
+    // The answer is the same whether or not the address was already on the
+    // list, so the form does not reveal who subscribes.
+    crate::db::transaction(move |tx| {
+        if let Some(pending) = store::subscribe(tx, &email, now)? {
+            outbox::enqueue(tx, &emails::confirmation(&config, &pending.email, &pending.confirm_token), now)?;
+            let after = Fields::from([("status".to_string(), SubscriberStatus::Pending.as_str().to_string())]);
+            recorder.record(tx, Action::NewsletterSubscribed, &format!("Inscrição #{}", pending.id), &Fields::new(), &after)?;
+        }
+        Ok::<_, crate::db::DbError>(())
+    })
+    .await
+    .map_err(ServerFnError::new)
+}
+
+#[server(ConfirmNewsletter, "/api/confirm_newsletter")]
+pub async fn confirm_newsletter(token: String) -> Result<bool, ServerFnError> {
+    use crate::audit::{Action, Fields, Recorder};
+    use crate::content::events::now_local;
+    use crate::newsletter::{store, SubscriberStatus};
+
+    let recorder = Recorder::new(None).await;
+    let now = now_local();
+    crate::db::transaction(move |tx| {
+        let Some(id) = store::confirm(tx, &token, now)? else {
+            return Ok(false);
+        };
+        let status = |s: SubscriberStatus| Fields::from([("status".to_string(), s.as_str().to_string())]);
+        recorder.record(
+            tx,
+            Action::NewsletterConfirmed,
+            &format!("Inscrição #{id}"),
+            &status(SubscriberStatus::Pending),
+            &status(SubscriberStatus::Active),
+        )?;
+        Ok::<_, crate::db::DbError>(true)
+    })
+    .await
+    .map_err(ServerFnError::new)
+}
+
+#[server(UnsubscribeNewsletter, "/api/unsubscribe_newsletter")]
+pub async fn unsubscribe_newsletter(token: String) -> Result<bool, ServerFnError> {
+    use crate::audit::Recorder;
+    use crate::content::events::now_local;
+
+    let recorder = Recorder::new(None).await;
+    let now = now_local();
+    crate::db::transaction(move |tx| crate::newsletter::unsubscribe(tx, &recorder, &token, now))
+        .await
+        .map_err(ServerFnError::new)
+}
@@ -0,0 +1,84 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::use_query_map;
+use crate::components::page_layout::PageLayout;
+use crate::components::seo::Seo;
+use crate::components::stacks::hstack::{HStack, JustifyContent as HJustify, FlexWrap};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::press::{self, PressClipping};
+use crate::routes::home::media_section::{get_press_clippings, MediaCard};
+use crate::i18n::{use_catalog, use_locale};
+
+/// Arquivo completo de matérias, filtrável por ano via `?ano=2025`.
+#[component]
+pub fn PressArchivePage() -> impl IntoView {
+    let t = use_catalog();
+    let page = &t.pages.press;
+    let clippings = Resource::new(|| (), |_| get_press_clippings(None));
+    let query = use_query_map();
+    let selected_year = move || query.read().get("ano").and_then(|y| y.parse::<i32>().ok());
+
+    view! {
+        <Seo title=page.title description=page.subtitle/>
+        <PageLayout title=page.title subtitle=page.subtitle>
+            <Suspense fallback=move || view! { <p>"Carregando matérias..."</p> }>
+                {move || clippings.get().map(|result| match result {
+                    Ok(all) => {
+                        let years = press::years(&all);
+                        let year = selected_year();
+                        let shown: Vec<PressClipping> = all
+                            .into_iter()
+                            .filter(|c| year.is_none_or(|y| c.year() == Some(y)))
+                            .collect();
+
+                        view! {
+                            <VStack spacing="2.5rem".to_string() align=VAlign::Stretch>
+                                // Nothing to filter until some clipping has a confirmed date.
+                                {(!years.is_empty()).then(|| view! { <YearFilter years=years selected=year /> })}
+                                {if shown.is_empty() {
+                                    view! { <p style="color: #666;">"Nenhuma matéria encontrada."</p> }.into_any()
+                                } else {
+                                    view! {
+                                        <HStack wrap=FlexWrap::Wrap justify=HJustify::Center spacing="2rem".to_string()>
+                                            {shown.into_iter().map(|clipping| view! {
+                                                <MediaCard clipping=clipping color="#332175" />
+                                            }).collect_view()}
+                                        </HStack>
+                                    }.into_any()
+                                }}
+                            </VStack>
+                        }.into_any()
+                    }
+                    Err(_) => view! { <p>"Não foi possível carregar as matérias."</p> }.into_any(),
+                })}
+            </Suspense>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn YearFilter(years: Vec<i32>, selected: Option<i32>) -> impl IntoView {
+    let primary_bg = "#332175";
+    let locale = use_locale();
+
+    let chip = move |label: String, href: String, active: bool| {
+        let (bg, fg) = if active { (primary_bg, "white") } else { ("white", primary_bg) };
+        view! {
+            <A
+                href=href
+                attr:style=format!("padding: 0.4rem 1.1rem; border-radius: 50px; border: 2px solid {}; background-color: {}; color: {}; font-weight: 700; text-decoration: none; font-size: 0.9rem;", primary_bg, bg, fg)
+            >
+                {label}
+            </A>
+        }
+    };
+
+    view! {
+        <HStack wrap=FlexWrap::Wrap justify=HJustify::Center spacing="0.6rem".to_string()>
+            {chip("Todos".to_string(), locale.href("/na-midia"), selected.is_none())}
+            {years.into_iter().map(|year| {
+                chip(year.to_string(), locale.href(&format!("/na-midia?ano={}", year)), selected == Some(year))
+            }).collect_view()}
+        </HStack>
+    }
+}
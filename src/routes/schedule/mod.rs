@@ -0,0 +1,162 @@
+pub mod session_card;
+
+use leptos::prelude::*;
+use crate::components::page_layout::PageLayout;
+use crate::components::seo::Seo;
+use crate::components::responsive_view::ResponsiveView;
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::dates;
+use crate::content::schedule::{Cancellation, ExtraSession, SessionView, WeekView, WEEK};
+use crate::i18n::{use_catalog, use_locale};
+
+use self::session_card::SessionCard;
+
+/// Below this width the grid collapses into a day-by-day list.
+const GRID_MIN_WIDTH: u32 = 900;
+
+type ScheduleView = Box<dyn Fn() -> AnyView + Send>;
+
+#[component]
+pub fn SchedulePage() -> impl IntoView {
+    let t = use_catalog();
+    let page = &t.pages.schedule;
+    let schedule = Resource::new(|| (), |_| get_week_schedule());
+
+    view! {
+        <Seo title=page.title description=page.subtitle/>
+        <PageLayout title=page.title subtitle=page.subtitle>
+            <Suspense fallback=move || view! { <p>{t.schedule.loading}</p> }>
+                {move || schedule.get().map(|result| match result {
+                    Ok(week) => view! { <WeekSchedule week=week /> }.into_any(),
+                    Err(_) => view! { <p>{t.schedule.failed}</p> }.into_any(),
+                })}
+            </Suspense>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn WeekSchedule(week: WeekView) -> impl IntoView {
+    if week.sessions.is_empty() && week.extras.is_empty() {
+        return view! { <p style="color: #666;">{use_catalog().schedule.empty}</p> }.into_any();
+    }
+
+    let WeekView { sessions, cancellations, extras } = week;
+    let list_sessions = sessions.clone();
+
+    let list: ScheduleView = Box::new(move || view! { <ScheduleList sessions=list_sessions.clone() /> }.into_any());
+    let grid: ScheduleView = Box::new(move || view! { <ScheduleGrid sessions=sessions.clone() /> }.into_any());
+
+    view! {
+        <VStack spacing="3rem".to_string() align=VAlign::Stretch>
+            <CancellationNotices cancellations=cancellations />
+            <ExtraSessions extras=extras />
+            <ResponsiveView breakpoints=vec![(GRID_MIN_WIDTH, list)] fallback=grid />
+        </VStack>
+    }
+    .into_any()
+}
+
+/// Sessions grouped per weekday in a single column, for phones.
+#[component]
+fn ScheduleList(sessions: Vec<SessionView>) -> impl IntoView {
+    let primary_bg = "#332175";
+    let locale = use_locale();
+
+    WEEK.iter()
+        .filter_map(|weekday| {
+            let day: Vec<SessionView> = sessions.iter().filter(|s| s.session.weekday == *weekday).cloned().collect();
+            (!day.is_empty()).then(|| view! {
+                <VStack spacing="1rem".to_string() align=VAlign::Stretch style="margin-bottom: 2rem;">
+                    <h2 style=format!("color: {}; font-size: 1.4rem; font-weight: 700; margin: 0;", primary_bg)>
+                        {dates::weekday_short(*weekday, locale)}
+                    </h2>
+                    {day.into_iter().map(|s| view! { <SessionCard view=s /> }).collect_view()}
+                </VStack>
+            })
+        })
+        .collect_view()
+}
+
+/// One column per weekday that has classes.
+#[component]
+fn ScheduleGrid(sessions: Vec<SessionView>) -> impl IntoView {
+    let primary_bg = "#332175";
+    let accent_color = "#f2e300";
+    let locale = use_locale();
+
+    let days: Vec<_> = WEEK
+        .iter()
+        .filter(|weekday| sessions.iter().any(|s| s.session.weekday == **weekday))
+        .copied()
+        .collect();
+    let columns = days.len();
+
+    view! {
+        <div style=format!("display: grid; grid-template-columns: repeat({}, minmax(0, 1fr)); gap: 1rem; align-items: start;", columns)>
+            {days.into_iter().map(|weekday| {
+                let day: Vec<SessionView> = sessions.iter().filter(|s| s.session.weekday == weekday).cloned().collect();
+                view! {
+                    <VStack spacing="0.75rem".to_string() align=VAlign::Stretch>
+                        <h2 style=format!("margin: 0; padding: 0.6rem; text-align: center; font-size: 1rem; font-weight: 700; color: white; background-color: {}; border-bottom: 4px solid {}; border-radius: 8px 8px 0 0;", primary_bg, accent_color)>
+                            {dates::weekday_short(weekday, locale)}
+                        </h2>
+                        {day.into_iter().map(|s| view! { <SessionCard view=s /> }).collect_view()}
+                    </VStack>
+                }
+            }).collect_view()}
+        </div>
+    }
+}
+
+#[component]
+fn CancellationNotices(cancellations: Vec<Cancellation>) -> impl IntoView {
+    let accent_color = "#f2e300";
+    let locale = use_locale();
+
+    (!cancellations.is_empty()).then(|| view! {
+        <div style=format!("background: white; padding: 1.5rem 2rem; border-radius: 12px; border-left: 5px solid {}; box-shadow: 0 4px 15px rgba(0,0,0,0.05);", accent_color)>
+            <h2 style="margin: 0 0 1rem; font-size: 1.2rem; color: #333;">{locale.catalog().schedule.cancellations}</h2>
+            <ul style="margin: 0; padding-left: 1.2rem; display: flex; flex-direction: column; gap: 0.5rem; color: #444;">
+                {cancellations.into_iter().map(|c| view! {
+                    <li>
+                        <strong>{dates::day_month(c.date)}</strong>
+                        " — "{c.session.title(locale)}" ("{c.session.time_label(locale)}"): "{c.reason}
+                    </li>
+                }).collect_view()}
+            </ul>
+        </div>
+    })
+}
+
+#[component]
+fn ExtraSessions(extras: Vec<ExtraSession>) -> impl IntoView {
+    let primary_bg = "#332175";
+    let locale = use_locale();
+
+    (!extras.is_empty()).then(|| view! {
+        <div style=format!("background: white; padding: 1.5rem 2rem; border-radius: 12px; border-left: 5px solid {}; box-shadow: 0 4px 15px rgba(0,0,0,0.05);", primary_bg)>
+            <h2 style="margin: 0 0 1rem; font-size: 1.2rem; color: #333;">{locale.catalog().schedule.extras}</h2>
+            <ul style="margin: 0; padding-left: 1.2rem; display: flex; flex-direction: column; gap: 0.5rem; color: #444;">
+                {extras.into_iter().map(|e| view! {
+                    <li>
+                        <strong>{dates::day_month(e.date)}</strong>
+                        " — "{e.title(locale)}" ("{e.time_label(locale)}", "{e.location}"): "{e.reason}
+                    </li>
+                }).collect_view()}
+            </ul>
+        </div>
+    })
+}
+
+#[server(GetWeekSchedule, "/api/get_week_schedule")]
+pub async fn get_week_schedule() -> Result<WeekView, ServerFnError> {
+    use crate::content::events::now_local;
+    use crate::content::schedule::load_schedule;
+
+    // How far ahead cancellations and extra classes are announced.
+    const NOTICE_DAYS: i64 = 45;
+
+    let schedule = load_schedule().map_err(ServerFnError::new)?;
+    Ok(schedule.week_view(now_local().date(), NOTICE_DAYS))
+}
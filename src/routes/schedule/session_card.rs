@@ -0,0 +1,34 @@
+use leptos::prelude::*;
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::dates;
+use crate::content::schedule::SessionView;
+use crate::i18n::use_locale;
+
+#[component]
+pub fn SessionCard(view: SessionView) -> impl IntoView {
+    let SessionView { session, next_date, cancelled } = view;
+    let locale = use_locale();
+    let color = session.modality.color();
+    let opacity = if cancelled.is_some() { "0.6" } else { "1" };
+
+    view! {
+        <VStack
+            spacing="0.35rem".to_string()
+            align=VAlign::FlexStart
+            style=format!("background: white; padding: 1rem 1.2rem; border-radius: 10px; border-top: 4px solid {}; box-shadow: 0 4px 15px rgba(0,0,0,0.05); opacity: {};", color, opacity)
+        >
+            <span style="font-size: 1.1rem; font-weight: 800; color: #222;">{session.time_label(locale)}</span>
+            <span style=format!("font-size: 0.75rem; font-weight: 700; text-transform: uppercase; letter-spacing: 0.05em; color: {};", color)>
+                {session.modality.label(locale)}
+            </span>
+            <span style="font-size: 0.95rem; color: #333; font-weight: 600;">{session.age_group.clone()}</span>
+            <span style="font-size: 0.85rem; color: #666;">{session.location.clone()}</span>
+            <span style="font-size: 0.85rem; color: #666;">{session.instructor.clone()}</span>
+            {cancelled.map(|reason| view! {
+                <span style="margin-top: 0.4rem; font-size: 0.8rem; font-weight: 700; color: #b00020;">
+                    {locale.catalog().schedule.no_class_on}" "{dates::day_month(next_date)}": "{reason}
+                </span>
+            })}
+        </VStack>
+    }
+}
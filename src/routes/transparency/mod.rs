@@ -0,0 +1,157 @@
+use leptos::prelude::*;
+use crate::components::page_layout::PageLayout;
+use crate::components::seo::Seo;
+use crate::components::stacks::hstack::{HStack, AlignItems as HAlign, JustifyContent as HJustify, FlexWrap};
+use crate::components::stacks::vstack::{VStack, AlignItems as VAlign};
+use crate::content::transparency::{Table, TransparencyDocument, TransparencyReport};
+use crate::i18n::use_catalog;
+
+/// Portal de transparência ("Prestação de Contas") exigido pelos termos de fomento.
+#[component]
+pub fn TransparencyPage() -> impl IntoView {
+    let t = use_catalog();
+    let page = &t.pages.transparency;
+    let report = Resource::new(|| (), |_| get_transparency_report());
+
+    view! {
+        <Seo title=page.title description=page.subtitle/>
+        <PageLayout title=page.title subtitle=page.subtitle>
+            <Suspense fallback=move || view! { <p>"Carregando..."</p> }>
+                {move || report.get().map(|result| match result {
+                    Ok(TransparencyReport { documents, tables }) => view! {
+                        <VStack spacing="3.5rem".to_string() align=VAlign::Stretch>
+                            {tables.into_iter().map(|table| view! { <FinancialTable table=table /> }).collect_view()}
+                            <DocumentList documents=documents />
+                        </VStack>
+                    }.into_any(),
+                    Err(_) => view! { <p>"Não foi possível carregar os dados de transparência."</p> }.into_any(),
+                })}
+            </Suspense>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn FinancialTable(table: Table) -> impl IntoView {
+    let primary_bg = "#332175";
+    let csv_href = table.csv_href();
+    let Table { title, headers, rows, footer, .. } = table;
+
+    let cell_style = |numeric: bool| format!(
+        "padding: 0.7rem 1rem; border-bottom: 1px solid #eee; white-space: nowrap; text-align: {};",
+        if numeric { "right" } else { "left" }
+    );
+
+    view! {
+        <VStack spacing="1rem".to_string() align=VAlign::Stretch>
+            <HStack justify=HJustify::SpaceBetween align=HAlign::Center wrap=FlexWrap::Wrap spacing="1rem".to_string()>
+                <h2 style=format!("color: {}; font-size: 1.6rem; font-weight: 700; margin: 0;", primary_bg)>{title}</h2>
+                <a
+                    href=csv_href
+                    rel="external"
+                    download=""
+                    style=format!("padding: 0.5rem 1.2rem; border: 2px solid {}; color: {}; font-weight: 700; text-decoration: none; border-radius: 50px; font-size: 0.85rem;", primary_bg, primary_bg)
+                >
+                    "Baixar CSV"
+                </a>
+            </HStack>
+
+            {if rows.is_empty() {
+                view! { <p style="color: #666;">"Nenhum lançamento publicado."</p> }.into_any()
+            } else {
+                view! {
+                    <div style="overflow-x: auto; background: white; border-radius: 12px; box-shadow: 0 4px 15px rgba(0,0,0,0.05);">
+                        <table style="width: 100%; border-collapse: collapse; font-size: 0.95rem;">
+                            <thead>
+                                <tr style=format!("background-color: {}; color: white;", primary_bg)>
+                                    {headers.into_iter().map(|h| view! {
+                                        <th style="padding: 0.8rem 1rem; text-align: left; font-weight: 700;">{h}</th>
+                                    }).collect_view()}
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {rows.into_iter().map(|row| view! {
+                                    <tr>
+                                        {row.into_iter().map(|cell| view! {
+                                            <td style=cell_style(cell.is_numeric())>{cell.display()}</td>
+                                        }).collect_view()}
+                                    </tr>
+                                }).collect_view()}
+                            </tbody>
+                            {footer.map(|row| view! {
+                                <tfoot>
+                                    <tr style="font-weight: 800; background: #f9f9fb;">
+                                        {row.into_iter().map(|cell| view! {
+                                            <td style=cell_style(cell.is_numeric())>{cell.display()}</td>
+                                        }).collect_view()}
+                                    </tr>
+                                </tfoot>
+                            })}
+                        </table>
+                    </div>
+                }.into_any()
+            }}
+        </VStack>
+    }
+}
+
+#[component]
+fn DocumentList(documents: Vec<TransparencyDocument>) -> impl IntoView {
+    let primary_bg = "#332175";
+
+    let mut years: Vec<i32> = documents.iter().map(|d| d.fiscal_year).collect();
+    years.dedup();
+
+    view! {
+        <VStack spacing="1.5rem".to_string() align=VAlign::Stretch>
+            <h2 style=format!("color: {}; font-size: 1.6rem; font-weight: 700; margin: 0;", primary_bg)>"Documentos"</h2>
+            {if documents.is_empty() {
+                view! { <p style="color: #666;">"Nenhum documento publicado."</p> }.into_any()
+            } else {
+                years.into_iter().map(|year| {
+                    let of_year: Vec<_> = documents.iter().filter(|d| d.fiscal_year == year).cloned().collect();
+                    view! {
+                        <VStack spacing="0.75rem".to_string() align=VAlign::Stretch>
+                            <h3 style="margin: 0; font-size: 1.1rem; color: #444;">"Exercício "{year}</h3>
+                            {of_year.into_iter().map(|doc| view! { <DocumentRow document=doc /> }).collect_view()}
+                        </VStack>
+                    }
+                }).collect_view().into_any()
+            }}
+        </VStack>
+    }
+}
+
+#[component]
+fn DocumentRow(document: TransparencyDocument) -> impl IntoView {
+    let primary_bg = "#332175";
+    let href = document.href();
+
+    view! {
+        <a href=href target="_blank" rel="external" style="text-decoration: none; color: inherit;">
+            <HStack
+                align=HAlign::Center
+                justify=HJustify::SpaceBetween
+                wrap=FlexWrap::Wrap
+                spacing="1rem".to_string()
+                style="background: white; padding: 1rem 1.5rem; border-radius: 10px; box-shadow: 0 2px 8px rgba(0,0,0,0.04);"
+            >
+                <VStack spacing="0.25rem".to_string() align=VAlign::FlexStart>
+                    <span style="font-weight: 700; color: #222;">{document.title.clone()}</span>
+                    <span style="font-size: 0.85rem; color: #666;">{document.project.clone()}</span>
+                </VStack>
+                <span style=format!("font-size: 0.75rem; font-weight: 700; text-transform: uppercase; letter-spacing: 0.05em; color: {};", primary_bg)>
+                    {document.category.label()}" · PDF"
+                </span>
+            </HStack>
+        </a>
+    }
+}
+
+#[server(GetTransparencyReport, "/api/get_transparency_report")]
+pub async fn get_transparency_report() -> Result<TransparencyReport, ServerFnError> {
+    use crate::content::transparency::load_transparency;
+
+    let data = load_transparency().map_err(ServerFnError::new)?;
+    Ok(data.report())
+}
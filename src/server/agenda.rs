@@ -0,0 +1,38 @@
+use actix_web::{get, http::header, web, HttpResponse};
+
+use super::ical;
+use crate::content::events::load_events;
+
+const CALENDAR_NAME: &str = "Agenda Camará Capoeira";
+const CALENDAR_CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+
+/// Subscribable feed with every event, past and upcoming.
+#[get("/agenda.ics")]
+pub async fn agenda_feed() -> actix_web::Result<HttpResponse> {
+    // Reading the content file blocks; keep it off the async worker.
+    let events = web::block(load_events).await?.map_err(actix_web::error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok()
+        .content_type(CALENDAR_CONTENT_TYPE)
+        .body(ical::calendar(CALENDAR_NAME, &events)))
+}
+
+/// Single-event download, offered on each event detail page.
+#[get("/agenda/{slug}.ics")]
+pub async fn event_ics(slug: web::Path<String>) -> actix_web::Result<HttpResponse> {
+    let slug = slug.into_inner();
+    // Reading the content file blocks; keep it off the async worker.
+    let events = web::block(load_events).await?.map_err(actix_web::error::ErrorInternalServerError)?;
+
+    let Some(event) = events.into_iter().find(|e| e.slug == slug) else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+
+    Ok(HttpResponse::Ok()
+        .content_type(CALENDAR_CONTENT_TYPE)
+        .insert_header((
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{slug}.ics\""),
+        ))
+        .body(ical::calendar(CALENDAR_NAME, std::slice::from_ref(&event))))
+}
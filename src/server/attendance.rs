@@ -0,0 +1,137 @@
+use actix_session::Session;
+use actix_web::{get, http::header, web, HttpResponse};
+use chrono::Datelike;
+use std::collections::BTreeSet;
+
+use super::spreadsheet::{self, Sheet, Value};
+use crate::attendance::{store, summarize, AttendanceRecord, Month};
+use crate::auth::{require_role_for, Role};
+use crate::content::dates;
+use crate::i18n::Locale;
+use crate::content::schedule::{load_schedule, Schedule};
+use crate::db::{self, DbError, Pool};
+
+/// Totals per modality and age group, one row each, plus an overall total.
+fn summary_sheet(records: &[AttendanceRecord], schedule: &Schedule) -> Sheet {
+    let rows = summarize(records, schedule);
+    let students: BTreeSet<i64> = records.iter().filter(|r| r.present).map(|r| r.enrollment_id).collect();
+    let presences: u32 = rows.iter().map(|r| r.presences).sum();
+    let absences: u32 = rows.iter().map(|r| r.absences).sum();
+    let total_rate = match presences + absences {
+        0 => Value::Text(String::new()),
+        total => Value::Percent(f64::from(presences) / f64::from(total)),
+    };
+
+    let mut table: Vec<Vec<Value>> = rows
+        .iter()
+        .map(|row| vec![
+            row.modality.label(Locale::PtBr).into(),
+            row.age_group.as_str().into(),
+            row.meetings.into(),
+            row.students.into(),
+            row.presences.into(),
+            row.absences.into(),
+            row.rate().map_or(Value::Text(String::new()), Value::Percent),
+        ])
+        .collect();
+    table.push(vec![
+        "Total".into(),
+        "".into(),
+        rows.iter().map(|r| r.meetings).sum::<u32>().into(),
+        (students.len() as u32).into(),
+        presences.into(),
+        absences.into(),
+        total_rate,
+    ]);
+
+    Sheet {
+        name: "Resumo".to_string(),
+        headers: ["Modalidade", "Faixa etária", "Encontros realizados", "Alunos atendidos", "Presenças", "Faltas", "Frequência (%)"]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+        rows: table,
+    }
+}
+
+/// Every mark of the period, one line per student and meeting.
+fn detail_sheet(records: &[AttendanceRecord], schedule: &Schedule) -> Sheet {
+    Sheet {
+        name: "Chamadas".to_string(),
+        headers: ["Data", "Dia", "Turma", "Modalidade", "Faixa etária", "Núcleo", "Professor(a)", "Aluno(a)", "Presença"]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+        rows: records
+            .iter()
+            .map(|record| {
+                let session = schedule.session(&record.session_id);
+                vec![
+                    dates::day_month_year(record.date).into(),
+                    dates::weekday_short(record.date.weekday(), Locale::PtBr).into(),
+                    session.map_or_else(|| record.session_id.clone(), |s| s.title(Locale::PtBr)).into(),
+                    session.map_or("", |s| s.modality.label(Locale::PtBr)).into(),
+                    session.map_or("", |s| s.age_group.as_str()).into(),
+                    session.map_or("", |s| s.location.as_str()).into(),
+                    session.map_or("", |s| s.instructor.as_str()).into(),
+                    record.student_name.as_str().into(),
+                    if record.present { "Presente" } else { "Falta" }.into(),
+                ]
+            })
+            .collect(),
+    }
+}
+
+/// Summary and detail sheets of `month`, or `None` if it is not a valid `AAAA-MM`.
+async fn month_sheets(pool: &Pool, month: &str) -> actix_web::Result<Option<(Month, Sheet, Sheet)>> {
+    let Some((month, from, until)) = Month::parse(month).and_then(|m| Some((m, m.first_day()?, m.end()?))) else {
+        return Ok(None);
+    };
+
+    let schedule = load_schedule().map_err(actix_web::error::ErrorInternalServerError)?;
+    let records = db::with_pool(pool.clone(), move |conn| store::records_between(conn, from, until))
+        .await
+        .map_err(|e: DbError| actix_web::error::ErrorInternalServerError(e))?;
+
+    Ok(Some((month, summary_sheet(&records, &schedule), detail_sheet(&records, &schedule))))
+}
+
+fn download(body: Vec<u8>, content_type: &str, file_name: String) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(content_type)
+        .insert_header((header::CONTENT_DISPOSITION, format!("attachment; filename=\"{file_name}\"")))
+        .body(body)
+}
+
+/// `/admin/frequencia/2026-03/resumo.csv`
+#[get("/admin/frequencia/{month}/resumo.csv")]
+pub async fn summary_csv(session: Session, pool: web::Data<Pool>, month: web::Path<String>) -> actix_web::Result<HttpResponse> {
+    require_role_for(&session, &pool, Role::Voluntario).await?;
+    let Some((month, summary, _)) = month_sheets(&pool, &month).await? else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+    let body = spreadsheet::to_csv(&summary).map_err(actix_web::error::ErrorInternalServerError)?;
+    Ok(download(body, "text/csv; charset=utf-8", format!("frequencia-resumo-{month}.csv")))
+}
+
+/// `/admin/frequencia/2026-03/chamadas.csv`
+#[get("/admin/frequencia/{month}/chamadas.csv")]
+pub async fn detail_csv(session: Session, pool: web::Data<Pool>, month: web::Path<String>) -> actix_web::Result<HttpResponse> {
+    require_role_for(&session, &pool, Role::Voluntario).await?;
+    let Some((month, _, detail)) = month_sheets(&pool, &month).await? else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+    let body = spreadsheet::to_csv(&detail).map_err(actix_web::error::ErrorInternalServerError)?;
+    Ok(download(body, "text/csv; charset=utf-8", format!("frequencia-chamadas-{month}.csv")))
+}
+
+/// `/admin/frequencia/2026-03.ods`, with both sheets.
+#[get("/admin/frequencia/{month}.ods")]
+pub async fn report_ods(session: Session, pool: web::Data<Pool>, month: web::Path<String>) -> actix_web::Result<HttpResponse> {
+    require_role_for(&session, &pool, Role::Voluntario).await?;
+    let Some((month, summary, detail)) = month_sheets(&pool, &month).await? else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+    let body = spreadsheet::to_ods(&[summary, detail]).map_err(actix_web::error::ErrorInternalServerError)?;
+    Ok(download(body, "application/vnd.oasis.opendocument.spreadsheet", format!("frequencia-{month}.ods")))
+}
@@ -0,0 +1,183 @@
+//! Endpoints for the deploy script and the uptime monitor.
+//!
+//! `/healthz` answers as soon as the server accepts requests; `/readyz` also
+//! checks what pages need (media, database, the `pkg` bundle) and answers
+//! `503` until all of it is there. The probe is public, so it only says which
+//! check failed; the reason (paths, database errors) goes to the log. `/version` says which build is running,
+//! from what `build.rs` embedded at compile time.
+
+use std::path::Path;
+use std::time::Duration;
+
+use actix_web::http::header::{CacheControl, CacheDirective};
+use actix_web::{get, web, HttpResponse};
+use chrono::DateTime;
+use leptos::config::LeptosOptions;
+use serde::Serialize;
+
+use crate::db::Pool;
+use crate::settings::Settings;
+
+/// A readiness probe should fail quickly rather than wait for the pool's default 30 s.
+const DATABASE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// What the running binary was built from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BuildInfo {
+    pub version: &'static str,
+    /// Abbreviated hash, `unknown` when built outside a git checkout.
+    pub commit: &'static str,
+    /// RFC 3339, UTC.
+    pub built_at: String,
+}
+
+impl BuildInfo {
+    pub fn current() -> BuildInfo {
+        let built_at = env!("CAMARA_BUILD_TIMESTAMP")
+            .parse()
+            .ok()
+            .and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+            .map(|time| time.to_rfc3339())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        BuildInfo {
+            version: env!("CARGO_PKG_VERSION"),
+            commit: env!("CAMARA_GIT_COMMIT"),
+            built_at,
+        }
+    }
+}
+
+/// Outcome of one `/readyz` check, as published: `ok` or `failed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Check {
+    Ok,
+    Failed,
+}
+
+impl Check {
+    /// Logs why `name` failed and keeps only the outcome for the response.
+    fn logged(name: &'static str, result: Result<(), String>) -> Check {
+        match result {
+            Ok(()) => Check::Ok,
+            Err(error) => {
+                tracing::error!(check = name, %error, "readiness check failed");
+                Check::Failed
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Readiness {
+    pub media: Check,
+    pub database: Check,
+    pub pkg: Check,
+}
+
+impl Readiness {
+    pub fn is_ready(&self) -> bool {
+        [&self.media, &self.database, &self.pkg].iter().all(|check| **check == Check::Ok)
+    }
+}
+
+/// The media root can be listed.
+fn check_media(media_root: &Path) -> Result<(), String> {
+    std::fs::read_dir(media_root).map(drop).map_err(|e| format!("{}: {e}", media_root.display()))
+}
+
+/// The site root holds the bundle cargo-leptos builds (`<site-pkg>/<output-name>.js`).
+fn check_pkg(options: &LeptosOptions) -> Result<(), String> {
+    let script = Path::new(options.site_root.as_ref())
+        .join(options.site_pkg_dir.as_ref())
+        .join(format!("{}.js", options.output_name));
+    if script.is_file() {
+        Ok(())
+    } else {
+        Err(format!("{} not found", script.display()))
+    }
+}
+
+/// A connection can be taken from the pool and answers a query.
+async fn check_database(pool: Pool) -> Result<(), String> {
+    let result = web::block(move || -> Result<(), String> {
+        let conn = pool.get_timeout(DATABASE_TIMEOUT).map_err(|e| e.to_string())?;
+        conn.query_row("SELECT 1", [], |_| Ok(())).map_err(|e| e.to_string())
+    })
+    .await;
+    result.unwrap_or_else(|_| Err("database task was interrupted".to_string()))
+}
+
+fn uncached() -> CacheControl {
+    CacheControl(vec![CacheDirective::NoStore])
+}
+
+#[get("/healthz")]
+pub async fn healthz() -> HttpResponse {
+    HttpResponse::Ok().insert_header(uncached()).content_type("text/plain; charset=utf-8").body("ok\n")
+}
+
+#[get("/readyz")]
+pub async fn readyz(
+    settings: web::Data<Settings>,
+    options: web::Data<LeptosOptions>,
+    pool: web::Data<Pool>,
+) -> HttpResponse {
+    let readiness = Readiness {
+        media: Check::logged("media", check_media(&settings.media_root)),
+        database: Check::logged("database", check_database(pool.get_ref().clone()).await),
+        pkg: Check::logged("pkg", check_pkg(&options)),
+    };
+
+    let mut response = if readiness.is_ready() {
+        HttpResponse::Ok()
+    } else {
+        HttpResponse::ServiceUnavailable()
+    };
+    response.insert_header(uncached()).json(readiness)
+}
+
+#[get("/version")]
+pub async fn version() -> HttpResponse {
+    HttpResponse::Ok().insert_header(uncached()).json(BuildInfo::current())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn readiness_reports_each_check() {
+        let dir = tempfile::tempdir().unwrap();
+        let options = LeptosOptions::builder()
+            .output_name("camaracapoeira")
+            .site_root(dir.path().to_string_lossy().to_string())
+            .build();
+
+        assert_eq!(check_media(dir.path()), Ok(()));
+        assert!(check_media(&dir.path().join("acervo")).is_err());
+        assert!(check_pkg(&options).is_err());
+
+        std::fs::create_dir(dir.path().join("pkg")).unwrap();
+        std::fs::write(dir.path().join("pkg/camaracapoeira.js"), "").unwrap();
+        assert_eq!(check_pkg(&options), Ok(()));
+
+        // The reason stays in the log: the public body only names the failed check.
+        let database = Check::logged("database", Err(format!("{}: database is locked", dir.path().display())));
+        let readiness = Readiness { media: Check::Ok, database, pkg: Check::Ok };
+        assert!(!readiness.is_ready());
+        assert_eq!(
+            serde_json::to_value(&readiness).unwrap(),
+            serde_json::json!({ "media": "ok", "database": "failed", "pkg": "ok" })
+        );
+    }
+
+    #[test]
+    fn build_info_is_embedded() {
+        let info = BuildInfo::current();
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+        assert!(!info.commit.is_empty());
+        assert!(DateTime::parse_from_rfc3339(&info.built_at).is_ok());
+    }
+}
@@ -0,0 +1,138 @@
+//! Minimal RFC 5545 (iCalendar) writer for the public agenda.
+
+use chrono::{NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::fmt::Write;
+
+use crate::content::events::{Event, TIMEZONE};
+use crate::i18n::Locale;
+
+const PRODID: &str = "-//Associação Camará Capoeira//Agenda//PT-BR";
+const UID_DOMAIN: &str = "camaracapoeira.org.br";
+/// Events without an explicit end are exported as two hours long.
+const DEFAULT_DURATION_HOURS: i64 = 2;
+
+/// Renders a `VCALENDAR` containing one `VEVENT` per event.
+pub fn calendar(name: &str, events: &[Event]) -> String {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    let mut out = String::with_capacity(512 + events.len() * 512);
+
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, &format!("PRODID:{PRODID}"));
+    push_line(&mut out, "CALSCALE:GREGORIAN");
+    push_line(&mut out, "METHOD:PUBLISH");
+    push_line(&mut out, &format!("X-WR-CALNAME:{}", escape_text(name)));
+    push_line(&mut out, &format!("X-WR-TIMEZONE:{}", TIMEZONE.name()));
+
+    for event in events {
+        let end = event
+            .end
+            .unwrap_or(event.start + chrono::Duration::hours(DEFAULT_DURATION_HOURS));
+
+        push_line(&mut out, "BEGIN:VEVENT");
+        push_line(&mut out, &format!("UID:{}@{UID_DOMAIN}", event.slug));
+        push_line(&mut out, &format!("DTSTAMP:{stamp}"));
+        push_line(&mut out, &format!("DTSTART:{}", to_utc(event.start, TIMEZONE)));
+        push_line(&mut out, &format!("DTEND:{}", to_utc(end, TIMEZONE)));
+        push_line(&mut out, &format!("SUMMARY:{}", escape_text(&event.title)));
+        push_line(&mut out, &format!("CATEGORIES:{}", escape_text(event.kind.label(Locale::PtBr))));
+
+        let location = match &event.address {
+            Some(address) => format!("{} - {}", event.location, address),
+            None => event.location.clone(),
+        };
+        push_line(&mut out, &format!("LOCATION:{}", escape_text(&location)));
+
+        if !event.description.is_empty() {
+            push_line(&mut out, &format!("DESCRIPTION:{}", escape_text(event.description.trim())));
+        }
+        push_line(&mut out, "END:VEVENT");
+    }
+
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+/// Converts a wall-clock time in `timezone` (Campo Grande for the agenda) to
+/// the UTC "form #2" of RFC 5545.
+fn to_utc(local: NaiveDateTime, timezone: Tz) -> String {
+    let utc = timezone
+        .from_local_datetime(&local)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+        // Inexistent local times (DST gaps) fall back to treating the input as UTC.
+        .unwrap_or_else(|| Utc.from_utc_datetime(&local));
+    utc.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escapes a TEXT value (RFC 5545 §3.3.11).
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Appends a content line, folded at 75 octets and terminated by CRLF (RFC 5545 §3.1).
+fn push_line(out: &mut String, line: &str) {
+    let mut octets = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if octets + len > 75 {
+            out.push_str("\r\n ");
+            octets = 1;
+        }
+        let _ = out.write_char(c);
+        octets += len;
+    }
+    out.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_values_escape_separators() {
+        assert_eq!(escape_text("Roda, samba; maculelê"), r"Roda\, samba\; maculelê");
+        assert_eq!(escape_text("C:\\capoeira\r\nsegunda linha"), "C:\\\\capoeira\\nsegunda linha");
+    }
+
+    #[test]
+    fn long_lines_fold_between_characters() {
+        let summary = format!("SUMMARY:{}", "Batizado e Troca de Cordas — Camará Capoeira ação ".repeat(4));
+        let mut out = String::new();
+        push_line(&mut out, &summary);
+
+        let lines: Vec<&str> = out.strip_suffix("\r\n").unwrap().split("\r\n").collect();
+        assert!(lines.len() > 1);
+        assert!(lines.iter().all(|line| line.len() <= 75));
+        assert!(lines[1..].iter().all(|line| line.starts_with(' ')));
+        // Unfolding gives back the original line, so no character was split.
+        let unfolded: String = lines.iter().enumerate().map(|(i, line)| if i == 0 { *line } else { &line[1..] }).collect();
+        assert_eq!(unfolded, summary);
+
+        // A two-octet "ã" that would end at octet 76 goes to the next line whole.
+        let mut out = String::new();
+        push_line(&mut out, &format!("SUMMARY:{}", "ã".repeat(40)));
+        assert_eq!(out.split("\r\n").next().unwrap().len(), 74);
+    }
+
+    #[test]
+    fn local_times_become_utc() {
+        let local = NaiveDateTime::parse_from_str("2026-01-15 19:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        // No daylight saving time in Brazil since 2019: summer is UTC-3 in São Paulo.
+        assert_eq!(to_utc(local, chrono_tz::America::Sao_Paulo), "20260115T220000Z");
+        assert_eq!(to_utc(local, TIMEZONE), "20260115T230000Z");
+    }
+}
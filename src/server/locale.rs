@@ -0,0 +1,81 @@
+//! Chooses the language of a first visit and remembers the visitor's choice.
+//!
+//! A page request without the [`i18n::COOKIE`] is redirected to the version
+//! that best matches `Accept-Language`; every page response then stores the
+//! language being viewed, so the switcher in the header (and a link shared in
+//! another language) decides from there on.
+
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::cookie::{time::Duration, Cookie, SameSite};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::{header, Method};
+use actix_web::middleware::Next;
+use actix_web::HttpResponse;
+
+use crate::i18n::{self, Locale};
+
+/// Paths that are never pages in another language: files, server functions
+/// and the admin area, which is Portuguese only.
+const SKIP: &[&str] = &["/api/", "/pkg/", "/assets/", "/admin"];
+
+/// Middleware (`middleware::from_fn(locale::negotiate)`).
+pub async fn negotiate(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, actix_web::Error> {
+    if !is_page_request(&req) {
+        return next.call(req).await.map(ServiceResponse::map_into_left_body);
+    }
+
+    let (locale, rest) = Locale::from_path(req.path());
+    let remembered = req.cookie(i18n::COOKIE).and_then(|cookie| Locale::parse(cookie.value()));
+
+    if remembered.is_none() && locale == Locale::PtBr {
+        let preferred = req
+            .headers()
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .map_or(Locale::PtBr, Locale::negotiate);
+        if preferred != Locale::PtBr {
+            let mut location = preferred.href(rest);
+            if !req.query_string().is_empty() {
+                location = format!("{location}?{}", req.query_string());
+            }
+            let response = HttpResponse::Found()
+                .insert_header((header::LOCATION, location))
+                .insert_header((header::VARY, "Accept-Language"))
+                .cookie(cookie(preferred))
+                .finish();
+            return Ok(req.into_response(response).map_into_right_body());
+        }
+    }
+
+    let mut res = next.call(req).await?;
+    if remembered != Some(locale) {
+        res.response_mut().add_cookie(&cookie(locale))?;
+    }
+    Ok(res.map_into_left_body())
+}
+
+fn is_page_request(req: &ServiceRequest) -> bool {
+    let path = req.path();
+    let accepts_html = req
+        .headers()
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|accept| accept.contains("text/html"));
+    let last_segment = path.rsplit('/').next().unwrap_or_default();
+
+    req.method() == Method::GET
+        && accepts_html
+        && !SKIP.iter().any(|prefix| path.starts_with(prefix))
+        && !last_segment.contains('.')
+}
+
+fn cookie(locale: Locale) -> Cookie<'static> {
+    Cookie::build(i18n::COOKIE, locale.tag())
+        .path("/")
+        .same_site(SameSite::Lax)
+        .max_age(Duration::days(365))
+        .finish()
+}
@@ -0,0 +1,38 @@
+//! `/admin/log-level`: reads or changes the log level without a restart.
+//!
+//! `curl -b <session cookie> -X PUT --data debug https://…/admin/log-level`
+//! turns on debug output while chasing a problem; the level returns to the
+//! `log_level` setting at the next restart.
+
+use actix_session::Session;
+use actix_web::{get, put, web, HttpResponse};
+
+use crate::auth::{require_role_for, Role};
+use crate::db::Pool;
+use crate::logging::LogControl;
+use crate::settings::LogLevel;
+
+#[get("/admin/log-level")]
+pub async fn current(session: Session, pool: web::Data<Pool>, control: web::Data<LogControl>) -> actix_web::Result<HttpResponse> {
+    require_role_for(&session, &pool, Role::Admin).await?;
+    let level = control.level().map_or("off", LogLevel::as_str);
+    Ok(HttpResponse::Ok().content_type("text/plain; charset=utf-8").body(format!("{level}\n")))
+}
+
+#[put("/admin/log-level")]
+pub async fn change(
+    session: Session,
+    pool: web::Data<Pool>,
+    control: web::Data<LogControl>,
+    body: String,
+) -> actix_web::Result<HttpResponse> {
+    let user = require_role_for(&session, &pool, Role::Admin).await?;
+    let Some(level) = LogLevel::parse(body.trim()) else {
+        let known: Vec<_> = LogLevel::ALL.iter().map(|level| level.as_str()).collect();
+        return Ok(HttpResponse::BadRequest().body(format!("expected one of {}\n", known.join(", "))));
+    };
+
+    control.set_level(level).map_err(actix_web::error::ErrorInternalServerError)?;
+    tracing::warn!(level = level.as_str(), user = %user.email, "log level changed");
+    Ok(HttpResponse::Ok().content_type("text/plain; charset=utf-8").body(format!("{}\n", level.as_str())))
+}
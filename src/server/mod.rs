@@ -0,0 +1,17 @@
+//! Plain actix handlers served next to the Leptos routes (feeds, downloads, reports).
+
+pub mod agenda;
+pub mod attendance;
+pub mod health;
+pub mod ical;
+pub mod locale;
+pub mod log_level;
+pub mod newsletter;
+pub mod prerender;
+pub mod request_id;
+pub mod robots;
+pub mod security_headers;
+pub mod share_image;
+pub mod sitemap;
+pub mod spreadsheet;
+pub mod transparency;
@@ -0,0 +1,32 @@
+use actix_web::{post, web, HttpRequest, HttpResponse};
+
+use crate::audit::Recorder;
+use crate::db::Pool;
+
+/// One-click unsubscribe (RFC 8058): mail clients POST here, with the
+/// `List-Unsubscribe=One-Click` body, when the reader clicks "unsubscribe".
+/// Browsers land on the Leptos page at the same path with a GET.
+#[post("/newsletter/sair/{token}")]
+pub async fn one_click_unsubscribe(
+    req: HttpRequest,
+    token: web::Path<String>,
+    pool: web::Data<Pool>,
+) -> actix_web::Result<HttpResponse> {
+    let token = token.into_inner();
+    let recorder = Recorder::for_request(Some(&req), None);
+    let now = crate::content::events::now_local();
+
+    let found = crate::db::with_pool(pool.get_ref().clone(), move |conn| {
+        let tx = conn.transaction().map_err(crate::db::DbError::from)?;
+        let found = crate::newsletter::unsubscribe(&tx, &recorder, &token, now)?;
+        tx.commit().map_err(crate::db::DbError::from)?;
+        Ok::<_, crate::db::DbError>(found)
+    })
+    .await
+    .map_err(actix_web::error::ErrorInternalServerError)?;
+
+    Ok(match found {
+        true => HttpResponse::Ok().body("Inscrição cancelada."),
+        false => HttpResponse::NotFound().finish(),
+    })
+}
@@ -0,0 +1,248 @@
+//! `camaracapoeira prerender`: a static copy of the public pages.
+//!
+//! Every page of the sitemap, in the three languages, goes through the same
+//! Leptos SSR as a live request (fully resolved, like `SsrMode::Async`): a
+//! one-worker server is bound to a free port on 127.0.0.1 for the run and each
+//! page is fetched from it over HTTP. A page that does not answer `2xx` stops
+//! the run with an error rather than leaving a gap in the copy. Pages are
+//! written to `<site-root>/<path>/index.html`, next to the `pkg` bundle and the
+//! assets, so the site root can be uploaded to any static host while the
+//! server is down. Pages still hydrate; what needs the server (forms, admin,
+//! searches) fails until it is back.
+//!
+//! Images are linked under `/assets/`, which the live server maps to the
+//! media root; the media root is copied to `<site-root>/assets` so the same
+//! links work from a static host.
+
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+
+use actix_web::{web, App, HttpServer};
+use leptos::config::LeptosOptions;
+use leptos::prelude::provide_context;
+use leptos_actix::render_app_async_with_context;
+
+use super::sitemap::{self, SiteIndex};
+use crate::app::shell;
+use crate::db::Pool;
+use crate::i18n::Locale;
+use crate::settings::Settings;
+
+/// Rendered as `404.html`, which static hosts serve for unknown paths.
+const NOT_FOUND_PATH: &str = "/404";
+
+/// Where the media root goes inside the site root: `/assets/`, as live.
+const ASSETS_DIR: &str = "assets";
+
+/// In context while pre-rendering; random choices (the home slideshow) use
+/// [`Prerender::seed`] so that two runs produce the same files.
+#[derive(Debug, Clone, Copy)]
+pub struct Prerender {
+    /// The `prerender_seed` setting.
+    pub seed: u64,
+}
+
+/// Renders every public page into the site root.
+pub async fn run(options: LeptosOptions, pool: Pool, settings: Settings, site: &SiteIndex) -> std::io::Result<()> {
+    let entries = sitemap::entries(site.pages()).map_err(std::io::Error::other)?;
+    let seed = settings.prerender_seed;
+    let site_root = PathBuf::from(options.site_root.as_ref());
+    let media = copy_media(&settings.media_root, &site_root, &options.site_pkg_dir)?;
+    tracing::info!(files = media, "prerender: media copied to {ASSETS_DIR}/");
+
+    let provide_state = {
+        let pool = pool.clone();
+        move || {
+            provide_context(pool.clone());
+            provide_context(settings.mail.clone());
+            provide_context(settings.clone());
+            provide_context(Prerender { seed });
+        }
+    };
+    let server = HttpServer::new(move || {
+        let options = options.clone();
+        App::new()
+            .app_data(web::Data::new(options.clone()))
+            .app_data(web::Data::new(pool.clone()))
+            .route(
+                "/{tail:.*}",
+                render_app_async_with_context(provide_state.clone(), move || shell(options.clone()), leptos_router::Method::Get),
+            )
+    })
+    .workers(1)
+    .disable_signals()
+    .bind(("127.0.0.1", 0))?;
+    let addr = server.addrs()[0];
+    let server = server.run();
+    let handle = server.handle();
+    actix_web::rt::spawn(server);
+
+    let paths: Vec<String> = entries
+        .iter()
+        .flat_map(|entry| Locale::ALL.map(|locale| locale.href(&entry.path)))
+        .chain([NOT_FOUND_PATH.to_string()])
+        .collect();
+    let written = {
+        let site_root = site_root.clone();
+        web::block(move || write_pages(addr, &paths, &site_root)).await.map_err(std::io::Error::other)
+    };
+    handle.stop(true).await;
+    let written = written??;
+
+    tracing::info!(written, site_root = %site_root.display(), "prerender: pages written");
+    Ok(())
+}
+
+/// Fetches each path from the server at `addr` and writes it under the site
+/// root. Pages must answer `2xx`; the 404 page, `404`.
+fn write_pages(addr: SocketAddr, paths: &[String], site_root: &Path) -> std::io::Result<usize> {
+    let agent: ureq::Agent = ureq::Agent::config_builder().http_status_as_error(false).build().into();
+    let mut written = 0;
+    for path in paths {
+        let mut response = agent.get(format!("http://{addr}{path}")).call().map_err(std::io::Error::other)?;
+        let status = response.status();
+        let expected = if path == NOT_FOUND_PATH { status.as_u16() == 404 } else { status.is_success() };
+        if !expected {
+            return Err(std::io::Error::other(format!("prerender: {path} answered {status}")));
+        }
+
+        let body = response.body_mut().read_to_vec().map_err(std::io::Error::other)?;
+        let file = output_file(site_root, path);
+        if let Some(dir) = file.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&file, body)?;
+        written += 1;
+    }
+    Ok(written)
+}
+
+/// Copies the media root to `<site-root>/assets`, replacing an older copy,
+/// and returns how many files were copied.
+///
+/// With the default `media_root` (the site root itself) the media sit next to
+/// the `pkg` bundle and the pages of earlier runs; those are left out.
+fn copy_media(media_root: &Path, site_root: &Path, pkg_dir: &str) -> std::io::Result<u64> {
+    let media_root = media_root.canonicalize()?;
+    let site_root = site_root.canonicalize()?;
+    let assets = site_root.join(ASSETS_DIR);
+    if media_root.starts_with(&assets) || assets.canonicalize().is_ok_and(|assets| assets == media_root) {
+        // Already there (or linked); nothing to copy, and nothing to delete.
+        return Ok(0);
+    }
+    if assets.exists() {
+        std::fs::remove_dir_all(&assets)?;
+    }
+
+    let from_site_root = media_root == site_root;
+    let skip = [site_root.join(pkg_dir), assets.clone()];
+    copy_tree(&media_root, &assets, &skip, from_site_root)
+}
+
+fn copy_tree(from: &Path, to: &Path, skip: &[PathBuf], skip_pages: bool) -> std::io::Result<u64> {
+    let mut copied = 0;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let path = entry.path();
+        if skip.contains(&path) {
+            continue;
+        }
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copied += copy_tree(&path, &target, skip, skip_pages)?;
+        } else if !(skip_pages && matches!(entry.file_name().to_str(), Some("index.html" | "404.html"))) {
+            std::fs::create_dir_all(to)?;
+            std::fs::copy(&path, &target)?;
+            copied += 1;
+        }
+    }
+    Ok(copied)
+}
+
+/// `/` → `index.html`, `/es/agenda` → `es/agenda/index.html`, the 404 page → `404.html`.
+fn output_file(site_root: &Path, path: &str) -> PathBuf {
+    if path == NOT_FOUND_PATH {
+        return site_root.join("404.html");
+    }
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .fold(site_root.to_path_buf(), |dir, segment| dir.join(segment))
+        .join("index.html")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pages_become_index_files() {
+        let root = Path::new("target/site");
+        assert_eq!(output_file(root, "/"), root.join("index.html"));
+        assert_eq!(output_file(root, "/es"), root.join("es/index.html"));
+        assert_eq!(output_file(root, "/en/noticias/roda-na-praca"), root.join("en/noticias/roda-na-praca/index.html"));
+        assert_eq!(output_file(root, NOT_FOUND_PATH), root.join("404.html"));
+    }
+
+    #[actix_web::test]
+    async fn pages_are_fetched_over_http_and_errors_stop_the_run() {
+        use actix_web::HttpResponse;
+
+        let server = HttpServer::new(|| {
+            App::new()
+                .route("/", web::get().to(|| async { HttpResponse::Ok().body("inicio") }))
+                .route("/404", web::get().to(|| async { HttpResponse::NotFound().body("nada aqui") }))
+                .route("/quebrada", web::get().to(|| async { HttpResponse::InternalServerError().finish() }))
+        })
+        .workers(1)
+        .disable_signals()
+        .bind(("127.0.0.1", 0))
+        .unwrap();
+        let addr = server.addrs()[0];
+        let server = server.run();
+        let handle = server.handle();
+        actix_web::rt::spawn(server);
+
+        let site = tempfile::tempdir().unwrap();
+        let root = site.path().to_path_buf();
+        let fetch = move |paths: &[&str]| {
+            let paths: Vec<String> = paths.iter().map(|path| path.to_string()).collect();
+            let root = root.clone();
+            web::block(move || write_pages(addr, &paths, &root))
+        };
+
+        assert_eq!(fetch(&["/", NOT_FOUND_PATH]).await.unwrap().unwrap(), 2);
+        assert_eq!(std::fs::read_to_string(site.path().join("index.html")).unwrap(), "inicio");
+        assert_eq!(std::fs::read_to_string(site.path().join("404.html")).unwrap(), "nada aqui");
+
+        let error = fetch(&["/quebrada"]).await.unwrap().unwrap_err();
+        assert!(error.to_string().contains("/quebrada answered 500"), "{error}");
+        assert!(!site.path().join("quebrada").exists());
+
+        handle.stop(true).await;
+    }
+
+    #[test]
+    fn media_is_copied_under_assets_without_the_bundle_or_pages() {
+        let site = tempfile::tempdir().unwrap();
+        let root = site.path();
+        for file in ["logo.svg", "fotos/roda.jpg", "pkg/camaracapoeira.js", "index.html", "agenda/index.html", "assets/antiga.jpg"] {
+            std::fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+            std::fs::write(root.join(file), file).unwrap();
+        }
+
+        assert_eq!(copy_media(root, root, "pkg").unwrap(), 2);
+        assert!(root.join("assets/logo.svg").is_file());
+        assert!(root.join("assets/fotos/roda.jpg").is_file());
+        assert!(!root.join("assets/pkg").exists());
+        assert!(!root.join("assets/index.html").exists());
+        assert!(!root.join("assets/agenda").exists());
+        assert!(!root.join("assets/antiga.jpg").exists());
+
+        // A media root elsewhere is copied whole.
+        let media = tempfile::tempdir().unwrap();
+        std::fs::write(media.path().join("index.html"), "").unwrap();
+        assert_eq!(copy_media(media.path(), root, "pkg").unwrap(), 1);
+        assert!(root.join("assets/index.html").is_file());
+        assert!(!root.join("assets/logo.svg").exists());
+    }
+}
@@ -0,0 +1,70 @@
+//! Gives every request an id, kept in the request extensions and returned in
+//! the `X-Request-Id` header, so an audit entry can be matched to the request
+//! (and the logs) that produced it.
+//!
+//! The rest of the request runs inside a `request` span carrying the id,
+//! method and path, so every event logged while handling it (server
+//! functions and the SSR render included) can be traced back; when it is done
+//! one event reports the status and how long it took.
+
+use std::time::Instant;
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header::{HeaderName, HeaderValue};
+use actix_web::http::StatusCode;
+use actix_web::middleware::Next;
+use actix_web::{HttpMessage, HttpRequest};
+use tracing::Instrument;
+use uuid::Uuid;
+
+pub const HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestId(pub Uuid);
+
+impl RequestId {
+    /// The id assigned to `req` by [`assign`], if the middleware ran.
+    pub fn of(req: &HttpRequest) -> Option<RequestId> {
+        req.extensions().get::<RequestId>().copied()
+    }
+}
+
+/// Middleware (`middleware::from_fn(request_id::assign)`).
+pub async fn assign(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let id = RequestId(Uuid::new_v4());
+    req.extensions_mut().insert(id);
+
+    let span = tracing::info_span!("request", id = %id.0, method = %req.method(), path = %req.path());
+    let started = Instant::now();
+    let result = next.call(req).instrument(span.clone()).await;
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    let _entered = span.enter();
+    match result {
+        Ok(mut res) => {
+            log_completion(res.status(), latency_ms, res.response().error());
+            if let Ok(value) = HeaderValue::from_str(&id.0.to_string()) {
+                res.headers_mut().insert(HEADER, value);
+            }
+            Ok(res)
+        }
+        Err(e) => {
+            log_completion(e.as_response_error().status_code(), latency_ms, Some(&e));
+            Err(e)
+        }
+    }
+}
+
+/// Server errors at `error`, with the cause; everything else (404s included) at `info`.
+fn log_completion(status: StatusCode, latency_ms: u64, error: Option<&actix_web::Error>) {
+    let status = status.as_u16();
+    match error {
+        _ if status < 500 => tracing::info!(status, latency_ms, "request completed"),
+        Some(error) => tracing::error!(status, latency_ms, %error, "request failed"),
+        None => tracing::error!(status, latency_ms, "request failed"),
+    }
+}
@@ -0,0 +1,61 @@
+//! `/robots.txt`, configured in `content/robots.toml`.
+
+use actix_web::{get, web, HttpResponse};
+use serde::Deserialize;
+
+use super::sitemap::SiteIndex;
+use crate::content::{load_toml, ContentError};
+use crate::i18n::Locale;
+
+/// Contents of `content/robots.toml`; a missing file means the defaults.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct RobotsConfig {
+    /// `false` asks every crawler to stay away (staging copies of the site).
+    pub index: bool,
+    /// Paths crawlers should skip.
+    pub disallow: Vec<String>,
+}
+
+/// Pages kept out of search engines, in every language.
+const PRIVATE_PATHS: [&str; 3] = ["/admin", "/entrar", "/newsletter/"];
+
+impl Default for RobotsConfig {
+    fn default() -> Self {
+        RobotsConfig {
+            index: true,
+            disallow: Locale::ALL
+                .into_iter()
+                .flat_map(|locale| PRIVATE_PATHS.map(|path| format!("{}{path}", locale.base())))
+                .chain(["/api/".to_string()])
+                .collect(),
+        }
+    }
+}
+
+pub fn load_robots() -> Result<RobotsConfig, ContentError> {
+    load_toml::<RobotsConfig>("robots.toml")
+}
+
+pub fn render(config: &RobotsConfig, site_url: &str) -> String {
+    let mut out = String::from("User-agent: *\n");
+    if config.index {
+        for path in &config.disallow {
+            out.push_str(&format!("Disallow: {path}\n"));
+        }
+        out.push_str(&format!("\nSitemap: {site_url}/sitemap.xml\n"));
+    } else {
+        out.push_str("Disallow: /\n");
+    }
+    out
+}
+
+#[get("/robots.txt")]
+pub async fn robots(site: web::Data<SiteIndex>) -> actix_web::Result<HttpResponse> {
+    // Reading the content file blocks; keep it off the async worker.
+    let config = web::block(load_robots).await?.map_err(actix_web::error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/plain; charset=utf-8")
+        .body(render(&config, site.site_url())))
+}
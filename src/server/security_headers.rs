@@ -0,0 +1,191 @@
+//! Security headers on every response and the Content Security Policy of the pages.
+//!
+//! Scripts are allowed by nonce: for each page it renders, `leptos_actix`
+//! draws a fresh [`Nonce`](leptos::nonce::Nonce) and puts it on the hydration
+//! scripts and on every `leptos_meta` `<Script>` (Leaflet, the JSON-LD). The
+//! same nonce has to be in the policy, so pages get it from
+//! [`provide_policy`], called in `main`'s context function, while the
+//! [`apply`] middleware adds the fixed headers and, for HTML that did not
+//! come from Leptos, the policy without any nonce (no scripts at all).
+//!
+//! `'strict-dynamic'` lets the hydration script import the `pkg` bundle and
+//! `'wasm-unsafe-eval'` compile it; nothing needs `'unsafe-eval'`. Styles keep
+//! `'unsafe-inline'` because every component writes `style="…"`.
+//!
+//! Pre-rendered copies (`server::prerender`) carry the nonces of the run that
+//! wrote them but no policy; the static host is on its own there.
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header::{self, HeaderName, HeaderValue};
+use actix_web::middleware::Next;
+use actix_web::web;
+use leptos::config::{Env, LeptosOptions};
+
+use crate::settings::Security;
+
+/// One year, as the HSTS preload list asks.
+const HSTS: &str = "max-age=31536000; includeSubDomains";
+
+/// Features the site never uses, denied to it and to anything it embeds.
+const PERMISSIONS_POLICY: &str = "camera=(), microphone=(), geolocation=(), payment=(), usb=()";
+
+/// The policy for this deployment; `main` builds it once from the settings.
+#[derive(Debug, Clone)]
+pub struct SecurityHeaders {
+    hsts: bool,
+    report_only: bool,
+    report_uri: Option<String>,
+    /// `cargo leptos watch` reloads the page over a WebSocket on this port.
+    live_reload_port: Option<u32>,
+}
+
+impl SecurityHeaders {
+    pub fn new(security: &Security, options: &LeptosOptions) -> SecurityHeaders {
+        SecurityHeaders {
+            hsts: security.hsts,
+            report_only: security.csp_report_only,
+            report_uri: security.csp_report_uri.clone(),
+            live_reload_port: (options.env == Env::DEV)
+                .then(|| options.reload_external_port.unwrap_or(options.reload_port)),
+        }
+    }
+
+    fn header_name(&self) -> HeaderName {
+        if self.report_only {
+            header::CONTENT_SECURITY_POLICY_REPORT_ONLY
+        } else {
+            header::CONTENT_SECURITY_POLICY
+        }
+    }
+
+    /// The policy, allowing the scripts marked with `nonce`; none without it.
+    pub fn policy(&self, nonce: Option<&str>) -> String {
+        let script_src = match nonce {
+            // `'self'` and unpkg only count in browsers too old for `'strict-dynamic'`.
+            Some(nonce) => format!("'nonce-{nonce}' 'strict-dynamic' 'wasm-unsafe-eval' 'self' https://unpkg.com"),
+            None => "'none'".to_string(),
+        };
+        let connect_src = match self.live_reload_port {
+            Some(port) => format!("'self' ws://*:{port} wss://*:{port}"),
+            None => "'self'".to_string(),
+        };
+
+        let mut directives = vec![
+            "default-src 'self'".to_string(),
+            format!("script-src {script_src}"),
+            "style-src 'self' 'unsafe-inline' https://fonts.googleapis.com https://unpkg.com".to_string(),
+            "font-src 'self' https://fonts.gstatic.com".to_string(),
+            // News covers and press clippings may be hosted elsewhere; map tiles come from OpenStreetMap.
+            "img-src 'self' data: blob: https:".to_string(),
+            format!("connect-src {connect_src}"),
+            "object-src 'none'".to_string(),
+            "base-uri 'self'".to_string(),
+            "form-action 'self'".to_string(),
+            "frame-ancestors 'none'".to_string(),
+        ];
+        if self.hsts {
+            directives.push("upgrade-insecure-requests".to_string());
+        }
+        if let Some(uri) = &self.report_uri {
+            directives.push(format!("report-uri {uri}"));
+        }
+        directives.join("; ")
+    }
+}
+
+/// Sets the policy of the page being rendered, with its nonce. For the
+/// context function given to `leptos_routes_with_context`; does nothing
+/// where no page is rendered (server functions).
+pub fn provide_policy(headers: &SecurityHeaders) {
+    use leptos::prelude::use_context;
+
+    let (Some(nonce), Some(response)) = (leptos::nonce::use_nonce(), use_context::<leptos_actix::ResponseOptions>()) else {
+        return;
+    };
+    if let Ok(value) = HeaderValue::from_str(&headers.policy(Some(&nonce))) {
+        response.insert_header(headers.header_name(), value);
+    }
+}
+
+/// Middleware (`middleware::from_fn(security_headers::apply)`); needs the
+/// [`SecurityHeaders`] in `app_data`.
+pub async fn apply(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let config = req.app_data::<web::Data<SecurityHeaders>>().cloned();
+    let mut res = next.call(req).await?;
+    let Some(config) = config else {
+        return Ok(res);
+    };
+
+    let is_html = res
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|content_type| content_type.starts_with("text/html"));
+    let headers = res.headers_mut();
+
+    if is_html && !headers.contains_key(config.header_name()) {
+        if let Ok(value) = HeaderValue::from_str(&config.policy(None)) {
+            headers.insert(config.header_name(), value);
+        }
+    }
+    if config.hsts {
+        headers.insert(header::STRICT_TRANSPORT_SECURITY, HeaderValue::from_static(HSTS));
+    }
+    headers.insert(header::X_CONTENT_TYPE_OPTIONS, HeaderValue::from_static("nosniff"));
+    headers.insert(header::X_FRAME_OPTIONS, HeaderValue::from_static("DENY"));
+    headers.insert(header::REFERRER_POLICY, HeaderValue::from_static("strict-origin-when-cross-origin"));
+    headers.insert(HeaderName::from_static("permissions-policy"), HeaderValue::from_static(PERMISSIONS_POLICY));
+    headers.insert(HeaderName::from_static("cross-origin-opener-policy"), HeaderValue::from_static("same-origin"));
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(security: Security, env: Env) -> SecurityHeaders {
+        let options = LeptosOptions::builder().output_name("camaracapoeira").env(env).build();
+        SecurityHeaders::new(&security, &options)
+    }
+
+    #[test]
+    fn pages_allow_their_nonce_and_nothing_evaluated() {
+        let security = Security {
+            hsts: true,
+            csp_report_only: false,
+            csp_report_uri: None,
+            trusted_proxies: Vec::new(),
+            session_key: None,
+        };
+        let policy = headers(security, Env::PROD).policy(Some("abc123"));
+
+        assert!(policy.contains("script-src 'nonce-abc123' 'strict-dynamic' 'wasm-unsafe-eval'"));
+        assert!(!policy.contains("'unsafe-eval'"));
+        assert!(policy.contains("frame-ancestors 'none'"));
+        assert!(policy.contains("upgrade-insecure-requests"));
+        assert!(policy.contains("connect-src 'self';"));
+    }
+
+    #[test]
+    fn environment_shapes_the_policy() {
+        let security = Security {
+            hsts: false,
+            csp_report_only: true,
+            csp_report_uri: Some("/csp".into()),
+            trusted_proxies: Vec::new(),
+            session_key: None,
+        };
+        let dev = headers(security, Env::DEV);
+
+        assert_eq!(dev.header_name(), header::CONTENT_SECURITY_POLICY_REPORT_ONLY);
+        let policy = dev.policy(None);
+        assert!(policy.contains("script-src 'none'"));
+        assert!(policy.contains("ws://*:3001"));
+        assert!(policy.ends_with("report-uri /csp"));
+        assert!(!policy.contains("upgrade-insecure-requests"));
+    }
+}
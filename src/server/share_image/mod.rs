@@ -0,0 +1,547 @@
+//! Imagens de compartilhamento (Open Graph) das notícias e dos eventos.
+//!
+//! `/og/noticias/<slug>.png` and `/og/agenda/<slug>.png` are 1200×630 cards
+//! drawn like the `HeroSection`: an acervo photo under the brand tint, the
+//! yellow accent bar, the logo and the title. Drawing one takes a moment, so
+//! [`ShareImages`] keeps them in memory and in the `share_images_cache`
+//! directory, named after
+//! their contents so an edited title or photo gets a new card. With the
+//! `share_images` feature off the addresses redirect to the default picture.
+
+use std::io::Cursor;
+use std::path::{Component, Path, PathBuf};
+use std::sync::OnceLock;
+
+use ab_glyph::{point, Font, FontRef, PxScale, ScaleFont};
+use actix_web::{get, http::header, web, HttpResponse};
+use image::imageops::{self, FilterType};
+use image::{DynamicImage, ImageDecoder, ImageError, ImageFormat, ImageReader, Rgba, RgbaImage};
+
+use crate::components::seo::DEFAULT_IMAGE;
+use crate::content::dates;
+use crate::i18n::Locale;
+use crate::content::events::{load_events, Event};
+use crate::content::news::{load_news, NewsArticle};
+use crate::routes::home::SLIDESHOW_DIR;
+use crate::settings::Settings;
+
+pub const WIDTH: u32 = 1200;
+pub const HEIGHT: u32 = 630;
+
+/// Under the media root, like the photos.
+const LOGO_FILE: &str = "logo.svg";
+
+/// DejaVu Sans Bold (Bitstream Vera license, see `LICENSE-DejaVu.txt`).
+static FONT: &[u8] = include_bytes!("DejaVuSans-Bold.ttf");
+
+const PRIMARY: [u8; 3] = [0x33, 0x21, 0x75];
+const ACCENT: [u8; 3] = [0xf2, 0xe3, 0x00];
+const WHITE: [u8; 3] = [0xff, 0xff, 0xff];
+
+const MARGIN: u32 = 72;
+const LOGO_SIZE: u32 = 112;
+const TITLE_SIZES: [f32; 4] = [72.0, 64.0, 56.0, 48.0];
+const TITLE_MAX_LINES: usize = 3;
+const KICKER_SIZE: f32 = 28.0;
+
+/// What goes on a card.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    /// Small line above the title: section and date.
+    pub kicker: String,
+    pub title: String,
+    /// Background photo, a file under the media root.
+    pub photo: Option<PathBuf>,
+}
+
+impl Card {
+    /// The article's cover when it is one of ours (`/assets/…`), else an acervo photo.
+    pub fn for_article(article: &NewsArticle, media_root: &Path) -> Card {
+        Card {
+            kicker: format!("Notícias · {}", dates::medium_date(article.date, Locale::PtBr)),
+            title: article.title.clone(),
+            photo: article
+                .cover
+                .as_deref()
+                .and_then(|cover| media_file(media_root, cover))
+                .or_else(|| acervo_photo(media_root, &article.slug)),
+        }
+    }
+
+    pub fn for_event(event: &Event, media_root: &Path) -> Card {
+        Card {
+            kicker: format!("{} · {}", event.kind.label(Locale::PtBr), dates::medium_date(event.start.date(), Locale::PtBr)),
+            title: event.title.clone(),
+            photo: acervo_photo(media_root, &event.slug),
+        }
+    }
+
+    /// File name of the card in the caches, different for any change of contents.
+    fn cache_key(&self, name: &str) -> String {
+        let photo = self.photo.as_deref().map(|photo| photo.to_string_lossy()).unwrap_or_default();
+        let hash = fnv1a(&[self.kicker.as_bytes(), self.title.as_bytes(), photo.as_bytes()]);
+        format!("{name}-{hash:016x}")
+    }
+}
+
+/// `/assets/<path>` as a file under the media root; anything that could step
+/// out of it (`..`, an absolute path) is refused.
+fn media_file(media_root: &Path, href: &str) -> Option<PathBuf> {
+    let path = Path::new(href.strip_prefix("/assets/")?);
+    let plain = path.components().next().is_some() && path.components().all(|c| matches!(c, Component::Normal(_)));
+    plain.then(|| media_root.join(path))
+}
+
+/// 64-bit FNV-1a over `parts`, each followed by a separator byte. Unlike
+/// `DefaultHasher` it never changes between Rust releases, so photo choices
+/// and the card names on disk stay put across toolchain updates.
+fn fnv1a(parts: &[&[u8]]) -> u64 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    parts
+        .iter()
+        .flat_map(|part| part.iter().chain([&0xff]))
+        .fold(OFFSET, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(PRIME))
+}
+
+/// One of the slideshow photos, always the same for a given slug.
+fn acervo_photo(media_root: &Path, slug: &str) -> Option<PathBuf> {
+    let mut photos: Vec<PathBuf> = std::fs::read_dir(media_root.join(SLIDESHOW_DIR))
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| ["jpg", "jpeg", "png", "webp"].contains(&ext.to_lowercase().as_str()))
+        })
+        .collect();
+    if photos.is_empty() {
+        return None;
+    }
+    photos.sort();
+
+    let index = fnv1a(&[slug.as_bytes()]) % photos.len() as u64;
+    Some(photos.swap_remove(index as usize))
+}
+
+/// The card as a PNG.
+pub fn render_png(card: &Card, logo: Option<&RgbaImage>) -> Result<Vec<u8>, ImageError> {
+    let mut png = Vec::new();
+    render(card, logo).write_to(&mut Cursor::new(&mut png), ImageFormat::Png)?;
+    Ok(png)
+}
+
+fn render(card: &Card, logo: Option<&RgbaImage>) -> RgbaImage {
+    let font = FontRef::try_from_slice(FONT).expect("embedded font is valid");
+
+    let mut canvas = match card.photo.as_deref().and_then(open_photo) {
+        Some(photo) => photo.resize_to_fill(WIDTH, HEIGHT, FilterType::Triangle).to_rgba8(),
+        None => RgbaImage::from_pixel(WIDTH, HEIGHT, opaque(PRIMARY)),
+    };
+    tint(&mut canvas);
+
+    if let Some(logo) = logo {
+        imageops::overlay(&mut canvas, logo, MARGIN as i64, (MARGIN - 16) as i64);
+    }
+
+    // Bottom-up: title lines, accent bar, kicker.
+    let max_width = (WIDTH - 2 * MARGIN) as f32;
+    let (size, lines) = fit_title(&font, &card.title, max_width);
+    let line_height = size * 1.15;
+    let mut baseline = HEIGHT as f32 - MARGIN as f32 - line_height * (lines.len() as f32 - 1.0);
+    let title_top = baseline - size;
+    for line in &lines {
+        draw_text(&mut canvas, &font, size, MARGIN as f32, baseline, line, WHITE);
+        baseline += line_height;
+    }
+
+    let bar_top = (title_top - 28.0) as u32;
+    fill_rect(&mut canvas, MARGIN, bar_top, 120, 10, ACCENT);
+
+    let kicker = truncate(&font, KICKER_SIZE, &card.kicker.to_uppercase(), max_width);
+    draw_text(&mut canvas, &font, KICKER_SIZE, MARGIN as f32, bar_top as f32 - 22.0, &kicker, ACCENT);
+
+    canvas
+}
+
+/// The photo upright: phone pictures are stored sideways with an EXIF orientation.
+fn open_photo(path: &Path) -> Option<DynamicImage> {
+    let mut decoder = ImageReader::open(path).ok()?.with_guessed_format().ok()?.into_decoder().ok()?;
+    let orientation = decoder.orientation().ok()?;
+    let mut photo = DynamicImage::from_decoder(decoder).ok()?;
+    photo.apply_orientation(orientation);
+    Some(photo)
+}
+
+/// Brand color over the photo, stronger towards the bottom where the text goes.
+fn tint(canvas: &mut RgbaImage) {
+    for (_, y, pixel) in canvas.enumerate_pixels_mut() {
+        let alpha = 0.6 + 0.3 * y as f32 / HEIGHT as f32;
+        blend(pixel, PRIMARY, alpha);
+    }
+}
+
+/// The largest title size that fits in [`TITLE_MAX_LINES`], cut short at the smallest.
+fn fit_title(font: &FontRef, title: &str, max_width: f32) -> (f32, Vec<String>) {
+    for size in TITLE_SIZES {
+        let lines = wrap(font, size, title, max_width);
+        if lines.len() <= TITLE_MAX_LINES {
+            return (size, lines);
+        }
+    }
+
+    let size = TITLE_SIZES[TITLE_SIZES.len() - 1];
+    let mut lines = wrap(font, size, title, max_width);
+    let rest = lines.split_off(TITLE_MAX_LINES - 1).join(" ");
+    lines.push(truncate(font, size, &rest, max_width));
+    (size, lines)
+}
+
+/// Greedy word wrap; a single word wider than the line gets a line of its own.
+fn wrap(font: &FontRef, size: f32, text: &str, max_width: f32) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let candidate = if line.is_empty() { word.to_string() } else { format!("{line} {word}") };
+        if line.is_empty() || text_width(font, size, &candidate) <= max_width {
+            line = candidate;
+        } else {
+            lines.push(std::mem::replace(&mut line, word.to_string()));
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// `text`, shortened with an ellipsis if wider than `max_width`.
+fn truncate(font: &FontRef, size: f32, text: &str, max_width: f32) -> String {
+    if text_width(font, size, text) <= max_width {
+        return text.to_string();
+    }
+    let mut chars: Vec<char> = text.chars().collect();
+    while !chars.is_empty() {
+        chars.pop();
+        let shortened = format!("{}…", chars.iter().collect::<String>().trim_end());
+        if text_width(font, size, &shortened) <= max_width {
+            return shortened;
+        }
+    }
+    "…".to_string()
+}
+
+fn text_width(font: &FontRef, size: f32, text: &str) -> f32 {
+    let font = font.as_scaled(PxScale::from(size));
+    let mut width = 0.0;
+    let mut previous = None;
+    for c in text.chars() {
+        let id = font.glyph_id(c);
+        if let Some(previous) = previous {
+            width += font.kern(previous, id);
+        }
+        width += font.h_advance(id);
+        previous = Some(id);
+    }
+    width
+}
+
+fn draw_text(canvas: &mut RgbaImage, font: &FontRef, size: f32, x: f32, baseline: f32, text: &str, color: [u8; 3]) {
+    let scaled = font.as_scaled(PxScale::from(size));
+    let mut caret = x;
+    let mut previous = None;
+    for c in text.chars() {
+        let id = scaled.glyph_id(c);
+        if let Some(previous) = previous {
+            caret += scaled.kern(previous, id);
+        }
+        let glyph = id.with_scale_and_position(size, point(caret, baseline));
+        caret += scaled.h_advance(id);
+        previous = Some(id);
+
+        let Some(outline) = font.outline_glyph(glyph) else { continue };
+        let bounds = outline.px_bounds();
+        outline.draw(|gx, gy, coverage| {
+            let px = bounds.min.x as i64 + gx as i64;
+            let py = bounds.min.y as i64 + gy as i64;
+            if (0..WIDTH as i64).contains(&px) && (0..HEIGHT as i64).contains(&py) {
+                blend(canvas.get_pixel_mut(px as u32, py as u32), color, coverage);
+            }
+        });
+    }
+}
+
+fn fill_rect(canvas: &mut RgbaImage, x: u32, y: u32, width: u32, height: u32, color: [u8; 3]) {
+    for py in y..(y + height).min(canvas.height()) {
+        for px in x..(x + width).min(canvas.width()) {
+            canvas.put_pixel(px, py, opaque(color));
+        }
+    }
+}
+
+fn blend(pixel: &mut Rgba<u8>, color: [u8; 3], alpha: f32) {
+    for (channel, value) in pixel.0.iter_mut().zip(color) {
+        *channel = (*channel as f32 * (1.0 - alpha) + value as f32 * alpha).round() as u8;
+    }
+}
+
+fn opaque([r, g, b]: [u8; 3]) -> Rgba<u8> {
+    Rgba([r, g, b, 255])
+}
+
+/// `logo.svg` rasterized once, [`LOGO_SIZE`] pixels on its longest side.
+fn logo(media_root: &Path) -> Option<&'static RgbaImage> {
+    static LOGO: OnceLock<Option<RgbaImage>> = OnceLock::new();
+    LOGO.get_or_init(|| {
+        let path = media_root.join(LOGO_FILE);
+        let rasterized = rasterize_svg(&path, LOGO_SIZE);
+        if rasterized.is_none() {
+            tracing::warn!(path = %path.display(), "share images: could not rasterize the logo; cards go without it");
+        }
+        rasterized
+    })
+    .as_ref()
+}
+
+fn rasterize_svg(path: &Path, size: u32) -> Option<RgbaImage> {
+    use resvg::{tiny_skia, usvg};
+
+    let data = std::fs::read(path).ok()?;
+    let tree = usvg::Tree::from_data(&data, &usvg::Options::default()).ok()?;
+    let scale = size as f32 / tree.size().width().max(tree.size().height());
+    let width = (tree.size().width() * scale).ceil() as u32;
+    let height = (tree.size().height() * scale).ceil() as u32;
+
+    let mut pixmap = tiny_skia::Pixmap::new(width, height)?;
+    resvg::render(&tree, tiny_skia::Transform::from_scale(scale, scale), &mut pixmap.as_mut());
+
+    // tiny-skia keeps premultiplied alpha; `image` wants it straight.
+    let pixels = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let pixel = pixel.demultiply();
+            [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()]
+        })
+        .collect();
+    RgbaImage::from_raw(width, height, pixels)
+}
+
+/// Rendered cards, in memory and under the `share_images_cache` setting.
+///
+/// Built once by `main` and shared with every worker.
+#[derive(Clone)]
+pub struct ShareImages {
+    memory: moka::sync::Cache<String, web::Bytes>,
+    dir: PathBuf,
+    media_root: PathBuf,
+}
+
+impl ShareImages {
+    pub fn new(dir: impl Into<PathBuf>, media_root: impl Into<PathBuf>) -> ShareImages {
+        ShareImages {
+            memory: moka::sync::Cache::new(128),
+            dir: dir.into(),
+            media_root: media_root.into(),
+        }
+    }
+
+    /// The card's PNG from memory, from disk, or drawn now and stored in both.
+    fn get(&self, name: &str, card: &Card) -> Result<web::Bytes, ImageError> {
+        let key = card.cache_key(name);
+        if let Some(png) = self.memory.get(&key) {
+            return Ok(png);
+        }
+
+        let path = self.dir.join(format!("{key}.png"));
+        let png = match std::fs::read(&path) {
+            Ok(png) => png,
+            Err(_) => {
+                let png = render_png(card, logo(&self.media_root))?;
+                // A card that cannot be saved is still served; it is drawn again next time.
+                if let Err(e) = std::fs::create_dir_all(&self.dir).and_then(|_| std::fs::write(&path, &png)) {
+                    tracing::warn!(path = %path.display(), error = %e, "share images: could not save the card");
+                }
+                // Older versions of this card (before an edit) are not needed any more.
+                self.remove_cards(|file| is_card_of(file, name) && file != format!("{key}.png"));
+                png
+            }
+        };
+
+        let png = web::Bytes::from(png);
+        self.memory.insert(key, png.clone());
+        Ok(png)
+    }
+}
+
+impl ShareImages {
+    /// Deletes the saved cards of news and events that no longer exist or
+    /// have changed since; `main` calls it at startup.
+    pub fn prune(&self) -> Result<usize, crate::content::ContentError> {
+        let mut keep: Vec<String> = load_news()?
+            .iter()
+            .map(|article| Card::for_article(article, &self.media_root).cache_key(&format!("noticia-{}", article.slug)))
+            .collect();
+        keep.extend(
+            load_events()?
+                .iter()
+                .map(|event| Card::for_event(event, &self.media_root).cache_key(&format!("evento-{}", event.slug))),
+        );
+        Ok(self.remove_cards(|file| !keep.iter().any(|key| file == format!("{key}.png"))))
+    }
+
+    /// Removes the saved cards whose file name matches, returning how many went.
+    fn remove_cards(&self, remove: impl Fn(&str) -> bool) -> usize {
+        let Ok(entries) = std::fs::read_dir(&self.dir) else {
+            return 0;
+        };
+        let mut removed = 0;
+        for entry in entries.flatten() {
+            let file = entry.file_name();
+            let Some(file) = file.to_str() else { continue };
+            if !file.ends_with(".png") || !remove(file) {
+                continue;
+            }
+            match std::fs::remove_file(entry.path()) {
+                Ok(()) => removed += 1,
+                Err(e) => tracing::warn!(file, error = %e, "share images: could not remove an old card"),
+            }
+        }
+        removed
+    }
+}
+
+/// Whether `file` is a saved version of the card `name` (`<name>-<hash>.png`).
+fn is_card_of(file: &str, name: &str) -> bool {
+    file.strip_prefix(name)
+        .and_then(|rest| rest.strip_prefix('-'))
+        .and_then(|rest| rest.strip_suffix(".png"))
+        .is_some_and(|hash| hash.len() == 16 && hash.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Builds the card (picking a photo lists the media root) and draws it, both
+/// off the async worker.
+async fn respond(
+    images: web::Data<ShareImages>,
+    name: String,
+    card: impl FnOnce(&Path) -> Card + Send + 'static,
+) -> actix_web::Result<HttpResponse> {
+    let png = web::block(move || images.get(&name, &card(&images.media_root)))
+        .await?
+        .map_err(actix_web::error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("image/png")
+        .insert_header((header::CACHE_CONTROL, "public, max-age=86400"))
+        .body(png))
+}
+
+fn disabled() -> HttpResponse {
+    HttpResponse::Found().insert_header((header::LOCATION, DEFAULT_IMAGE)).finish()
+}
+
+#[get("/og/noticias/{slug}.png")]
+pub async fn article_image(
+    slug: web::Path<String>,
+    images: web::Data<ShareImages>,
+    settings: web::Data<Settings>,
+) -> actix_web::Result<HttpResponse> {
+    if !settings.features.share_images {
+        return Ok(disabled());
+    }
+    let slug = slug.into_inner();
+    // Reading the content file blocks; keep it off the async worker.
+    let articles = web::block(load_news).await?.map_err(actix_web::error::ErrorInternalServerError)?;
+
+    let Some(article) = articles.into_iter().find(|a| a.slug == slug) else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+    respond(images, format!("noticia-{slug}"), move |media_root| Card::for_article(&article, media_root)).await
+}
+
+#[get("/og/agenda/{slug}.png")]
+pub async fn event_image(
+    slug: web::Path<String>,
+    images: web::Data<ShareImages>,
+    settings: web::Data<Settings>,
+) -> actix_web::Result<HttpResponse> {
+    if !settings.features.share_images {
+        return Ok(disabled());
+    }
+    let slug = slug.into_inner();
+    // Reading the content file blocks; keep it off the async worker.
+    let events = web::block(load_events).await?.map_err(actix_web::error::ErrorInternalServerError)?;
+
+    let Some(event) = events.into_iter().find(|e| e.slug == slug) else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+    respond(images, format!("evento-{slug}"), move |media_root| Card::for_event(&event, media_root)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn font() -> FontRef<'static> {
+        FontRef::try_from_slice(FONT).unwrap()
+    }
+
+    #[test]
+    fn long_titles_shrink_then_get_cut() {
+        let max_width = (WIDTH - 2 * MARGIN) as f32;
+
+        let (size, lines) = fit_title(&font(), "Roda aberta", max_width);
+        assert_eq!((size, lines), (TITLE_SIZES[0], vec!["Roda aberta".to_string()]));
+
+        let long = "Batizado e troca de cordas do Camará Capoeira ".repeat(6);
+        let (size, lines) = fit_title(&font(), &long, max_width);
+        assert_eq!(size, TITLE_SIZES[TITLE_SIZES.len() - 1]);
+        assert_eq!(lines.len(), TITLE_MAX_LINES);
+        assert!(lines.last().unwrap().ends_with('…'));
+        assert!(lines.iter().all(|line| text_width(&font(), size, line) <= max_width));
+    }
+
+    #[test]
+    fn cards_have_the_open_graph_size() {
+        let card = Card { kicker: "Notícias · 14 de março de 2026".into(), title: "Roda na praça".into(), photo: None };
+        let canvas = render(&card, None);
+
+        assert_eq!(canvas.dimensions(), (WIDTH, HEIGHT));
+        // Accent bar above the title.
+        assert!(canvas.pixels().any(|pixel| *pixel == opaque(ACCENT)));
+    }
+
+    #[test]
+    fn cache_key_follows_the_contents() {
+        let card = Card { kicker: "Oficina".into(), title: "Maculelê".into(), photo: None };
+        let edited = Card { title: "Maculelê e samba de roda".into(), ..card.clone() };
+
+        assert_eq!(card.cache_key("evento-oficina"), card.clone().cache_key("evento-oficina"));
+        assert_ne!(card.cache_key("evento-oficina"), edited.cache_key("evento-oficina"));
+        assert!(card.cache_key("evento-oficina").starts_with("evento-oficina-"));
+        // Fixed across Rust releases: the cards on disk stay valid.
+        let bytes = |parts: &[&'static str]| parts.iter().map(|part| part.as_bytes()).collect::<Vec<_>>();
+        let expected = fnv1a(&bytes(&["Oficina", "Maculelê", ""]));
+        assert_eq!(card.cache_key("evento-oficina"), format!("evento-oficina-{expected:016x}"));
+        assert_eq!(fnv1a(&[]), 0xcbf2_9ce4_8422_2325);
+        assert_ne!(fnv1a(&bytes(&["ab", ""])), fnv1a(&bytes(&["a", "b"])));
+    }
+
+    #[test]
+    fn covers_stay_inside_the_media_root() {
+        let root = Path::new("/srv/media");
+        assert_eq!(media_file(root, "/assets/noticias/roda.jpg"), Some(root.join("noticias/roda.jpg")));
+        assert_eq!(media_file(root, "/assets/../camara.toml"), None);
+        assert_eq!(media_file(root, "/assets/noticias/../../data/camara.db"), None);
+        assert_eq!(media_file(root, "/assets//etc/passwd"), None);
+        assert_eq!(media_file(root, "/assets/"), None);
+        assert_eq!(media_file(root, "https://exemplo.com/foto.jpg"), None);
+    }
+
+    #[test]
+    fn old_versions_of_a_card_are_recognized() {
+        assert!(is_card_of("noticia-roda-0123456789abcdef.png", "noticia-roda"));
+        assert!(!is_card_of("noticia-roda-na-praca-0123456789abcdef.png", "noticia-roda"));
+        assert!(!is_card_of("noticia-roda-0123.png", "noticia-roda"));
+    }
+}
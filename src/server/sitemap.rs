@@ -0,0 +1,179 @@
+//! `/sitemap.xml`: every public page in the three languages, with the pages
+//! of news, events, albums and cantigas read from `content/` on each request.
+
+use std::fmt::Write;
+
+use actix_web::{get, web, HttpResponse};
+use chrono::NaiveDate;
+use leptos_actix::ActixRouteListing;
+
+use crate::content::{self, albums, cantigas, events, news, ContentError};
+use crate::i18n::Locale;
+
+/// Routes kept out of the sitemap: staff pages, token links from e-mails and
+/// the printable copy of `/cantigas`.
+const PRIVATE: &[&str] = &["/admin", "/entrar", "/newsletter", "/cantigas/caderno"];
+
+/// Where the site lives and which of its routes are public pages.
+///
+/// Built by `main` from the Leptos route list, shared with `robots`.
+#[derive(Debug, Clone)]
+pub struct SiteIndex {
+    site_url: String,
+    pages: Vec<String>,
+}
+
+impl SiteIndex {
+    pub fn new(site_url: &str, routes: &[ActixRouteListing]) -> SiteIndex {
+        SiteIndex {
+            site_url: site_url.trim_end_matches('/').to_string(),
+            pages: public_pages(routes.iter().map(|route| route.path())),
+        }
+    }
+
+    /// Public address of the site, without a trailing slash.
+    pub fn site_url(&self) -> &str {
+        &self.site_url
+    }
+
+    /// Portuguese paths of the public pages without parameters.
+    pub fn pages(&self) -> &[String] {
+        &self.pages
+    }
+}
+
+/// Portuguese paths of the routes without parameters, minus [`PRIVATE`] ones.
+fn public_pages<'a>(paths: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let mut pages: Vec<String> = paths
+        .filter(|path| !path.contains('{'))
+        .filter(|path| Locale::from_path(path).0 == Locale::PtBr)
+        .filter(|path| !PRIVATE.iter().any(|private| path.starts_with(private)))
+        .map(str::to_string)
+        .collect();
+    pages.sort();
+    pages.dedup();
+    pages
+}
+
+/// One page of the sitemap, by its Portuguese path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub path: String,
+    pub lastmod: Option<NaiveDate>,
+}
+
+impl Entry {
+    fn new(path: impl Into<String>, lastmod: Option<NaiveDate>) -> Entry {
+        Entry { path: path.into(), lastmod }
+    }
+}
+
+/// The static pages plus one entry per content item.
+///
+/// News and albums carry their own date; events and cantigas have none, so
+/// the modification day of their file stands in.
+pub fn entries(pages: &[String]) -> Result<Vec<Entry>, ContentError> {
+    let news = news::load_news()?;
+    let albums = albums::load_albums()?;
+    let events = events::load_events()?;
+    let songs = cantigas::load_songs()?;
+    let events_modified = content::modified("events.toml");
+    let songs_modified = content::modified("cantigas.toml");
+
+    let mut entries: Vec<Entry> = pages
+        .iter()
+        .map(|path| {
+            let lastmod = match path.as_str() {
+                "/noticias" => news.iter().map(|a| a.date).max(),
+                "/galeria" => albums.iter().map(|a| a.date).max(),
+                "/agenda" => events_modified,
+                "/cantigas" => songs_modified,
+                _ => None,
+            };
+            Entry::new(path, lastmod)
+        })
+        .collect();
+
+    entries.extend(news.iter().map(|article| Entry::new(article.href(), Some(article.date))));
+    entries.extend(events.iter().map(|event| Entry::new(format!("/agenda/{}", event.slug), events_modified)));
+    entries.extend(albums.iter().map(|album| Entry::new(album.href(), Some(album.date))));
+    entries.extend(songs.iter().map(|song| Entry::new(song.href(), songs_modified)));
+    Ok(entries)
+}
+
+/// Sitemap XML with one `<url>` per entry and language, each listing its
+/// translations as `hreflang` alternates.
+pub fn render(site_url: &str, entries: &[Entry]) -> String {
+    let mut out = String::with_capacity(256 + entries.len() * Locale::ALL.len() * 512);
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\" xmlns:xhtml=\"http://www.w3.org/1999/xhtml\">\n");
+
+    for entry in entries {
+        for locale in Locale::ALL {
+            let _ = writeln!(out, "  <url>\n    <loc>{}</loc>", escape(&format!("{site_url}{}", locale.href(&entry.path))));
+            if let Some(lastmod) = entry.lastmod {
+                let _ = writeln!(out, "    <lastmod>{}</lastmod>", lastmod.format("%Y-%m-%d"));
+            }
+            for alternate in Locale::ALL {
+                let _ = writeln!(
+                    out,
+                    "    <xhtml:link rel=\"alternate\" hreflang=\"{}\" href=\"{}\"/>",
+                    alternate.tag(),
+                    escape(&format!("{site_url}{}", alternate.href(&entry.path))),
+                );
+            }
+            out.push_str("  </url>\n");
+        }
+    }
+
+    out.push_str("</urlset>\n");
+    out
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[get("/sitemap.xml")]
+pub async fn sitemap(site: web::Data<SiteIndex>) -> actix_web::Result<HttpResponse> {
+    // Reading the content files blocks; keep it off the async worker.
+    let index = site.clone();
+    let entries = web::block(move || entries(index.pages())).await?.map_err(actix_web::error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("application/xml; charset=utf-8")
+        .body(render(&site.site_url, &entries)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_public_pages_in_portuguese() {
+        let routes = [
+            "/", "/agenda", "/agenda/{slug}", "/es", "/es/agenda", "/en/noticias", "/noticias",
+            "/admin", "/admin/auditoria", "/entrar", "/newsletter/sair/{token}", "/cantigas",
+            "/cantigas/caderno", "/agenda",
+        ];
+        assert_eq!(public_pages(routes.into_iter()), ["/", "/agenda", "/cantigas", "/noticias"]);
+    }
+
+    #[test]
+    fn renders_every_language_with_alternates() {
+        let date = NaiveDate::from_ymd_opt(2026, 3, 14);
+        let xml = render("https://camara.org", &[Entry::new("/", None), Entry::new("/noticias/roda&samba", date)]);
+
+        assert_eq!(xml.matches("<url>").count(), 6);
+        assert_eq!(xml.matches("<lastmod>2026-03-14</lastmod>").count(), 3);
+        assert!(xml.contains("<loc>https://camara.org/</loc>"));
+        assert!(xml.contains("<loc>https://camara.org/es</loc>"));
+        assert!(xml.contains("<loc>https://camara.org/en/noticias/roda&amp;samba</loc>"));
+        assert!(xml.contains("hreflang=\"pt-BR\" href=\"https://camara.org/noticias/roda&amp;samba\""));
+        assert_eq!(xml.matches("<xhtml:link").count(), 18);
+    }
+}
@@ -0,0 +1,227 @@
+//! CSV and OpenDocument (`.ods`) writers for report downloads.
+//!
+//! The ODS writer covers only what the reports need: typed cells in plain
+//! sheets, no styles or formulas.
+
+use std::io::Write;
+
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::content::money::Money;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Text(String),
+    Int(i64),
+    /// A ratio between 0 and 1, shown as a percentage.
+    Percent(f64),
+    /// Reais, written with a decimal comma and no grouping.
+    Money(Money),
+}
+
+impl Value {
+    fn csv(&self) -> String {
+        match self {
+            Value::Text(text) => csv_text(text),
+            Value::Int(n) => n.to_string(),
+            Value::Percent(ratio) => format!("{:.1}", ratio * 100.0).replace('.', ","),
+            Value::Money(money) => money.to_decimal(false),
+        }
+    }
+}
+
+/// Text a spreadsheet would run as a formula (`=`, `+`, `-`, `@`, tab, CR)
+/// gets a leading `'`: names come from public forms and staff open these
+/// files in a spreadsheet program.
+fn csv_text(text: &str) -> String {
+    if text.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{text}")
+    } else {
+        text.to_string()
+    }
+}
+
+impl From<&str> for Value {
+    fn from(text: &str) -> Self {
+        Value::Text(text.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(text: String) -> Self {
+        Value::Text(text)
+    }
+}
+
+impl From<Money> for Value {
+    fn from(money: Money) -> Self {
+        Value::Money(money)
+    }
+}
+
+impl From<u32> for Value {
+    fn from(n: u32) -> Self {
+        Value::Int(n.into())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sheet {
+    pub name: String,
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<Value>>,
+}
+
+/// Semicolon-separated, decimal comma and a UTF-8 BOM, so the files open
+/// directly in spreadsheet software configured for pt-BR.
+pub fn to_csv(sheet: &Sheet) -> Result<Vec<u8>, csv::Error> {
+    let mut writer = csv::WriterBuilder::new().delimiter(b';').from_writer(b"\xEF\xBB\xBF".to_vec());
+
+    writer.write_record(sheet.headers.iter().map(|header| csv_text(header)))?;
+    for row in &sheet.rows {
+        writer.write_record(row.iter().map(Value::csv))?;
+    }
+
+    writer.into_inner().map_err(|e| e.into_error().into())
+}
+
+const MIMETYPE: &str = "application/vnd.oasis.opendocument.spreadsheet";
+
+const MANIFEST: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<manifest:manifest xmlns:manifest="urn:oasis:names:tc:opendocument:xmlns:manifest:1.0" manifest:version="1.2">
+ <manifest:file-entry manifest:full-path="/" manifest:version="1.2" manifest:media-type="application/vnd.oasis.opendocument.spreadsheet"/>
+ <manifest:file-entry manifest:full-path="content.xml" manifest:media-type="text/xml"/>
+</manifest:manifest>
+"#;
+
+/// A spreadsheet document with one table per sheet, in order.
+pub fn to_ods(sheets: &[Sheet]) -> zip::result::ZipResult<Vec<u8>> {
+    let mut zip = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+
+    // The mimetype must be the first entry, uncompressed, for the file to be recognized.
+    zip.start_file("mimetype", SimpleFileOptions::default().compression_method(CompressionMethod::Stored))?;
+    zip.write_all(MIMETYPE.as_bytes())?;
+
+    let deflated = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    zip.start_file("META-INF/manifest.xml", deflated)?;
+    zip.write_all(MANIFEST.as_bytes())?;
+    zip.start_file("content.xml", deflated)?;
+    zip.write_all(content_xml(sheets).as_bytes())?;
+
+    Ok(zip.finish()?.into_inner())
+}
+
+fn content_xml(sheets: &[Sheet]) -> String {
+    let mut xml = String::from(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        r#"<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0""#,
+        r#" xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0""#,
+        r#" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" office:version="1.2">"#,
+        "<office:body><office:spreadsheet>",
+    ));
+
+    for sheet in sheets {
+        xml.push_str(&format!(r#"<table:table table:name="{}">"#, escape(&sheet.name)));
+        let headers = sheet.headers.iter().map(|h| Value::Text(h.clone())).collect::<Vec<_>>();
+        for row in std::iter::once(&headers).chain(&sheet.rows) {
+            xml.push_str("<table:table-row>");
+            for value in row {
+                xml.push_str(&cell_xml(value));
+            }
+            xml.push_str("</table:table-row>");
+        }
+        xml.push_str("</table:table>");
+    }
+
+    xml.push_str("</office:spreadsheet></office:body></office:document-content>");
+    xml
+}
+
+fn cell_xml(value: &Value) -> String {
+    match value {
+        Value::Text(text) => format!(
+            r#"<table:table-cell office:value-type="string"><text:p>{}</text:p></table:table-cell>"#,
+            escape(text)
+        ),
+        Value::Int(n) => format!(
+            r#"<table:table-cell office:value-type="float" office:value="{n}"><text:p>{n}</text:p></table:table-cell>"#
+        ),
+        Value::Percent(ratio) => format!(
+            r#"<table:table-cell office:value-type="percentage" office:value="{ratio}"><text:p>{}%</text:p></table:table-cell>"#,
+            value.csv()
+        ),
+        Value::Money(money) => format!(
+            r#"<table:table-cell office:value-type="currency" office:currency="BRL" office:value="{}"><text:p>{}</text:p></table:table-cell>"#,
+            money.0 as f64 / 100.0,
+            money.to_brl()
+        ),
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sheet(rows: Vec<Vec<Value>>) -> Sheet {
+        Sheet { name: "Frequência".into(), headers: vec!["Aluno".into(), "Valor".into()], rows }
+    }
+
+    #[test]
+    fn csv_has_a_bom_semicolons_and_decimal_commas() {
+        let csv = to_csv(&sheet(vec![vec!["Ana; Maria".into(), Money(123_456).into()]])).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+
+        assert!(csv.starts_with('\u{feff}'));
+        assert_eq!(&csv['\u{feff}'.len_utf8()..], "Aluno;Valor\n\"Ana; Maria\";1234,56\n");
+    }
+
+    #[test]
+    fn csv_text_never_starts_a_formula() {
+        let rows = ["=HYPERLINK(\"x\")", "+55", "-1", "@SUM(A1)", "\tTab", "\rCR", "Ana"]
+            .map(|name| vec![Value::from(name), Value::Int(-1)])
+            .to_vec();
+        let csv = String::from_utf8(to_csv(&sheet(rows)).unwrap()).unwrap();
+        let cells: Vec<_> = csv.lines().skip(1).map(|line| line.rsplit_once(';').unwrap()).collect();
+
+        assert_eq!(cells[0].0, "\"'=HYPERLINK(\"\"x\"\")\"");
+        assert_eq!(cells[1].0, "'+55");
+        assert_eq!(cells[2].0, "'-1");
+        assert_eq!(cells[3].0, "'@SUM(A1)");
+        assert_eq!(cells[4].0, "'\tTab");
+        assert_eq!(cells[5].0, "\"'\rCR\"");
+        assert_eq!(cells[6].0, "Ana");
+        // Numbers are numbers, not text, and keep their sign.
+        assert!(cells.iter().all(|(_, n)| *n == "-1"));
+    }
+
+    #[test]
+    fn ods_starts_with_the_stored_mimetype() {
+        let ods = to_ods(&[sheet(Vec::new())]).unwrap();
+
+        // First local file header: signature, compression method 0 (stored), then name and data.
+        assert_eq!(&ods[..4], b"PK\x03\x04");
+        assert_eq!(u16::from_le_bytes([ods[8], ods[9]]), 0);
+        let name_len = u16::from_le_bytes([ods[26], ods[27]]) as usize;
+        let extra_len = u16::from_le_bytes([ods[28], ods[29]]) as usize;
+        assert_eq!(&ods[30..30 + name_len], b"mimetype");
+        let data = 30 + name_len + extra_len;
+        assert_eq!(&ods[data..data + MIMETYPE.len()], MIMETYPE.as_bytes());
+    }
+
+    #[test]
+    fn cell_xml_escapes_markup() {
+        let xml = cell_xml(&Value::from("<b>Tom & \"Jerry\"</b>"));
+        assert!(xml.contains("<text:p>&lt;b&gt;Tom &amp; &quot;Jerry&quot;&lt;/b&gt;</text:p>"));
+
+        let xml = content_xml(&[Sheet { name: "A&B".into(), headers: Vec::new(), rows: Vec::new() }]);
+        assert!(xml.contains(r#"table:name="A&amp;B""#));
+    }
+}
@@ -0,0 +1,151 @@
+use actix_files::NamedFile;
+use actix_web::{get, http::header, web, HttpRequest, HttpResponse};
+use std::path::Path;
+
+use super::spreadsheet::{self, Sheet, Value};
+use crate::content::transparency::{load_transparency, Cell, Table};
+use crate::settings::Settings;
+
+impl From<&Table> for Sheet {
+    /// The totals row, if any, goes last like on the page.
+    fn from(table: &Table) -> Sheet {
+        let cell = |cell: &Cell| match cell {
+            Cell::Text(text) => Value::Text(text.clone()),
+            Cell::Money(money) => Value::Money(*money),
+        };
+        Sheet {
+            name: table.title.clone(),
+            headers: table.headers.clone(),
+            rows: table.rows.iter().chain(table.footer.iter()).map(|row| row.iter().map(cell).collect()).collect(),
+        }
+    }
+}
+
+/// `/transparencia/resumo.csv`, `/transparencia/recebidos.csv`, `/transparencia/aplicados.csv`
+#[get("/transparencia/{table}.csv")]
+pub async fn table_csv(table: web::Path<String>) -> actix_web::Result<HttpResponse> {
+    let slug = table.into_inner();
+    let data = load_transparency().map_err(actix_web::error::ErrorInternalServerError)?;
+
+    let Some(table) = data.tables().into_iter().find(|t| t.slug == slug) else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+    let body = spreadsheet::to_csv(&Sheet::from(&table)).map_err(actix_web::error::ErrorInternalServerError)?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/csv; charset=utf-8")
+        .insert_header((
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"transparencia-{slug}.csv\""),
+        ))
+        .body(body))
+}
+
+/// Serves a registered document by slug. Only files listed in the registry are reachable.
+#[get("/transparencia/documentos/{slug}")]
+pub async fn document(req: HttpRequest, slug: web::Path<String>, settings: web::Data<Settings>) -> actix_web::Result<HttpResponse> {
+    let data = load_transparency().map_err(actix_web::error::ErrorInternalServerError)?;
+
+    let Some(document) = data.document(&slug) else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+
+    // The registry stores bare file names; anything resembling a path is rejected.
+    let file_name = Path::new(&document.file);
+    if file_name.components().count() != 1 || file_name.file_name().is_none() {
+        return Ok(HttpResponse::NotFound().finish());
+    }
+
+    let file = NamedFile::open_async(settings.documents_dir.join(file_name)).await?;
+    Ok(file
+        .set_content_disposition(header::ContentDisposition {
+            disposition: header::DispositionType::Inline,
+            parameters: vec![header::DispositionParam::Filename(document.file.clone())],
+        })
+        .into_response(&req))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::money::Money;
+    use crate::content::transparency::TransparencyFile;
+
+    fn file() -> TransparencyFile {
+        toml::from_str(
+            r#"
+            [[received]]
+            project = "Capoeira na Escola"
+            fiscal_year = 2025
+            date = "2025-03-10"
+            source = "Prefeitura"
+            amount = 10000
+
+            [[received]]
+            project = "Capoeira na Escola"
+            fiscal_year = 2025
+            date = "2025-08-10"
+            source = "Prefeitura"
+            amount = "2500.50"
+
+            [[received]]
+            project = "Berimbaus"
+            fiscal_year = 2025
+            date = "2025-05-02"
+            source = "Doação"
+            amount = 800
+
+            [[spent]]
+            project = "Capoeira na Escola"
+            fiscal_year = 2025
+            category = "Instrutores"
+            amount = 9000
+
+            [[spent]]
+            project = "Berimbaus"
+            fiscal_year = 2025
+            category = "Material"
+            amount = 1200.25
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn summary_sums_received_and_spent_per_project() {
+        let summary = file().summary_table();
+
+        assert_eq!(summary.rows.len(), 2);
+        // Projects in order, the overspent one with a negative balance.
+        assert_eq!(summary.rows[0][0], Cell::Text("Berimbaus".into()));
+        assert_eq!(summary.rows[0][2..], [Cell::Money(Money(80_000)), Cell::Money(Money(120_025)), Cell::Money(Money(-40_025))]);
+        assert_eq!(
+            summary.rows[1][2..],
+            [Cell::Money(Money(1_250_050)), Cell::Money(Money(900_000)), Cell::Money(Money(350_050))]
+        );
+        assert_eq!(
+            summary.footer.unwrap()[2..],
+            [Cell::Money(Money(1_330_050)), Cell::Money(Money(1_020_025)), Cell::Money(Money(310_025))]
+        );
+
+        let received = file().received_table();
+        assert_eq!(received.footer.as_ref().unwrap().last(), Some(&Cell::Money(Money(1_330_050))));
+    }
+
+    #[test]
+    fn csv_export_keeps_the_columns_and_ends_with_the_totals() {
+        let csv = spreadsheet::to_csv(&Sheet::from(&file().summary_table())).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.trim_start_matches('\u{feff}').lines().collect();
+
+        assert_eq!(
+            lines,
+            [
+                "Projeto;Exercício;Recebido;Aplicado;Saldo",
+                "Berimbaus;2025;800,00;1200,25;-400,25",
+                "Capoeira na Escola;2025;12500,50;9000,00;3500,50",
+                "Total;;13300,50;10200,25;3100,25",
+            ]
+        );
+    }
+}
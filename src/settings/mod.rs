@@ -0,0 +1,535 @@
+//! Server settings: `camara.toml` plus `CAMARA_*` environment variables.
+//!
+//! `main` loads them once, before anything else starts. A variable wins over
+//! the file and the file over the defaults, and every value is checked up
+//! front: a typo stops the server with a message naming the setting instead
+//! of surfacing later as a broken page. See `camara.example.toml`.
+
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
+
+use leptos::config::{Env, LeptosOptions};
+use serde::Deserialize;
+
+use crate::db::DATABASE_PATH;
+use crate::mail::{self, MailConfig};
+
+/// Settings file read when `CAMARA_SETTINGS` does not name another one.
+pub const DEFAULT_PATH: &str = "camara.toml";
+
+const MAX_WORKERS: usize = 64;
+
+/// Shortest `session_key` accepted, as required by `actix_web::cookie::Key`.
+const MIN_SESSION_KEY_BYTES: usize = 64;
+
+/// Default `share_images_cache`, relative to the working directory.
+const DEFAULT_SHARE_IMAGES_CACHE: &str = "data/og";
+
+/// Everything the server needs to know about its surroundings, validated.
+///
+/// `main` provides it to the server functions (context) and the actix
+/// handlers (`app_data`).
+#[derive(Debug, Clone)]
+pub struct Settings {
+    /// Address and port to listen on.
+    pub bind: SocketAddr,
+    /// Actix worker threads.
+    pub workers: usize,
+    /// Directory served under `/assets/` (photos, logos, recordings).
+    pub media_root: PathBuf,
+    /// SQLite database file.
+    pub database: PathBuf,
+    /// The editable content files (`events.toml`, `news.toml`…); see `content::dir`.
+    pub content_dir: PathBuf,
+    /// Documents listed in `transparency.toml`. Only registered files are served.
+    pub documents_dir: PathBuf,
+    /// Share cards already drawn (`server::share_image`). Not versioned.
+    pub share_images_cache: PathBuf,
+    /// Seed of the random choices made by `camaracapoeira prerender`, so
+    /// that two runs produce the same files.
+    pub prerender_seed: u64,
+    /// Verbosity of the server log at startup (see `logging`).
+    pub log_level: LogLevel,
+    pub log_format: LogFormat,
+    pub mail: MailConfig,
+    pub features: Features,
+    pub security: Security,
+}
+
+/// Parts of the server that can be switched off, e.g. on a staging copy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Features {
+    /// Deliver queued e-mail. Off, messages wait in the outbox.
+    pub mail_worker: bool,
+    /// Draw the share cards of news and events (`server::share_image`).
+    /// Off, their addresses redirect to the default picture.
+    pub share_images: bool,
+}
+
+/// How strict the browser is told to be (`server::security_headers`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Security {
+    /// Send `Strict-Transport-Security`. On by default only in production,
+    /// where the site is behind HTTPS.
+    pub hsts: bool,
+    /// Send the Content Security Policy as `…-Report-Only`: violations are
+    /// reported but nothing is blocked. For trying a policy change.
+    pub csp_report_only: bool,
+    /// Where browsers send CSP violation reports.
+    pub csp_report_uri: Option<String>,
+    /// Reverse proxies in front of the server. Only requests coming from one
+    /// of them have their `X-Forwarded-For` believed (see `audit::Recorder`).
+    pub trusted_proxies: Vec<IpAddr>,
+    /// Encrypts the session cookies. Without a fixed one `main` draws a
+    /// random key and every restart signs everybody out.
+    pub session_key: Option<SessionKey>,
+}
+
+/// The `session_key` secret; kept out of `Debug` so it never reaches the log.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SessionKey(Vec<u8>);
+
+impl SessionKey {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for SessionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SessionKey(..)")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 5] = [LogLevel::Error, LogLevel::Warn, LogLevel::Info, LogLevel::Debug, LogLevel::Trace];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<LogLevel> {
+        LogLevel::ALL.into_iter().find(|level| level.as_str().eq_ignore_ascii_case(s))
+    }
+}
+
+/// How `logging` writes events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// One readable line per event, for a terminal or `journalctl`.
+    Text,
+    /// One JSON object per line, for log collectors.
+    Json,
+}
+
+impl LogFormat {
+    pub fn parse(s: &str) -> Option<LogFormat> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" => Some(LogFormat::Text),
+            "json" => Some(LogFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+/// A setting that is missing, malformed or points somewhere unusable.
+#[derive(Debug)]
+pub struct SettingsError {
+    /// Where the bad value came from: `workers`, `CAMARA_WORKERS`, `camara.toml`.
+    pub setting: String,
+    pub message: String,
+}
+
+impl SettingsError {
+    fn new(setting: impl Into<String>, message: impl Into<String>) -> SettingsError {
+        SettingsError { setting: setting.into(), message: message.into() }
+    }
+}
+
+impl std::fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid setting {}: {}", self.setting, self.message)
+    }
+}
+
+impl std::error::Error for SettingsError {}
+
+/// The file as written; everything optional.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct SettingsFile {
+    bind: Option<String>,
+    workers: Option<usize>,
+    media_root: Option<PathBuf>,
+    database: Option<PathBuf>,
+    content_dir: Option<PathBuf>,
+    documents_dir: Option<PathBuf>,
+    share_images_cache: Option<PathBuf>,
+    prerender_seed: Option<u64>,
+    site_url: Option<String>,
+    log_level: Option<String>,
+    log_format: Option<String>,
+    mail: MailFile,
+    features: FeaturesFile,
+    security: SecurityFile,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct MailFile {
+    from: Option<String>,
+    url: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct FeaturesFile {
+    mail_worker: Option<bool>,
+    share_images: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct SecurityFile {
+    hsts: Option<bool>,
+    csp_report_only: Option<bool>,
+    csp_report_uri: Option<String>,
+    trusted_proxies: Option<Vec<String>>,
+    session_key: Option<String>,
+}
+
+/// Reads the settings file (`CAMARA_SETTINGS` or [`DEFAULT_PATH`]), applies
+/// the environment and checks the result. Unset values fall back to the
+/// Leptos configuration (`site-addr`, `site-root`) and the built-in defaults.
+pub fn load(leptos: &LeptosOptions) -> Result<Settings, SettingsError> {
+    let env = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+
+    let mut file = match env("CAMARA_SETTINGS") {
+        Some(path) => read_file(Path::new(&path), true)?,
+        None => read_file(Path::new(DEFAULT_PATH), false)?,
+    };
+    apply_env(&mut file, env)?;
+    let settings = resolve(file, leptos)?;
+
+    for (setting, dir) in [("media_root", &settings.media_root), ("content_dir", &settings.content_dir)] {
+        if !dir.is_dir() {
+            return Err(SettingsError::new(setting, format!("{} is not a directory", dir.display())));
+        }
+    }
+    // These two may not exist yet: no documents registered, no card drawn.
+    for (setting, dir) in [("documents_dir", &settings.documents_dir), ("share_images_cache", &settings.share_images_cache)] {
+        if dir.exists() && !dir.is_dir() {
+            return Err(SettingsError::new(setting, format!("{} is not a directory", dir.display())));
+        }
+    }
+    Ok(settings)
+}
+
+/// An absent file is only an error if it was asked for by name.
+fn read_file(path: &Path, required: bool) -> Result<SettingsFile, SettingsError> {
+    let display = path.display().to_string();
+    match std::fs::read_to_string(path) {
+        Ok(raw) => toml::from_str(&raw).map_err(|e| SettingsError::new(display, e.to_string())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound && !required => Ok(SettingsFile::default()),
+        Err(e) => Err(SettingsError::new(display, e.to_string())),
+    }
+}
+
+fn apply_env(file: &mut SettingsFile, env: impl Fn(&str) -> Option<String>) -> Result<(), SettingsError> {
+    let flag = |name: &str| -> Result<Option<bool>, SettingsError> {
+        env(name)
+            .map(|value| match value.to_ascii_lowercase().as_str() {
+                "1" | "true" | "on" | "sim" => Ok(true),
+                "0" | "false" | "off" | "nao" | "não" => Ok(false),
+                _ => Err(SettingsError::new(name, format!("expected true or false, not {value:?}"))),
+            })
+            .transpose()
+    };
+
+    if let Some(bind) = env("CAMARA_BIND") {
+        file.bind = Some(bind);
+    }
+    if let Some(workers) = env("CAMARA_WORKERS") {
+        let workers = workers
+            .parse()
+            .map_err(|_| SettingsError::new("CAMARA_WORKERS", format!("expected a number, not {workers:?}")))?;
+        file.workers = Some(workers);
+    }
+    if let Some(media_root) = env("CAMARA_MEDIA_ROOT") {
+        file.media_root = Some(media_root.into());
+    }
+    if let Some(database) = env("CAMARA_DATABASE") {
+        file.database = Some(database.into());
+    }
+    if let Some(content_dir) = env("CAMARA_CONTENT_DIR") {
+        file.content_dir = Some(content_dir.into());
+    }
+    if let Some(documents_dir) = env("CAMARA_DOCUMENTS_DIR") {
+        file.documents_dir = Some(documents_dir.into());
+    }
+    if let Some(cache) = env("CAMARA_SHARE_IMAGES_CACHE") {
+        file.share_images_cache = Some(cache.into());
+    }
+    if let Some(seed) = env("CAMARA_PRERENDER_SEED") {
+        let seed = seed
+            .parse()
+            .map_err(|_| SettingsError::new("CAMARA_PRERENDER_SEED", format!("expected a number, not {seed:?}")))?;
+        file.prerender_seed = Some(seed);
+    }
+    if let Some(site_url) = env("CAMARA_SITE_URL") {
+        file.site_url = Some(site_url);
+    }
+    if let Some(log_level) = env("CAMARA_LOG") {
+        file.log_level = Some(log_level);
+    }
+    if let Some(log_format) = env("CAMARA_LOG_FORMAT") {
+        file.log_format = Some(log_format);
+    }
+    if let Some(from) = env("CAMARA_MAIL_FROM") {
+        file.mail.from = Some(from);
+    }
+    if let Some(url) = env("CAMARA_MAIL_URL") {
+        file.mail.url = Some(url);
+    }
+    if let Some(mail_worker) = flag("CAMARA_MAIL_WORKER")? {
+        file.features.mail_worker = Some(mail_worker);
+    }
+    if let Some(share_images) = flag("CAMARA_SHARE_IMAGES")? {
+        file.features.share_images = Some(share_images);
+    }
+    if let Some(hsts) = flag("CAMARA_HSTS")? {
+        file.security.hsts = Some(hsts);
+    }
+    if let Some(report_only) = flag("CAMARA_CSP_REPORT_ONLY")? {
+        file.security.csp_report_only = Some(report_only);
+    }
+    if let Some(report_uri) = env("CAMARA_CSP_REPORT_URI") {
+        file.security.csp_report_uri = Some(report_uri);
+    }
+    if let Some(proxies) = env("CAMARA_TRUSTED_PROXIES") {
+        file.security.trusted_proxies = Some(proxies.split(',').map(|proxy| proxy.trim().to_string()).collect());
+    }
+    if let Some(session_key) = env("CAMARA_SESSION_KEY") {
+        file.security.session_key = Some(session_key);
+    }
+    Ok(())
+}
+
+fn resolve(file: SettingsFile, leptos: &LeptosOptions) -> Result<Settings, SettingsError> {
+    let bind = match file.bind {
+        Some(bind) => bind
+            .parse()
+            .map_err(|_| SettingsError::new("bind", format!("expected an address like 127.0.0.1:3020, not {bind:?}")))?,
+        None => leptos.site_addr,
+    };
+
+    let workers = file.workers.unwrap_or(1);
+    if !(1..=MAX_WORKERS).contains(&workers) {
+        return Err(SettingsError::new("workers", format!("must be between 1 and {MAX_WORKERS}, not {workers}")));
+    }
+
+    let log_level = match file.log_level {
+        Some(level) => LogLevel::parse(&level).ok_or_else(|| {
+            let known: Vec<_> = LogLevel::ALL.iter().map(|level| level.as_str()).collect();
+            SettingsError::new("log_level", format!("expected one of {}, not {level:?}", known.join(", ")))
+        })?,
+        None => LogLevel::Info,
+    };
+    let log_format = match file.log_format {
+        Some(format) => LogFormat::parse(&format)
+            .ok_or_else(|| SettingsError::new("log_format", format!("expected text or json, not {format:?}")))?,
+        None => LogFormat::Text,
+    };
+
+    let site_url = file.site_url.as_deref().unwrap_or(mail::DEFAULT_SITE_URL);
+    if !(site_url.starts_with("https://") || site_url.starts_with("http://")) {
+        return Err(SettingsError::new("site_url", format!("expected an http(s):// address, not {site_url:?}")));
+    }
+    let trusted_proxies = file
+        .security
+        .trusted_proxies
+        .unwrap_or_default()
+        .iter()
+        .filter(|proxy| !proxy.is_empty())
+        .map(|proxy| {
+            proxy
+                .parse()
+                .map_err(|_| SettingsError::new("trusted_proxies", format!("expected an IP address, not {proxy:?}")))
+        })
+        .collect::<Result<Vec<IpAddr>, _>>()?;
+    let session_key = match file.security.session_key {
+        Some(key) if key.len() < MIN_SESSION_KEY_BYTES => {
+            return Err(SettingsError::new(
+                "session_key",
+                format!("must have at least {MIN_SESSION_KEY_BYTES} bytes, not {}", key.len()),
+            ));
+        }
+        key => key.map(|key| SessionKey(key.into_bytes())),
+    };
+    let content_dir = file.content_dir.unwrap_or_else(|| PathBuf::from(crate::content::DEFAULT_DIR));
+
+    let mail = MailConfig::new(
+        site_url,
+        file.mail.from.as_deref().unwrap_or(mail::DEFAULT_FROM),
+        file.mail.url.as_deref().unwrap_or(mail::DEFAULT_MAIL_URL),
+    )
+    .map_err(|e| SettingsError::new("mail", e.to_string()))?;
+
+    Ok(Settings {
+        bind,
+        workers,
+        media_root: file.media_root.unwrap_or_else(|| PathBuf::from(leptos.site_root.as_ref())),
+        database: file.database.unwrap_or_else(|| PathBuf::from(DATABASE_PATH)),
+        documents_dir: file.documents_dir.unwrap_or_else(|| content_dir.join(crate::content::transparency::DOCUMENTS_SUBDIR)),
+        content_dir,
+        share_images_cache: file.share_images_cache.unwrap_or_else(|| PathBuf::from(DEFAULT_SHARE_IMAGES_CACHE)),
+        prerender_seed: file.prerender_seed.unwrap_or(0),
+        log_level,
+        log_format,
+        mail,
+        features: Features {
+            mail_worker: file.features.mail_worker.unwrap_or(true),
+            share_images: file.features.share_images.unwrap_or(true),
+        },
+        security: Security {
+            hsts: file.security.hsts.unwrap_or(leptos.env == Env::PROD),
+            csp_report_only: file.security.csp_report_only.unwrap_or(false),
+            csp_report_uri: file.security.csp_report_uri,
+            trusted_proxies,
+            session_key,
+        },
+    })
+}
+
+/// The [`Settings`] `main` put in the Leptos context, for server functions.
+pub fn config() -> Result<Settings, SettingsError> {
+    leptos::prelude::use_context::<Settings>()
+        .ok_or_else(|| SettingsError::new("context", "settings not available in this context"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn leptos() -> LeptosOptions {
+        LeptosOptions::builder().output_name("camaracapoeira").site_root("target/site").env(Env::DEV).build()
+    }
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn defaults_come_from_leptos_and_the_modules() {
+        let settings = resolve(SettingsFile::default(), &leptos()).unwrap();
+
+        assert_eq!(settings.bind, leptos().site_addr);
+        assert_eq!(settings.workers, 1);
+        assert_eq!(settings.media_root, PathBuf::from("target/site"));
+        assert_eq!(settings.database, PathBuf::from(DATABASE_PATH));
+        assert_eq!(settings.content_dir, PathBuf::from("content"));
+        assert_eq!(settings.documents_dir, PathBuf::from("content/transparencia"));
+        assert_eq!(settings.share_images_cache, PathBuf::from("data/og"));
+        assert_eq!(settings.prerender_seed, 0);
+        assert_eq!(settings.log_level, LogLevel::Info);
+        assert_eq!(settings.log_format, LogFormat::Text);
+        assert_eq!(settings.mail.site_url, mail::DEFAULT_SITE_URL);
+        assert_eq!(settings.features, Features { mail_worker: true, share_images: true });
+        assert_eq!(
+            settings.security,
+            Security {
+                hsts: false,
+                csp_report_only: false,
+                csp_report_uri: None,
+                trusted_proxies: Vec::new(),
+                session_key: None,
+            }
+        );
+    }
+
+    #[test]
+    fn environment_wins_over_the_file() {
+        let mut file: SettingsFile = toml::from_str(
+            r#"
+            bind = "0.0.0.0:8080"
+            workers = 4
+            log_level = "debug"
+            content_dir = "/srv/camara/content"
+
+            [features]
+            share_images = false
+            "#,
+        )
+        .unwrap();
+        apply_env(
+            &mut file,
+            env(&[
+                ("CAMARA_WORKERS", "2"),
+                ("CAMARA_MAIL_WORKER", "off"),
+                ("CAMARA_LOG_FORMAT", "JSON"),
+                ("CAMARA_TRUSTED_PROXIES", "127.0.0.1, ::1"),
+                ("CAMARA_PRERENDER_SEED", "42"),
+                ("CAMARA_SESSION_KEY", &"k".repeat(64)),
+            ]),
+        )
+        .unwrap();
+        let settings = resolve(file, &leptos()).unwrap();
+
+        assert_eq!(settings.bind, "0.0.0.0:8080".parse().unwrap());
+        assert_eq!(settings.workers, 2);
+        assert_eq!(settings.log_level, LogLevel::Debug);
+        assert_eq!(settings.log_format, LogFormat::Json);
+        assert_eq!(settings.features, Features { mail_worker: false, share_images: false });
+        assert_eq!(settings.security.trusted_proxies, vec![IpAddr::from([127, 0, 0, 1]), "::1".parse().unwrap()]);
+        assert_eq!(settings.prerender_seed, 42);
+        assert_eq!(settings.security.session_key.as_ref().map(SessionKey::as_bytes), Some("k".repeat(64).as_bytes()));
+        assert_eq!(format!("{:?}", settings.security.session_key), "Some(SessionKey(..))");
+        // The documents follow the content unless set apart.
+        assert_eq!(settings.documents_dir, PathBuf::from("/srv/camara/content/transparencia"));
+    }
+
+    #[test]
+    fn errors_name_the_setting() {
+        let error = apply_env(&mut SettingsFile::default(), env(&[("CAMARA_WORKERS", "muitos")])).unwrap_err();
+        assert_eq!(error.setting, "CAMARA_WORKERS");
+
+        let error = resolve(SettingsFile { workers: Some(0), ..Default::default() }, &leptos()).unwrap_err();
+        assert_eq!(error.setting, "workers");
+
+        let error = resolve(SettingsFile { bind: Some("localhost".into()), ..Default::default() }, &leptos()).unwrap_err();
+        assert_eq!(error.setting, "bind");
+
+        let error = resolve(SettingsFile { log_level: Some("loud".into()), ..Default::default() }, &leptos()).unwrap_err();
+        assert!(error.to_string().contains("error, warn, info, debug, trace"));
+
+        let proxies = SecurityFile { trusted_proxies: Some(vec!["nginx".into()]), ..Default::default() };
+        let error = resolve(SettingsFile { security: proxies, ..Default::default() }, &leptos()).unwrap_err();
+        assert_eq!(error.setting, "trusted_proxies");
+
+        let key = SecurityFile { session_key: Some("curta".into()), ..Default::default() };
+        let error = resolve(SettingsFile { security: key, ..Default::default() }, &leptos()).unwrap_err();
+        assert_eq!(error.setting, "session_key");
+
+        let error = apply_env(&mut SettingsFile::default(), env(&[("CAMARA_PRERENDER_SEED", "-1")])).unwrap_err();
+        assert_eq!(error.setting, "CAMARA_PRERENDER_SEED");
+
+        assert!(toml::from_str::<SettingsFile>("wokers = 2").is_err());
+    }
+}